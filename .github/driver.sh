@@ -36,4 +36,16 @@ CLIPPY=$(LD_LIBRARY_PATH=${SYSROOT}/lib ./target/debug/clippy-driver ./target/dr
 RUSTC=$(rustc ./target/driver_test.rs)
 diff -u <($CLIPPY) <($RUSTC)
 
+# make sure the args of CLIPPY_ARGS override the command line args, in both formats
+echo "fn main() { let x = 1; let _ = --x; }" >target/driver_args_test.rs
+CLIPPY_ARGS="-D clippy::double_neg" ./target/debug/clippy-driver -A clippy::double_neg --emit metadata --crate-type bin \
+	target/driver_args_test.rs --out-dir target && exit 1
+CLIPPY_ARGS="-A__CLIPPY_HACKERY__clippy::double_neg__CLIPPY_HACKERY__" ./target/debug/clippy-driver -D clippy::double_neg \
+	--emit metadata --crate-type bin target/driver_args_test.rs --out-dir target
+
+# make sure --clippy-json-out writes the diagnostics of the lints, even if the compilation fails
+./target/debug/clippy-driver --clippy-json-out target/driver_args_test.json -D clippy::double_neg --emit metadata \
+	--crate-type bin target/driver_args_test.rs --out-dir target && exit 1
+grep -q '"lint": "clippy::double_neg"' target/driver_args_test.json
+
 # TODO: CLIPPY_CONF_DIR / CARGO_MANIFEST_DIR
@@ -0,0 +1,153 @@
+use crate::{clippy_project_root, gather_all, rename_lint, update_lints};
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+/// Deprecates the lint `name`.
+///
+/// * If `rename_to` is given, `name` is added to `clippy_lints/src/renamed_lints.rs` forwarding to
+///   `rename_to`. Otherwise a `declare_deprecated_lint!` entry with the given `reason` is added to
+///   `clippy_lints/src/deprecated_lints.rs`.
+/// * The `declare_clippy_lint!` declaration and the entry in the lint pass are removed from the
+///   module the lint was declared in.
+/// * `update_lints` is run afterwards.
+///
+/// The code implementing the lint and its tests have to be removed manually.
+///
+/// # Panics
+///
+/// Panics if the lint doesn't exist or if one of the files can't be read or written.
+pub fn run(name: &str, reason: Option<&str>, rename_to: Option<&str>) {
+    let name = name.trim_start_matches("clippy::").to_lowercase();
+    let lint = gather_all()
+        .find(|lint| lint.name == name && lint.deprecation.is_none())
+        .unwrap_or_else(|| panic!("error: the lint `{}` does not exist", name));
+    let project_root = clippy_project_root();
+
+    let module_path = project_root.join("clippy_lints/src").join(lint_file(&lint.module));
+    let module_path = if module_path.exists() {
+        module_path
+    } else {
+        project_root
+            .join("clippy_lints/src")
+            .join(lint.module.replace("::", "/"))
+            .join("mod.rs")
+    };
+    let content = read(&module_path);
+    let content = remove_lint_declaration(&content, &name.to_uppercase())
+        .unwrap_or_else(|| panic!("error: cannot find the declaration of `{}`", name));
+    write(&module_path, &content);
+
+    if let Some(new_name) = rename_to {
+        rename_lint::add_renamed_lint(
+            &project_root.join("clippy_lints/src/renamed_lints.rs"),
+            &name,
+            new_name.trim_start_matches("clippy::"),
+        );
+    } else {
+        let reason = reason.expect("a reason is needed to deprecate a lint");
+        let deprecated_path = project_root.join("clippy_lints/src/deprecated_lints.rs");
+        let mut deprecated = read(&deprecated_path);
+        deprecated.push_str(&deprecated_lint_declaration(&name.to_uppercase(), reason));
+        write(&deprecated_path, &deprecated);
+    }
+
+    update_lints::run(update_lints::UpdateMode::Change);
+
+    println!("`{}` has been deprecated.", name);
+    println!(
+        "Please remove the code of the lint from `{}` and update the tests, including `tests/ui/{}.rs`.",
+        module_path
+            .strip_prefix(&project_root)
+            .unwrap_or(&module_path)
+            .display(),
+        if rename_to.is_some() { "rename" } else { "deprecated" },
+    );
+}
+
+fn lint_file(module: &str) -> String {
+    format!("{}.rs", module.replace("::", "/"))
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("Cannot read from {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) {
+    fs::write(path, content).unwrap_or_else(|e| panic!("Cannot write to {}: {}", path.display(), e));
+}
+
+/// Removes the `declare_clippy_lint!` block declaring `name` and removes `name` from the list of
+/// lints of the lint pass.
+fn remove_lint_declaration(content: &str, name: &str) -> Option<String> {
+    let decl = content.find(&format!("pub {},", name))?;
+    let start = content[..decl].rfind("declare_clippy_lint!")?;
+    let end = decl + content[decl..].find("\n}\n")? + "\n}\n".len();
+    let rest = &content[end..];
+    let content = format!("{}{}", &content[..start], rest.strip_prefix('\n').unwrap_or(rest));
+
+    let pass_re = Regex::new(r"(?s)((?:declare|impl)_lint_pass!\s*\([^\[]*=>\s*\[)([^\]]*)(\])").unwrap();
+    let content = pass_re.replace_all(&content, |caps: &regex::Captures<'_>| {
+        let lints: Vec<&str> = caps[2]
+            .split(',')
+            .map(str::trim)
+            .filter(|lint| !lint.is_empty() && *lint != name)
+            .collect();
+        let lints = if caps[2].contains('\n') {
+            format!("\n    {},\n", lints.join(",\n    "))
+        } else {
+            lints.join(", ")
+        };
+        format!("{}{}{}", &caps[1], lints, &caps[3])
+    });
+
+    Some(content.into_owned())
+}
+
+fn deprecated_lint_declaration(name: &str, reason: &str) -> String {
+    format!(
+        "
+declare_deprecated_lint! {{
+    /// **What it does:** Nothing. This lint has been deprecated.
+    ///
+    /// **Deprecation reason:** {reason}
+    pub {name},
+    \"{reason}\"
+}}
+",
+        name = name,
+        reason = reason,
+    )
+}
+
+#[test]
+fn test_remove_lint_declaration() {
+    let content = r#"use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Foo.
+    pub FOO,
+    nursery,
+    "foo"
+}
+
+declare_clippy_lint! {
+    pub BAR,
+    style,
+    "bar"
+}
+
+declare_lint_pass!(FooBar => [FOO, BAR]);
+"#;
+    let expected = r#"use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    pub BAR,
+    style,
+    "bar"
+}
+
+declare_lint_pass!(FooBar => [BAR]);
+"#;
+    assert_eq!(remove_lint_declaration(content, "FOO").as_deref(), Some(expected));
+}
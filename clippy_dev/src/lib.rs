@@ -10,9 +10,12 @@ use std::lazy::SyncLazy;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+pub mod deprecate;
 pub mod fmt;
 pub mod new_lint;
+pub mod nursery_report;
 pub mod ra_setup;
+pub mod rename_lint;
 pub mod serve;
 pub mod stderr_length_check;
 pub mod update_lints;
@@ -83,6 +86,12 @@ impl Lint {
         lints.iter().filter(|l| l.group == "internal").cloned().collect()
     }
 
+    /// Returns all `internal_warn` lints, which are registered but not part of the `internal` group
+    #[must_use]
+    pub fn internal_warn_lints(lints: &[Self]) -> Vec<Self> {
+        lints.iter().filter(|l| l.group == "internal_warn").cloned().collect()
+    }
+
     /// Returns all deprecated lints
     #[must_use]
     pub fn deprecated_lints(lints: &[Self]) -> Vec<Self> {
@@ -125,6 +134,15 @@ pub fn gen_changelog_lint_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec
         .collect()
 }
 
+/// Generates the list of the lints and their groups in `src/lint_groups.rs`, sorted by name.
+#[must_use]
+pub fn gen_lint_groups<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
+    lints
+        .sorted_by_key(|l| &l.name)
+        .map(|l| format!("    (\"{}\", \"{}\"),", l.name, l.group))
+        .collect()
+}
+
 /// Generates the `register_removed` code in `./clippy_lints/src/lib.rs`.
 #[must_use]
 pub fn gen_deprecated<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
@@ -442,6 +460,23 @@ fn test_usable_lints() {
     assert_eq!(expected, Lint::usable_lints(&lints));
 }
 
+#[test]
+fn test_internal_warn_lints() {
+    let lints = vec![
+        Lint::new("should_assert_eq", "internal", "abc", None, "module_name"),
+        Lint::new("should_assert_eq2", "internal_warn", "abc", None, "module_name"),
+        Lint::new("should_assert_eq3", "style", "abc", None, "module_name"),
+    ];
+    let expected = vec![Lint::new(
+        "should_assert_eq2",
+        "internal_warn",
+        "abc",
+        None,
+        "module_name",
+    )];
+    assert_eq!(expected, Lint::internal_warn_lints(&lints));
+}
+
 #[test]
 fn test_by_lint_group() {
     let lints = vec![
@@ -477,6 +512,19 @@ fn test_gen_changelog_lint_list() {
     assert_eq!(expected, gen_changelog_lint_list(lints.iter()));
 }
 
+#[test]
+fn test_gen_lint_groups() {
+    let lints = vec![
+        Lint::new("should_assert_eq2", "group2", "abc", None, "module_name"),
+        Lint::new("should_assert_eq", "group1", "abc", None, "module_name"),
+    ];
+    let expected = vec![
+        "    (\"should_assert_eq\", \"group1\"),".to_string(),
+        "    (\"should_assert_eq2\", \"group2\"),".to_string(),
+    ];
+    assert_eq!(expected, gen_lint_groups(lints.iter()));
+}
+
 #[test]
 fn test_gen_deprecated() {
     let lints = vec![
@@ -1,7 +1,9 @@
 #![cfg_attr(feature = "deny-warnings", deny(warnings))]
 
 use clap::{App, Arg, SubCommand};
-use clippy_dev::{fmt, new_lint, ra_setup, serve, stderr_length_check, update_lints};
+use clippy_dev::{
+    deprecate, fmt, new_lint, nursery_report, ra_setup, rename_lint, serve, stderr_length_check, update_lints,
+};
 
 fn main() {
     let matches = App::new("Clippy developer tooling")
@@ -83,6 +85,68 @@ fn main() {
                         .takes_value(true),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("deprecate")
+                .about("Deprecate a lint and run `cargo dev update_lints`")
+                .arg(
+                    Arg::with_name("name")
+                        .help("Name of the lint to deprecate, ex: fn_too_long")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("reason")
+                        .long("reason")
+                        .short("r")
+                        .help("The reason for the deprecation, shown to users of the lint")
+                        .takes_value(true)
+                        .required_unless("rename-to"),
+                )
+                .arg(
+                    Arg::with_name("rename-to")
+                        .long("rename-to")
+                        .help("Register the lint as renamed to this lint instead of deprecating it")
+                        .takes_value(true)
+                        .value_name("new_name"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("rename_lint")
+                .about("Rename a lint and run `cargo dev update_lints`")
+                .long_about(
+                    "Renames the lint declaration, its module and its tests and adds the old name to \
+                     `clippy_lints/src/renamed_lints.rs`, so that it keeps working with a rename warning.",
+                )
+                .arg(
+                    Arg::with_name("old_name")
+                        .help("The current name of the lint")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("new_name")
+                        .help("The new name of the lint")
+                        .required(true)
+                        .index(2),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("nursery_report")
+                .about("Print a report about the lints in the nursery group")
+                .long_about(
+                    "Prints a table of all nursery lints with:\n \
+                     * the date of the commit that moved the lint to nursery\n \
+                     * the open false positive issues listed in `clippy_dev/nursery_issues.txt`\n \
+                     * the number of warnings emitted in a lintcheck run, if a log is given",
+                )
+                .arg(
+                    Arg::with_name("lintcheck-log")
+                        .long("lintcheck-log")
+                        .help("Path to the log of a lintcheck run")
+                        .takes_value(true)
+                        .value_name("file"),
+                ),
+        )
         .subcommand(
             SubCommand::with_name("limit_stderr_length")
                 .about("Ensures that stderr files do not grow longer than a certain amount of lines."),
@@ -138,6 +202,20 @@ fn main() {
                 Err(e) => eprintln!("Unable to create lint: {}", e),
             }
         },
+        ("deprecate", Some(matches)) => deprecate::run(
+            matches.value_of("name").expect("`name` argument is validated by clap"),
+            matches.value_of("reason"),
+            matches.value_of("rename-to"),
+        ),
+        ("rename_lint", Some(matches)) => rename_lint::run(
+            matches
+                .value_of("old_name")
+                .expect("`old_name` argument is validated by clap"),
+            matches
+                .value_of("new_name")
+                .expect("`new_name` argument is validated by clap"),
+        ),
+        ("nursery_report", Some(matches)) => nursery_report::run(matches.value_of("lintcheck-log")),
         ("limit_stderr_length", _) => {
             stderr_length_check::check();
         },
@@ -0,0 +1,160 @@
+use crate::{clippy_project_root, gather_all, Lint};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File mapping nursery lints to their open false positive issues.
+const ISSUES_FILE: &str = "clippy_dev/nursery_issues.txt";
+
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+/// Prints a report about all lints in the `nursery` group, to help deciding which lints are ready
+/// to be moved to another group and which should rather be deprecated.
+///
+/// For every lint the report contains:
+/// * the date of the last commit that moved the lint into `nursery`,
+/// * the open false positive issues listed in `clippy_dev/nursery_issues.txt`,
+/// * the number of warnings the lint emitted in the given lintcheck log, if any.
+pub fn run(lintcheck_log: Option<&str>) {
+    let project_root = clippy_project_root();
+
+    let issues = match fs::read_to_string(project_root.join(ISSUES_FILE)) {
+        Ok(content) => parse_issue_mapping(&content),
+        Err(e) => {
+            eprintln!("warning: cannot read `{}`: {}", ISSUES_FILE, e);
+            HashMap::new()
+        },
+    };
+    let warnings = lintcheck_log.map(|path| {
+        let content = fs::read_to_string(path).unwrap_or_else(|e| panic!("Cannot read from {}: {}", path, e));
+        count_lintcheck_warnings(&content)
+    });
+
+    let mut lints: Vec<Lint> = gather_all().filter(|lint| lint.group == "nursery").collect();
+    lints.sort_by_key(|lint| lint.name.clone());
+
+    println!("| lint | in nursery since | days | open FP issues | lintcheck warnings |");
+    println!("|------|------------------|------|----------------|--------------------|");
+    for lint in &lints {
+        let (since, days) = match nursery_since(&project_root, lint) {
+            Some((timestamp, date)) => (date, days_since(timestamp).to_string()),
+            None => ("unknown".to_string(), "-".to_string()),
+        };
+        let fp_issues = issues.get(&lint.name).map_or_else(
+            || "-".to_string(),
+            |issues| {
+                issues
+                    .iter()
+                    .map(|issue| format!("#{}", issue))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+        );
+        let lintcheck = warnings.as_ref().map_or_else(
+            || "-".to_string(),
+            |warnings| warnings.get(&lint.name).copied().unwrap_or(0).to_string(),
+        );
+
+        println!(
+            "| `{}` | {} | {} | {} | {} |",
+            lint.name, since, days, fp_issues, lintcheck
+        );
+    }
+
+    println!("there are {} nursery lints", lints.len());
+}
+
+/// Returns the commit timestamp and date of the last commit that changed the lint declaration to
+/// the `nursery` group.
+fn nursery_since(project_root: &Path, lint: &Lint) -> Option<(u64, String)> {
+    let declaration = format!("pub {},\n    nursery,", lint.name.to_uppercase());
+    let output = Command::new("git")
+        .current_dir(project_root)
+        .args(&["log", "-1", "--date=short", "--format=%ct %ad", "-S"])
+        .arg(declaration)
+        .args(&["--", "clippy_lints/src"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let mut parts = stdout.trim().splitn(2, ' ');
+    let timestamp = parts.next()?.parse().ok()?;
+    let date = parts.next()?.to_string();
+    Some((timestamp, date))
+}
+
+fn days_since(timestamp: u64) -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    now.saturating_sub(timestamp) / SECONDS_PER_DAY
+}
+
+/// Parses the issue mapping file. Every line that is neither empty nor a comment has the form
+/// `lint_name: 1234, 5678`.
+fn parse_issue_mapping(content: &str) -> HashMap<String, Vec<u32>> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut split = line.splitn(2, ':');
+            let name = split.next()?.trim();
+            let issues = split
+                .next()?
+                .split(',')
+                .filter_map(|issue| issue.trim().trim_start_matches('#').parse().ok())
+                .collect();
+            Some((name.to_string(), issues))
+        })
+        .collect()
+}
+
+/// Counts the warnings per lint in a lintcheck log, where every warning is reported on its own
+/// line containing the `clippy::lint_name`.
+fn count_lintcheck_warnings(log: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for line in log.lines() {
+        if let Some(pos) = line.find("clippy::") {
+            let name: String = line[pos + "clippy::".len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+            if !name.is_empty() {
+                *counts.entry(name).or_insert(0) += 1;
+            }
+        }
+    }
+    counts
+}
+
+#[test]
+fn test_parse_issue_mapping() {
+    let content = "
+# comment
+use_self: 1234, #5678
+
+redundant_pub_crate:
+";
+    let mapping = parse_issue_mapping(content);
+    assert_eq!(mapping.len(), 2);
+    assert_eq!(mapping["use_self"], vec![1234, 5678]);
+    assert!(mapping["redundant_pub_crate"].is_empty());
+}
+
+#[test]
+fn test_count_lintcheck_warnings() {
+    let log = r#"
+cratename-0.1.0/src/lib.rs:1:1 clippy::use_self "unnecessary structure name repetition"
+cratename-0.1.0/src/lib.rs:8:5 clippy::use_self "unnecessary structure name repetition"
+cratename-0.1.0/src/main.rs:2:9 clippy::missing_const_for_fn "this could be a `const fn`"
+"#;
+    let counts = count_lintcheck_warnings(log);
+    assert_eq!(counts["use_self"], 2);
+    assert_eq!(counts["missing_const_for_fn"], 1);
+}
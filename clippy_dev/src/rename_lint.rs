@@ -0,0 +1,164 @@
+use crate::{clippy_project_root, gather_all, update_lints};
+use regex::Regex;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// The file containing the table of renamed lints.
+const RENAMED_LINTS_FILE: &str = "clippy_lints/src/renamed_lints.rs";
+
+/// Renames the lint `old_name` to `new_name`.
+///
+/// * Renames the lint declaration and all uses of it in `clippy_lints/src`. If the lint is defined
+///   in a module of the same name, the module is renamed as well.
+/// * Adds the old name to `clippy_lints/src/renamed_lints.rs`.
+/// * Updates the UI tests to use the new name and adds the old name to `tests/ui/rename.rs`.
+/// * Runs `update_lints`.
+///
+/// # Panics
+///
+/// Panics if `old_name` doesn't exist, if `new_name` already exists or if one of the files can't
+/// be read or written.
+pub fn run(old_name: &str, new_name: &str) {
+    let old_name = old_name.trim_start_matches("clippy::").to_lowercase();
+    let new_name = new_name.trim_start_matches("clippy::").to_lowercase();
+    let lints: Vec<_> = gather_all().collect();
+    let lint = lints
+        .iter()
+        .find(|lint| lint.name == old_name && lint.deprecation.is_none())
+        .unwrap_or_else(|| panic!("error: the lint `{}` does not exist", old_name));
+    if lints.iter().any(|lint| lint.name == new_name) {
+        panic!("error: a lint named `{}` already exists", new_name);
+    }
+    let project_root = clippy_project_root();
+
+    // Rename the lint in the source code
+    let lint_re = Regex::new(&format!(r"\b{}\b", old_name.to_uppercase())).unwrap();
+    for path in files_with_extensions(&project_root.join("clippy_lints/src"), &["rs"]) {
+        replace_in_file(&path, &lint_re, &new_name.to_uppercase());
+    }
+    let module_path = project_root.join(format!("clippy_lints/src/{}.rs", old_name));
+    if lint.module == old_name && module_path.exists() {
+        rename_file(
+            &module_path,
+            &project_root.join(format!("clippy_lints/src/{}.rs", new_name)),
+        );
+        let module_re = Regex::new(&format!(r"\b{}::", old_name)).unwrap();
+        replace_in_file(
+            &project_root.join("clippy_lints/src/lib.rs"),
+            &module_re,
+            &format!("{}::", new_name),
+        );
+    }
+
+    add_renamed_lint(&project_root.join(RENAMED_LINTS_FILE), &old_name, &new_name);
+
+    // Update the tests
+    let test_re = Regex::new(&format!(r"\bclippy::({}|{})\b", old_name, old_name.replace('_', "-"))).unwrap();
+    for path in files_with_extensions(&project_root.join("tests"), &["rs", "stderr", "fixed", "stdout"]) {
+        if path.file_stem() == Some(OsStr::new("rename")) {
+            continue;
+        }
+        let content = read(&path);
+        let new_content = test_re.replace_all(&content, |caps: &regex::Captures<'_>| {
+            if caps[1].contains('-') {
+                format!("clippy::{}", new_name.replace('_', "-"))
+            } else {
+                format!("clippy::{}", new_name)
+            }
+        });
+        let new_content = new_content.replace(&format!("$DIR/{}.rs", old_name), &format!("$DIR/{}.rs", new_name));
+        if new_content != content {
+            write(&path, &new_content);
+        }
+        if path.file_stem() == Some(OsStr::new(&old_name)) {
+            rename_file(
+                &path,
+                &path.with_file_name(&new_name).with_extension(path.extension().unwrap()),
+            );
+        }
+    }
+    add_rename_test(&project_root.join("tests/ui/rename.rs"), &old_name, &new_name, false);
+    add_rename_test(&project_root.join("tests/ui/rename.fixed"), &old_name, &new_name, true);
+
+    update_lints::run(update_lints::UpdateMode::Change);
+
+    println!("`{}` has been renamed to `{}`.", old_name, new_name);
+    println!("Please bless the `rename` UI test and check the changes made to the tests.");
+}
+
+/// Adds the rename of `old_name` to `new_name` to the table of renamed lints. Renames that pointed
+/// to `old_name` are forwarded to `new_name`.
+pub fn add_renamed_lint(path: &Path, old_name: &str, new_name: &str) {
+    let content = read(path);
+    let content = content.replace(
+        &format!(", \"clippy::{}\"),", old_name),
+        &format!(", \"clippy::{}\"),", new_name),
+    );
+    let end = content.rfind("];").expect("the renamed lints table is missing");
+    let content = format!(
+        "{}    (\"clippy::{}\", \"clippy::{}\"),\n{}",
+        &content[..end],
+        old_name,
+        new_name,
+        &content[end..]
+    );
+    write(path, &content);
+}
+
+/// Adds an `allow` attribute for the new name and a `warn` attribute for the old name to the
+/// rename UI test. In the `.fixed` file, both attributes use the new name.
+fn add_rename_test(path: &Path, old_name: &str, new_name: &str, fixed: bool) {
+    let content = read(path);
+    let mut lines: Vec<String> = content.lines().map(ToString::to_string).collect();
+
+    let last_allow = lines
+        .iter()
+        .rposition(|line| line.starts_with("#![allow(clippy::"))
+        .expect("no `allow` attribute in rename test");
+    lines.insert(last_allow + 1, format!("#![allow(clippy::{})]", new_name));
+    let last_warn = lines
+        .iter()
+        .rposition(|line| line.starts_with("#![warn(clippy::"))
+        .expect("no `warn` attribute in rename test");
+    lines.insert(
+        last_warn + 1,
+        format!("#![warn(clippy::{})]", if fixed { new_name } else { old_name }),
+    );
+
+    write(path, &(lines.join("\n") + "\n"));
+}
+
+fn files_with_extensions(dir: &Path, extensions: &[&str]) -> Vec<std::path::PathBuf> {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| {
+            path.extension()
+                .and_then(OsStr::to_str)
+                .map_or(false, |ext| extensions.contains(&ext))
+        })
+        .collect()
+}
+
+fn replace_in_file(path: &Path, re: &Regex, replacement: &str) {
+    let content = read(path);
+    let new_content = re.replace_all(&content, replacement);
+    if new_content != content {
+        write(path, &new_content);
+    }
+}
+
+fn rename_file(from: &Path, to: &Path) {
+    fs::rename(from, to).unwrap_or_else(|e| panic!("Cannot rename {} to {}: {}", from.display(), to.display(), e));
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("Cannot read from {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) {
+    fs::write(path, content).unwrap_or_else(|e| panic!("Cannot write to {}: {}", path.display(), e));
+}
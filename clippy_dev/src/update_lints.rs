@@ -1,6 +1,6 @@
 use crate::{
-    gather_all, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_modules_list, gen_register_lint_list,
-    replace_region_in_file, Lint, DOCS_LINK,
+    gather_all, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_lint_groups, gen_modules_list,
+    gen_register_lint_list, replace_region_in_file, Lint, DOCS_LINK,
 };
 use std::path::Path;
 
@@ -15,6 +15,7 @@ pub fn run(update_mode: UpdateMode) {
     let lint_list: Vec<Lint> = gather_all().collect();
 
     let internal_lints = Lint::internal_lints(&lint_list);
+    let internal_warn_lints = Lint::internal_warn_lints(&lint_list);
     let deprecated_lints = Lint::deprecated_lints(&lint_list);
     let usable_lints = Lint::usable_lints(&lint_list);
     let mut sorted_usable_lints = usable_lints.clone();
@@ -52,6 +53,16 @@ pub fn run(update_mode: UpdateMode) {
     )
     .changed;
 
+    file_change |= replace_region_in_file(
+        Path::new("src/lint_groups.rs"),
+        "begin lint groups",
+        "end lint groups",
+        false,
+        update_mode == UpdateMode::Change,
+        || gen_lint_groups(usable_lints.iter()),
+    )
+    .changed;
+
     file_change |= replace_region_in_file(
         Path::new("clippy_lints/src/lib.rs"),
         "begin deprecated lints",
@@ -68,7 +79,7 @@ pub fn run(update_mode: UpdateMode) {
         "end register lints",
         false,
         update_mode == UpdateMode::Change,
-        || gen_register_lint_list(internal_lints.iter(), usable_lints.iter()),
+        || gen_register_lint_list(internal_lints.iter().chain(&internal_warn_lints), usable_lints.iter()),
     )
     .changed;
 
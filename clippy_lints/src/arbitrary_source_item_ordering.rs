@@ -0,0 +1,227 @@
+use crate::utils::{in_macro, is_cfg_test, span_lint_and_note};
+use rustc_ast::ast::{Item, ItemKind, Mod, NodeId, VariantData};
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::kw;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks that the items of a module are ordered by their kind, and that the
+    /// variants of enums and the fields of structs and unions are sorted alphabetically.
+    ///
+    /// The order of the item kinds is configured with `module-item-order`, the default is
+    /// `["extern_crate", "mod", "use", "macro", "const", "static", "type", "struct", "enum",
+    /// "union", "trait", "trait_alias", "foreign_mod", "impl", "fn"]`. Item kinds which aren't in
+    /// the list can be placed anywhere. The kinds of items whose members are sorted alphabetically
+    /// are configured with `alphabetical-ordering`, e.g. `["enum", "struct"]`, and none by default.
+    ///
+    /// **Why is this bad?** Nothing is wrong with arbitrarily ordered items, but some teams prefer
+    /// a consistent order to make large modules easier to navigate.
+    ///
+    /// **Known problems:** Items with `#[cfg(test)]` are ignored, so the usual `tests` module at
+    /// the end of a file isn't linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo() {}
+    ///
+    /// const BAR: u32 = 0;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// const BAR: u32 = 0;
+    ///
+    /// fn foo() {}
+    /// ```
+    pub ARBITRARY_SOURCE_ITEM_ORDERING,
+    restriction,
+    "items which are not ordered as configured"
+}
+
+/// The kind of an item, as it's named in the configuration.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ItemCategory {
+    ExternCrate,
+    Mod,
+    Use,
+    Macro,
+    Const,
+    Static,
+    TyAlias,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    TraitAlias,
+    ForeignMod,
+    Impl,
+    Fn,
+}
+
+impl ItemCategory {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "extern_crate" => Self::ExternCrate,
+            "mod" => Self::Mod,
+            "use" => Self::Use,
+            "macro" => Self::Macro,
+            "const" => Self::Const,
+            "static" => Self::Static,
+            "type" => Self::TyAlias,
+            "struct" => Self::Struct,
+            "enum" => Self::Enum,
+            "union" => Self::Union,
+            "trait" => Self::Trait,
+            "trait_alias" => Self::TraitAlias,
+            "foreign_mod" => Self::ForeignMod,
+            "impl" => Self::Impl,
+            "fn" => Self::Fn,
+            _ => return None,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::ExternCrate => "extern_crate",
+            Self::Mod => "mod",
+            Self::Use => "use",
+            Self::Macro => "macro",
+            Self::Const => "const",
+            Self::Static => "static",
+            Self::TyAlias => "type",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Union => "union",
+            Self::Trait => "trait",
+            Self::TraitAlias => "trait_alias",
+            Self::ForeignMod => "foreign_mod",
+            Self::Impl => "impl",
+            Self::Fn => "fn",
+        }
+    }
+
+    fn of(kind: &ItemKind) -> Option<Self> {
+        Some(match kind {
+            ItemKind::ExternCrate(..) => Self::ExternCrate,
+            ItemKind::Mod(..) => Self::Mod,
+            ItemKind::Use(..) => Self::Use,
+            ItemKind::MacroDef(..) => Self::Macro,
+            ItemKind::Const(..) => Self::Const,
+            ItemKind::Static(..) => Self::Static,
+            ItemKind::TyAlias(..) => Self::TyAlias,
+            ItemKind::Struct(..) => Self::Struct,
+            ItemKind::Enum(..) => Self::Enum,
+            ItemKind::Union(..) => Self::Union,
+            ItemKind::Trait(..) => Self::Trait,
+            ItemKind::TraitAlias(..) => Self::TraitAlias,
+            ItemKind::ForeignMod(..) => Self::ForeignMod,
+            ItemKind::Impl { .. } => Self::Impl,
+            ItemKind::Fn(..) => Self::Fn,
+            ItemKind::GlobalAsm(..) | ItemKind::MacCall(..) => return None,
+        })
+    }
+}
+
+pub struct ArbitrarySourceItemOrdering {
+    module_item_order: Vec<ItemCategory>,
+    alphabetical_ordering: Vec<ItemCategory>,
+}
+
+impl ArbitrarySourceItemOrdering {
+    pub fn new(module_item_order: Vec<ItemCategory>, alphabetical_ordering: Vec<ItemCategory>) -> Self {
+        Self {
+            module_item_order,
+            alphabetical_ordering,
+        }
+    }
+
+    fn rank(&self, item: &Item) -> Option<usize> {
+        let category = ItemCategory::of(&item.kind)?;
+        self.module_item_order.iter().position(|c| *c == category)
+    }
+}
+
+impl_lint_pass!(ArbitrarySourceItemOrdering => [ARBITRARY_SOURCE_ITEM_ORDERING]);
+
+impl EarlyLintPass for ArbitrarySourceItemOrdering {
+    fn check_mod(&mut self, cx: &EarlyContext<'_>, module: &Mod, _: Span, _: NodeId) {
+        let items: Vec<(usize, &Item)> = module
+            .items
+            .iter()
+            .filter(|item| !in_macro(item.span) && !is_cfg_test(&item.attrs))
+            .filter_map(|item| Some((self.rank(item)?, &**item)))
+            .collect();
+
+        for (i, &(rank, item)) in items.iter().enumerate() {
+            // The item belongs in front of the first preceding item with a greater rank
+            if let Some(&(expected_rank, expected)) = items[..i].iter().find(|(other_rank, _)| *other_rank > rank) {
+                span_lint_and_note(
+                    cx,
+                    ARBITRARY_SOURCE_ITEM_ORDERING,
+                    head_span(cx, item),
+                    &format!(
+                        "incorrect ordering of items, `{}` items should come before `{}` items",
+                        self.module_item_order[rank].name(),
+                        self.module_item_order[expected_rank].name(),
+                    ),
+                    Some(head_span(cx, expected)),
+                    "should be placed before this item",
+                );
+                return;
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        let category = match ItemCategory::of(&item.kind) {
+            Some(category) if self.alphabetical_ordering.contains(&category) => category,
+            _ => return,
+        };
+
+        let (members, what): (Vec<(String, Span)>, _) = match item.kind {
+            ItemKind::Enum(ref def, _) => (
+                def.variants.iter().map(|v| (v.ident.to_string(), v.span)).collect(),
+                "enum variants",
+            ),
+            ItemKind::Struct(VariantData::Struct(ref fields, _), _)
+            | ItemKind::Union(VariantData::Struct(ref fields, _), _) => (
+                fields
+                    .iter()
+                    .filter_map(|field| Some((field.ident?.to_string(), field.span)))
+                    .collect(),
+                if category == ItemCategory::Struct {
+                    "struct fields"
+                } else {
+                    "union fields"
+                },
+            ),
+            _ => return,
+        };
+
+        for (i, (name, span)) in members.iter().enumerate() {
+            if let Some((expected, expected_span)) = members[..i].iter().find(|(other, _)| other > name) {
+                span_lint_and_note(
+                    cx,
+                    ARBITRARY_SOURCE_ITEM_ORDERING,
+                    *span,
+                    &format!("incorrect ordering of {}, they should be sorted alphabetically", what),
+                    Some(*expected_span),
+                    &format!("should be placed before `{}`", expected),
+                );
+                return;
+            }
+        }
+    }
+}
+
+/// The span of the first line of the item, or of its name if it has one.
+fn head_span(cx: &EarlyContext<'_>, item: &Item) -> Span {
+    if item.ident.name == kw::Invalid {
+        cx.sess.source_map().guess_head_span(item.span)
+    } else {
+        item.ident.span
+    }
+}
@@ -0,0 +1,77 @@
+use crate::utils::{implements_trait, match_def_path, paths, qpath_res, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeFoldable;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Arc::new` with a value whose type doesn't implement `Send`
+    /// or `Sync`.
+    ///
+    /// **Why is this bad?** `Arc<T>` is only `Send` and `Sync` if `T` is both `Send` and `Sync`.
+    /// Otherwise the `Arc` can't be shared between threads, and an `Rc` does the same without
+    /// the cost of atomic reference counting. If the value is meant to be shared between
+    /// threads, its type has to be made thread-safe, e.g. by wrapping it in a `Mutex`.
+    ///
+    /// **Known problems:** Values of generic types are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::cell::RefCell;
+    /// # use std::sync::Arc;
+    /// let shared = Arc::new(RefCell::new(1));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// let shared = Rc::new(RefCell::new(1));
+    /// ```
+    pub ARC_WITH_NON_SEND_SYNC,
+    pedantic,
+    "using `Arc` with a type that doesn't implement `Send` or `Sync`"
+}
+
+declare_lint_pass!(ArcWithNonSendSync => [ARC_WITH_NON_SEND_SYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for ArcWithNonSendSync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Call(ref func, ref args) = expr.kind;
+            if args.len() == 1;
+            if let ExprKind::Path(ref qpath) = func.kind;
+            if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+            if match_def_path(cx, def_id, &paths::ARC_NEW);
+            let arg_ty = cx.typeck_results().expr_ty(&args[0]);
+            if !arg_ty.needs_subst();
+            if let Some(send_trait) = cx.tcx.get_diagnostic_item(sym::send_trait);
+            if let Some(sync_trait) = cx.tcx.lang_items().sync_trait();
+            then {
+                let is_send = implements_trait(cx, arg_ty, send_trait, &[]);
+                let is_sync = implements_trait(cx, arg_ty, sync_trait, &[]);
+                let missing = match (is_send, is_sync) {
+                    (true, true) => return,
+                    (false, true) => "`Send`",
+                    (true, false) => "`Sync`",
+                    (false, false) => "`Send` and `Sync`",
+                };
+                span_lint_and_then(
+                    cx,
+                    ARC_WITH_NON_SEND_SYNC,
+                    expr.span,
+                    &format!("usage of an `Arc` with a value that isn't {}", missing),
+                    |diag| {
+                        diag.note(&format!(
+                            "`{}` doesn't implement {}, so the `Arc` can't be shared between threads",
+                            arg_ty, missing
+                        ));
+                        diag.help("consider using an `Rc` instead, or wrapping the value in a `Mutex` to share it");
+                    },
+                );
+            }
+        }
+    }
+}
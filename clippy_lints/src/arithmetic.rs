@@ -59,6 +59,7 @@ pub struct Arithmetic {
 impl_lint_pass!(Arithmetic => [INTEGER_ARITHMETIC, FLOAT_ARITHMETIC]);
 
 impl<'tcx> LateLintPass<'tcx> for Arithmetic {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         if self.expr_span.is_some() {
             return;
@@ -1,9 +1,12 @@
-use rustc_ast::ast::{Expr, ExprKind};
-use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
+use rustc_ast::ast::{FloatTy, UintTy};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 
-use crate::utils::span_lint_and_help;
+use crate::types::{int_ty_to_nbits, is_isize_or_usize};
+use crate::utils::{get_parent_expr, higher, span_lint_and_help};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `as` conversions.
@@ -13,6 +16,12 @@ declare_clippy_lint! {
     /// There are cases when it makes sense to use `as`, so the lint is
     /// Allow by default.
     ///
+    /// Some kinds of conversions can be allowed with the `allowed-as-conversions` configuration:
+    /// `"lossless"` for widening conversions which can't lose information, like `u8` to `u32`,
+    /// `"index"` for `as usize` in index expressions, `"pointer"` for conversions between raw
+    /// pointers and from references to raw pointers, and `"enum"` for conversions of fieldless
+    /// enums to integers.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -36,15 +45,63 @@ declare_clippy_lint! {
     "using a potentially dangerous silent `as` conversion"
 }
 
-declare_lint_pass!(AsConversions => [AS_CONVERSIONS]);
+/// A kind of `as` conversion which can be allowed in the configuration.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AllowedConversion {
+    Lossless,
+    Index,
+    Pointer,
+    Enum,
+}
+
+impl AllowedConversion {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "lossless" => Self::Lossless,
+            "index" => Self::Index,
+            "pointer" => Self::Pointer,
+            "enum" => Self::Enum,
+            _ => return None,
+        })
+    }
+}
+
+pub struct AsConversions {
+    allowed: Vec<AllowedConversion>,
+}
+
+impl AsConversions {
+    pub fn new(allowed: Vec<AllowedConversion>) -> Self {
+        Self { allowed }
+    }
+
+    fn is_allowed(&self, cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) -> bool {
+        (self.allowed.contains(&AllowedConversion::Lossless) && is_lossless(cx, cast_from, cast_to))
+            || (self.allowed.contains(&AllowedConversion::Index) && is_usize_index(cx, expr, cast_to))
+            || (self.allowed.contains(&AllowedConversion::Pointer)
+                && cast_to.is_unsafe_ptr()
+                && (cast_from.is_unsafe_ptr() || cast_from.is_region_ptr()))
+            || (self.allowed.contains(&AllowedConversion::Enum)
+                && matches!(cast_from.kind(), ty::Adt(def, _) if def.is_enum())
+                && cast_to.is_integral())
+    }
+}
 
-impl EarlyLintPass for AsConversions {
-    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+impl_lint_pass!(AsConversions => [AS_CONVERSIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for AsConversions {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if in_external_macro(cx.sess(), expr.span) {
             return;
         }
 
-        if let ExprKind::Cast(_, _) = expr.kind {
+        if let ExprKind::Cast(ref cast_expr, _) = expr.kind {
+            let cast_from = cx.typeck_results().expr_ty(cast_expr);
+            let cast_to = cx.typeck_results().expr_ty(expr);
+            if self.is_allowed(cx, expr, cast_from, cast_to) {
+                return;
+            }
+
             span_lint_and_help(
                 cx,
                 AS_CONVERSIONS,
@@ -56,3 +113,39 @@ impl EarlyLintPass for AsConversions {
         }
     }
 }
+
+/// Checks if the conversion widens an integer or float without losing information.
+fn is_lossless(cx: &LateContext<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) -> bool {
+    if is_isize_or_usize(cast_from) || is_isize_or_usize(cast_to) {
+        return false;
+    }
+    match (cast_from.kind(), cast_to.kind()) {
+        (ty::Int(_) | ty::Uint(_), ty::Int(_) | ty::Uint(_)) => {
+            !(cast_from.is_signed() && !cast_to.is_signed())
+                && int_ty_to_nbits(cast_from, cx.tcx) < int_ty_to_nbits(cast_to, cx.tcx)
+        },
+        (ty::Int(_) | ty::Uint(_), ty::Float(float_ty)) => {
+            let to_nbits = if *float_ty == FloatTy::F32 { 32 } else { 64 };
+            int_ty_to_nbits(cast_from, cx.tcx) < to_nbits
+        },
+        (ty::Float(FloatTy::F32), ty::Float(FloatTy::F64)) => true,
+        _ => false,
+    }
+}
+
+/// Checks if the conversion to `usize` is used to index, including as a bound of an indexing
+/// range.
+fn is_usize_index(cx: &LateContext<'_>, expr: &Expr<'_>, cast_to: Ty<'_>) -> bool {
+    if !matches!(cast_to.kind(), ty::Uint(UintTy::Usize)) {
+        return false;
+    }
+    let mut index = expr;
+    while let Some(parent) = get_parent_expr(cx, index) {
+        match parent.kind {
+            ExprKind::Index(_, idx) => return idx.hir_id == index.hir_id,
+            ExprKind::Struct(..) if higher::range(parent).is_some() => index = parent,
+            _ => return false,
+        }
+    }
+    false
+}
@@ -1,10 +1,11 @@
-use crate::utils::{match_def_path, span_lint_and_help};
+use crate::utils::{match_def_path, span_lint_and_help, span_lint_and_then};
 use if_chain::if_chain;
 use rustc_hir::def_id::DefId;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::{Expr, ExprKind, MatchSource, PatKind, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Symbol;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of invalid atomic
@@ -49,9 +50,73 @@ declare_clippy_lint! {
     "usage of invalid atomic ordering in atomic operations and memory fences"
 }
 
-declare_lint_pass!(AtomicOrdering => [INVALID_ATOMIC_ORDERING]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for valid but suspicious atomic orderings in read-modify-write
+    /// operations like `swap` and `fetch_add`:
+    /// * `Acquire` when the loaded value is unused, where `Release` may have been meant
+    /// * `Release` when the loaded value is directly used as an `if` or `while` condition, where
+    /// `Acquire` may have been meant
+    ///
+    /// **Why is this bad?** With `Acquire`, the store part of the operation is `Relaxed`, and with
+    /// `Release`, the load part is `Relaxed`. So the operation doesn't synchronize the way it
+    /// seems to, which is a data race waiting to happen.
+    ///
+    /// **Known problems:** Some algorithms use these orderings on purpose, e.g. the writer of a
+    /// seqlock increments the sequence number with `seq.fetch_add(1, Ordering::Acquire)` so that
+    /// the following writes aren't reordered before it.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    /// # let locked = AtomicBool::new(false);
+    /// # let ready = AtomicUsize::new(0);
+    /// while locked.swap(true, Ordering::Release) {}
+    /// ready.fetch_add(1, Ordering::Acquire);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    /// # let locked = AtomicBool::new(false);
+    /// # let ready = AtomicUsize::new(0);
+    /// while locked.swap(true, Ordering::Acquire) {}
+    /// ready.fetch_add(1, Ordering::Release);
+    /// ```
+    pub ATOMIC_ORDERING_MISUSE,
+    nursery,
+    "suspicious atomic ordering in a read-modify-write operation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the `SeqCst` ordering in atomic operations and memory fences.
+    ///
+    /// **Why is this bad?** `SeqCst` is the strongest and most expensive ordering. Besides
+    /// synchronizing like `Acquire` and `Release`, it orders the operation with all the other
+    /// `SeqCst` operations of the program, which is rarely needed. In performance sensitive
+    /// code, the weaker orderings are preferable.
+    ///
+    /// **Known problems:** Some algorithms do need a single total order, e.g. when two threads
+    /// each store to one atomic and then load the other one.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # let ready = AtomicBool::new(false);
+    /// ready.store(true, Ordering::SeqCst);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # let ready = AtomicBool::new(false);
+    /// ready.store(true, Ordering::Release);
+    /// ```
+    pub SEQ_CST_ORDERING,
+    pedantic,
+    "usage of the `SeqCst` atomic ordering"
+}
 
-const ATOMIC_TYPES: [&str; 12] = [
+declare_lint_pass!(AtomicOrdering => [INVALID_ATOMIC_ORDERING, ATOMIC_ORDERING_MISUSE, SEQ_CST_ORDERING]);
+
+pub const ATOMIC_TYPES: [&str; 12] = [
     "AtomicBool",
     "AtomicI8",
     "AtomicI16",
@@ -220,10 +285,146 @@ fn check_atomic_compare_exchange(cx: &LateContext<'_>, expr: &Expr<'_>) {
     }
 }
 
+/// The read-modify-write methods of the atomic types taking a single ordering
+const ATOMIC_RMW_METHODS: [&str; 9] = [
+    "fetch_add",
+    "fetch_and",
+    "fetch_max",
+    "fetch_min",
+    "fetch_nand",
+    "fetch_or",
+    "fetch_sub",
+    "fetch_xor",
+    "swap",
+];
+
+/// Returns the name of the method and the ordering argument of a read-modify-write operation like
+/// `x.fetch_add(1, Ordering::Acquire)`.
+fn atomic_rmw_ordering<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<(Symbol, &'tcx Expr<'tcx>)> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref method_path, _, args, _) = expr.kind;
+        if ATOMIC_RMW_METHODS.contains(&&*method_path.ident.name.as_str());
+        if type_is_atomic(cx, &args[0]);
+        if let [_, _, ordering_arg] = args;
+        then {
+            Some((method_path.ident.name, ordering_arg))
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks for `Acquire` read-modify-write operations whose loaded value is unused.
+fn check_unused_acquire(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+    let expr = match stmt.kind {
+        StmtKind::Semi(expr) => expr,
+        StmtKind::Local(local) if matches!(local.pat.kind, PatKind::Wild) => match local.init {
+            Some(init) => init,
+            None => return,
+        },
+        _ => return,
+    };
+    if_chain! {
+        if let Some((method, ordering_arg)) = atomic_rmw_ordering(cx, expr);
+        if is_ordering(cx, ordering_arg, &["Acquire"]);
+        then {
+            span_lint_and_then(
+                cx,
+                ATOMIC_ORDERING_MISUSE,
+                ordering_arg.span,
+                &format!("the value loaded by `{}` with `Acquire` ordering is unused", method),
+                |diag| {
+                    diag.note(&format!(
+                        "the store of `{}` is `Relaxed`, it doesn't publish the preceding writes",
+                        method
+                    ));
+                    diag.help("did you mean ordering modes `Release` or `AcqRel`?");
+                },
+            );
+        }
+    }
+}
+
+/// Checks for `Release` read-modify-write operations used as the condition of an `if` or a
+/// `while`, e.g. `while locked.swap(true, Ordering::Release) {}`.
+fn check_release_condition(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if_chain! {
+        if let ExprKind::Match(scrutinee, _, MatchSource::IfDesugar { .. } | MatchSource::WhileDesugar) = expr.kind;
+        if let ExprKind::DropTemps(condition) = scrutinee.kind;
+        if let Some((method, ordering_arg)) = atomic_rmw_ordering(cx, condition);
+        if is_ordering(cx, ordering_arg, &["Release"]);
+        then {
+            span_lint_and_then(
+                cx,
+                ATOMIC_ORDERING_MISUSE,
+                ordering_arg.span,
+                &format!("the value loaded by `{}` with `Release` ordering is used as a condition", method),
+                |diag| {
+                    diag.note(&format!(
+                        "the load of `{}` is `Relaxed`, the code it guards may not see the writes of other threads",
+                        method
+                    ));
+                    diag.help("did you mean ordering modes `Acquire` or `AcqRel`?");
+                },
+            );
+        }
+    }
+}
+
+fn is_ordering(cx: &LateContext<'_>, ord_arg: &Expr<'_>, orderings: &[&str]) -> bool {
+    opt_ordering_defid(cx, ord_arg).map_or(false, |def_id| match_ordering_def_path(cx, def_id, orderings))
+}
+
+fn is_memory_fence(cx: &LateContext<'_>, func: &Expr<'_>) -> bool {
+    if let ExprKind::Path(ref func_qpath) = func.kind {
+        cx.qpath_res(func_qpath, func.hir_id)
+            .opt_def_id()
+            .map_or(false, |def_id| {
+                ["fence", "compiler_fence"]
+                    .iter()
+                    .any(|func| match_def_path(cx, def_id, &["core", "sync", "atomic", func]))
+            })
+    } else {
+        false
+    }
+}
+
+fn check_seq_cst(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    let (ordering_args, suggested) = match expr.kind {
+        ExprKind::MethodCall(_, _, args, _) if type_is_atomic(cx, &args[0]) => (
+            &args[1..],
+            "`Acquire` for loads, `Release` for stores, or `AcqRel` for read-modify-write operations",
+        ),
+        ExprKind::Call(func, args) if is_memory_fence(cx, func) => (args, "`Acquire`, `Release` or `AcqRel`"),
+        _ => return,
+    };
+
+    for ordering_arg in ordering_args {
+        if is_ordering(cx, ordering_arg, &["SeqCst"]) {
+            span_lint_and_then(
+                cx,
+                SEQ_CST_ORDERING,
+                ordering_arg.span,
+                "usage of the `SeqCst` ordering",
+                |diag| {
+                    diag.note("`SeqCst` also orders the operation with all the other `SeqCst` operations");
+                    diag.help(&format!("consider using ordering modes {}", suggested));
+                },
+            );
+        }
+    }
+}
+
 impl<'tcx> LateLintPass<'tcx> for AtomicOrdering {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         check_atomic_load_store(cx, expr);
         check_memory_fence(cx, expr);
         check_atomic_compare_exchange(cx, expr);
+        check_release_condition(cx, expr);
+        check_seq_cst(cx, expr);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        check_unused_acquire(cx, stmt);
     }
 }
@@ -1,8 +1,8 @@
 //! checks for attributes
 
 use crate::utils::{
-    first_line_of_span, is_present_in_source, match_panic_def_id, snippet_opt, span_lint, span_lint_and_help,
-    span_lint_and_sugg, span_lint_and_then, without_block_comments,
+    first_line_of_span, indent_of, is_present_in_source, match_panic_def_id, snippet_opt, span_lint,
+    span_lint_and_help, span_lint_and_sugg, span_lint_and_then, without_block_comments,
 };
 use if_chain::if_chain;
 use rustc_ast::{AttrKind, AttrStyle, Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem};
@@ -268,6 +268,37 @@ declare_clippy_lint! {
     "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for items that have both outer (`///`, `#[...]`) and inner
+    /// (`//!`, `#![...]`) attributes.
+    ///
+    /// **Why is this bad?** Mixing both styles splits the documentation and the configuration of
+    /// an item over two places, which makes it easy to overlook one of them. For doc comments
+    /// this can even lead to parts of the documentation being rendered in an unexpected order.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// Bad:
+    /// ```rust
+    /// /// Outer documentation.
+    /// mod foo {
+    ///     //! Inner documentation.
+    /// }
+    /// ```
+    ///
+    /// Good:
+    /// ```rust
+    /// /// Outer documentation.
+    /// /// Inner documentation.
+    /// mod foo {}
+    /// ```
+    pub MIXED_ATTRIBUTES_STYLE,
+    style,
+    "item has both inner and outer attributes"
+}
+
 declare_lint_pass!(Attributes => [
     INLINE_ALWAYS,
     DEPRECATED_SEMVER,
@@ -394,12 +425,12 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
 }
 
 /// Returns the lint name if it is clippy lint.
-fn extract_clippy_lint(lint: &NestedMetaItem) -> Option<SymbolStr> {
+pub(crate) fn extract_clippy_lint(lint: &NestedMetaItem) -> Option<SymbolStr> {
     if_chain! {
         if let Some(meta_item) = lint.meta_item();
         if meta_item.path.segments.len() > 1;
         if let tool_name = meta_item.path.segments[0].ident;
-        if tool_name.as_str() == "clippy";
+        if tool_name.name == sym::clippy;
         let lint_name = meta_item.path.segments.last().unwrap().ident.name;
         then {
             return Some(lint_name.as_str());
@@ -573,11 +604,13 @@ declare_lint_pass!(EarlyAttributes => [
     DEPRECATED_CFG_ATTR,
     MISMATCHED_TARGET_OS,
     EMPTY_LINE_AFTER_OUTER_ATTR,
+    MIXED_ATTRIBUTES_STYLE,
 ]);
 
 impl EarlyLintPass for EarlyAttributes {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
         check_empty_line_after_outer_attr(cx, item);
+        check_mixed_attributes_style(cx, item);
     }
 
     fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
@@ -620,6 +653,77 @@ fn check_empty_line_after_outer_attr(cx: &EarlyContext<'_>, item: &rustc_ast::It
     }
 }
 
+fn check_mixed_attributes_style(cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
+    let mut outer = None;
+    let mut inner = Vec::new();
+
+    for attr in &item.attrs {
+        if attr.span.from_expansion() {
+            continue;
+        }
+
+        match attr.style {
+            AttrStyle::Outer => {
+                outer.get_or_insert(attr.span);
+            },
+            // Inner attributes of out-of-line modules live in another file, they can't be mixed
+            // up with the outer ones.
+            AttrStyle::Inner if item.span.contains(attr.span) => inner.push(attr.span),
+            AttrStyle::Inner => {},
+        }
+    }
+
+    if_chain! {
+        if let Some(outer_span) = outer;
+        if let Some(&first_inner) = inner.first();
+        then {
+            span_lint_and_then(
+                cx,
+                MIXED_ATTRIBUTES_STYLE,
+                first_inner,
+                "item has both inner and outer attributes",
+                |diag| {
+                    diag.span_note(outer_span, "outer attribute found here");
+
+                    let converted = inner
+                        .iter()
+                        .filter_map(|&span| snippet_opt(cx, span).map(|snip| to_outer_attr_style(&snip)))
+                        .collect::<Vec<_>>();
+                    if converted.len() != inner.len() {
+                        return;
+                    }
+
+                    let indent = " ".repeat(indent_of(cx, item.span).unwrap_or(0));
+                    let mut moved = converted.join(&format!("\n{}", indent));
+                    moved.push('\n');
+                    moved.push_str(&indent);
+
+                    let mut suggestion = vec![(item.span.shrink_to_lo(), moved)];
+                    suggestion.extend(inner.iter().map(|&span| (span, String::new())));
+                    diag.multipart_suggestion(
+                        "move the inner attributes in front of the item",
+                        suggestion,
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Turns the source of an inner attribute or doc comment into its outer counterpart.
+fn to_outer_attr_style(snip: &str) -> String {
+    if let Some(rest) = snip.strip_prefix("//!") {
+        format!("///{}", rest)
+    } else if let Some(rest) = snip.strip_prefix("/*!") {
+        format!("/**{}", rest)
+    } else if let Some(rest) = snip.strip_prefix("#!") {
+        format!("#{}", rest)
+    } else {
+        snip.to_string()
+    }
+}
+
 fn check_deprecated_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute) {
     if_chain! {
         // check cfg_attr
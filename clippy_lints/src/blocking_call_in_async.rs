@@ -0,0 +1,159 @@
+use crate::utils::{match_def_path, paths, qpath_res, span_lint_and_help};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind, GeneratorKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to functions which block the thread, like
+    /// `std::thread::sleep`, the functions of `std::fs` and `std::net` or `std::sync::Mutex::lock`,
+    /// inside of `async fn`s and `async` blocks.
+    ///
+    /// More functions can be added with the `blocking-call-paths` configuration.
+    ///
+    /// **Why is this bad?** An async runtime runs many tasks on few threads. A task blocking its
+    /// thread stops the other tasks of the thread from making progress until the call returns.
+    ///
+    /// **Known problems:** Short blocking calls may be fine, e.g. locking a `std::sync::Mutex`
+    /// which is never held across an `.await` is often faster than using an async mutex.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// async fn retry() {
+    ///     std::thread::sleep(Duration::from_secs(1));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// # use std::time::Duration;
+    /// async fn retry() {
+    ///     tokio::time::sleep(Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    pub BLOCKING_CALL_IN_ASYNC,
+    pedantic,
+    "calling a blocking function in an async context"
+}
+
+/// The blocking functions of std, with the non-blocking alternatives to suggest
+const BLOCKING_CALLS: [(&[&str], &str); 12] = [
+    (
+        &paths::FILE_CREATE,
+        "`tokio::fs::File::create` or `async_std::fs::File::create`",
+    ),
+    (
+        &paths::FILE_OPEN,
+        "`tokio::fs::File::open` or `async_std::fs::File::open`",
+    ),
+    (&paths::MUTEX_LOCK, "`tokio::sync::Mutex` or `async_std::sync::Mutex`"),
+    (
+        &paths::STD_FS_CREATE_DIR,
+        "`tokio::fs::create_dir` or `async_std::fs::create_dir`",
+    ),
+    (&paths::STD_FS_READ, "`tokio::fs::read` or `async_std::fs::read`"),
+    (
+        &paths::STD_FS_READ_TO_STRING,
+        "`tokio::fs::read_to_string` or `async_std::fs::read_to_string`",
+    ),
+    (&paths::STD_FS_WRITE, "`tokio::fs::write` or `async_std::fs::write`"),
+    (
+        &paths::STD_THREAD_SLEEP,
+        "`tokio::time::sleep` or `async_std::task::sleep`",
+    ),
+    (
+        &paths::TCP_LISTENER_ACCEPT,
+        "`tokio::net::TcpListener` or `async_std::net::TcpListener`",
+    ),
+    (
+        &paths::TCP_LISTENER_BIND,
+        "`tokio::net::TcpListener` or `async_std::net::TcpListener`",
+    ),
+    (
+        &paths::TCP_STREAM_CONNECT,
+        "`tokio::net::TcpStream` or `async_std::net::TcpStream`",
+    ),
+    (
+        &paths::UDP_SOCKET_BIND,
+        "`tokio::net::UdpSocket` or `async_std::net::UdpSocket`",
+    ),
+];
+
+pub struct BlockingCallInAsync {
+    /// The blocking functions of the `blocking-call-paths` configuration
+    blocking_call_paths: Vec<Vec<String>>,
+    /// Whether each body being checked is async, the last one is the innermost body
+    async_bodies: Vec<bool>,
+}
+
+impl BlockingCallInAsync {
+    #[must_use]
+    pub fn new(blocking_call_paths: &[String]) -> Self {
+        Self {
+            blocking_call_paths: blocking_call_paths
+                .iter()
+                .map(|path| path.split("::").map(ToString::to_string).collect())
+                .collect(),
+            async_bodies: Vec::new(),
+        }
+    }
+
+    /// Returns the path of the function and the alternatives to suggest, if it's blocking.
+    fn blocking_call(&self, cx: &LateContext<'_>, def_id: DefId) -> Option<(String, Option<&'static str>)> {
+        if let Some((path, alternatives)) = BLOCKING_CALLS.iter().find(|(path, _)| match_def_path(cx, def_id, path)) {
+            return Some((path.join("::"), Some(*alternatives)));
+        }
+        self.blocking_call_paths
+            .iter()
+            .find(|path| {
+                let path: Vec<&str> = path.iter().map(String::as_str).collect();
+                match_def_path(cx, def_id, &path)
+            })
+            .map(|path| (path.join("::"), None))
+    }
+}
+
+impl_lint_pass!(BlockingCallInAsync => [BLOCKING_CALL_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingCallInAsync {
+    fn check_body(&mut self, _: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        // closures in async blocks are separate bodies, e.g. the closures passed to
+        // `spawn_blocking` aren't async
+        self.async_bodies.push(matches!(body.generator_kind, Some(GeneratorKind::Async(_))));
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx Body<'_>) {
+        self.async_bodies.pop();
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if self.async_bodies.last() != Some(&true) || expr.span.from_expansion() {
+            return;
+        }
+
+        let def_id = match expr.kind {
+            ExprKind::Call(func, _) => match func.kind {
+                ExprKind::Path(ref qpath) => qpath_res(cx, qpath, func.hir_id).opt_def_id(),
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            _ => None,
+        };
+
+        if let Some((path, alternatives)) = def_id.and_then(|def_id| self.blocking_call(cx, def_id)) {
+            let help = match alternatives {
+                Some(alternatives) => format!("use {} instead", alternatives),
+                None => "use a non-blocking alternative, or move the call to `spawn_blocking`".to_string(),
+            };
+            span_lint_and_help(
+                cx,
+                BLOCKING_CALL_IN_ASYNC,
+                expr.span,
+                &format!("call to the blocking function `{}` in an async context", path),
+                None,
+                &help,
+            );
+        }
+    }
+}
@@ -36,6 +36,7 @@ declare_clippy_lint! {
 declare_lint_pass!(ByteCount => [NAIVE_BYTECOUNT]);
 
 impl<'tcx> LateLintPass<'tcx> for ByteCount {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
         if_chain! {
             if let ExprKind::MethodCall(ref count, _, ref count_args, _) = expr.kind;
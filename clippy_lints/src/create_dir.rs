@@ -29,6 +29,7 @@ declare_clippy_lint! {
 declare_lint_pass!(CreateDir => [CREATE_DIR]);
 
 impl LateLintPass<'_> for CreateDir {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
         if_chain! {
             if let ExprKind::Call(ref func, ref args) = expr.kind;
@@ -1,10 +1,16 @@
-use crate::utils::{snippet_opt, span_lint_and_help, span_lint_and_sugg};
+use crate::utils::sugg::Sugg;
+use crate::utils::{is_cfg_test, span_lint_and_help, span_lint_and_sugg};
 use rustc_ast::ast;
+use rustc_ast::token;
 use rustc_ast::tokenstream::TokenStream;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
-use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_lint::{EarlyContext, EarlyLintPass, Lint};
+use rustc_parse::parser;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
+use rustc_span::sym;
+use std::borrow::Cow;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of dbg!() macro.
@@ -27,39 +33,274 @@ declare_clippy_lint! {
     "`dbg!` macro is intended as a debugging tool"
 }
 
-declare_lint_pass!(DbgMacro => [DBG_MACRO]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of the `dbg!`, `todo!` and `unimplemented!` macros
+    /// outside of `#[cfg(test)]` modules and `#[test]` functions.
+    ///
+    /// **Why is this bad?** These macros are placeholders during development. Left in the
+    /// released code, `dbg!` prints to stderr and the others panic when they are reached.
+    /// Unlike `dbg_macro`, this lint allows them in tests.
+    ///
+    /// **Known problems:** `dbg!` calls outside of tests are also linted by `dbg_macro` if
+    /// both lints are enabled.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // Bad
+    /// fn parse(input: &str) -> u32 {
+    ///     dbg!(input.parse().unwrap())
+    /// }
+    ///
+    /// // Good
+    /// fn parse(input: &str) -> u32 {
+    ///     input.parse().unwrap()
+    /// }
+    /// ```
+    pub DBG_MACRO_IN_RELEASE,
+    restriction,
+    "`dbg!`, `todo!` or `unimplemented!` outside of test code"
+}
+
+#[derive(Default)]
+pub struct DbgMacro {
+    /// The span of the statement being checked, if it's a macro call
+    stmt_span: Option<Span>,
+    /// The spans of `dbg!` calls which are operands, like in `dbg!(a) + b`, so their argument
+    /// may need parentheses
+    operands: FxHashSet<Span>,
+    /// The number of `#[cfg(test)]` or `#[test]` items containing the checked code
+    test_items: usize,
+}
+
+impl_lint_pass!(DbgMacro => [DBG_MACRO, DBG_MACRO_IN_RELEASE]);
 
 impl EarlyLintPass for DbgMacro {
+    fn check_item(&mut self, _: &EarlyContext<'_>, item: &ast::Item) {
+        if is_test_item(&item.attrs) {
+            self.test_items += 1;
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext<'_>, item: &ast::Item) {
+        if is_test_item(&item.attrs) {
+            self.test_items -= 1;
+        }
+    }
+
+    fn check_impl_item(&mut self, _: &EarlyContext<'_>, item: &ast::AssocItem) {
+        if is_test_item(&item.attrs) {
+            self.test_items += 1;
+        }
+    }
+
+    fn check_impl_item_post(&mut self, _: &EarlyContext<'_>, item: &ast::AssocItem) {
+        if is_test_item(&item.attrs) {
+            self.test_items -= 1;
+        }
+    }
+
+    fn check_trait_item(&mut self, _: &EarlyContext<'_>, item: &ast::AssocItem) {
+        if is_test_item(&item.attrs) {
+            self.test_items += 1;
+        }
+    }
+
+    fn check_trait_item_post(&mut self, _: &EarlyContext<'_>, item: &ast::AssocItem) {
+        if is_test_item(&item.attrs) {
+            self.test_items -= 1;
+        }
+    }
+
+    fn check_stmt(&mut self, _: &EarlyContext<'_>, stmt: &ast::Stmt) {
+        if let ast::StmtKind::MacCall(..) = stmt.kind {
+            self.stmt_span = Some(stmt.span);
+        }
+    }
+
+    fn check_expr(&mut self, _: &EarlyContext<'_>, expr: &ast::Expr) {
+        let mut mark_operand = |operand: &ast::Expr| {
+            if let ast::ExprKind::MacCall(ref mac) = operand.kind {
+                if mac.path == sym!(dbg) {
+                    self.operands.insert(operand.span);
+                }
+            }
+        };
+
+        match expr.kind {
+            ast::ExprKind::Binary(_, ref lhs, ref rhs) => {
+                mark_operand(lhs);
+                mark_operand(rhs);
+            },
+            ast::ExprKind::Unary(_, ref operand)
+            | ast::ExprKind::AddrOf(_, _, ref operand)
+            | ast::ExprKind::Cast(ref operand, _)
+            | ast::ExprKind::Type(ref operand, _)
+            | ast::ExprKind::Field(ref operand, _)
+            | ast::ExprKind::Index(ref operand, _)
+            | ast::ExprKind::Call(ref operand, _)
+            | ast::ExprKind::Try(ref operand)
+            | ast::ExprKind::Await(ref operand) => mark_operand(operand),
+            ast::ExprKind::MethodCall(_, ref args, ..) => mark_operand(&args[0]),
+            _ => {},
+        }
+    }
+
     fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &ast::MacCall) {
+        // the statement is `dbg!(..);`, so the value is unused
+        let stmt_span = self
+            .stmt_span
+            .take()
+            .filter(|span| span.lo() == mac.span().lo() && span.hi() > mac.span().hi());
+
         if mac.path == sym!(dbg) {
-            if let Some(sugg) = tts_span(mac.args.inner_tokens()).and_then(|span| snippet_opt(cx, span)) {
-                span_lint_and_sugg(
+            self.lint_dbg(
+                cx,
+                DBG_MACRO,
+                mac,
+                stmt_span,
+                "`dbg!` macro is intended as a debugging tool",
+            );
+            if self.test_items == 0 {
+                self.lint_dbg(
                     cx,
-                    DBG_MACRO,
-                    mac.span(),
-                    "`dbg!` macro is intended as a debugging tool",
-                    "ensure to avoid having uses of it in version control",
-                    sugg,
-                    Applicability::MaybeIncorrect,
+                    DBG_MACRO_IN_RELEASE,
+                    mac,
+                    stmt_span,
+                    "`dbg!` macro outside of test code",
                 );
+            }
+        } else if self.test_items == 0 {
+            let name = if mac.path == sym!(todo) {
+                "todo"
+            } else if mac.path == sym!(unimplemented) {
+                "unimplemented"
             } else {
+                return;
+            };
+            span_lint_and_help(
+                cx,
+                DBG_MACRO_IN_RELEASE,
+                mac.span(),
+                &format!("`{}!` macro outside of test code", name),
+                None,
+                "implement the missing code, or return an error if it can't be supported",
+            );
+        }
+    }
+}
+
+impl DbgMacro {
+    fn lint_dbg(
+        &self,
+        cx: &EarlyContext<'_>,
+        lint: &'static Lint,
+        mac: &ast::MacCall,
+        stmt_span: Option<Span>,
+        msg: &str,
+    ) {
+        let args = match parse_args(cx, mac.args.inner_tokens()) {
+            Some(args) => args,
+            None => {
                 span_lint_and_help(
                     cx,
-                    DBG_MACRO,
+                    lint,
                     mac.span(),
-                    "`dbg!` macro is intended as a debugging tool",
+                    msg,
                     None,
                     "ensure to avoid having uses of it in version control",
                 );
+                return;
+            },
+        };
+
+        if let Some(stmt_span) = stmt_span {
+            if args.iter().all(|arg| is_side_effect_free(cx, arg)) {
+                span_lint_and_sugg(
+                    cx,
+                    lint,
+                    stmt_span,
+                    msg,
+                    "remove the invocation before committing it to version control",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+                return;
             }
         }
+
+        let mut sugg = args_sugg(cx, &args);
+        if self.operands.contains(&mac.span()) {
+            sugg = sugg.maybe_par();
+        }
+        span_lint_and_sugg(
+            cx,
+            lint,
+            mac.span(),
+            msg,
+            "ensure to avoid having uses of it in version control",
+            sugg.to_string(),
+            Applicability::MachineApplicable,
+        );
     }
 }
 
-// Get span enclosing entire the token stream.
-fn tts_span(tts: TokenStream) -> Option<Span> {
-    let mut cursor = tts.into_trees();
-    let first = cursor.next()?.span();
-    let span = cursor.last().map_or(first, |tree| first.to(tree.span()));
-    Some(span)
+/// Checks if the attributes of an item or associated item make it only compiled for tests, or a
+/// test.
+fn is_test_item(attrs: &[ast::Attribute]) -> bool {
+    is_cfg_test(attrs) || attrs.iter().any(|attr| attr.has_name(sym::test))
+}
+
+/// Parses the comma separated arguments of a `dbg!` call.
+fn parse_args(cx: &EarlyContext<'_>, tts: TokenStream) -> Option<Vec<ast::Expr>> {
+    let mut parser = parser::Parser::new(&cx.sess.parse_sess, tts, false, None);
+    let mut args = Vec::new();
+    while parser.token != token::Eof {
+        let arg = parser.parse_expr().map_err(|mut err| err.cancel()).ok()?;
+        args.push(arg.into_inner());
+        if parser.token != token::Eof {
+            parser.expect(&token::Comma).map_err(|mut err| err.cancel()).ok()?;
+        }
+    }
+    Some(args)
+}
+
+/// Returns the value of `dbg!` called with the arguments, removing nested `dbg!` calls.
+fn args_sugg(cx: &EarlyContext<'_>, args: &[ast::Expr]) -> Sugg<'static> {
+    let arg_sugg = |arg: &ast::Expr| {
+        if let Some(inner_args) = dbg_args(cx, arg) {
+            args_sugg(cx, &inner_args)
+        } else {
+            Sugg::ast(cx, arg, "..")
+        }
+    };
+
+    match args {
+        [] => Sugg::NonParen(Cow::Borrowed("()")),
+        [arg] => arg_sugg(arg),
+        _ => {
+            let args: Vec<_> = args.iter().map(|arg| arg_sugg(arg).to_string()).collect();
+            Sugg::NonParen(Cow::Owned(format!("({})", args.join(", "))))
+        },
+    }
+}
+
+/// Returns the arguments of the expression if it's a `dbg!` call.
+fn dbg_args(cx: &EarlyContext<'_>, expr: &ast::Expr) -> Option<Vec<ast::Expr>> {
+    match expr.kind {
+        ast::ExprKind::MacCall(ref mac) if mac.path == sym!(dbg) => parse_args(cx, mac.args.inner_tokens()),
+        _ => None,
+    }
+}
+
+/// Checks if evaluating the expression has no effect besides printing with `dbg!`, so
+/// `dbg!(expr);` can be removed.
+fn is_side_effect_free(cx: &EarlyContext<'_>, expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Lit(..) | ast::ExprKind::Path(..) => true,
+        ast::ExprKind::Field(ref base, _) | ast::ExprKind::Paren(ref base) => is_side_effect_free(cx, base),
+        ast::ExprKind::MacCall(..) => {
+            dbg_args(cx, expr).map_or(false, |args| args.iter().all(|arg| is_side_effect_free(cx, arg)))
+        },
+        _ => false,
+    }
 }
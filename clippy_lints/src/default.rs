@@ -4,7 +4,7 @@ use if_chain::if_chain;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
-use rustc_hir::{Block, Expr, ExprKind, PatKind, QPath, Stmt, StmtKind};
+use rustc_hir::{Block, Expr, ExprKind, Field, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Adt, Ty};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -62,15 +62,51 @@ declare_clippy_lint! {
     "binding initialized with Default should have its fields set in the initializer"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for fields filled with `..Default::default()` in a struct
+    /// expression, which are reassigned right after the initialization.
+    ///
+    /// **Why is this bad?** The initializer hides that these fields get other values. Setting them
+    /// in the struct expression shows the actual value, and lets the binding be immutable. The
+    /// whole default struct is still built either way, only the reassignments go away.
+    ///
+    /// **Known problems:** Only the statements directly following the initialization are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # #[derive(Default)]
+    /// # struct A { i: i32, j: i32, k: i32 }
+    /// let mut a = A {
+    ///     i: 1,
+    ///     ..Default::default()
+    /// };
+    /// a.j = 42;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[derive(Default)]
+    /// # struct A { i: i32, j: i32, k: i32 }
+    /// let a = A {
+    ///     i: 1,
+    ///     j: 42,
+    ///     ..Default::default()
+    /// };
+    /// ```
+    pub STRUCT_FIELDS_REST_DEFAULT,
+    style,
+    "fields filled with `..Default::default()` which are reassigned after the initialization"
+}
+
 #[derive(Default)]
 pub struct Default {
     // Spans linted by `field_reassign_with_default`.
     reassigned_linted: FxHashSet<Span>,
 }
 
-impl_lint_pass!(Default => [DEFAULT_TRAIT_ACCESS, FIELD_REASSIGN_WITH_DEFAULT]);
+impl_lint_pass!(Default => [DEFAULT_TRAIT_ACCESS, FIELD_REASSIGN_WITH_DEFAULT, STRUCT_FIELDS_REST_DEFAULT]);
 
 impl LateLintPass<'_> for Default {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             // Avoid cases already linted by `field_reassign_with_default`
@@ -118,99 +154,115 @@ impl LateLintPass<'_> for Default {
 
             // find all "later statement"'s where the fields of the binding set as
             // Default::default() get reassigned, unless the reassignment refers to the original binding
-            let mut first_assign = None;
-            let mut assigned_fields = Vec::new();
-            let mut cancel_lint = false;
-            for consecutive_statement in &block.stmts[stmt_idx + 1..] {
-                // interrupt if the statement is a let binding (`Local`) that shadows the original
-                // binding
-                if stmt_shadows_binding(consecutive_statement, binding_name) {
-                    break;
-                }
-                // find out if and which field was set by this `consecutive_statement`
-                else if let Some((field_ident, assign_rhs)) =
-                    field_reassigned_by_stmt(consecutive_statement, binding_name)
-                {
-                    // interrupt and cancel lint if assign_rhs references the original binding
-                    if contains_name(binding_name, assign_rhs) {
-                        cancel_lint = true;
-                        break;
-                    }
+            let reassignments = match consecutive_field_reassignments(&block.stmts[stmt_idx + 1..], binding_name) {
+                Some(reassignments) if !reassignments.is_empty() => reassignments,
+                _ => continue,
+            };
+            let first_assign = reassignments[0].0;
+            let assigned_fields = last_assigned_values(&reassignments);
+
+            // do a span_lint_and_note to suggest construction using `Ty { fields, ..Default::default() }`
+            // take the original assignment as span
+            let stmt = &block.stmts[stmt_idx];
+
+            if let StmtKind::Local(preceding_local) = &stmt.kind {
+                // filter out fields like `= Default::default()`, because the FRU already covers them
+                let assigned_fields = assigned_fields
+                    .into_iter()
+                    .filter(|(_, rhs)| !is_expr_default(rhs, cx))
+                    .collect::<Vec<(Symbol, &Expr<'_>)>>();
 
-                    // if the field was previously assigned, replace the assignment, otherwise insert the assignment
-                    if let Some(prev) = assigned_fields
-                        .iter_mut()
-                        .find(|(field_name, _)| field_name == &field_ident.name)
-                    {
-                        *prev = (field_ident.name, assign_rhs);
+                // if all fields of the struct are not assigned, add `.. Default::default()` to the suggestion.
+                let ext_with_default = !fields_of_type(binding_type)
+                    .iter()
+                    .all(|field| assigned_fields.iter().any(|(a, _)| a == &field.name));
+
+                let field_list = assigned_fields
+                    .into_iter()
+                    .map(|(field, rhs)| {
+                        // extract and store the assigned value for help message
+                        let value_snippet = snippet(cx, rhs.span, "..");
+                        format!("{}: {}", field, value_snippet)
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let sugg = if ext_with_default {
+                    if field_list.is_empty() {
+                        format!("{}::default()", binding_type)
                     } else {
-                        assigned_fields.push((field_ident.name, assign_rhs));
+                        format!("{} {{ {}, ..Default::default() }}", binding_type, field_list)
                     }
+                } else {
+                    format!("{} {{ {} }}", binding_type, field_list)
+                };
 
-                    // also set first instance of error for help message
-                    if first_assign.is_none() {
-                        first_assign = Some(consecutive_statement);
-                    }
-                }
-                // interrupt also if no field was assigned, since we only want to look at consecutive statements
-                else {
-                    break;
-                }
+                // span lint once per statement that binds default
+                span_lint_and_note(
+                    cx,
+                    FIELD_REASSIGN_WITH_DEFAULT,
+                    first_assign.span,
+                    "field assignment outside of initializer for an instance created with Default::default()",
+                    Some(preceding_local.span),
+                    &format!(
+                        "consider initializing the variable with `{}` and removing relevant reassignments",
+                        sugg
+                    ),
+                );
+                self.reassigned_linted.insert(span);
             }
+        }
 
-            // if there are incorrectly assigned fields, do a span_lint_and_note to suggest
-            // construction using `Ty { fields, ..Default::default() }`
-            if !assigned_fields.is_empty() && !cancel_lint {
-                // take the original assignment as span
-                let stmt = &block.stmts[stmt_idx];
-
-                if let StmtKind::Local(preceding_local) = &stmt.kind {
-                    // filter out fields like `= Default::default()`, because the FRU already covers them
-                    let assigned_fields = assigned_fields
-                        .into_iter()
-                        .filter(|(_, rhs)| !is_expr_default(rhs, cx))
-                        .collect::<Vec<(Symbol, &Expr<'_>)>>();
-
-                    // if all fields of the struct are not assigned, add `.. Default::default()` to the suggestion.
-                    let ext_with_default = !fields_of_type(binding_type)
-                        .iter()
-                        .all(|field| assigned_fields.iter().any(|(a, _)| a == &field.name));
+        // same for the bindings like `let mut _ = T { a: 1, ..Default::default() };`, whose defaulted
+        // fields are reassigned by the following statements
+        for (stmt_idx, binding_name, binding_type, fields) in enumerate_bindings_using_struct_default(cx, block) {
+            let reassignments = match consecutive_field_reassignments(&block.stmts[stmt_idx + 1..], binding_name) {
+                Some(reassignments) => reassignments,
+                None => continue,
+            };
+            // a reassignment of an explicitly initialized field can't be moved into the initializer
+            // without dropping the initial value, which may have side effects
+            let reassignments = reassignments
+                .into_iter()
+                .take_while(|(_, field, _)| !fields.iter().any(|init| init.ident.name == *field))
+                .collect::<Vec<_>>();
+            let first_assign = match reassignments.first() {
+                Some((first_assign, ..)) => first_assign,
+                None => continue,
+            };
+            let assigned_fields = last_assigned_values(&reassignments);
 
-                    let field_list = assigned_fields
+            let ext_with_default = fields_of_type(binding_type).iter().any(|field| {
+                !fields.iter().any(|init| init.ident.name == field.name)
+                    && !assigned_fields.iter().any(|(a, _)| a == &field.name)
+            });
+            let field_list = fields
+                .iter()
+                .map(|init| snippet(cx, init.span, "..").into_owned())
+                .chain(
+                    assigned_fields
                         .into_iter()
-                        .map(|(field, rhs)| {
-                            // extract and store the assigned value for help message
-                            let value_snippet = snippet(cx, rhs.span, "..");
-                            format!("{}: {}", field, value_snippet)
-                        })
-                        .collect::<Vec<String>>()
-                        .join(", ");
-
-                    let sugg = if ext_with_default {
-                        if field_list.is_empty() {
-                            format!("{}::default()", binding_type)
-                        } else {
-                            format!("{} {{ {}, ..Default::default() }}", binding_type, field_list)
-                        }
-                    } else {
-                        format!("{} {{ {} }}", binding_type, field_list)
-                    };
+                        .map(|(field, rhs)| format!("{}: {}", field, snippet(cx, rhs.span, ".."))),
+                )
+                .collect::<Vec<String>>()
+                .join(", ");
+            let sugg = if ext_with_default {
+                format!("{} {{ {}, ..Default::default() }}", binding_type, field_list)
+            } else {
+                format!("{} {{ {} }}", binding_type, field_list)
+            };
 
-                    // span lint once per statement that binds default
-                    span_lint_and_note(
-                        cx,
-                        FIELD_REASSIGN_WITH_DEFAULT,
-                        first_assign.unwrap().span,
-                        "field assignment outside of initializer for an instance created with Default::default()",
-                        Some(preceding_local.span),
-                        &format!(
-                            "consider initializing the variable with `{}` and removing relevant reassignments",
-                            sugg
-                        ),
-                    );
-                    self.reassigned_linted.insert(span);
-                }
-            }
+            span_lint_and_note(
+                cx,
+                STRUCT_FIELDS_REST_DEFAULT,
+                first_assign.span,
+                "field assignment outside of initializer for a field filled with `..Default::default()`",
+                Some(block.stmts[stmt_idx].span),
+                &format!(
+                    "consider initializing the variable with `{}` and removing relevant reassignments",
+                    sugg
+                ),
+            );
         }
     }
 }
@@ -262,6 +314,76 @@ fn enumerate_bindings_using_default<'tcx>(
         .collect()
 }
 
+/// Returns the block indices, identifiers and types of bindings set as a struct expression with
+/// `..Default::default()`, together with the explicitly initialized fields.
+fn enumerate_bindings_using_struct_default<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &Block<'tcx>,
+) -> Vec<(usize, Symbol, Ty<'tcx>, &'tcx [Field<'tcx>])> {
+    block
+        .stmts
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, stmt)| {
+            if_chain! {
+                if let StmtKind::Local(ref local) = stmt.kind;
+                if let PatKind::Binding(_, _, ident, _) = local.pat.kind;
+                if let Some(ref expr) = local.init;
+                if !expr.span.from_expansion();
+                if let ExprKind::Struct(_, fields, Some(ref base)) = expr.kind;
+                if is_expr_default(base, cx);
+                then {
+                    Some((idx, ident.name, cx.typeck_results().pat_ty(local.pat), fields))
+                } else {
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the field reassignments of the binding by the statements at the start of `stmts`, with
+/// the reassigning statements, or `None` if one of the assigned values refers to the binding.
+fn consecutive_field_reassignments<'tcx>(
+    stmts: &'tcx [Stmt<'tcx>],
+    binding_name: Symbol,
+) -> Option<Vec<(&'tcx Stmt<'tcx>, Symbol, &'tcx Expr<'tcx>)>> {
+    let mut reassignments = Vec::new();
+    for stmt in stmts {
+        // interrupt if the statement is a let binding (`Local`) that shadows the original
+        // binding
+        if stmt_shadows_binding(stmt, binding_name) {
+            break;
+        }
+        // find out if and which field was set by this statement, interrupt also if no field was
+        // assigned, since we only want to look at consecutive statements
+        match field_reassigned_by_stmt(stmt, binding_name) {
+            // cancel the lint if assign_rhs references the original binding
+            Some((_, assign_rhs)) if contains_name(binding_name, assign_rhs) => return None,
+            Some((field_ident, assign_rhs)) => reassignments.push((stmt, field_ident.name, assign_rhs)),
+            None => break,
+        }
+    }
+    Some(reassignments)
+}
+
+/// Returns the reassigned fields in the order of their first assignment, with the value of their
+/// last one.
+fn last_assigned_values<'tcx>(
+    reassignments: &[(&'tcx Stmt<'tcx>, Symbol, &'tcx Expr<'tcx>)],
+) -> Vec<(Symbol, &'tcx Expr<'tcx>)> {
+    let mut assigned_fields: Vec<(Symbol, &Expr<'_>)> = Vec::new();
+    for &(_, field, assign_rhs) in reassignments {
+        // if the field was previously assigned, replace the assignment, otherwise insert the assignment
+        if let Some(prev) = assigned_fields.iter_mut().find(|(field_name, _)| field_name == &field) {
+            *prev = (field, assign_rhs);
+        } else {
+            assigned_fields.push((field, assign_rhs));
+        }
+    }
+    assigned_fields
+}
+
 fn stmt_shadows_binding(this: &Stmt<'_>, shadowed: Symbol) -> bool {
     if let StmtKind::Local(local) = &this.kind {
         if let PatKind::Binding(_, _, ident, _) = local.pat.kind {
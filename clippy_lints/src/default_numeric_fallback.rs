@@ -0,0 +1,325 @@
+use crate::utils::{snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_ast::ast::{FloatTy, IntTy, LitFloatType, LitIntType, LitKind};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, HirId, Lit, PatKind, QPath, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::map::Map;
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::subst::Subst;
+use rustc_middle::ty::{self, Ty, TypeFoldable};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of unconstrained numeric literals which may cause
+    /// default numeric fallback in type inference.
+    ///
+    /// Default numeric fallback means that if numeric types have not yet been bound to concrete
+    /// types at the end of type inference, then integer types fall back to `i32` and float types
+    /// fall back to `f64`.
+    /// See [RFC0212](https://github.com/rust-lang/rfcs/blob/master/text/0212-restore-int-fallback.md) for more information.
+    ///
+    /// **Why is this bad?** For those who are very careful about types, default numeric fallback
+    /// can be a pitfall that causes unexpected runtime behavior.
+    ///
+    /// **Known problems:** The type of a literal is only considered fixed by type annotations,
+    /// parameter and field types, the other operand of a binary operation, and for `let`
+    /// bindings, being passed as a numeric argument. Literals whose type is inferred from other
+    /// usages are still linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let i = 10;
+    /// let f = 1.23;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// let i = 10i32;
+    /// let f = 1.23f64;
+    /// ```
+    pub DEFAULT_NUMERIC_FALLBACK,
+    restriction,
+    "usage of unconstrained numeric literals which may cause default numeric fallback"
+}
+
+declare_lint_pass!(DefaultNumericFallback => [DEFAULT_NUMERIC_FALLBACK]);
+
+impl<'tcx> LateLintPass<'tcx> for DefaultNumericFallback {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        let mut pinned = PinnedLocalsVisitor {
+            cx,
+            locals: FxHashSet::default(),
+        };
+        pinned.visit_body(body);
+
+        let mut visitor = NumericFallbackVisitor {
+            cx,
+            pinned_locals: pinned.locals,
+            ty_bounds: Vec::new(),
+        };
+        visitor.visit_body(body);
+    }
+}
+
+/// What is known about the expected type of an expression.
+#[derive(Clone, Copy)]
+enum TyBound<'tcx> {
+    /// The type is fixed by the context, e.g. by a type annotation.
+    Any,
+    /// The type is expected to be the declared type of a parameter or field.
+    Ty(Ty<'tcx>),
+    /// Nothing fixes the type.
+    Nothing,
+}
+
+impl<'tcx> TyBound<'tcx> {
+    fn is_numeric(self) -> bool {
+        match self {
+            TyBound::Any => true,
+            TyBound::Ty(ty) => ty.is_numeric(),
+            TyBound::Nothing => false,
+        }
+    }
+}
+
+struct NumericFallbackVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// Local bindings which are passed as numeric arguments, so their type is inferred from that.
+    pinned_locals: FxHashSet<HirId>,
+    ty_bounds: Vec<TyBound<'tcx>>,
+}
+
+impl<'a, 'tcx> NumericFallbackVisitor<'a, 'tcx> {
+    fn check_lit(&self, lit: &Lit, lit_ty: Ty<'tcx>) {
+        if_chain! {
+            if !in_external_macro(self.cx.sess(), lit.span);
+            if let Some(ty_bound) = self.ty_bounds.last();
+            if matches!(
+                lit.node,
+                LitKind::Int(_, LitIntType::Unsuffixed) | LitKind::Float(_, LitFloatType::Unsuffixed)
+            );
+            if !ty_bound.is_numeric();
+            then {
+                let suffix = match lit_ty.kind() {
+                    ty::Int(IntTy::I32) => "i32",
+                    ty::Float(FloatTy::F64) => "f64",
+                    // Default numeric fallback never results in other types.
+                    _ => return,
+                };
+
+                let sugg = format!("{}_{}", snippet(self.cx, lit.span, ""), suffix);
+                span_lint_and_sugg(
+                    self.cx,
+                    DEFAULT_NUMERIC_FALLBACK,
+                    lit.span,
+                    "default numeric fallback might occur",
+                    "consider adding suffix",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+
+    /// The bound of a field of an ADT constructor, from its declared type. A field of a generic
+    /// type takes its type from the type expected for the ADT, like the literal in
+    /// `let x: Option<i32> = Some(1)`.
+    fn field_bound(&self, adt_did: DefId, field_ty: Ty<'tcx>) -> TyBound<'tcx> {
+        if !field_ty.has_param_types_or_consts() {
+            return TyBound::Ty(field_ty);
+        }
+        match self.ty_bounds.last() {
+            Some(TyBound::Any) => TyBound::Any,
+            Some(&TyBound::Ty(expected)) => match expected.kind() {
+                ty::Adt(def, substs) if def.did == adt_did => TyBound::Ty(field_ty.subst(self.cx.tcx, substs)),
+                _ => TyBound::Nothing,
+            },
+            _ => TyBound::Nothing,
+        }
+    }
+
+    fn visit_with_bound(&mut self, expr: &'tcx Expr<'tcx>, bound: TyBound<'tcx>) {
+        self.ty_bounds.push(bound);
+        self.visit_expr(expr);
+        self.ty_bounds.pop();
+    }
+
+    /// Visits the operands of a binary operation. A literal operand takes the type of the other
+    /// operand, unless that's a literal, too.
+    fn visit_operands(&mut self, lhs: &'tcx Expr<'tcx>, rhs: &'tcx Expr<'tcx>) {
+        let lhs_is_lit = matches!(lhs.kind, ExprKind::Lit(_));
+        let rhs_is_lit = matches!(rhs.kind, ExprKind::Lit(_));
+        if lhs_is_lit && !rhs_is_lit {
+            self.visit_with_bound(lhs, TyBound::Any);
+            self.visit_expr(rhs);
+        } else if rhs_is_lit && !lhs_is_lit {
+            self.visit_expr(lhs);
+            self.visit_with_bound(rhs, TyBound::Any);
+        } else {
+            self.visit_expr(lhs);
+            self.visit_expr(rhs);
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for NumericFallbackVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Call(func, args) => {
+                if let Some(inputs) = fn_inputs(self.cx, func) {
+                    let ctor_adt = match (&func.kind, self.cx.typeck_results().expr_ty(expr).kind()) {
+                        (ExprKind::Path(qpath), ty::Adt(def, _))
+                            if matches!(self.cx.qpath_res(qpath, func.hir_id), Res::Def(DefKind::Ctor(..), _)) =>
+                        {
+                            Some(def.did)
+                        },
+                        _ => None,
+                    };
+                    self.visit_expr(func);
+                    for (arg, &input) in args.iter().zip(inputs.iter()) {
+                        let bound = match ctor_adt {
+                            Some(adt_did) => self.field_bound(adt_did, input),
+                            None => TyBound::Ty(input),
+                        };
+                        self.visit_with_bound(arg, bound);
+                    }
+                    return;
+                }
+            },
+            ExprKind::MethodCall(_, _, args, _) => {
+                if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    let inputs = self.cx.tcx.fn_sig(def_id).inputs().skip_binder();
+                    for (arg, bound) in args.iter().zip(inputs.iter()) {
+                        self.visit_with_bound(arg, TyBound::Ty(*bound));
+                    }
+                    return;
+                }
+            },
+            ExprKind::Struct(_, fields, base) => {
+                if_chain! {
+                    if let ty::Adt(def, _) = self.cx.typeck_results().expr_ty(expr).kind();
+                    if def.is_struct() || def.is_union();
+                    then {
+                        let variant = def.non_enum_variant();
+                        for field in fields {
+                            let bound = match variant.fields.iter().find(|f| f.ident == field.ident) {
+                                Some(field_def) => self.field_bound(def.did, self.cx.tcx.type_of(field_def.did)),
+                                None => TyBound::Nothing,
+                            };
+                            self.visit_with_bound(field.expr, bound);
+                        }
+                        if let Some(base) = base {
+                            self.visit_expr(base);
+                        }
+                        return;
+                    }
+                }
+            },
+            ExprKind::Binary(_, lhs, rhs) | ExprKind::AssignOp(_, lhs, rhs) | ExprKind::Assign(lhs, rhs, _) => {
+                self.visit_operands(lhs, rhs);
+                return;
+            },
+            ExprKind::Ret(Some(value)) => {
+                // The return type of the function fixes the type
+                self.visit_with_bound(value, TyBound::Any);
+                return;
+            },
+            ExprKind::Lit(ref lit) => {
+                let ty = self.cx.typeck_results().expr_ty(expr);
+                self.check_lit(lit, ty);
+                return;
+            },
+            _ => {},
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt<'_>) {
+        let bound = match stmt.kind {
+            StmtKind::Local(local) => {
+                let is_pinned = match local.pat.kind {
+                    PatKind::Binding(_, id, _, _) => self.pinned_locals.contains(&id),
+                    _ => false,
+                };
+                if local.ty.is_some() || is_pinned {
+                    TyBound::Any
+                } else {
+                    TyBound::Nothing
+                }
+            },
+            _ => TyBound::Nothing,
+        };
+
+        self.ty_bounds.push(bound);
+        walk_stmt(self, stmt);
+        self.ty_bounds.pop();
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects the local bindings which are passed as arguments of a numeric parameter type.
+struct PinnedLocalsVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    locals: FxHashSet<HirId>,
+}
+
+impl<'a, 'tcx> PinnedLocalsVisitor<'a, 'tcx> {
+    fn check_args(&mut self, args: &[Expr<'_>], inputs: &[Ty<'tcx>]) {
+        for (arg, ty) in args.iter().zip(inputs.iter()) {
+            if_chain! {
+                if ty.is_numeric();
+                if let ExprKind::Path(QPath::Resolved(None, path)) = arg.kind;
+                if let Res::Local(id) = path.res;
+                then {
+                    self.locals.insert(id);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PinnedLocalsVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Call(func, args) => {
+                if let Some(inputs) = fn_inputs(self.cx, func) {
+                    self.check_args(args, inputs);
+                }
+            },
+            ExprKind::MethodCall(_, _, args, _) => {
+                if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    self.check_args(args, self.cx.tcx.fn_sig(def_id).inputs().skip_binder());
+                }
+            },
+            _ => {},
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns the declared parameter types of the called function.
+fn fn_inputs<'tcx>(cx: &LateContext<'tcx>, func: &Expr<'_>) -> Option<&'tcx [Ty<'tcx>]> {
+    match cx.typeck_results().expr_ty(func).kind() {
+        ty::FnDef(def_id, _) => Some(cx.tcx.fn_sig(*def_id).inputs().skip_binder()),
+        ty::FnPtr(fn_sig) => Some(fn_sig.inputs().skip_binder()),
+        _ => None,
+    }
+}
@@ -0,0 +1,94 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::{qpath_res, span_lint_and_then};
+
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Lints for uses of specific functions defined in clippy.toml, including
+    /// associated functions like `Utc::now`.
+    ///
+    /// The functions are configured with the paths where they're defined, e.g. `std::env::var`.
+    /// A path can be given as a `{ path = "..", reason = ".." }` table to show the reason in the
+    /// warning.
+    ///
+    /// **Why is this bad?** Some functions are undesirable in certain contexts, e.g. a project
+    /// reading its environment in a single place, and it would be beneficial to lint for them as
+    /// needed.
+    ///
+    /// **Known problems:** Method calls like `x.foo()` are checked by `disallowed_method`.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-functions = [{ path = "std::env::var", reason = "use the `Config` instead" }]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// let home = std::env::var("HOME");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let home = &config.home;
+    /// ```
+    pub DISALLOWED_FUNCTION,
+    nursery,
+    "use of a disallowed function"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedFunction {
+    /// The paths of the disallowed functions, with the reason from the configuration
+    disallowed: FxHashMap<Vec<Symbol>, Option<String>>,
+}
+
+impl DisallowedFunction {
+    pub fn new(disallowed: &[DisallowedPath]) -> Self {
+        Self {
+            disallowed: disallowed
+                .iter()
+                .map(|path| (path.segments(), path.reason().map(ToString::to_string)))
+                .collect(),
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedFunction => [DISALLOWED_FUNCTION]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedFunction {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Path(ref qpath) = expr.kind;
+            if let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = qpath_res(cx, qpath, expr.hir_id);
+            let fn_path = cx.get_def_path(def_id);
+            if let Some(reason) = self.disallowed.get(&fn_path);
+            then {
+                let function = fn_path
+                    .iter()
+                    .map(|s| s.to_ident_string())
+                    .collect::<Vec<_>>()
+                    .join("::");
+
+                span_lint_and_then(
+                    cx,
+                    DISALLOWED_FUNCTION,
+                    expr.span,
+                    &format!("use of a disallowed function `{}`", function),
+                    |diag| {
+                        if let Some(reason) = reason {
+                            diag.note(reason);
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
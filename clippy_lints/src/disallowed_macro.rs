@@ -0,0 +1,88 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::span_lint_and_then;
+
+use rustc_ast::ast::MacCall;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Lints for calls of specific macros defined in clippy.toml.
+    ///
+    /// The macros are configured with their paths, e.g. `std::dbg`. A path can be given as a
+    /// `{ path = "..", reason = ".." }` table to show the reason in the warning.
+    ///
+    /// **Why is this bad?** Some macros are undesirable in certain contexts, e.g. a project
+    /// logging with its own macros, and it would be beneficial to lint for them as needed.
+    ///
+    /// **Known problems:** Macro paths aren't resolved when the lint runs, so a call matches if
+    /// its path is a suffix of a configured path, e.g. `dbg!` matches `std::dbg` as well as any
+    /// other `dbg` macro. Calls in the expansion of other macros aren't checked.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-macros = [{ path = "std::println", reason = "use `log::info` instead" }]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// println!("starting");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// log::info!("starting");
+    /// ```
+    pub DISALLOWED_MACRO,
+    nursery,
+    "use of a disallowed macro"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedMacro {
+    /// The paths of the disallowed macros, with the reason from the configuration
+    disallowed: Vec<(Vec<Symbol>, Option<String>)>,
+}
+
+impl DisallowedMacro {
+    pub fn new(disallowed: &[DisallowedPath]) -> Self {
+        Self {
+            disallowed: disallowed
+                .iter()
+                .map(|path| (path.segments(), path.reason().map(ToString::to_string)))
+                .collect(),
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedMacro => [DISALLOWED_MACRO]);
+
+impl EarlyLintPass for DisallowedMacro {
+    fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &MacCall) {
+        let segments = &mac.path.segments;
+        let disallowed = self.disallowed.iter().find(|(path, _)| {
+            segments.len() <= path.len()
+                && segments
+                    .iter()
+                    .rev()
+                    .zip(path.iter().rev())
+                    .all(|(segment, name)| segment.ident.name == *name)
+        });
+        if let Some((path, reason)) = disallowed {
+            let name = path.iter().map(|s| s.to_ident_string()).collect::<Vec<_>>().join("::");
+
+            span_lint_and_then(
+                cx,
+                DISALLOWED_MACRO,
+                mac.span(),
+                &format!("use of a disallowed macro `{}`", name),
+                |diag| {
+                    if let Some(reason) = reason {
+                        diag.note(reason);
+                    }
+                },
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use crate::utils::span_lint;
+use crate::utils::conf::DisallowedPath;
+use crate::utils::span_lint_and_then;
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -9,6 +10,10 @@ use rustc_span::Symbol;
 declare_clippy_lint! {
     /// **What it does:** Lints for specific trait methods defined in clippy.toml
     ///
+    /// The methods are configured with the paths where they're defined, e.g.
+    /// `regex::re_unicode::Regex::is_match`. A path can be given as a
+    /// `{ path = "..", reason = ".." }` table to show the reason in the warning.
+    ///
     /// **Why is this bad?** Some methods are undesirable in certain contexts,
     /// and it would be beneficial to lint for them as needed.
     ///
@@ -32,15 +37,16 @@ declare_clippy_lint! {
 
 #[derive(Clone, Debug)]
 pub struct DisallowedMethod {
-    disallowed: FxHashSet<Vec<Symbol>>,
+    /// The paths of the disallowed methods, with the reason from the configuration
+    disallowed: FxHashMap<Vec<Symbol>, Option<String>>,
 }
 
 impl DisallowedMethod {
-    pub fn new(disallowed: &FxHashSet<String>) -> Self {
+    pub fn new(disallowed: &[DisallowedPath]) -> Self {
         Self {
             disallowed: disallowed
                 .iter()
-                .map(|s| s.split("::").map(|seg| Symbol::intern(seg)).collect::<Vec<_>>())
+                .map(|path| (path.segments(), path.reason().map(ToString::to_string)))
                 .collect(),
         }
     }
@@ -49,23 +55,29 @@ impl DisallowedMethod {
 impl_lint_pass!(DisallowedMethod => [DISALLOWED_METHOD]);
 
 impl<'tcx> LateLintPass<'tcx> for DisallowedMethod {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::MethodCall(_path, _, _args, _) = &expr.kind {
             let def_id = cx.typeck_results().type_dependent_def_id(expr.hir_id).unwrap();
 
             let method_call = cx.get_def_path(def_id);
-            if self.disallowed.contains(&method_call) {
+            if let Some(reason) = self.disallowed.get(&method_call) {
                 let method = method_call
                     .iter()
                     .map(|s| s.to_ident_string())
                     .collect::<Vec<_>>()
                     .join("::");
 
-                span_lint(
+                span_lint_and_then(
                     cx,
                     DISALLOWED_METHOD,
                     expr.span,
                     &format!("use of a disallowed method `{}`", method),
+                    |diag| {
+                        if let Some(reason) = reason {
+                            diag.note(reason);
+                        }
+                    },
                 );
             }
         }
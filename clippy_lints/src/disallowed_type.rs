@@ -0,0 +1,109 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::span_lint_and_then;
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, ItemKind, Path, QPath, Ty, TyKind, UseKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Lints for uses of specific types defined in clippy.toml, in type
+    /// annotations and imports.
+    ///
+    /// The types are configured with the paths where they're defined, e.g.
+    /// `std::collections::hash::map::HashMap`. A path can be given as a
+    /// `{ path = "..", reason = ".." }` table to show the reason in the warning.
+    ///
+    /// **Why is this bad?** Some types are undesirable in certain contexts, e.g. a project
+    /// using a faster hash map everywhere, and it would be beneficial to lint for them as needed.
+    ///
+    /// **Known problems:** Struct expressions and patterns like `Foo { .. }` aren't checked.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-types = ["std::collections::hash::map::HashMap"]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use rustc_hash::FxHashMap;
+    ///
+    /// let map: FxHashMap<u32, u32> = FxHashMap::default();
+    /// ```
+    pub DISALLOWED_TYPE,
+    nursery,
+    "use of a disallowed type"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedType {
+    /// The paths of the disallowed types, with the reason from the configuration
+    disallowed: FxHashMap<Vec<Symbol>, Option<String>>,
+}
+
+impl DisallowedType {
+    pub fn new(disallowed: &[DisallowedPath]) -> Self {
+        Self {
+            disallowed: disallowed
+                .iter()
+                .map(|path| (path.segments(), path.reason().map(ToString::to_string)))
+                .collect(),
+        }
+    }
+
+    fn check_path(&self, cx: &LateContext<'_>, path: &Path<'_>, span: Span) {
+        let def_id = match path.res {
+            Res::Def(
+                DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::TyAlias | DefKind::ForeignTy,
+                def_id,
+            ) => def_id,
+            _ => return,
+        };
+        let type_path = cx.get_def_path(def_id);
+        if let Some(reason) = self.disallowed.get(&type_path) {
+            let name = type_path
+                .iter()
+                .map(|s| s.to_ident_string())
+                .collect::<Vec<_>>()
+                .join("::");
+
+            span_lint_and_then(
+                cx,
+                DISALLOWED_TYPE,
+                span,
+                &format!("use of a disallowed type `{}`", name),
+                |diag| {
+                    if let Some(reason) = reason {
+                        diag.note(reason);
+                    }
+                },
+            );
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedType => [DISALLOWED_TYPE]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedType {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Use(path, UseKind::Single) = item.kind {
+            self.check_path(cx, path, item.span);
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'_>) {
+        if let TyKind::Path(QPath::Resolved(_, path)) = ty.kind {
+            self.check_path(cx, path, ty.span);
+        }
+    }
+}
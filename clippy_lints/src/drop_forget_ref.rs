@@ -1,6 +1,6 @@
-use crate::utils::{is_copy, match_def_path, paths, qpath_res, span_lint_and_note};
+use crate::utils::{is_copy, is_type_lang_item, match_def_path, paths, qpath_res, span_lint_and_note};
 use if_chain::if_chain;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::{Expr, ExprKind, LangItem};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
@@ -97,6 +97,53 @@ declare_clippy_lint! {
     "calls to `std::mem::forget` with a value that implements Copy"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::mem::drop` with a value that doesn't need to
+    /// be dropped, neither implementing `Drop` nor containing a field which does.
+    ///
+    /// **Why is this bad?** Calling `std::mem::drop` on such a value does nothing but move it,
+    /// it misleads the reader into thinking that something is released.
+    ///
+    /// **Known problems:** The call may be used to end a borrow held by the value, which is
+    /// rarely needed since non-lexical lifetimes.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Span<'a> {
+    ///     text: &'a str,
+    /// }
+    ///
+    /// let span = Span { text: "a" };
+    /// std::mem::drop(span);
+    /// ```
+    pub DROP_NON_DROP,
+    complexity,
+    "calls to `std::mem::drop` with a value that doesn't need to be dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::mem::forget` with a value that doesn't need
+    /// to be dropped, neither implementing `Drop` nor containing a field which does.
+    ///
+    /// **Why is this bad?** Calling `std::mem::forget` on such a value does nothing but move it,
+    /// since there is no destructor to skip.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Span<'a> {
+    ///     text: &'a str,
+    /// }
+    ///
+    /// let span = Span { text: "a" };
+    /// std::mem::forget(span);
+    /// ```
+    pub FORGET_NON_DROP,
+    complexity,
+    "calls to `std::mem::forget` with a value that doesn't need to be dropped"
+}
+
 const DROP_REF_SUMMARY: &str = "calls to `std::mem::drop` with a reference instead of an owned value. \
                                 Dropping a reference does nothing.";
 const FORGET_REF_SUMMARY: &str = "calls to `std::mem::forget` with a reference instead of an owned value. \
@@ -105,10 +152,15 @@ const DROP_COPY_SUMMARY: &str = "calls to `std::mem::drop` with a value that imp
                                  Dropping a copy leaves the original intact.";
 const FORGET_COPY_SUMMARY: &str = "calls to `std::mem::forget` with a value that implements `Copy`. \
                                    Forgetting a copy leaves the original intact.";
+const DROP_NON_DROP_SUMMARY: &str = "calls to `std::mem::drop` with a value that doesn't need to be dropped. \
+                                     Dropping such a value only moves it.";
+const FORGET_NON_DROP_SUMMARY: &str = "calls to `std::mem::forget` with a value that doesn't need to be dropped. \
+                                       Forgetting such a value only moves it.";
 
-declare_lint_pass!(DropForgetRef => [DROP_REF, FORGET_REF, DROP_COPY, FORGET_COPY]);
+declare_lint_pass!(DropForgetRef => [DROP_REF, FORGET_REF, DROP_COPY, FORGET_COPY, DROP_NON_DROP, FORGET_NON_DROP]);
 
 impl<'tcx> LateLintPass<'tcx> for DropForgetRef {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             if let ExprKind::Call(ref path, ref args) = expr.kind;
@@ -153,6 +205,24 @@ impl<'tcx> LateLintPass<'tcx> for DropForgetRef {
                                        &msg,
                                        Some(arg.span),
                                        &format!("argument has type {}", arg_ty));
+                } else if !arg_ty.needs_drop(cx.tcx, cx.param_env)
+                    // dropping a `ManuallyDrop` is linted by `undropped_manually_drops`
+                    && !is_type_lang_item(cx, arg_ty, LangItem::ManuallyDrop) {
+                    if match_def_path(cx, def_id, &paths::DROP) {
+                        lint = DROP_NON_DROP;
+                        msg = DROP_NON_DROP_SUMMARY.to_string();
+                    } else if match_def_path(cx, def_id, &paths::MEM_FORGET) {
+                        lint = FORGET_NON_DROP;
+                        msg = FORGET_NON_DROP_SUMMARY.to_string();
+                    } else {
+                        return;
+                    }
+                    span_lint_and_note(cx,
+                                       lint,
+                                       expr.span,
+                                       &msg,
+                                       Some(arg.span),
+                                       &format!("argument has type `{}`", arg_ty));
                 }
             }
         }
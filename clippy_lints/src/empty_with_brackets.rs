@@ -0,0 +1,225 @@
+//! lint on enum variants that are declared with empty brackets
+
+use crate::utils::{get_parent_expr, in_macro, qpath_res, snippet_opt, span_lint_hir_and_then};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Crate, Expr, ExprKind, HirId, Item, ItemKind, Pat, PatKind, QPath, VariantData};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Finds enum variants without fields that are declared with empty brackets.
+    ///
+    /// **Why is this bad?** The empty brackets are redundant, and have to be repeated everywhere
+    /// the variant is constructed or matched on.
+    ///
+    /// **Known problems:** The suggestion removes the brackets from all uses of the variant in the
+    /// current crate, but other crates using a public enum will break. Variants whose constructor is
+    /// used as a function, e.g. in `.map(MyEnum::Variant)`, are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// enum MyEnum {
+    ///     HasData(u8),
+    ///     HasNoData(),
+    /// }
+    ///
+    /// let _ = MyEnum::HasNoData();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// enum MyEnum {
+    ///     HasData(u8),
+    ///     HasNoData,
+    /// }
+    ///
+    /// let _ = MyEnum::HasNoData;
+    /// ```
+    pub EMPTY_ENUM_VARIANTS_WITH_BRACKETS,
+    restriction,
+    "enum variants without fields declared with empty brackets"
+}
+
+/// An enum variant declared with empty brackets.
+struct EmptyVariant {
+    hir_id: HirId,
+    span: Span,
+    /// The span of the brackets in the declaration.
+    brackets: Span,
+    /// The `DefId`s the variant is resolved to, the one of the constructor only exists for `V()`.
+    def_ids: Vec<DefId>,
+}
+
+/// The uses of a variant, or of its constructor.
+#[derive(Default)]
+struct Uses {
+    /// The spans of the brackets at the uses which can be fixed.
+    brackets: Vec<Span>,
+    /// Whether the variant is used in a way the brackets can't be removed automatically, e.g. in
+    /// a macro call.
+    has_unfixable: bool,
+}
+
+#[derive(Default)]
+pub struct EmptyWithBrackets {
+    variants: Vec<EmptyVariant>,
+    uses: FxHashMap<DefId, Uses>,
+    /// The constructors that are used as functions, like `Variant` in `.map(Variant)`.
+    used_as_fn: FxHashSet<DefId>,
+}
+
+impl_lint_pass!(EmptyWithBrackets => [EMPTY_ENUM_VARIANTS_WITH_BRACKETS]);
+
+impl<'tcx> LateLintPass<'tcx> for EmptyWithBrackets {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Enum(ref def, _) = item.kind {
+            if in_macro(item.span) {
+                return;
+            }
+
+            for variant in def.variants {
+                let is_empty = match variant.data {
+                    VariantData::Struct(fields, _) | VariantData::Tuple(fields, _) => fields.is_empty(),
+                    VariantData::Unit(_) => false,
+                };
+                let brackets = variant.span.with_lo(variant.ident.span.hi());
+                if !is_empty || variant.disr_expr.is_some() || !has_only_brackets(cx, brackets) {
+                    continue;
+                }
+
+                let mut def_ids = vec![cx.tcx.hir().local_def_id(variant.id).to_def_id()];
+                if let Some(ctor_hir_id) = variant.data.ctor_hir_id() {
+                    def_ids.push(cx.tcx.hir().local_def_id(ctor_hir_id).to_def_id());
+                }
+                self.variants.push(EmptyVariant {
+                    hir_id: variant.id,
+                    span: variant.span,
+                    brackets,
+                    def_ids,
+                });
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::Call(func, []) => {
+                if let ExprKind::Path(ref qpath) = func.kind {
+                    let res = qpath_res(cx, qpath, func.hir_id);
+                    self.add_use(res, expr.span, func.span, false);
+                }
+            },
+            ExprKind::Struct(qpath, [], base) => {
+                let res = qpath_res(cx, qpath, expr.hir_id);
+                self.add_use(res, expr.span, qpath_span(qpath), base.is_some());
+            },
+            ExprKind::Path(ref qpath) => {
+                let is_callee = get_parent_expr(cx, expr).map_or(
+                    false,
+                    |parent| matches!(parent.kind, ExprKind::Call(func, _) if func.hir_id == expr.hir_id),
+                );
+                if let Res::Def(DefKind::Ctor(CtorOf::Variant, _), def_id) = qpath_res(cx, qpath, expr.hir_id) {
+                    if !is_callee && def_id.is_local() {
+                        self.used_as_fn.insert(def_id);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
+        match pat.kind {
+            PatKind::TupleStruct(ref qpath, [], _) | PatKind::Struct(ref qpath, [], _) => {
+                let res = qpath_res(cx, qpath, pat.hir_id);
+                self.add_use(res, pat.span, qpath_span(qpath), false);
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        for variant in &self.variants {
+            if variant.def_ids.iter().any(|def_id| self.used_as_fn.contains(def_id)) {
+                continue;
+            }
+
+            let mut applicability = if cx.access_levels.is_exported(variant.hir_id) {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let mut suggestion = vec![(variant.brackets, String::new())];
+            for uses in variant.def_ids.iter().filter_map(|def_id| self.uses.get(def_id)) {
+                if uses.has_unfixable {
+                    applicability = Applicability::MaybeIncorrect;
+                }
+                suggestion.extend(uses.brackets.iter().map(|span| (*span, String::new())));
+            }
+
+            span_lint_hir_and_then(
+                cx,
+                EMPTY_ENUM_VARIANTS_WITH_BRACKETS,
+                variant.hir_id,
+                variant.span,
+                "enum variant has empty brackets",
+                |diag| {
+                    diag.multipart_suggestion(
+                        "remove the brackets here and where the variant is used",
+                        suggestion,
+                        applicability,
+                    );
+                },
+            );
+        }
+    }
+}
+
+impl EmptyWithBrackets {
+    /// Records a use of a variant, where `span` is the whole expression or pattern and `path` the
+    /// path to the variant within it.
+    fn add_use(&mut self, res: Res, span: Span, path: Span, has_base: bool) {
+        let def_id = match res {
+            Res::Def(DefKind::Variant | DefKind::Ctor(CtorOf::Variant, _), def_id) if def_id.is_local() => def_id,
+            _ => return,
+        };
+        // Derived impls are expanded again after the brackets are removed
+        if is_from_derive(span) {
+            return;
+        }
+
+        let uses = self.uses.entry(def_id).or_default();
+        if in_macro(span) || has_base {
+            uses.has_unfixable = true;
+        } else {
+            uses.brackets.push(span.with_lo(path.hi()));
+        }
+    }
+}
+
+/// Checks if the span contains nothing but a pair of brackets, and whitespace.
+fn has_only_brackets(cx: &LateContext<'_>, span: Span) -> bool {
+    snippet_opt(cx, span).map_or(false, |snippet| {
+        let snippet: String = snippet.chars().filter(|c| !c.is_whitespace()).collect();
+        snippet == "()" || snippet == "{}"
+    })
+}
+
+fn is_from_derive(span: Span) -> bool {
+    matches!(
+        span.ctxt().outer_expn_data().kind,
+        ExpnKind::Macro(MacroKind::Derive, _)
+    )
+}
+
+fn qpath_span(qpath: &QPath<'_>) -> Span {
+    match qpath {
+        QPath::Resolved(_, path) => path.span,
+        QPath::TypeRelative(ty, segment) => ty.span.to(segment.ident.span),
+        QPath::LangItem(_, span) => *span,
+    }
+}
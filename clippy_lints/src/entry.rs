@@ -1,5 +1,5 @@
 use crate::utils::SpanlessEq;
-use crate::utils::{get_item_name, higher, is_type_diagnostic_item, match_type, paths, snippet, snippet_opt};
+use crate::utils::{get_item_name, higher, is_type_std_item, snippet, snippet_opt, std_items};
 use crate::utils::{snippet_with_applicability, span_lint_and_then};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -108,10 +108,10 @@ fn check_cond<'a>(cx: &LateContext<'_>, check: &'a Expr<'a>) -> Option<(&'static
             let map = &params[0];
             let obj_ty = cx.typeck_results().expr_ty(map).peel_refs();
 
-            return if match_type(cx, obj_ty, &paths::BTREEMAP) {
+            return if is_type_std_item(cx, obj_ty, &std_items::BTREEMAP) {
                 Some(("BTreeMap", map, key))
             }
-            else if is_type_diagnostic_item(cx, obj_ty, sym!(hashmap_type)) {
+            else if is_type_std_item(cx, obj_ty, &std_items::HASHMAP) {
                 Some(("HashMap", map, key))
             }
             else {
@@ -2,7 +2,9 @@
 
 use crate::utils::{camel_case, is_present_in_source};
 use crate::utils::{span_lint, span_lint_and_help};
-use rustc_ast::ast::{EnumDef, Item, ItemKind, VisibilityKind};
+use rustc_ast::ast::{Crate, EnumDef, Item, ItemKind, UseTree, UseTreeKind, VisibilityKind};
+use rustc_ast::ptr::P;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_lint::{EarlyContext, EarlyLintPass, Lint};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
@@ -74,7 +76,13 @@ declare_clippy_lint! {
     ///
     /// **Why is this bad?** It requires the user to type the module name twice.
     ///
-    /// **Known problems:** None.
+    /// Names which only differ from the module's name by a prefix in `allowed-prefixes` (by
+    /// default `to`, `as`, `into`, `from`, `try_into` and `try_from`) or a suffix in
+    /// `allowed-suffixes` are allowed, like `to_cake` in `mod cake`. Items which are re-exported by a
+    /// `pub use` outside of their module aren't linted, as their public path doesn't repeat the name.
+    ///
+    /// **Known problems:** Re-exports are matched by the names of the module and the item, so a
+    /// re-export of an item from a different module with the same name also counts.
     ///
     /// **Example:**
     /// ```rust
@@ -126,14 +134,65 @@ declare_clippy_lint! {
 pub struct EnumVariantNames {
     modules: Vec<(Symbol, String)>,
     threshold: u64,
+    /// The allowed prefixes and suffixes of names repeating the module's name, in CamelCase.
+    allowed_prefixes: FxHashSet<String>,
+    allowed_suffixes: FxHashSet<String>,
+    /// The items re-exported by `pub use`, as the names of their module and the item.
+    reexports: FxHashSet<(Symbol, Symbol)>,
+    /// The modules whose items are all re-exported by `pub use module::*`.
+    glob_reexports: FxHashSet<Symbol>,
 }
 
 impl EnumVariantNames {
     #[must_use]
-    pub fn new(threshold: u64) -> Self {
+    pub fn new(threshold: u64, allowed_prefixes: &[String], allowed_suffixes: &[String]) -> Self {
         Self {
             modules: Vec::new(),
             threshold,
+            allowed_prefixes: allowed_prefixes.iter().map(|s| to_camel_case(s)).collect(),
+            allowed_suffixes: allowed_suffixes.iter().map(|s| to_camel_case(s)).collect(),
+            reexports: FxHashSet::default(),
+            glob_reexports: FxHashSet::default(),
+        }
+    }
+
+    fn is_reexported(&self, mod_name: Symbol, item_name: Symbol) -> bool {
+        self.glob_reexports.contains(&mod_name) || self.reexports.contains(&(mod_name, item_name))
+    }
+
+    /// Collects the `pub use` items in the given items and the modules nested in them.
+    fn collect_reexports(&mut self, items: &[P<Item>]) {
+        for item in items {
+            match item.kind {
+                ItemKind::Use(ref use_tree) if !matches!(item.vis.kind, VisibilityKind::Inherited) => {
+                    self.collect_use_tree(use_tree, None);
+                },
+                ItemKind::Mod(ref module) => self.collect_reexports(&module.items),
+                _ => {},
+            }
+        }
+    }
+
+    fn collect_use_tree(&mut self, use_tree: &UseTree, parent: Option<Symbol>) {
+        let mut segments = use_tree.prefix.segments.iter().map(|segment| segment.ident.name);
+        match use_tree.kind {
+            UseTreeKind::Simple(..) => {
+                let item = segments.next_back();
+                if let (Some(module), Some(item)) = (segments.next_back().or(parent), item) {
+                    self.reexports.insert((module, item));
+                }
+            },
+            UseTreeKind::Glob => {
+                if let Some(module) = segments.next_back().or(parent) {
+                    self.glob_reexports.insert(module);
+                }
+            },
+            UseTreeKind::Nested(ref nested) => {
+                let module = segments.next_back().or(parent);
+                for (tree, _) in nested {
+                    self.collect_use_tree(tree, module);
+                }
+            },
         }
     }
 }
@@ -261,6 +320,10 @@ fn to_camel_case(item_name: &str) -> String {
 }
 
 impl EarlyLintPass for EnumVariantNames {
+    fn check_crate(&mut self, _cx: &EarlyContext<'_>, krate: &Crate) {
+        self.collect_reexports(&krate.module.items);
+    }
+
     fn check_item_post(&mut self, _cx: &EarlyContext<'_>, _item: &Item) {
         let last = self.modules.pop();
         assert!(last.is_some());
@@ -285,14 +348,20 @@ impl EarlyLintPass for EnumVariantNames {
                             );
                         }
                     }
-                    if item.vis.kind.is_pub() {
+                    if item.vis.kind.is_pub() && !self.is_reexported(*mod_name, item.ident.name) {
                         let matching = partial_match(mod_camel, &item_camel);
                         let rmatching = partial_rmatch(mod_camel, &item_camel);
                         let nchars = mod_camel.chars().count();
+                        let item_camel_chars = item_camel.chars().count();
 
                         let is_word_beginning = |c: char| c == '_' || c.is_uppercase() || c.is_numeric();
+                        let suffix: String = item_camel.chars().skip(nchars).collect();
+                        let prefix: String = item_camel
+                            .chars()
+                            .take(item_camel_chars.saturating_sub(nchars))
+                            .collect();
 
-                        if matching == nchars {
+                        if matching == nchars && !self.allowed_suffixes.contains(&suffix) {
                             match item_camel.chars().nth(nchars) {
                                 Some(c) if is_word_beginning(c) => span_lint(
                                     cx,
@@ -303,7 +372,7 @@ impl EarlyLintPass for EnumVariantNames {
                                 _ => (),
                             }
                         }
-                        if rmatching == nchars {
+                        if rmatching == nchars && !self.allowed_prefixes.contains(&prefix) {
                             span_lint(
                                 cx,
                                 MODULE_NAME_REPETITIONS,
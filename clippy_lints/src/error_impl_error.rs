@@ -0,0 +1,179 @@
+use crate::utils::{in_macro, match_def_path, paths, span_lint_hir_and_then};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
+use rustc_hir::{Crate, HirId, Item, ItemKind, UseKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for exported types named `Error` that implement
+    /// `std::error::Error`, in crates which export more than one of them.
+    ///
+    /// **Why is this bad?** Users of the crate have to rename the types when they import more than
+    /// one of them, or the `Error` trait itself. Distinctive names like `ParseError` can be
+    /// imported directly.
+    ///
+    /// **Known problems:** Types which are re-exported under a different name by `pub use` are
+    /// only ignored if their own module isn't public. The number of `Error` types a crate can export
+    /// can be configured with `max-exported-error-types`.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// pub mod parse {
+    ///     #[derive(Debug)]
+    ///     pub struct Error;
+    ///
+    ///     impl std::error::Error for Error {}
+    /// }
+    ///
+    /// pub mod io {
+    ///     #[derive(Debug)]
+    ///     pub struct Error;
+    ///
+    ///     impl std::error::Error for Error {}
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// pub mod parse {
+    ///     #[derive(Debug)]
+    ///     pub struct ParseError;
+    ///
+    ///     impl std::error::Error for ParseError {}
+    /// }
+    ///
+    /// pub mod io {
+    ///     #[derive(Debug)]
+    ///     pub struct IoError;
+    ///
+    ///     impl std::error::Error for IoError {}
+    /// }
+    /// ```
+    pub ERROR_IMPL_ERROR,
+    restriction,
+    "exported types named `Error` that implement `Error`"
+}
+
+/// An exported type named `Error`.
+struct ErrorType {
+    def_id: DefId,
+    hir_id: HirId,
+    span: Span,
+    in_public_module: bool,
+    /// The name to suggest, made from the name of the module, e.g. `ParseError` in `parse`.
+    suggested_name: String,
+}
+
+pub struct ErrorImplError {
+    max_exported_error_types: u64,
+    error_types: Vec<ErrorType>,
+    /// The types implementing `std::error::Error`.
+    impls_error: FxHashSet<DefId>,
+    /// The types which are re-exported under a different name.
+    renamed: FxHashSet<DefId>,
+}
+
+impl ErrorImplError {
+    pub fn new(max_exported_error_types: u64) -> Self {
+        Self {
+            max_exported_error_types,
+            error_types: Vec::new(),
+            impls_error: FxHashSet::default(),
+            renamed: FxHashSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(ErrorImplError => [ERROR_IMPL_ERROR]);
+
+impl<'tcx> LateLintPass<'tcx> for ErrorImplError {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        match item.kind {
+            ItemKind::Struct(..) | ItemKind::Enum(..) | ItemKind::Union(..) => {
+                if item.ident.as_str() == "Error" && !in_macro(item.span) && cx.access_levels.is_exported(item.hir_id) {
+                    let module = cx.tcx.parent_module(item.hir_id);
+                    let module_name = if module.local_def_index == CRATE_DEF_INDEX {
+                        cx.tcx.crate_name(LOCAL_CRATE)
+                    } else {
+                        cx.tcx.item_name(module.to_def_id())
+                    };
+                    self.error_types.push(ErrorType {
+                        def_id: item.hir_id.owner.to_def_id(),
+                        hir_id: item.hir_id,
+                        span: item.ident.span,
+                        in_public_module: cx
+                            .access_levels
+                            .is_exported(cx.tcx.hir().local_def_id_to_hir_id(module)),
+                        suggested_name: format!("{}Error", to_camel_case(&module_name.as_str())),
+                    });
+                }
+            },
+            ItemKind::Impl {
+                of_trait: Some(ref trait_ref),
+                ..
+            } => {
+                let is_error_impl = trait_ref
+                    .trait_def_id()
+                    .map_or(false, |def_id| match_def_path(cx, def_id, &paths::ERROR_TRAIT));
+                if is_error_impl {
+                    if let Some(adt) = cx.tcx.type_of(item.hir_id.owner.to_def_id()).ty_adt_def() {
+                        self.impls_error.insert(adt.did);
+                    }
+                }
+            },
+            ItemKind::Use(path, UseKind::Single) => {
+                if let Res::Def(_, def_id) = path.res {
+                    if item.ident.as_str() != "Error" && cx.access_levels.is_exported(item.hir_id) {
+                        self.renamed.insert(def_id);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        let error_types: Vec<&ErrorType> = self
+            .error_types
+            .iter()
+            .filter(|ty| self.impls_error.contains(&ty.def_id))
+            .filter(|ty| ty.in_public_module || !self.renamed.contains(&ty.def_id))
+            .collect();
+        if error_types.len() as u64 <= self.max_exported_error_types {
+            return;
+        }
+
+        for ty in &error_types {
+            span_lint_hir_and_then(
+                cx,
+                ERROR_IMPL_ERROR,
+                ty.hir_id,
+                ty.span,
+                "exported type named `Error` that implements `Error`",
+                |diag| {
+                    if error_types.len() > 1 {
+                        diag.note(&format!("the crate exports {} types named `Error`", error_types.len()));
+                    }
+                    diag.help(&format!(
+                        "consider using a more distinctive name, like `{}`",
+                        ty.suggested_name
+                    ));
+                },
+            );
+        }
+    }
+}
+
+/// Converts a snake case module name to camel case, e.g. `parse_utils` to `ParseUtils`.
+fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+        })
+        .collect()
+}
@@ -1,13 +1,17 @@
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::{def_id, Expr, ExprKind, Param, PatKind, QPath};
+use rustc_hir::def::Res;
+use rustc_hir::{def_id, BindingAnnotation, Expr, ExprKind, Node, Param, PatKind, QPath, Unsafety};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{self, Ty};
+use rustc_middle::ty::fold::{TypeFoldable, TypeVisitor};
+use rustc_middle::ty::subst::Subst;
+use rustc_middle::ty::{self, ClosureKind, FnSig, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use std::ops::ControlFlow;
 
 use crate::utils::{
-    implements_trait, is_adjusted, iter_input_pats, snippet_opt, span_lint_and_sugg, span_lint_and_then,
+    implements_trait, is_adjusted, is_copy, iter_input_pats, snippet_opt, span_lint_and_sugg, span_lint_and_then,
     type_is_unsafe_function,
 };
 
@@ -24,6 +28,9 @@ declare_clippy_lint! {
     /// effect runs.
     /// See rust-lang/rust-clippy#1439 for more details.
     ///
+    /// Calls of local closures which aren't `Copy` are suggested to be replaced by a
+    /// reference to the closure, which doesn't compile if a `'static` closure is needed.
+    ///
     /// **Example:**
     /// ```rust,ignore
     /// // Bad
@@ -85,6 +92,13 @@ fn check_closure(cx: &LateContext<'_>, expr: &Expr<'_>) {
     if let ExprKind::Closure(_, ref decl, eid, _, _) = expr.kind {
         let body = cx.tcx.hir().body(eid);
         let ex = &body.value;
+        let closure_sig = match cx.typeck_results().expr_ty(expr).kind() {
+            ty::Closure(_, substs) => cx
+                .tcx
+                .signature_unclosure(substs.as_closure().sig(), Unsafety::Normal)
+                .skip_binder(),
+            _ => return,
+        };
 
         if_chain!(
             if let ExprKind::Call(ref caller, ref args) = ex.kind;
@@ -105,14 +119,19 @@ fn check_closure(cx: &LateContext<'_>, expr: &Expr<'_>) {
 
             if compare_inputs(&mut iter_input_pats(decl, body), &mut args.iter());
 
+            if let Some(callee_sig) = callee_sig(cx, fn_ty);
+            if !has_late_bound_to_non_late_bound_regions(closure_sig, callee_sig);
+
+            if let Some((prefix, applicability)) = callee_prefix(cx, caller, fn_ty);
+
             then {
                 span_lint_and_then(cx, REDUNDANT_CLOSURE, expr.span, "redundant closure found", |diag| {
                     if let Some(snippet) = snippet_opt(cx, caller.span) {
                         diag.span_suggestion(
                             expr.span,
                             "remove closure as shown",
-                            snippet,
-                            Applicability::MachineApplicable,
+                            format!("{}{}", prefix, snippet),
+                            applicability,
                         );
                     }
                 });
@@ -133,7 +152,11 @@ fn check_closure(cx: &LateContext<'_>, expr: &Expr<'_>) {
 
             if compare_inputs(&mut iter_input_pats(decl, body), &mut args.iter());
 
-            if let Some(name) = get_ufcs_type_name(cx, method_def_id, &args[0]);
+            let method_substs = cx.typeck_results().node_substs(ex.hir_id);
+            let method_sig = cx.tcx.fn_sig(method_def_id).subst(cx.tcx, method_substs).skip_binder();
+            if !has_late_bound_to_non_late_bound_regions(closure_sig, method_sig);
+
+            if let Some(name) = get_ufcs_type_name(cx, method_def_id, method_substs, &args[0]);
 
             then {
                 span_lint_and_sugg(
@@ -150,14 +173,106 @@ fn check_closure(cx: &LateContext<'_>, expr: &Expr<'_>) {
     }
 }
 
+/// Returns the signature of a called function, function pointer or closure.
+fn callee_sig<'tcx>(cx: &LateContext<'tcx>, fn_ty: Ty<'tcx>) -> Option<FnSig<'tcx>> {
+    match fn_ty.kind() {
+        ty::FnDef(..) | ty::FnPtr(_) => Some(fn_ty.fn_sig(cx.tcx).skip_binder()),
+        ty::Closure(_, substs) => Some(
+            cx.tcx
+                .signature_unclosure(substs.as_closure().sig(), Unsafety::Normal)
+                .skip_binder(),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns the prefix needed to pass the callee instead of the closure, and the applicability of
+/// the suggestion. Local closures which aren't `Copy` can't be moved into the call, as they may be
+/// used later, so a reference to them is passed instead. Returns `None` if that is not possible,
+/// for `FnMut` closures in immutable bindings.
+fn callee_prefix<'tcx>(
+    cx: &LateContext<'tcx>,
+    caller: &Expr<'_>,
+    fn_ty: Ty<'tcx>,
+) -> Option<(&'static str, Applicability)> {
+    if_chain! {
+        if let ty::Closure(_, substs) = fn_ty.kind();
+        if !is_copy(cx, fn_ty);
+        if let ExprKind::Path(QPath::Resolved(None, path)) = caller.kind;
+        if let Res::Local(hir_id) = path.res;
+        then {
+            return match substs.as_closure().kind() {
+                ClosureKind::Fn => Some(("&", Applicability::MaybeIncorrect)),
+                ClosureKind::FnMut => match cx.tcx.hir().get(hir_id) {
+                    Node::Binding(pat) if matches!(pat.kind, PatKind::Binding(BindingAnnotation::Mutable, ..)) => {
+                        Some(("&mut ", Applicability::MaybeIncorrect))
+                    },
+                    _ => None,
+                },
+                // the closure can only be called once anyway
+                ClosureKind::FnOnce => Some(("", Applicability::MachineApplicable)),
+            };
+        }
+    }
+    Some(("", Applicability::MachineApplicable))
+}
+
+struct RegionCollector<'tcx>(Vec<ty::Region<'tcx>>);
+
+impl<'tcx> TypeVisitor<'tcx> for RegionCollector<'tcx> {
+    type BreakTy = ();
+
+    fn visit_region(&mut self, region: ty::Region<'tcx>) -> ControlFlow<Self::BreakTy> {
+        self.0.push(region);
+        ControlFlow::CONTINUE
+    }
+}
+
+/// Returns the regions of the type, in the order they appear in.
+fn regions<'tcx>(ty: Ty<'tcx>) -> Vec<ty::Region<'tcx>> {
+    let mut collector = RegionCollector(Vec::new());
+    let _ = ty.visit_with(&mut collector);
+    collector.0
+}
+
+/// Checks if a late-bound region of the closure signature is not late-bound in the signature of
+/// the callee, so that the callee is less general than the closure. E.g. `generic::<&'a u8>`
+/// only accepts references with the lifetime `'a`, while `|x: &u8| generic(x)` accepts all.
+fn has_late_bound_to_non_late_bound_regions(closure_sig: FnSig<'_>, callee_sig: FnSig<'_>) -> bool {
+    closure_sig
+        .inputs()
+        .iter()
+        .zip(callee_sig.inputs())
+        .any(|(closure_ty, callee_ty)| {
+            let closure_regions = regions(closure_ty);
+            let callee_regions = regions(callee_ty);
+
+            // the types only differ in their regions, as the arguments are not adjusted
+            closure_regions.len() != callee_regions.len()
+                || closure_regions
+                    .iter()
+                    .zip(callee_regions)
+                    .any(|(closure_region, callee_region)| {
+                        matches!(closure_region, ty::ReLateBound(..)) && !matches!(callee_region, ty::ReLateBound(..))
+                    })
+        })
+}
+
 /// Tries to determine the type for universal function call to be used instead of the closure
-fn get_ufcs_type_name(cx: &LateContext<'_>, method_def_id: def_id::DefId, self_arg: &Expr<'_>) -> Option<String> {
+fn get_ufcs_type_name<'tcx>(
+    cx: &LateContext<'tcx>,
+    method_def_id: def_id::DefId,
+    method_substs: ty::subst::SubstsRef<'tcx>,
+    self_arg: &Expr<'_>,
+) -> Option<String> {
     let expected_type_of_self = &cx.tcx.fn_sig(method_def_id).inputs_and_output().skip_binder()[0];
     let actual_type_of_self = &cx.typeck_results().node_type(self_arg.hir_id);
 
     if let Some(trait_id) = cx.tcx.trait_of_item(method_def_id) {
+        // the parameters of the trait, without `Self` and the parameters of the method
+        let trait_params = &method_substs[1..cx.tcx.generics_of(trait_id).count()];
         if match_borrow_depth(expected_type_of_self, &actual_type_of_self)
-            && implements_trait(cx, actual_type_of_self, trait_id, &[])
+            && implements_trait(cx, actual_type_of_self, trait_id, trait_params)
         {
             return Some(cx.tcx.def_path_str(trait_id));
         }
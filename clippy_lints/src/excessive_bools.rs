@@ -1,8 +1,11 @@
-use crate::utils::{attr_by_name, in_macro, match_path_ast, span_lint_and_help};
-use rustc_ast::ast::{AssocItemKind, Extern, FnSig, Item, ItemKind, Ty, TyKind};
-use rustc_lint::{EarlyContext, EarlyLintPass};
+use crate::utils::{attr_by_name, in_macro, is_trait_impl_item, span_lint_and_help};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, FnHeader, HirId, Item, ItemKind, TraitFn, TraitItem, TraitItemKind, Ty};
+use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+use rustc_typeck::hir_ty_to_ty;
 
 use std::convert::TryInto;
 
@@ -17,6 +20,9 @@ declare_clippy_lint! {
     /// from refactoring into two-variant enums for better
     /// readability and API.
     ///
+    /// Fields are counted through type aliases, e.g. a field of type
+    /// `type Flag = bool` is a bool, too.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -50,8 +56,9 @@ declare_clippy_lint! {
     /// are confusing and error prone, because it's
     /// hard to remember argument order and you have
     /// no type system support to back you up. Using
-    /// two-variant enums instead of bools often makes
-    /// API easier to use.
+    /// two-variant enums instead of bools, or passing
+    /// the flags in a builder or options struct, often
+    /// makes API easier to use.
     ///
     /// **Known problems:** None.
     ///
@@ -94,17 +101,15 @@ impl ExcessiveBools {
         }
     }
 
-    fn check_fn_sig(&self, cx: &EarlyContext<'_>, fn_sig: &FnSig, span: Span) {
-        match fn_sig.header.ext {
-            Extern::Implicit | Extern::Explicit(_) => return,
-            Extern::None => (),
+    fn check_fn_decl(&self, cx: &LateContext<'_>, header: FnHeader, decl: &FnDecl<'_>, span: Span) {
+        if header.abi != Abi::Rust || in_macro(span) {
+            return;
         }
 
-        let fn_sig_bools = fn_sig
-            .decl
+        let fn_sig_bools = decl
             .inputs
             .iter()
-            .filter(|param| is_bool_ty(&param.ty))
+            .filter(|ty| is_bool_ty(cx, ty))
             .count()
             .try_into()
             .unwrap();
@@ -115,7 +120,7 @@ impl ExcessiveBools {
                 span,
                 &format!("more than {} bools in function parameters", self.max_fn_params_bools),
                 None,
-                "consider refactoring bools into two-variant enums",
+                "consider refactoring bools into two-variant enums or passing them in a builder or options struct",
             );
         }
     }
@@ -123,54 +128,67 @@ impl ExcessiveBools {
 
 impl_lint_pass!(ExcessiveBools => [STRUCT_EXCESSIVE_BOOLS, FN_PARAMS_EXCESSIVE_BOOLS]);
 
-fn is_bool_ty(ty: &Ty) -> bool {
-    if let TyKind::Path(None, path) = &ty.kind {
-        return match_path_ast(path, &["bool"]);
-    }
-    false
+/// Checks if the type is `bool`, also through type aliases.
+fn is_bool_ty(cx: &LateContext<'_>, ty: &Ty<'_>) -> bool {
+    hir_ty_to_ty(cx.tcx, ty).is_bool()
 }
 
-impl EarlyLintPass for ExcessiveBools {
-    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+impl<'tcx> LateLintPass<'tcx> for ExcessiveBools {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
         if in_macro(item.span) {
             return;
         }
-        match &item.kind {
-            ItemKind::Struct(variant_data, _) => {
-                if attr_by_name(&item.attrs, "repr").is_some() {
-                    return;
-                }
+        if let ItemKind::Struct(variant_data, _) = &item.kind {
+            if attr_by_name(item.attrs, "repr").is_some() {
+                return;
+            }
 
-                let struct_bools = variant_data
-                    .fields()
-                    .iter()
-                    .filter(|field| is_bool_ty(&field.ty))
-                    .count()
-                    .try_into()
-                    .unwrap();
-                if self.max_struct_bools < struct_bools {
-                    span_lint_and_help(
-                        cx,
-                        STRUCT_EXCESSIVE_BOOLS,
-                        item.span,
-                        &format!("more than {} bools in a struct", self.max_struct_bools),
-                        None,
-                        "consider using a state machine or refactoring bools into two-variant enums",
-                    );
-                }
-            },
-            ItemKind::Impl {
-                of_trait: None, items, ..
+            let struct_bools = variant_data
+                .fields()
+                .iter()
+                .filter(|field| is_bool_ty(cx, field.ty))
+                .count()
+                .try_into()
+                .unwrap();
+            if self.max_struct_bools < struct_bools {
+                span_lint_and_help(
+                    cx,
+                    STRUCT_EXCESSIVE_BOOLS,
+                    item.span,
+                    &format!("more than {} bools in a struct", self.max_struct_bools),
+                    None,
+                    "consider using a state machine or refactoring bools into two-variant enums",
+                );
             }
-            | ItemKind::Trait(_, _, _, _, items) => {
-                for item in items {
-                    if let AssocItemKind::Fn(_, fn_sig, _, _) = &item.kind {
-                        self.check_fn_sig(cx, fn_sig, item.span);
-                    }
-                }
-            },
-            ItemKind::Fn(_, fn_sig, _, _) => self.check_fn_sig(cx, fn_sig, item.span),
-            _ => (),
         }
     }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        // Provided methods are checked in `check_fn`
+        if let TraitItemKind::Fn(fn_sig, TraitFn::Required(_)) = &item.kind {
+            self.check_fn_decl(cx, fn_sig.header, fn_sig.decl, item.span);
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        let header = match kind {
+            FnKind::ItemFn(.., header, _, _) => header,
+            FnKind::Method(_, fn_sig, ..) => fn_sig.header,
+            FnKind::Closure(..) => return,
+        };
+        // The signature of trait impls is given by the trait
+        if is_trait_impl_item(cx, hir_id) {
+            return;
+        }
+
+        self.check_fn_decl(cx, header, decl, span);
+    }
 }
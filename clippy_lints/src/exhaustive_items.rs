@@ -0,0 +1,118 @@
+use crate::utils::{in_macro, indent_of, span_lint_and_then};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Warns on any exported `enum`s that are not tagged `#[non_exhaustive]`
+    ///
+    /// **Why is this bad?** Exhaustive enums are typically fine, but a project which does
+    /// not wish to make a stability commitment around exported enums may wish to
+    /// disable them by default.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// enum Foo {
+    ///     Bar,
+    ///     Baz
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[non_exhaustive]
+    /// enum Foo {
+    ///     Bar,
+    ///     Baz
+    /// }
+    /// ```
+    pub EXHAUSTIVE_ENUMS,
+    restriction,
+    "detects exported enums that have not been marked #[non_exhaustive]"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Warns on any exported `struct`s with only public fields that are not
+    /// tagged `#[non_exhaustive]`
+    ///
+    /// **Why is this bad?** Exhaustive structs are typically fine, but a project which does
+    /// not wish to make a stability commitment around exported structs may wish to
+    /// disable them by default. Structs with private fields can't be constructed outside of the
+    /// crate anyway, so they aren't linted.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// struct Foo {
+    ///     pub bar: u8,
+    ///     pub baz: String,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[non_exhaustive]
+    /// struct Foo {
+    ///     pub bar: u8,
+    ///     pub baz: String,
+    /// }
+    /// ```
+    pub EXHAUSTIVE_STRUCTS,
+    restriction,
+    "detects exported structs that have not been marked #[non_exhaustive]"
+}
+
+pub struct ExhaustiveItems {
+    allowed: FxHashSet<String>,
+}
+
+impl ExhaustiveItems {
+    pub fn new(allowed: FxHashSet<String>) -> Self {
+        Self { allowed }
+    }
+}
+
+impl_lint_pass!(ExhaustiveItems => [EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS]);
+
+impl LateLintPass<'_> for ExhaustiveItems {
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        let (lint, msg) = match item.kind {
+            ItemKind::Enum(..) => (EXHAUSTIVE_ENUMS, "exported enums should not be exhaustive"),
+            ItemKind::Struct(ref data, _) => {
+                if data.fields().iter().any(|field| !field.vis.node.is_pub()) {
+                    return;
+                }
+                (EXHAUSTIVE_STRUCTS, "exported structs should not be exhaustive")
+            },
+            _ => return,
+        };
+
+        if in_macro(item.span)
+            || !cx.access_levels.is_exported(item.hir_id)
+            || item.attrs.iter().any(|attr| attr.has_name(sym::non_exhaustive))
+        {
+            return;
+        }
+
+        let def_id = item.hir_id.owner.to_def_id();
+        if self.allowed.contains(&*item.ident.as_str()) || self.allowed.contains(&cx.tcx.def_path_str(def_id)) {
+            return;
+        }
+
+        span_lint_and_then(cx, lint, item.span, msg, |diag| {
+            let indent = " ".repeat(indent_of(cx, item.span).unwrap_or(0));
+            diag.span_suggestion(
+                item.span.shrink_to_lo(),
+                "try adding #[non_exhaustive]",
+                format!("#[non_exhaustive]\n{}", indent),
+                Applicability::MachineApplicable,
+            );
+        });
+    }
+}
@@ -0,0 +1,137 @@
+//! lint on feature names with negative or redundant prefixes
+
+use crate::utils::{run_lints, span_lint_and_help};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{hir_id::CRATE_HIR_ID, Crate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::DUMMY_SP;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for feature names with prefix `use-` or `with-`.
+    ///
+    /// **Why is this bad?** These prefixes have no significant meaning, every feature enables or
+    /// uses something.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # The `Cargo.toml` with feature name redundancy
+    /// [features]
+    /// default = ["use-abc", "with-def"]
+    /// use-abc = []
+    /// with-def = []
+    /// ```
+    ///
+    /// Use instead:
+    /// ```toml
+    /// [features]
+    /// default = ["abc", "def"]
+    /// abc = []
+    /// def = []
+    /// ```
+    pub REDUNDANT_FEATURE_NAMES,
+    cargo,
+    "usage of a redundant feature name"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for negative feature names with prefix `no-` or `not-`.
+    ///
+    /// **Why is this bad?** Features are supposed to be additive, and negatively-named features
+    /// violate it. Cargo unifies the features of a crate used by multiple dependents, so enabling
+    /// a negative feature for one of them disables functionality for all of them.
+    ///
+    /// **Known problems:** Features which can't be made additive can be allowed with the
+    /// `allowed-feature-names` configuration option.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # The `Cargo.toml` with negative feature names
+    /// [features]
+    /// default = []
+    /// no-abc = []
+    /// not-def = []
+    /// ```
+    ///
+    /// Use instead:
+    /// ```toml
+    /// [features]
+    /// default = ["abc", "def"]
+    /// abc = []
+    /// def = []
+    /// ```
+    pub NEGATIVE_FEATURE_NAMES,
+    cargo,
+    "usage of a negative feature name"
+}
+
+#[derive(Clone)]
+pub struct FeatureName {
+    allowed_feature_names: FxHashSet<String>,
+}
+
+impl FeatureName {
+    pub fn new(allowed_feature_names: FxHashSet<String>) -> Self {
+        Self { allowed_feature_names }
+    }
+}
+
+impl_lint_pass!(FeatureName => [REDUNDANT_FEATURE_NAMES, NEGATIVE_FEATURE_NAMES]);
+
+static PREFIXES: [&str; 8] = ["no-", "no_", "not-", "not_", "use-", "use_", "with-", "with_"];
+
+fn lint(cx: &LateContext<'_>, feature: &str, prefix: &str) {
+    let is_negative = prefix.starts_with("no");
+    span_lint_and_help(
+        cx,
+        if is_negative {
+            NEGATIVE_FEATURE_NAMES
+        } else {
+            REDUNDANT_FEATURE_NAMES
+        },
+        DUMMY_SP,
+        &format!(
+            "the `{}` prefix in the feature name `{}` is {}",
+            prefix,
+            feature,
+            if is_negative { "negative" } else { "redundant" }
+        ),
+        None,
+        &format!(
+            "consider renaming the feature to `{}`{}",
+            feature.strip_prefix(prefix).unwrap_or(feature),
+            if is_negative {
+                ", but make sure the feature adds functionality"
+            } else {
+                ""
+            }
+        ),
+    );
+}
+
+impl LateLintPass<'_> for FeatureName {
+    fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
+        if !run_lints(cx, &[REDUNDANT_FEATURE_NAMES, NEGATIVE_FEATURE_NAMES], CRATE_HIR_ID) {
+            return;
+        }
+
+        let metadata = unwrap_cargo_metadata!(cx, REDUNDANT_FEATURE_NAMES, false);
+
+        for package in metadata.packages {
+            let mut features: Vec<&String> = package.features.keys().collect();
+            features.sort();
+
+            for feature in features {
+                if self.allowed_feature_names.contains(feature) {
+                    continue;
+                }
+
+                if let Some(prefix) = PREFIXES.iter().find(|prefix| feature.starts_with(*prefix)) {
+                    lint(cx, feature, prefix);
+                }
+            }
+        }
+    }
+}
@@ -42,6 +42,7 @@ declare_clippy_lint! {
 declare_lint_pass!(FloatEqualityWithoutAbs => [FLOAT_EQUALITY_WITHOUT_ABS]);
 
 impl<'tcx> LateLintPass<'tcx> for FloatEqualityWithoutAbs {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         let lhs;
         let rhs;
@@ -1,7 +1,7 @@
 use crate::utils::paths;
 use crate::utils::{
-    is_expn_of, is_type_diagnostic_item, last_path_segment, match_def_path, match_function_call, snippet, snippet_opt,
-    span_lint_and_then,
+    is_direct_expn_of, is_expn_of, is_type_diagnostic_item, last_path_segment, match_def_path, match_function_call,
+    snippet, snippet_opt, span_lint_and_then, LintScope,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
@@ -57,7 +57,7 @@ impl<'tcx> LateLintPass<'tcx> for UselessFormat {
     }
 }
 
-fn span_useless_format<T: LintContext>(cx: &T, span: Span, help: &str, mut sugg: String) {
+fn span_useless_format<T: LintContext + LintScope>(cx: &T, span: Span, help: &str, mut sugg: String) {
     let to_replace = span.source_callsite();
 
     // The callsite span contains the statement semicolon for some reason.
@@ -95,6 +95,10 @@ fn on_argumentv1_new<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arms: &
             if *ty.kind() != rustc_middle::ty::Str && !is_type_diagnostic_item(cx, ty, sym::string_type) {
                 return None;
             }
+            // `format!("{}", format!(..))` is linted by `format_in_format_args`
+            if is_direct_expn_of(format_args.span, "format").is_some() {
+                return None;
+            }
             if let ExprKind::Lit(ref lit) = format_args.kind {
                 if let LitKind::Str(ref s, _) = lit.node {
                     return Some(format!("{:?}.to_string()", s.as_str()));
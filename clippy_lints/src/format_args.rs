@@ -0,0 +1,175 @@
+use crate::utils::{
+    is_direct_expn_of, is_expn_of, match_def_path, match_function_call, paths, snippet_opt, span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, MatchSource, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `format!(..)` as an argument of `format!`, `print!`,
+    /// `println!`, `eprint!`, `eprintln!`, `write!` or `writeln!`, formatted with `{}`.
+    ///
+    /// **Why is this bad?** The inner `format!` allocates a `String` which is only formatted
+    /// again by the outer macro. Its format string and arguments can be inlined in the outer
+    /// macro instead.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name = "world";
+    /// println!("{}", format!("hello {}", name));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "world";
+    /// println!("hello {}", name);
+    /// ```
+    pub FORMAT_IN_FORMAT_ARGS,
+    perf,
+    "`format!` used as an argument of another formatting macro"
+}
+
+declare_lint_pass!(FormatArgs => [FORMAT_IN_FORMAT_ARGS]);
+
+/// The formatting macros whose arguments are checked.
+const FORMAT_MACROS: [&str; 7] = ["format", "print", "println", "eprint", "eprintln", "write", "writeln"];
+
+impl<'tcx> LateLintPass<'tcx> for FormatArgs {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `new_v1_formatted` is used if an argument has a width or other format spec, which apply
+        // to the formatted `String` as a whole
+        let args = match match_function_call(cx, expr, &paths::FMT_ARGUMENTS_NEW_V1) {
+            Some(args) => args,
+            None => return,
+        };
+        let (macro_name, call_site) = match FORMAT_MACROS
+            .iter()
+            .find_map(|name| is_expn_of(expr.span, name).map(|span| (name, span)))
+        {
+            Some(found) => found,
+            None => return,
+        };
+        if in_external_macro(cx.sess(), call_site) {
+            return;
+        }
+
+        // The arguments are expanded to
+        // `&match (&a, &b) { (arg0, arg1) => [ArgumentV1::new(arg0, Display::fmt), ..] }`
+        if_chain! {
+            if args.len() == 2;
+            if let ExprKind::AddrOf(BorrowKind::Ref, _, ref arg_match) = args[1].kind;
+            if let ExprKind::Match(ref matchee, ref arms, MatchSource::Normal) = arg_match.kind;
+            if let [ref arm] = **arms;
+            if let ExprKind::Tup(ref values) = matchee.kind;
+            if let PatKind::Tuple(ref pats, None) = arm.pat.kind;
+            if let ExprKind::Array(ref arguments) = arm.body.kind;
+            then {
+                for argument in arguments.iter() {
+                    if_chain! {
+                        if let Some([value, fmt]) = match_function_call(cx, argument, &paths::FMT_ARGUMENTV1_NEW);
+                        if let ExprKind::Path(ref fmt_path) = fmt.kind;
+                        if let Some(fmt_id) = cx.qpath_res(fmt_path, fmt.hir_id).opt_def_id();
+                        if match_def_path(cx, fmt_id, &paths::DISPLAY_FMT_METHOD);
+                        if let ExprKind::Path(QPath::Resolved(None, ref path)) = value.kind;
+                        if let Res::Local(binding_id) = path.res;
+                        if let Some(index) = pats
+                            .iter()
+                            .position(|pat| matches!(pat.kind, PatKind::Binding(_, id, ..) if id == binding_id));
+                        if let ExprKind::AddrOf(BorrowKind::Ref, _, ref value) = values[index].kind;
+                        if let Some(inner_call_site) = is_direct_expn_of(value.span, "format");
+                        if !in_external_macro(cx.sess(), inner_call_site);
+                        then {
+                            let only_arg = arguments.len() == 1 && is_empty_pieces(&args[0], macro_name.ends_with("ln"));
+                            lint(cx, macro_name, call_site, inner_call_site, only_arg);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, macro_name: &str, call_site: Span, inner_call_site: Span, only_arg: bool) {
+    span_lint_and_then(
+        cx,
+        FORMAT_IN_FORMAT_ARGS,
+        call_site,
+        &format!("`format!` in `{}!` args", macro_name),
+        |diag| {
+            // `println!("{}", format!("a {}", b))` can be replaced by `println!("a {}", b)`
+            let sugg = if only_arg {
+                inline_sugg(cx, call_site, inner_call_site)
+            } else {
+                None
+            };
+            if let Some(sugg) = sugg {
+                diag.span_suggestion(
+                    call_site,
+                    "inline the `format!` call",
+                    sugg,
+                    Applicability::MachineApplicable,
+                );
+            } else {
+                diag.help("combine the format string and arguments of `format!` with the outer ones");
+            }
+        },
+    );
+}
+
+/// Replaces the `"{}", format!(..)` of the outer macro call with the arguments of `format!`.
+fn inline_sugg(cx: &LateContext<'_>, call_site: Span, inner_call_site: Span) -> Option<String> {
+    if !call_site.contains(inner_call_site) {
+        return None;
+    }
+    let outer = snippet_opt(cx, call_site)?;
+    let inner = snippet_opt(cx, inner_call_site)?;
+    let inner_start = (inner_call_site.lo() - call_site.lo()).0 as usize;
+    let inner_end = inner_start + inner.len();
+    if outer.get(inner_start..inner_end)? != inner {
+        return None;
+    }
+
+    // the format string is the only one before `format!(..)`, a raw string is left out since
+    // the escapes of the inner format string mean something else in it
+    let before = outer[..inner_start].trim_end();
+    let before = before.strip_suffix(',')?.trim_end().strip_suffix("\"{}\"")?;
+    if before.ends_with(char::is_alphanumeric) {
+        return None;
+    }
+    let inner_args = inner.strip_prefix("format!")?.trim_start();
+    let inner_args = inner_args.get(1..inner_args.len().saturating_sub(1))?;
+    Some(format!("{}{}{}", before, inner_args.trim(), &outer[inner_end..]))
+}
+
+/// Checks if the pieces of the format string, the first argument of `Arguments::new_v1`, are
+/// `&[""]`, as for `"{}"`. The `println!`-like macros add a newline to the format string, so
+/// their pieces are `&["", "\n"]`.
+fn is_empty_pieces(pieces: &Expr<'_>, newline: bool) -> bool {
+    if_chain! {
+        if let ExprKind::AddrOf(BorrowKind::Ref, _, ref arr) = pieces.kind;
+        if let ExprKind::Array(ref pieces) = arr.kind;
+        then {
+            let pieces: Option<Vec<String>> = pieces
+                .iter()
+                .map(|piece| match piece.kind {
+                    ExprKind::Lit(ref lit) => match lit.node {
+                        LitKind::Str(ref s, _) => Some(s.to_string()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect();
+            let expected: &[&str] = if newline { &["", "\n"] } else { &[""] };
+            pieces.map_or(false, |pieces| pieces == expected)
+        } else {
+            false
+        }
+    }
+}
@@ -45,6 +45,7 @@ declare_clippy_lint! {
 declare_lint_pass!(GetLastWithLen => [GET_LAST_WITH_LEN]);
 
 impl<'tcx> LateLintPass<'tcx> for GetLastWithLen {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             // Is a method call
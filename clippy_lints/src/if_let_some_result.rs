@@ -39,6 +39,7 @@ declare_clippy_lint! {
 declare_lint_pass!(OkIfLet => [IF_LET_SOME_RESULT]);
 
 impl<'tcx> LateLintPass<'tcx> for OkIfLet {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! { //begin checking variables
             if let ExprKind::Match(ref op, ref body, MatchSource::IfLetDesugar { .. }) = expr.kind; //test if expr is if let
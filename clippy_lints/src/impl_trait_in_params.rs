@@ -0,0 +1,166 @@
+use crate::utils::{
+    in_macro, multispan_sugg_with_applicability, snippet_opt, snippet_with_applicability, span_lint_and_then,
+    trait_ref_of_method,
+};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::{
+    GenericParamKind, Generics, HirId, ImplItem, ImplItemKind, Item, ItemKind, SyntheticTyParamKind, TraitItem,
+    TraitItemKind,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::Ident;
+use rustc_span::{BytePos, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `impl Trait` in the parameters of exported functions and
+    /// methods.
+    ///
+    /// **Why is this bad?** The type of an `impl Trait` parameter is anonymous, so the callers
+    /// can't specify it with a turbofish, e.g. `parse::<u32>(..)`, and it can't be named in the
+    /// bounds of other items. The other type parameters of the function can't be specified with
+    /// a turbofish either while it has `impl Trait` parameters.
+    ///
+    /// **Known problems:** The parameters of the trait methods aren't rewritten, as the
+    /// implementations of the trait would have to be changed too.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn print(value: impl std::fmt::Display) {
+    ///     println!("{}", value);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn print<T: std::fmt::Display>(value: T) {
+    ///     println!("{}", value);
+    /// }
+    /// ```
+    pub IMPL_TRAIT_IN_PARAMS,
+    restriction,
+    "`impl Trait` in the parameters of an exported function"
+}
+
+declare_lint_pass!(ImplTraitInParams => [IMPL_TRAIT_IN_PARAMS]);
+
+impl<'tcx> LateLintPass<'tcx> for ImplTraitInParams {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Fn(_, ref generics, _) = item.kind {
+            check_fn(cx, item.hir_id, item.ident, generics, false);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        // the parameters of the trait methods are checked in the trait
+        if let ImplItemKind::Fn(..) = item.kind {
+            if trait_ref_of_method(cx, item.hir_id).is_none() {
+                check_fn(cx, item.hir_id, item.ident, &item.generics, false);
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(..) = item.kind {
+            check_fn(cx, item.hir_id, item.ident, &item.generics, true);
+        }
+    }
+}
+
+fn check_fn(cx: &LateContext<'_>, hir_id: HirId, ident: Ident, generics: &Generics<'_>, is_trait_method: bool) {
+    if !cx.access_levels.is_exported(hir_id) || in_macro(ident.span) {
+        return;
+    }
+
+    // each `impl Trait` is a synthetic type parameter, spanning the `impl Trait` in the parameters
+    let impl_trait_spans: Vec<Span> = generics
+        .params
+        .iter()
+        .filter(|param| {
+            matches!(
+                param.kind,
+                GenericParamKind::Type {
+                    synthetic: Some(SyntheticTyParamKind::ImplTrait),
+                    ..
+                }
+            )
+        })
+        .map(|param| param.span)
+        .collect();
+    // e.g. the `impl Display` of `impl Iterator<Item = impl Display>`
+    let is_nested = |span: Span| {
+        impl_trait_spans
+            .iter()
+            .any(|outer| *outer != span && outer.contains(span))
+    };
+    let first_span = match impl_trait_spans.iter().find(|span| !is_nested(**span)) {
+        Some(span) => *span,
+        None => return,
+    };
+    let has_nested = impl_trait_spans.iter().any(|span| is_nested(*span));
+
+    span_lint_and_then(
+        cx,
+        IMPL_TRAIT_IN_PARAMS,
+        first_span,
+        "`impl Trait` used in the parameters of an exported function",
+        |diag| {
+            diag.note("the callers can't specify the type of an `impl Trait` parameter with a turbofish");
+            // the implementations of the trait would have to be changed too, and a nested
+            // `impl Trait` can't be used in the bounds of a type parameter
+            if is_trait_method || has_nested {
+                diag.help("use a generic type parameter instead");
+                return;
+            }
+
+            let mut applicability = Applicability::MachineApplicable;
+            let mut suggestions = Vec::new();
+            let mut new_params = Vec::new();
+            for (span, name) in impl_trait_spans.iter().zip(new_param_names(cx, hir_id)) {
+                let impl_trait = snippet_with_applicability(cx, *span, "impl ..", &mut applicability);
+                let bounds = impl_trait.strip_prefix("impl").unwrap_or(&impl_trait).trim();
+                new_params.push(format!("{}: {}", name, bounds));
+                suggestions.push((*span, name));
+            }
+
+            let new_params = new_params.join(", ");
+            match snippet_opt(cx, generics.span) {
+                // add the parameters after the existing ones, which may end with a comma
+                Some(snippet) if snippet.starts_with('<') => {
+                    let existing = snippet[1..snippet.len() - 1].trim();
+                    let separator = if existing.is_empty() {
+                        ""
+                    } else if existing.ends_with(',') {
+                        " "
+                    } else {
+                        ", "
+                    };
+                    suggestions.push((
+                        generics.span.with_lo(generics.span.hi() - BytePos(1)).shrink_to_lo(),
+                        format!("{}{}", separator, new_params),
+                    ));
+                },
+                _ => suggestions.push((ident.span.shrink_to_hi(), format!("<{}>", new_params))),
+            }
+
+            multispan_sugg_with_applicability(diag, "use a generic type parameter instead", applicability, suggestions);
+        },
+    );
+}
+
+/// Returns the names for the new type parameters, `T`, `U`, `V`, `W`, `T1`, `T2`.., skipping the
+/// names of the type parameters in scope, e.g. of the `impl` block of a method.
+fn new_param_names(cx: &LateContext<'_>, hir_id: HirId) -> impl Iterator<Item = String> {
+    let mut used_names = FxHashSet::default();
+    let mut generics = Some(cx.tcx.generics_of(cx.tcx.hir().local_def_id(hir_id).to_def_id()));
+    while let Some(current) = generics {
+        used_names.extend(current.params.iter().map(|param| param.name.to_ident_string()));
+        generics = current.parent.map(|parent| cx.tcx.generics_of(parent));
+    }
+
+    ["T", "U", "V", "W"]
+        .iter()
+        .map(ToString::to_string)
+        .chain((1..).map(|i| format!("T{}", i)))
+        .filter(move |name| !used_names.contains(name))
+}
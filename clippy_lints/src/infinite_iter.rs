@@ -1,8 +1,15 @@
-use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BorrowKind, Crate, Expr, ExprKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 
-use crate::utils::{get_trait_def_id, higher, implements_trait, match_qpath, match_type, paths, span_lint};
+use crate::utils::{
+    contains_return, def_ids_of_paths, get_trait_def_id, higher, implements_trait, match_qpath, match_type, paths,
+    qpath_res, span_lint,
+};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for iteration that is guaranteed to be infinite.
@@ -10,7 +17,9 @@ declare_clippy_lint! {
     /// **Why is this bad?** While there may be places where this is acceptable
     /// (e.g., in event streams), in most cases this is simply an error.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** Only the iterators of the standard library are known, other iterator
+    /// types and the functions or methods returning infinite iterators can be added with the
+    /// `infinite-iterators` configuration.
     ///
     /// **Example:**
     /// ```no_run
@@ -26,6 +35,12 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// **What it does:** Checks for iteration that may be infinite.
     ///
+    /// The iteration is possibly infinite if an infinite iterator, like `(0..)`, is only limited
+    /// by a predicate, as with `take_while` and `scan`, or consumed by a method which stops at the
+    /// first match, like `find` or `any`. Iterators created with `iter::successors` or
+    /// `iter::from_fn` are infinite if their closure always returns `Some`, and possibly infinite
+    /// otherwise, unless `maybe-infinite-iter-sources` is set to `false`.
+    ///
     /// **Why is this bad?** While there may be places where this is acceptable
     /// (e.g., in event streams), in most cases this is simply an error.
     ///
@@ -36,17 +51,58 @@ declare_clippy_lint! {
     /// ```rust
     /// let infinite_iter = 0..;
     /// [0..].iter().zip(infinite_iter.take_while(|x| *x > 5));
+    ///
+    /// std::iter::successors(Some(1_u32), |x| x.checked_mul(2)).take_while(|x| *x != 100).count();
     /// ```
     pub MAYBE_INFINITE_ITER,
     pedantic,
     "possible infinite iteration"
 }
 
-declare_lint_pass!(InfiniteIter => [INFINITE_ITER, MAYBE_INFINITE_ITER]);
+pub struct InfiniteIter {
+    infinite_iterators: Vec<String>,
+    /// The iterator types, functions and methods configured as infinite.
+    infinite_def_ids: FxHashSet<DefId>,
+    /// Whether `successors` and `from_fn` iterators which may end are possibly infinite.
+    maybe_infinite_sources: bool,
+}
+
+impl InfiniteIter {
+    pub fn new(infinite_iterators: Vec<String>, maybe_infinite_sources: bool) -> Self {
+        Self {
+            infinite_iterators,
+            infinite_def_ids: FxHashSet::default(),
+            maybe_infinite_sources,
+        }
+    }
+}
+
+impl_lint_pass!(InfiniteIter => [INFINITE_ITER, MAYBE_INFINITE_ITER]);
 
 impl<'tcx> LateLintPass<'tcx> for InfiniteIter {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx Crate<'_>) {
+        if self.infinite_iterators.is_empty() {
+            return;
+        }
+        self.infinite_def_ids = def_ids_of_paths(cx, &self.infinite_iterators);
+
+        // Items of the current crate can't be resolved by their path, so compare the printed paths
+        let local_items = krate
+            .items
+            .values()
+            .map(|item| item.hir_id)
+            .chain(krate.impl_items.values().map(|item| item.hir_id));
+        for hir_id in local_items {
+            let def_id = cx.tcx.hir().local_def_id(hir_id).to_def_id();
+            if self.infinite_iterators.contains(&cx.tcx.def_path_str(def_id)) {
+                self.infinite_def_ids.insert(def_id);
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let (lint, msg) = match complete_infinite_iter(cx, expr) {
+        let (lint, msg) = match complete_infinite_iter(cx, expr, self) {
             Infinite => (INFINITE_ITER, "infinite iteration detected"),
             MaybeInfinite => (MAYBE_INFINITE_ITER, "possible infinite iteration detected"),
             Finite => {
@@ -118,55 +174,91 @@ use self::Heuristic::{All, Always, Any, First};
 /// returns an infinite or possibly infinite iterator. The finiteness
 /// is an upper bound, e.g., some methods can return a possibly
 /// infinite iterator at worst, e.g., `take_while`.
-const HEURISTICS: [(&str, usize, Heuristic, Finiteness); 19] = [
+const HEURISTICS: [(&str, usize, Heuristic, Finiteness); 21] = [
     ("zip", 2, All, Infinite),
     ("chain", 2, Any, Infinite),
     ("cycle", 1, Always, Infinite),
     ("map", 2, First, Infinite),
     ("by_ref", 1, First, Infinite),
     ("cloned", 1, First, Infinite),
+    ("copied", 1, First, Infinite),
     ("rev", 1, First, Infinite),
     ("inspect", 1, First, Infinite),
     ("enumerate", 1, First, Infinite),
-    ("peekable", 2, First, Infinite),
+    ("peekable", 1, First, Infinite),
     ("fuse", 1, First, Infinite),
     ("skip", 2, First, Infinite),
-    ("skip_while", 1, First, Infinite),
+    ("skip_while", 2, First, Infinite),
+    ("step_by", 2, First, Infinite),
     ("filter", 2, First, Infinite),
     ("filter_map", 2, First, Infinite),
-    ("flat_map", 2, First, Infinite),
+    ("flatten", 1, First, Infinite),
     ("unzip", 1, First, Infinite),
     ("take_while", 2, First, MaybeInfinite),
     ("scan", 3, First, MaybeInfinite),
 ];
 
-fn is_infinite(cx: &LateContext<'_>, expr: &Expr<'_>) -> Finiteness {
+/// The paths of functions returning infinite iterators
+const INFINITE_SOURCES: [&[&str]; 2] = [&paths::REPEAT, &paths::REPEAT_WITH];
+
+fn is_infinite(cx: &LateContext<'_>, expr: &Expr<'_>, conf: &InfiniteIter) -> Finiteness {
+    if let ty::Adt(def, _) = cx.typeck_results().expr_ty(expr).kind() {
+        if conf.infinite_def_ids.contains(&def.did) {
+            return Infinite;
+        }
+    }
+
     match expr.kind {
         ExprKind::MethodCall(ref method, _, ref args, _) => {
+            if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                if conf.infinite_def_ids.contains(&def_id) {
+                    return Infinite;
+                }
+            }
             for &(name, len, heuristic, cap) in &HEURISTICS {
                 if method.ident.name.as_str() == name && args.len() == len {
                     return (match heuristic {
                         Always => Infinite,
-                        First => is_infinite(cx, &args[0]),
-                        Any => is_infinite(cx, &args[0]).or(is_infinite(cx, &args[1])),
-                        All => is_infinite(cx, &args[0]).and(is_infinite(cx, &args[1])),
+                        First => is_infinite(cx, &args[0], conf),
+                        Any => is_infinite(cx, &args[0], conf).or(is_infinite(cx, &args[1], conf)),
+                        All => is_infinite(cx, &args[0], conf).and(is_infinite(cx, &args[1], conf)),
                     })
                     .and(cap);
                 }
             }
+            // `flat_map` is also infinite if the closure returns an infinite iterator
             if method.ident.name == sym!(flat_map) && args.len() == 2 {
-                if let ExprKind::Closure(_, _, body_id, _, _) = args[1].kind {
+                let flattened = if let ExprKind::Closure(_, _, body_id, _, _) = args[1].kind {
                     let body = cx.tcx.hir().body(body_id);
-                    return is_infinite(cx, &body.value);
-                }
+                    is_infinite(cx, &body.value, conf)
+                } else {
+                    Finite
+                };
+                return is_infinite(cx, &args[0], conf).or(flattened);
             }
             Finite
         },
-        ExprKind::Block(ref block, _) => block.expr.as_ref().map_or(Finite, |e| is_infinite(cx, e)),
-        ExprKind::Box(ref e) | ExprKind::AddrOf(BorrowKind::Ref, _, ref e) => is_infinite(cx, e),
-        ExprKind::Call(ref path, _) => {
+        ExprKind::Block(ref block, _) => block.expr.as_ref().map_or(Finite, |e| is_infinite(cx, e, conf)),
+        ExprKind::Box(ref e) | ExprKind::AddrOf(BorrowKind::Ref, _, ref e) => is_infinite(cx, e, conf),
+        ExprKind::Call(ref path, ref args) => {
             if let ExprKind::Path(ref qpath) = path.kind {
-                match_qpath(qpath, &paths::REPEAT).into()
+                let is_configured = match qpath_res(cx, qpath, path.hir_id) {
+                    Res::Def(_, def_id) => conf.infinite_def_ids.contains(&def_id),
+                    _ => false,
+                };
+                if is_configured || INFINITE_SOURCES.iter().any(|source| match_qpath(qpath, source)) {
+                    Infinite
+                } else if let Some(closure) = generator_closure(qpath, args) {
+                    if always_returns_some(cx, closure) {
+                        Infinite
+                    } else if conf.maybe_infinite_sources {
+                        MaybeInfinite
+                    } else {
+                        Finite
+                    }
+                } else {
+                    Finite
+                }
             } else {
                 Finite
             }
@@ -176,15 +268,49 @@ fn is_infinite(cx: &LateContext<'_>, expr: &Expr<'_>) -> Finiteness {
     }
 }
 
+/// Returns the closure generating the items of an `iter::successors` or `iter::from_fn` call.
+fn generator_closure<'a>(qpath: &QPath<'_>, args: &'a [Expr<'a>]) -> Option<&'a Expr<'a>> {
+    if match_qpath(qpath, &paths::ITER_SUCCESSORS) && args.len() == 2 {
+        Some(&args[1])
+    } else if match_qpath(qpath, &paths::ITER_FROM_FN) && args.len() == 1 {
+        Some(&args[0])
+    } else {
+        None
+    }
+}
+
+/// Checks if the expression is a closure which can only return `Some`.
+fn always_returns_some(cx: &LateContext<'_>, closure: &Expr<'_>) -> bool {
+    if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind {
+        let body = cx.tcx.hir().body(body_id);
+        let mut value = &body.value;
+        while let ExprKind::Block(ref block, _) = value.kind {
+            match block.expr {
+                Some(ref expr) => value = expr,
+                None => return false,
+            }
+        }
+        if let ExprKind::Call(ref func, _) = value.kind {
+            if let ExprKind::Path(ref qpath) = func.kind {
+                return match_qpath(qpath, &paths::OPTION_SOME) && !contains_return(&body.value);
+            }
+        }
+    }
+    false
+}
+
 /// the names and argument lengths of methods that *may* exhaust their
 /// iterators
-const POSSIBLY_COMPLETING_METHODS: [(&str, usize); 6] = [
+const POSSIBLY_COMPLETING_METHODS: [(&str, usize); 9] = [
     ("find", 2),
+    ("find_map", 2),
     ("rfind", 2),
     ("position", 2),
     ("rposition", 2),
     ("any", 2),
     ("all", 2),
+    ("try_fold", 3),
+    ("try_for_each", 2),
 ];
 
 /// the names and argument lengths of methods that *always* exhaust
@@ -216,17 +342,17 @@ const INFINITE_COLLECTORS: [&[&str]; 8] = [
     &paths::VEC_DEQUE,
 ];
 
-fn complete_infinite_iter(cx: &LateContext<'_>, expr: &Expr<'_>) -> Finiteness {
+fn complete_infinite_iter(cx: &LateContext<'_>, expr: &Expr<'_>, conf: &InfiniteIter) -> Finiteness {
     match expr.kind {
         ExprKind::MethodCall(ref method, _, ref args, _) => {
             for &(name, len) in &COMPLETING_METHODS {
                 if method.ident.name.as_str() == name && args.len() == len {
-                    return is_infinite(cx, &args[0]);
+                    return is_infinite(cx, &args[0], conf);
                 }
             }
             for &(name, len) in &POSSIBLY_COMPLETING_METHODS {
                 if method.ident.name.as_str() == name && args.len() == len {
-                    return MaybeInfinite.and(is_infinite(cx, &args[0]));
+                    return MaybeInfinite.and(is_infinite(cx, &args[0], conf));
                 }
             }
             if method.ident.name == sym!(last) && args.len() == 1 {
@@ -234,18 +360,20 @@ fn complete_infinite_iter(cx: &LateContext<'_>, expr: &Expr<'_>) -> Finiteness {
                     !implements_trait(cx, cx.typeck_results().expr_ty(&args[0]), id, &[])
                 });
                 if not_double_ended {
-                    return is_infinite(cx, &args[0]);
+                    return is_infinite(cx, &args[0], conf);
                 }
             } else if method.ident.name == sym!(collect) {
                 let ty = cx.typeck_results().expr_ty(expr);
                 if INFINITE_COLLECTORS.iter().any(|path| match_type(cx, ty, path)) {
-                    return is_infinite(cx, &args[0]);
+                    return is_infinite(cx, &args[0], conf);
                 }
             }
         },
         ExprKind::Binary(op, ref l, ref r) => {
             if op.node.is_comparison() {
-                return is_infinite(cx, l).and(is_infinite(cx, r)).and(MaybeInfinite);
+                return is_infinite(cx, l, conf)
+                    .and(is_infinite(cx, r, conf))
+                    .and(MaybeInfinite);
             }
         }, // TODO: ExprKind::Loop + Match
         _ => (),
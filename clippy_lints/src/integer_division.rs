@@ -31,6 +31,7 @@ declare_clippy_lint! {
 declare_lint_pass!(IntegerDivision => [INTEGER_DIVISION]);
 
 impl<'tcx> LateLintPass<'tcx> for IntegerDivision {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         if is_integer_division(cx, expr) {
             span_lint_and_help(
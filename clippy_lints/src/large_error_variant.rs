@@ -0,0 +1,121 @@
+use crate::utils::{is_type_diagnostic_item, return_ty, span_lint_and_then};
+use rustc_hir::{FnDecl, HirId, ImplItem, ImplItemKind, Item, ItemKind, TraitItem, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+use rustc_target::abi::LayoutOf;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions returning a `Result<T, E>` whose error type `E` is
+    /// larger than the `large-error-threshold` configuration, 128 bytes by default.
+    ///
+    /// **Why is this bad?** A `Result` is at least as large as its error type, so every call
+    /// moves the whole error around, even when it succeeds and the error is never created.
+    ///
+    /// **Known problems:** Generic error types are not checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// pub struct ParseError {
+    ///     line: [u8; 512],
+    /// }
+    ///
+    /// pub fn parse() -> Result<(), ParseError> {
+    ///     Ok(())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub struct ParseError {
+    ///     line: [u8; 512],
+    /// }
+    ///
+    /// pub fn parse() -> Result<(), Box<ParseError>> {
+    ///     Ok(())
+    /// }
+    /// ```
+    pub LARGE_ERROR_VARIANT,
+    perf,
+    "function returning a `Result` with a large error type"
+}
+
+#[derive(Copy, Clone)]
+pub struct LargeErrorVariant {
+    large_error_threshold: u64,
+}
+
+impl LargeErrorVariant {
+    #[must_use]
+    pub fn new(large_error_threshold: u64) -> Self {
+        Self { large_error_threshold }
+    }
+}
+
+impl_lint_pass!(LargeErrorVariant => [LARGE_ERROR_VARIANT]);
+
+impl<'tcx> LateLintPass<'tcx> for LargeErrorVariant {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Fn(ref sig, ..) = item.kind {
+            self.check_fn_decl(cx, item.hir_id, sig.decl);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        // the signatures of trait impls are linted at the trait
+        let parent = cx.tcx.hir().get_parent_item(item.hir_id);
+        if let Some(rustc_hir::Node::Item(Item {
+            kind: ItemKind::Impl { of_trait: None, .. },
+            ..
+        })) = cx.tcx.hir().find(parent)
+        {
+            if let ImplItemKind::Fn(ref sig, _) = item.kind {
+                self.check_fn_decl(cx, item.hir_id, sig.decl);
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(ref sig, _) = item.kind {
+            self.check_fn_decl(cx, item.hir_id, sig.decl);
+        }
+    }
+}
+
+impl LargeErrorVariant {
+    fn check_fn_decl(self, cx: &LateContext<'_>, hir_id: HirId, decl: &FnDecl<'_>) {
+        let ret_ty = return_ty(cx, hir_id);
+        if !is_type_diagnostic_item(cx, ret_ty, sym::result_type) {
+            return;
+        }
+        let err_ty = match ret_ty.kind() {
+            ty::Adt(_, substs) => substs.type_at(1),
+            _ => return,
+        };
+        // generic error types don't have a layout
+        let size = match cx.layout_of(err_ty) {
+            Ok(layout) => layout.size.bytes(),
+            Err(_) => return,
+        };
+
+        if size > self.large_error_threshold {
+            span_lint_and_then(
+                cx,
+                LARGE_ERROR_VARIANT,
+                decl.output.span(),
+                "the `Err`-variant returned from this function is very large",
+                |diag| {
+                    diag.span_label(
+                        decl.output.span(),
+                        format!("the `Err`-variant is at least {} bytes", size),
+                    );
+                    diag.help(&format!(
+                        "try reducing the size of `{}`, for example by boxing large elements or replacing it with `Box<{}>`",
+                        err_ty, err_ty
+                    ));
+                },
+            );
+        }
+    }
+}
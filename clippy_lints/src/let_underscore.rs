@@ -1,11 +1,13 @@
 use if_chain::if_chain;
-use rustc_hir::{Local, PatKind};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, Local, LocalSource, PatKind, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::subst::GenericArgKind;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
-use crate::utils::{implements_trait, is_must_use_func_call, is_must_use_ty, match_type, paths, span_lint_and_help};
+use crate::utils::{
+    is_allowed, is_must_use_func_call, significant_tys, span_lint_and_help, span_lint_and_then, SignificantTy,
+};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `let _ = <expr>`
@@ -58,6 +60,33 @@ declare_clippy_lint! {
     "non-binding let on a synchronization lock"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `let _ = <expr>`
+    /// where expr is a `Result`
+    ///
+    /// **Why is this bad?** The error is silently ignored. It's better to handle
+    /// it, or to ignore it explicitly with `.ok()`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::io::Write;
+    /// # let mut out = std::io::stdout();
+    /// let _ = out.flush();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # use std::io::Write;
+    /// # let mut out = std::io::stdout();
+    /// out.flush().ok();
+    /// ```
+    pub LET_UNDERSCORE_RESULT,
+    pedantic,
+    "non-binding let on a `Result`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for `let _ = <expr>`
     /// where expr has a type that implements `Drop`
@@ -99,13 +128,38 @@ declare_clippy_lint! {
     "non-binding let on a type that implements `Drop`"
 }
 
-declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_MUST_USE, LET_UNDERSCORE_LOCK, LET_UNDERSCORE_DROP]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for assignments to `_`, like `_ = <expr>`, with the
+    /// `destructuring_assignment` feature.
+    ///
+    /// **Why is this bad?** It's the same as `let _ = <expr>`, which is more common. Both drop
+    /// the value immediately, `let _name = <expr>` keeps it until the end of the scope.
+    /// `_ = <expr>` is only needed where a statement isn't allowed, like the body of a closure
+    /// without a block.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// _ = f();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// let _ = f();
+    /// ```
+    pub UNDERSCORE_ASSIGNMENT,
+    restriction,
+    "assignment to `_`, instead of `let _ = ..`"
+}
 
-const SYNC_GUARD_PATHS: [&[&str]; 3] = [
-    &paths::MUTEX_GUARD,
-    &paths::RWLOCK_READ_GUARD,
-    &paths::RWLOCK_WRITE_GUARD,
-];
+declare_lint_pass!(LetUnderscore => [
+    LET_UNDERSCORE_MUST_USE,
+    LET_UNDERSCORE_LOCK,
+    LET_UNDERSCORE_DROP,
+    LET_UNDERSCORE_RESULT,
+    UNDERSCORE_ASSIGNMENT,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
     fn check_local(&mut self, cx: &LateContext<'_>, local: &Local<'_>) {
@@ -117,63 +171,102 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
             if let PatKind::Wild = local.pat.kind;
             if let Some(ref init) = local.init;
             then {
-                let init_ty = cx.typeck_results().expr_ty(init);
-                let contains_sync_guard = init_ty.walk().any(|inner| match inner.unpack() {
-                    GenericArgKind::Type(inner_ty) => {
-                        SYNC_GUARD_PATHS.iter().any(|path| match_type(cx, inner_ty, path))
-                    },
-
-                    GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
-                });
-                let implements_drop = cx.tcx.lang_items().drop_trait().map_or(false, |drop_trait|
-                    init_ty.walk().any(|inner| match inner.unpack() {
-                        GenericArgKind::Type(inner_ty) => {
-                            implements_trait(cx, inner_ty, drop_trait, &[])
-                        },
-
-                        GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
-                    })
-                );
-                if contains_sync_guard {
+                // `_ = <expr>` is lowered to `let _ = <expr>`
+                let is_assignment = matches!(local.source, LocalSource::AssignDesugar(_));
+                if is_assignment {
                     span_lint_and_help(
+                        cx,
+                        UNDERSCORE_ASSIGNMENT,
+                        local.span,
+                        "assignment to `_`",
+                        None,
+                        "consider using `let _ = ..`, or `let _name = ..` to keep the value until the end of the scope",
+                    );
+                }
+                let non_binding = if is_assignment { "assignment to `_`" } else { "non-binding let" };
+                // the `Result` lint is more specific than the `Drop` and `#[must_use]` ones, these are
+                // used for a `Result` if it's allowed
+                let result_allowed = is_allowed(cx, LET_UNDERSCORE_RESULT, local.hir_id);
+                let kind = significant_tys(cx, cx.typeck_results().expr_ty(init))
+                    .into_iter()
+                    .find(|&kind| kind != SignificantTy::Result || !result_allowed);
+                match kind {
+                    Some(SignificantTy::SyncGuard) => span_lint_and_then(
                         cx,
                         LET_UNDERSCORE_LOCK,
                         local.span,
-                        "non-binding let on a synchronization lock",
+                        &format!("{} on a synchronization lock", non_binding),
+                        |diag| {
+                            diag.help(
+                                "consider using an underscore-prefixed named \
+                                    binding or dropping explicitly with `std::mem::drop`"
+                            );
+                            if !is_place_expr(init) {
+                                diag.note(
+                                    "the lock is released at the end of this statement, unlike with `let _guard = ..`"
+                                );
+                            }
+                        },
+                    ),
+                    Some(SignificantTy::Result) => span_lint_and_help(
+                        cx,
+                        LET_UNDERSCORE_RESULT,
+                        local.span,
+                        &format!("{} on a `Result`", non_binding),
                         None,
-                        "consider using an underscore-prefixed named \
-                            binding or dropping explicitly with `std::mem::drop`"
-                    )
-                } else if implements_drop {
-                    span_lint_and_help(
+                        "consider handling the error, or ignoring it explicitly with `.ok()`"
+                    ),
+                    Some(SignificantTy::Drop) => span_lint_and_then(
                         cx,
                         LET_UNDERSCORE_DROP,
                         local.span,
-                        "non-binding `let` on a type that implements `Drop`",
-                        None,
-                        "consider using an underscore-prefixed named \
-                            binding or dropping explicitly with `std::mem::drop`"
-                    )
-                } else if is_must_use_ty(cx, cx.typeck_results().expr_ty(init)) {
-                    span_lint_and_help(
+                        if is_assignment {
+                            "assignment to `_` on a type that implements `Drop`"
+                        } else {
+                            "non-binding `let` on a type that implements `Drop`"
+                        },
+                        |diag| {
+                            diag.help(
+                                "consider using an underscore-prefixed named \
+                                    binding or dropping explicitly with `std::mem::drop`"
+                            );
+                            if !is_place_expr(init) {
+                                diag.note(
+                                    "the value is dropped at the end of this statement, unlike with `let _name = ..`"
+                                );
+                            }
+                        },
+                    ),
+                    Some(SignificantTy::MustUse) => span_lint_and_help(
                         cx,
                         LET_UNDERSCORE_MUST_USE,
                         local.span,
-                        "non-binding let on an expression with `#[must_use]` type",
+                        &format!("{} on an expression with `#[must_use]` type", non_binding),
                         None,
                         "consider explicitly using expression value"
-                    )
-                } else if is_must_use_func_call(cx, init) {
-                    span_lint_and_help(
+                    ),
+                    None if is_must_use_func_call(cx, init) => span_lint_and_help(
                         cx,
                         LET_UNDERSCORE_MUST_USE,
                         local.span,
-                        "non-binding let on a result of a `#[must_use]` function",
+                        &format!("{} on a result of a `#[must_use]` function", non_binding),
                         None,
                         "consider explicitly using function result"
-                    )
+                    ),
+                    None => {},
                 }
             }
         }
     }
 }
+
+/// Checks if `expr` is a place expression, e.g. `guard` or `self.guard`. Binding such an expression
+/// to `_` doesn't move out of it, so the value is only dropped with its owner.
+fn is_place_expr(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(QPath::Resolved(None, path)) => matches!(path.res, Res::Local(_) | Res::Def(DefKind::Static, _)),
+        ExprKind::Field(base, _) | ExprKind::Index(base, _) => is_place_expr(base),
+        ExprKind::Unary(UnOp::UnDeref, _) => true,
+        _ => false,
+    }
+}
@@ -149,11 +149,14 @@ macro_rules! declare_clippy_lint {
 }
 
 mod consts;
+mod renamed_lints;
 #[macro_use]
 mod utils;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 mod approx_const;
+mod arbitrary_source_item_ordering;
+mod arc_with_non_send_sync;
 mod arithmetic;
 mod as_conversions;
 mod asm_syntax;
@@ -165,6 +168,7 @@ mod attrs;
 mod await_holding_invalid;
 mod bit_mask;
 mod blacklisted_name;
+mod blocking_call_in_async;
 mod blocks_in_if_conditions;
 mod booleans;
 mod bytecount;
@@ -179,9 +183,13 @@ mod copy_iterator;
 mod create_dir;
 mod dbg_macro;
 mod default;
+mod default_numeric_fallback;
 mod dereference;
 mod derive;
+mod disallowed_function;
+mod disallowed_macro;
 mod disallowed_method;
+mod disallowed_type;
 mod doc;
 mod double_comparison;
 mod double_parens;
@@ -189,22 +197,27 @@ mod drop_forget_ref;
 mod duration_subsec;
 mod else_if_without_else;
 mod empty_enum;
+mod empty_with_brackets;
 mod entry;
 mod enum_clike;
 mod enum_variants;
 mod eq_op;
 mod erasing_op;
+mod error_impl_error;
 mod escape;
 mod eta_reduction;
 mod eval_order_dependence;
 mod excessive_bools;
+mod exhaustive_items;
 mod exit;
 mod explicit_write;
 mod fallible_impl_from;
+mod feature_name;
 mod float_equality_without_abs;
 mod float_literal;
 mod floating_point_arithmetic;
 mod format;
+mod format_args;
 mod formatting;
 mod functions;
 mod future_not_send;
@@ -213,6 +226,7 @@ mod identity_op;
 mod if_let_mutex;
 mod if_let_some_result;
 mod if_not_else;
+mod impl_trait_in_params;
 mod implicit_return;
 mod implicit_saturating_sub;
 mod indexing_slicing;
@@ -225,23 +239,27 @@ mod integer_division;
 mod items_after_statements;
 mod large_const_arrays;
 mod large_enum_variant;
+mod large_error_variant;
 mod large_stack_arrays;
 mod len_zero;
 mod let_if_seq;
 mod let_underscore;
 mod lifetimes;
+mod lint_groups_priority;
 mod literal_representation;
+mod literal_string_with_formatting_args;
 mod loops;
 mod macro_use;
 mod main_recursion;
 mod manual_async_fn;
 mod manual_non_exhaustive;
 mod manual_ok_or;
+mod manual_range_patterns;
+mod manual_retain;
 mod manual_strip;
 mod manual_unwrap_or;
 mod map_clone;
 mod map_err_ignore;
-mod map_identity;
 mod map_unit_fn;
 mod match_on_vec_items;
 mod matches;
@@ -276,6 +294,8 @@ mod new_without_default;
 mod no_effect;
 mod non_copy_const;
 mod non_expressive_names;
+mod once_cell_lazy;
+mod only_used_in_recursion;
 mod open_options;
 mod option_env_unwrap;
 mod option_if_let_else;
@@ -285,6 +305,7 @@ mod panic_unimplemented;
 mod partialeq_ne_impl;
 mod pass_by_ref_or_value;
 mod path_buf_push_overwrite;
+mod path_join_correctness;
 mod pattern_type_mismatch;
 mod precedence;
 mod ptr;
@@ -292,6 +313,7 @@ mod ptr_eq;
 mod ptr_offset_with_cast;
 mod question_mark;
 mod ranges;
+mod read_zero_byte_vec;
 mod redundant_clone;
 mod redundant_closure_call;
 mod redundant_field_names;
@@ -305,15 +327,19 @@ mod returns;
 mod self_assignment;
 mod serde_api;
 mod shadow;
+mod significant_drop_in_scrutinee;
 mod single_component_path_imports;
+mod single_range_in_vec_init;
 mod slow_vector_initialization;
 mod stable_sort_primitive;
+mod std_instead_of_core;
 mod strings;
 mod suspicious_operation_groupings;
 mod suspicious_trait_impl;
 mod swap;
 mod tabs_in_doc_comments;
 mod temporary_assignment;
+mod thread_spawn_without_join_or_detach_policy;
 mod to_digit_is_some;
 mod to_string_in_display;
 mod trait_bounds;
@@ -321,16 +347,22 @@ mod transmute;
 mod transmuting_null;
 mod try_err;
 mod types;
+mod unbounded_channel;
 mod undropped_manually_drops;
+mod unfulfilled_allow;
 mod unicode;
 mod unit_return_expecting_ord;
 mod unnamed_address;
+mod unnecessary_debug_formatting;
+mod unnecessary_self_imports;
 mod unnecessary_sort_by;
 mod unnecessary_wraps;
 mod unnested_or_patterns;
+mod unordered_iter_in_assert;
 mod unsafe_removed_from_name;
 mod unused_io_amount;
 mod unused_self;
+mod unused_trait_names;
 mod unused_unit;
 mod unwrap;
 mod unwrap_in_result;
@@ -339,6 +371,7 @@ mod useless_conversion;
 mod vec;
 mod vec_resize_to_zero;
 mod verbose_file_reads;
+mod visibility;
 mod wildcard_dependencies;
 mod wildcard_imports;
 mod write;
@@ -346,6 +379,8 @@ mod zero_div_zero;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 pub use crate::utils::conf::Conf;
+#[doc(hidden)]
+pub use crate::utils::{record_diagnostics, take_recorded_diagnostics, RecordedDiagnostic};
 
 /// Register all pre expansion lints
 ///
@@ -355,24 +390,39 @@ pub use crate::utils::conf::Conf;
 /// level (i.e `#![cfg_attr(...)]`) will still be expanded even when using a pre-expansion pass.
 ///
 /// Used in `./src/driver.rs`.
-pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore) {
-    store.register_pre_expansion_pass(|| box write::Write::default());
+pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, conf: &Conf) {
+    // `unfulfilled_allow` checks which lints were called by all the passes, this one starts recording them
+    store.register_pre_expansion_pass(|| box unfulfilled_allow::RecordLintCalls);
+    let format_macro_paths = conf.format_macro_paths.clone();
+    let logging_macro = conf.logging_macro.clone();
+    store.register_pre_expansion_pass(move || box write::Write::new(&format_macro_paths, logging_macro.as_deref()));
     store.register_pre_expansion_pass(|| box attrs::EarlyAttributes);
-    store.register_pre_expansion_pass(|| box dbg_macro::DbgMacro);
+    store.register_pre_expansion_pass(|| box dbg_macro::DbgMacro::default());
+    let disallowed_macros = conf.disallowed_macros.clone();
+    store.register_pre_expansion_pass(move || box disallowed_macro::DisallowedMacro::new(&disallowed_macros));
 }
 
 #[doc(hidden)]
 pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
-    use std::path::Path;
+    let conf = read_crate_conf(args, sess);
+    // the configuration files of the subdirectories are only read when a lint is emitted in them
+    utils::conf::init_nested(&conf.allow, &conf.allow_in_tests);
+    conf
+}
+
+fn read_crate_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
+    use std::env;
+    use std::path::{Path, PathBuf};
     match utils::conf::file_from_args(args) {
         Ok(file_name) => {
-            // if the user specified a file, it must exist, otherwise default to `clippy.toml` but
-            // do not require the file to exist
-            let file_name = match file_name {
-                Some(file_name) => file_name,
-                None => match utils::conf::lookup_conf_file() {
-                    Ok(Some(path)) => path,
-                    Ok(None) => return Conf::default(),
+            // a file given with `--config-path` takes precedence over the `conf_file` attribute.
+            // If the user specified a file, it must exist, otherwise search for `clippy.toml`
+            // files but do not require them to exist
+            let file_names = match env::var_os("CLIPPY_CONF_PATH").map(PathBuf::from).or(file_name) {
+                Some(file_name) => vec![file_name],
+                None => match utils::conf::lookup_conf_files() {
+                    Ok(file_names) if file_names.is_empty() => return Conf::default(),
+                    Ok(file_names) => file_names,
                     Err(error) => {
                         sess.struct_err(&format!("error finding Clippy's configuration file: {}", error))
                             .emit();
@@ -381,26 +431,26 @@ pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
                 },
             };
 
-            let file_name = if file_name.is_relative() {
-                sess.local_crate_source_file
-                    .as_deref()
-                    .and_then(Path::parent)
-                    .unwrap_or_else(|| Path::new(""))
-                    .join(file_name)
-            } else {
-                file_name
-            };
+            let file_names: Vec<PathBuf> = file_names
+                .into_iter()
+                .map(|file_name| {
+                    if file_name.is_relative() {
+                        sess.local_crate_source_file
+                            .as_deref()
+                            .and_then(Path::parent)
+                            .unwrap_or_else(|| Path::new(""))
+                            .join(file_name)
+                    } else {
+                        file_name
+                    }
+                })
+                .collect();
 
-            let (conf, errors) = utils::conf::read(&file_name);
+            let (conf, errors) = utils::conf::read(&file_names);
 
             // all conf errors are non-fatal, we just use the default conf in case of error
-            for error in errors {
-                sess.struct_err(&format!(
-                    "error reading Clippy's configuration file `{}`: {}",
-                    file_name.display(),
-                    error
-                ))
-                .emit();
+            for (file_name, error) in errors {
+                utils::conf::report_error(sess, &file_name, &error);
             }
 
             conf
@@ -499,6 +549,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     // begin register lints, do not remove this comment, it’s used in `update_lints`
     store.register_lints(&[
+        #[cfg(feature = "internal-lints")]
+        &utils::author::LINT_AUTHOR,
+        #[cfg(feature = "internal-lints")]
+        &utils::inspector::DEEP_CODE_INSPECTION,
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::CLIPPY_LINTS_INTERNAL,
         #[cfg(feature = "internal-lints")]
@@ -508,16 +562,22 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::DEFAULT_LINT,
         #[cfg(feature = "internal-lints")]
+        &utils::internal_lints::INTERNING_DEFINED_SYMBOL,
+        #[cfg(feature = "internal-lints")]
         &utils::internal_lints::INVALID_PATHS,
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::LINT_WITHOUT_LINT_PASS,
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::MATCH_TYPE_ON_DIAGNOSTIC_ITEM,
         #[cfg(feature = "internal-lints")]
+        &utils::internal_lints::MISSING_EXPANSION_CHECK,
+        #[cfg(feature = "internal-lints")]
         &utils::internal_lints::OUTER_EXPN_EXPN_DATA,
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::PRODUCE_ICE,
         &approx_const::APPROX_CONSTANT,
+        &arbitrary_source_item_ordering::ARBITRARY_SOURCE_ITEM_ORDERING,
+        &arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC,
         &arithmetic::FLOAT_ARITHMETIC,
         &arithmetic::INTEGER_ARITHMETIC,
         &as_conversions::AS_CONVERSIONS,
@@ -527,13 +587,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &assign_ops::ASSIGN_OP_PATTERN,
         &assign_ops::MISREFACTORED_ASSIGN_OP,
         &async_yields_async::ASYNC_YIELDS_ASYNC,
+        &atomic_ordering::ATOMIC_ORDERING_MISUSE,
         &atomic_ordering::INVALID_ATOMIC_ORDERING,
+        &atomic_ordering::SEQ_CST_ORDERING,
         &attrs::BLANKET_CLIPPY_RESTRICTION_LINTS,
         &attrs::DEPRECATED_CFG_ATTR,
         &attrs::DEPRECATED_SEMVER,
         &attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
         &attrs::INLINE_ALWAYS,
         &attrs::MISMATCHED_TARGET_OS,
+        &attrs::MIXED_ATTRIBUTES_STYLE,
         &attrs::UNKNOWN_CLIPPY_LINTS,
         &attrs::USELESS_ATTRIBUTE,
         &await_holding_invalid::AWAIT_HOLDING_LOCK,
@@ -542,6 +605,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &bit_mask::INEFFECTIVE_BIT_MASK,
         &bit_mask::VERBOSE_BIT_MASK,
         &blacklisted_name::BLACKLISTED_NAME,
+        &blocking_call_in_async::BLOCKING_CALL_IN_ASYNC,
         &blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS,
         &booleans::LOGIC_BUG,
         &booleans::NONMINIMAL_BOOL,
@@ -558,14 +622,20 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &copy_iterator::COPY_ITERATOR,
         &create_dir::CREATE_DIR,
         &dbg_macro::DBG_MACRO,
+        &dbg_macro::DBG_MACRO_IN_RELEASE,
         &default::DEFAULT_TRAIT_ACCESS,
         &default::FIELD_REASSIGN_WITH_DEFAULT,
+        &default::STRUCT_FIELDS_REST_DEFAULT,
+        &default_numeric_fallback::DEFAULT_NUMERIC_FALLBACK,
         &dereference::EXPLICIT_DEREF_METHODS,
         &derive::DERIVE_HASH_XOR_EQ,
         &derive::DERIVE_ORD_XOR_PARTIAL_ORD,
         &derive::EXPL_IMPL_CLONE_ON_COPY,
         &derive::UNSAFE_DERIVE_DESERIALIZE,
+        &disallowed_function::DISALLOWED_FUNCTION,
+        &disallowed_macro::DISALLOWED_MACRO,
         &disallowed_method::DISALLOWED_METHOD,
+        &disallowed_type::DISALLOWED_TYPE,
         &doc::DOC_MARKDOWN,
         &doc::MISSING_ERRORS_DOC,
         &doc::MISSING_SAFETY_DOC,
@@ -573,12 +643,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &double_comparison::DOUBLE_COMPARISONS,
         &double_parens::DOUBLE_PARENS,
         &drop_forget_ref::DROP_COPY,
+        &drop_forget_ref::DROP_NON_DROP,
         &drop_forget_ref::DROP_REF,
         &drop_forget_ref::FORGET_COPY,
+        &drop_forget_ref::FORGET_NON_DROP,
         &drop_forget_ref::FORGET_REF,
         &duration_subsec::DURATION_SUBSEC,
         &else_if_without_else::ELSE_IF_WITHOUT_ELSE,
         &empty_enum::EMPTY_ENUM,
+        &empty_with_brackets::EMPTY_ENUM_VARIANTS_WITH_BRACKETS,
         &entry::MAP_ENTRY,
         &enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
         &enum_variants::ENUM_VARIANT_NAMES,
@@ -588,6 +661,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &eq_op::EQ_OP,
         &eq_op::OP_REF,
         &erasing_op::ERASING_OP,
+        &error_impl_error::ERROR_IMPL_ERROR,
         &escape::BOXED_LOCAL,
         &eta_reduction::REDUNDANT_CLOSURE,
         &eta_reduction::REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
@@ -595,15 +669,20 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &eval_order_dependence::EVAL_ORDER_DEPENDENCE,
         &excessive_bools::FN_PARAMS_EXCESSIVE_BOOLS,
         &excessive_bools::STRUCT_EXCESSIVE_BOOLS,
+        &exhaustive_items::EXHAUSTIVE_ENUMS,
+        &exhaustive_items::EXHAUSTIVE_STRUCTS,
         &exit::EXIT,
         &explicit_write::EXPLICIT_WRITE,
         &fallible_impl_from::FALLIBLE_IMPL_FROM,
+        &feature_name::NEGATIVE_FEATURE_NAMES,
+        &feature_name::REDUNDANT_FEATURE_NAMES,
         &float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS,
         &float_literal::EXCESSIVE_PRECISION,
         &float_literal::LOSSY_FLOAT_LITERAL,
         &floating_point_arithmetic::IMPRECISE_FLOPS,
         &floating_point_arithmetic::SUBOPTIMAL_FLOPS,
         &format::USELESS_FORMAT,
+        &format_args::FORMAT_IN_FORMAT_ARGS,
         &formatting::POSSIBLE_MISSING_COMMA,
         &formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         &formatting::SUSPICIOUS_ELSE_FORMATTING,
@@ -621,6 +700,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &if_let_mutex::IF_LET_MUTEX,
         &if_let_some_result::IF_LET_SOME_RESULT,
         &if_not_else::IF_NOT_ELSE,
+        &impl_trait_in_params::IMPL_TRAIT_IN_PARAMS,
         &implicit_return::IMPLICIT_RETURN,
         &implicit_saturating_sub::IMPLICIT_SATURATING_SUB,
         &indexing_slicing::INDEXING_SLICING,
@@ -636,6 +716,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &items_after_statements::ITEMS_AFTER_STATEMENTS,
         &large_const_arrays::LARGE_CONST_ARRAYS,
         &large_enum_variant::LARGE_ENUM_VARIANT,
+        &large_error_variant::LARGE_ERROR_VARIANT,
         &large_stack_arrays::LARGE_STACK_ARRAYS,
         &len_zero::COMPARISON_TO_EMPTY,
         &len_zero::LEN_WITHOUT_IS_EMPTY,
@@ -644,14 +725,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &let_underscore::LET_UNDERSCORE_DROP,
         &let_underscore::LET_UNDERSCORE_LOCK,
         &let_underscore::LET_UNDERSCORE_MUST_USE,
+        &let_underscore::LET_UNDERSCORE_RESULT,
+        &let_underscore::UNDERSCORE_ASSIGNMENT,
         &lifetimes::EXTRA_UNUSED_LIFETIMES,
         &lifetimes::NEEDLESS_LIFETIMES,
+        &lint_groups_priority::LINT_GROUPS_PRIORITY,
         &literal_representation::DECIMAL_LITERAL_REPRESENTATION,
         &literal_representation::INCONSISTENT_DIGIT_GROUPING,
         &literal_representation::LARGE_DIGIT_GROUPS,
         &literal_representation::MISTYPED_LITERAL_SUFFIXES,
         &literal_representation::UNREADABLE_LITERAL,
         &literal_representation::UNUSUAL_BYTE_GROUPINGS,
+        &literal_string_with_formatting_args::LITERAL_STRING_WITH_FORMATTING_ARGS,
         &loops::EMPTY_LOOP,
         &loops::EXPLICIT_COUNTER_LOOP,
         &loops::EXPLICIT_INTO_ITER_LOOP,
@@ -659,6 +744,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &loops::FOR_KV_MAP,
         &loops::FOR_LOOPS_OVER_FALLIBLES,
         &loops::ITER_NEXT_LOOP,
+        &loops::MANUAL_FIND,
         &loops::MANUAL_MEMCPY,
         &loops::MUT_RANGE_BOUND,
         &loops::NEEDLESS_COLLECT,
@@ -666,6 +752,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &loops::NEVER_LOOP,
         &loops::SAME_ITEM_PUSH,
         &loops::SINGLE_ELEMENT_LOOP,
+        &loops::SPIN_LOOP_WITHOUT_HINT,
+        &loops::WHILE_FLOAT,
         &loops::WHILE_IMMUTABLE_CONDITION,
         &loops::WHILE_LET_LOOP,
         &loops::WHILE_LET_ON_ITERATOR,
@@ -674,11 +762,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &manual_async_fn::MANUAL_ASYNC_FN,
         &manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
         &manual_ok_or::MANUAL_OK_OR,
+        &manual_range_patterns::MANUAL_RANGE_PATTERNS,
+        &manual_retain::MANUAL_RETAIN,
         &manual_strip::MANUAL_STRIP,
         &manual_unwrap_or::MANUAL_UNWRAP_OR,
         &map_clone::MAP_CLONE,
+        &map_clone::MAP_IDENTITY,
         &map_err_ignore::MAP_ERR_IGNORE,
-        &map_identity::MAP_IDENTITY,
         &map_unit_fn::OPTION_MAP_UNIT_FN,
         &map_unit_fn::RESULT_MAP_UNIT_FN,
         &match_on_vec_items::MATCH_ON_VEC_ITEMS,
@@ -692,6 +782,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &matches::MATCH_SINGLE_BINDING,
         &matches::MATCH_WILDCARD_FOR_SINGLE_VARIANTS,
         &matches::MATCH_WILD_ERR_ARM,
+        &matches::REDUNDANT_GUARDS,
         &matches::REDUNDANT_PATTERN_MATCHING,
         &matches::REST_PAT_IN_FULLY_BOUND_STRUCTS,
         &matches::SINGLE_MATCH,
@@ -727,16 +818,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &methods::ITER_NTH,
         &methods::ITER_NTH_ZERO,
         &methods::ITER_SKIP_NEXT,
+        &methods::LINES_FILTER_MAP_OK,
+        &methods::MANUAL_CONTAINS,
+        &methods::MANUAL_INSPECT,
         &methods::MANUAL_SATURATING_ARITHMETIC,
         &methods::MAP_COLLECT_RESULT_UNIT,
         &methods::MAP_FLATTEN,
         &methods::MAP_UNWRAP_OR,
         &methods::NEW_RET_NO_SELF,
         &methods::OK_EXPECT,
+        &methods::OPTION_AS_REF_CLONED,
         &methods::OPTION_AS_REF_DEREF,
         &methods::OPTION_MAP_OR_NONE,
         &methods::OR_FUN_CALL,
         &methods::RESULT_MAP_OR_INTO_OPTION,
+        &methods::RESULT_OK_OK_OR,
         &methods::SEARCH_IS_SOME,
         &methods::SHOULD_IMPLEMENT_TRAIT,
         &methods::SINGLE_CHAR_ADD_STR,
@@ -784,10 +880,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL,
         &mutex_atomic::MUTEX_ATOMIC,
         &mutex_atomic::MUTEX_INTEGER,
+        &mutex_atomic::MUTEX_INTEGER_USE_ATOMIC,
         &needless_arbitrary_self_type::NEEDLESS_ARBITRARY_SELF_TYPE,
         &needless_bool::BOOL_COMPARISON,
         &needless_bool::NEEDLESS_BOOL,
         &needless_borrow::NEEDLESS_BORROW,
+        &needless_borrow::NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
         &needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
         &needless_continue::NEEDLESS_CONTINUE,
         &needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
@@ -802,19 +900,24 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         &non_expressive_names::MANY_SINGLE_CHAR_NAMES,
         &non_expressive_names::SIMILAR_NAMES,
+        &once_cell_lazy::ONCE_CELL_LAZY,
+        &only_used_in_recursion::ONLY_USED_IN_RECURSION,
         &open_options::NONSENSICAL_OPEN_OPTIONS,
         &option_env_unwrap::OPTION_ENV_UNWRAP,
         &option_if_let_else::OPTION_IF_LET_ELSE,
         &overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
         &panic_in_result_fn::PANIC_IN_RESULT_FN,
+        &panic_in_result_fn::REACHABLE_PANIC,
         &panic_unimplemented::PANIC,
         &panic_unimplemented::TODO,
         &panic_unimplemented::UNIMPLEMENTED,
         &panic_unimplemented::UNREACHABLE,
+        &panic_unimplemented::UNTRACKED_TODO,
         &partialeq_ne_impl::PARTIALEQ_NE_IMPL,
         &pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE,
         &pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
         &path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
+        &path_join_correctness::JOIN_ABSOLUTE_PATHS,
         &pattern_type_mismatch::PATTERN_TYPE_MISMATCH,
         &precedence::PRECEDENCE,
         &ptr::CMP_NULL,
@@ -828,6 +931,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &ranges::RANGE_PLUS_ONE,
         &ranges::RANGE_ZIP_WITH_LEN,
         &ranges::REVERSED_EMPTY_RANGES,
+        &read_zero_byte_vec::READ_ZERO_BYTE_VEC,
         &redundant_clone::REDUNDANT_CLONE,
         &redundant_closure_call::REDUNDANT_CLOSURE_CALL,
         &redundant_field_names::REDUNDANT_FIELD_NAMES,
@@ -846,9 +950,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &shadow::SHADOW_REUSE,
         &shadow::SHADOW_SAME,
         &shadow::SHADOW_UNRELATED,
+        &significant_drop_in_scrutinee::SIGNIFICANT_DROP_IN_SCRUTINEE,
         &single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
+        &single_range_in_vec_init::SINGLE_RANGE_IN_VEC_INIT,
         &slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
         &stable_sort_primitive::STABLE_SORT_PRIMITIVE,
+        &std_instead_of_core::ALLOC_INSTEAD_OF_CORE,
+        &std_instead_of_core::STD_INSTEAD_OF_ALLOC,
+        &std_instead_of_core::STD_INSTEAD_OF_CORE,
         &strings::STRING_ADD,
         &strings::STRING_ADD_ASSIGN,
         &strings::STRING_FROM_UTF8_AS_BYTES,
@@ -862,6 +971,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &swap::MANUAL_SWAP,
         &tabs_in_doc_comments::TABS_IN_DOC_COMMENTS,
         &temporary_assignment::TEMPORARY_ASSIGNMENT,
+        &thread_spawn_without_join_or_detach_policy::THREAD_SPAWN_WITHOUT_JOIN_OR_DETACH_POLICY,
         &to_digit_is_some::TO_DIGIT_IS_SOME,
         &to_string_in_display::TO_STRING_IN_DISPLAY,
         &trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS,
@@ -897,6 +1007,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &types::INVALID_UPCAST_COMPARISONS,
         &types::LET_UNIT_VALUE,
         &types::LINKEDLIST,
+        &types::NESTED_CONTAINERS,
         &types::OPTION_OPTION,
         &types::RC_BUFFER,
         &types::REDUNDANT_ALLOCATION,
@@ -905,19 +1016,25 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &types::UNIT_CMP,
         &types::UNNECESSARY_CAST,
         &types::VEC_BOX,
+        &unbounded_channel::UNBOUNDED_CHANNEL,
         &undropped_manually_drops::UNDROPPED_MANUALLY_DROPS,
+        &unfulfilled_allow::UNFULFILLED_ALLOW,
         &unicode::INVISIBLE_CHARACTERS,
         &unicode::NON_ASCII_LITERAL,
         &unicode::UNICODE_NOT_NFC,
         &unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD,
         &unnamed_address::FN_ADDRESS_COMPARISONS,
         &unnamed_address::VTABLE_ADDRESS_COMPARISONS,
+        &unnecessary_debug_formatting::UNNECESSARY_DEBUG_FORMATTING,
+        &unnecessary_self_imports::UNNECESSARY_SELF_IMPORTS,
         &unnecessary_sort_by::UNNECESSARY_SORT_BY,
         &unnecessary_wraps::UNNECESSARY_WRAPS,
         &unnested_or_patterns::UNNESTED_OR_PATTERNS,
+        &unordered_iter_in_assert::UNORDERED_ITER_IN_ASSERT,
         &unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         &unused_io_amount::UNUSED_IO_AMOUNT,
         &unused_self::UNUSED_SELF,
+        &unused_trait_names::UNUSED_TRAIT_NAMES,
         &unused_unit::UNUSED_UNIT,
         &unwrap::PANICKING_UNWRAP,
         &unwrap::UNNECESSARY_UNWRAP,
@@ -927,6 +1044,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &vec::USELESS_VEC,
         &vec_resize_to_zero::VEC_RESIZE_TO_ZERO,
         &verbose_file_reads::VERBOSE_FILE_READS,
+        &visibility::PUB_WITHOUT_SHORTHAND,
+        &visibility::PUB_WITH_SHORTHAND,
+        &wildcard_dependencies::PATH_DEPENDENCIES,
+        &wildcard_dependencies::UNPINNED_GIT_DEPENDENCIES,
         &wildcard_dependencies::WILDCARD_DEPENDENCIES,
         &wildcard_imports::ENUM_GLOB_USE,
         &wildcard_imports::WILDCARD_IMPORTS,
@@ -950,16 +1071,22 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         store.register_late_pass(|| box utils::inspector::DeepCodeInspector);
         store.register_late_pass(|| box utils::internal_lints::CollapsibleCalls);
         store.register_late_pass(|| box utils::internal_lints::CompilerLintFunctions::new());
+        store.register_late_pass(|| box utils::internal_lints::InterningDefinedSymbol::default());
         store.register_late_pass(|| box utils::internal_lints::InvalidPaths);
         store.register_late_pass(|| box utils::internal_lints::LintWithoutLintPass::default());
         store.register_late_pass(|| box utils::internal_lints::MatchTypeOnDiagItem);
+        store.register_late_pass(|| box utils::internal_lints::MissingExpansionCheck);
         store.register_late_pass(|| box utils::internal_lints::OuterExpnDataPass);
     }
     store.register_late_pass(|| box utils::author::Author);
+    let set_keys = conf.set_keys.clone();
+    store.register_late_pass(move || box utils::unused_conf_keys::UnusedConfKeys::new(set_keys.clone()));
     store.register_late_pass(|| box await_holding_invalid::AwaitHolding);
     store.register_late_pass(|| box serde_api::SerdeAPI);
     let vec_box_size_threshold = conf.vec_box_size_threshold;
-    store.register_late_pass(move || box types::Types::new(vec_box_size_threshold));
+    let max_result_nesting = conf.max_result_nesting;
+    let max_vec_nesting = conf.max_vec_nesting;
+    store.register_late_pass(move || box types::Types::new(vec_box_size_threshold, max_result_nesting, max_vec_nesting));
     store.register_late_pass(|| box booleans::NonminimalBool);
     store.register_late_pass(|| box eq_op::EqOp);
     store.register_late_pass(|| box enum_clike::UnportableVariant);
@@ -998,23 +1125,25 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box matches::Matches::new(msrv));
     store.register_early_pass(move || box manual_non_exhaustive::ManualNonExhaustive::new(msrv));
     store.register_late_pass(move || box manual_strip::ManualStrip::new(msrv));
+    store.register_late_pass(move || box missing_const_for_fn::MissingConstForFn::new(msrv));
     store.register_late_pass(|| box map_clone::MapClone);
-    store.register_late_pass(|| box map_err_ignore::MapErrIgnore);
+    let map_err_ignore_allowed_types = conf.map_err_ignore_allowed_types.clone();
+    store.register_late_pass(move || box map_err_ignore::MapErrIgnore::new(map_err_ignore_allowed_types.clone()));
     store.register_late_pass(|| box shadow::Shadow);
     store.register_late_pass(|| box types::LetUnitValue);
     store.register_late_pass(|| box types::UnitCmp);
-    store.register_late_pass(|| box loops::Loops);
+    store.register_late_pass(move || box loops::Loops::new(msrv));
     store.register_late_pass(|| box main_recursion::MainRecursion::default());
     store.register_late_pass(|| box lifetimes::Lifetimes);
     store.register_late_pass(|| box entry::HashMapPass);
-    store.register_late_pass(|| box ranges::Ranges);
+    store.register_late_pass(move || box ranges::Ranges::new(msrv));
     store.register_late_pass(|| box types::Casts);
     let type_complexity_threshold = conf.type_complexity_threshold;
     store.register_late_pass(move || box types::TypeComplexity::new(type_complexity_threshold));
     store.register_late_pass(|| box minmax::MinMaxPass);
     store.register_late_pass(|| box open_options::OpenOptions);
     store.register_late_pass(|| box zero_div_zero::ZeroDiv);
-    store.register_late_pass(|| box mutex_atomic::Mutex);
+    store.register_late_pass(|| box mutex_atomic::Mutex::default());
     store.register_late_pass(|| box needless_update::NeedlessUpdate);
     store.register_late_pass(|| box needless_borrow::NeedlessBorrow::default());
     store.register_late_pass(|| box needless_borrowed_ref::NeedlessBorrowedRef);
@@ -1026,7 +1155,17 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let too_large_for_stack = conf.too_large_for_stack;
     store.register_late_pass(move || box escape::BoxedLocal{too_large_for_stack});
     store.register_late_pass(move || box vec::UselessVec{too_large_for_stack});
-    store.register_late_pass(|| box panic_unimplemented::PanicUnimplemented);
+    let todo_tracking_regex = match regex::Regex::new(&conf.todo_tracking_regex) {
+        Ok(regex) => Some(regex),
+        Err(error) => {
+            sess.err(&format!(
+                "error reading Clippy's configuration file. `{}` is not a valid regex: {}",
+                conf.todo_tracking_regex, error
+            ));
+            None
+        },
+    };
+    store.register_late_pass(move || box panic_unimplemented::PanicUnimplemented::new(todo_tracking_regex.clone()));
     store.register_late_pass(|| box strings::StringLitAsBytes);
     store.register_late_pass(|| box derive::Derive);
     store.register_late_pass(|| box types::CharLitAsU8);
@@ -1052,13 +1191,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box neg_multiply::NegMultiply);
     store.register_late_pass(|| box mem_discriminant::MemDiscriminant);
     store.register_late_pass(|| box mem_forget::MemForget);
-    store.register_late_pass(|| box mem_replace::MemReplace);
+    store.register_late_pass(move || box mem_replace::MemReplace::new(msrv));
     store.register_late_pass(|| box arithmetic::Arithmetic::default());
     store.register_late_pass(|| box assign_ops::AssignOps);
     store.register_late_pass(|| box let_if_seq::LetIfSeq);
     store.register_late_pass(|| box eval_order_dependence::EvalOrderDependence);
     store.register_late_pass(|| box missing_doc::MissingDoc::new());
-    store.register_late_pass(|| box missing_inline::MissingInline);
+    let missing_inline_max_statements = conf.missing_inline_max_statements;
+    store.register_late_pass(move || box missing_inline::MissingInline::new(missing_inline_max_statements));
     store.register_late_pass(|| box if_let_some_result::OkIfLet);
     store.register_late_pass(|| box partialeq_ne_impl::PartialEqNeImpl);
     store.register_late_pass(|| box unused_io_amount::UnusedIoAmount);
@@ -1074,9 +1214,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box pass_by_ref_or_value);
     store.register_late_pass(|| box ref_option_ref::RefOptionRef);
     store.register_late_pass(|| box try_err::TryErr);
-    store.register_late_pass(|| box use_self::UseSelf);
+    store.register_late_pass(move || box use_self::UseSelf::new(msrv));
     store.register_late_pass(|| box bytecount::ByteCount);
-    store.register_late_pass(|| box infinite_iter::InfiniteIter);
+    let infinite_iterators = conf.infinite_iterators.clone();
+    let maybe_infinite_iter_sources = conf.maybe_infinite_iter_sources;
+    store.register_late_pass(move || {
+        box infinite_iter::InfiniteIter::new(infinite_iterators.clone(), maybe_infinite_iter_sources)
+    });
     store.register_late_pass(|| box inline_fn_without_body::InlineFnWithoutBody);
     store.register_late_pass(|| box useless_conversion::UselessConversion::default());
     store.register_late_pass(|| box types::ImplicitHasher);
@@ -1092,7 +1236,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box unwrap::Unwrap);
     store.register_late_pass(|| box duration_subsec::DurationSubsec);
     store.register_late_pass(|| box indexing_slicing::IndexingSlicing);
-    store.register_late_pass(|| box non_copy_const::NonCopyConst);
+    let ignore_interior_mutable_consts = conf.ignore_interior_mutability.clone();
+    store.register_late_pass(move || box non_copy_const::NonCopyConst::new(ignore_interior_mutable_consts.clone()));
     store.register_late_pass(|| box ptr_offset_with_cast::PtrOffsetWithCast);
     store.register_late_pass(|| box redundant_clone::RedundantClone);
     store.register_late_pass(|| box slow_vector_initialization::SlowVectorInit);
@@ -1100,16 +1245,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box unnecessary_wraps::UnnecessaryWraps);
     store.register_late_pass(|| box types::RefToMut);
     store.register_late_pass(|| box assertions_on_constants::AssertionsOnConstants);
-    store.register_late_pass(|| box missing_const_for_fn::MissingConstForFn);
     store.register_late_pass(|| box transmuting_null::TransmutingNull);
     store.register_late_pass(|| box path_buf_push_overwrite::PathBufPushOverwrite);
-    store.register_late_pass(|| box checked_conversions::CheckedConversions);
+    store.register_late_pass(move || box checked_conversions::CheckedConversions::new(msrv));
     store.register_late_pass(|| box integer_division::IntegerDivision);
     store.register_late_pass(|| box inherent_to_string::InherentToString);
     let max_trait_bounds = conf.max_trait_bounds;
     store.register_late_pass(move || box trait_bounds::TraitBounds::new(max_trait_bounds));
     store.register_late_pass(|| box comparison_chain::ComparisonChain);
-    store.register_late_pass(|| box mut_key::MutableKeyType);
+    let ignore_interior_mutability = conf.ignore_interior_mutability.clone();
+    store.register_late_pass(move || box mut_key::MutableKeyType::new(ignore_interior_mutability.clone()));
     store.register_late_pass(|| box modulo_arithmetic::ModuloArithmetic);
     store.register_early_pass(|| box reference::DerefAddrOf);
     store.register_early_pass(|| box reference::RefInDeref);
@@ -1131,15 +1276,25 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(|| box needless_continue::NeedlessContinue);
     store.register_late_pass(|| box create_dir::CreateDir);
     store.register_early_pass(|| box needless_arbitrary_self_type::NeedlessArbitrarySelfType);
-    store.register_early_pass(|| box redundant_static_lifetimes::RedundantStaticLifetimes);
+    store.register_early_pass(move || box redundant_static_lifetimes::RedundantStaticLifetimes::new(msrv));
     store.register_late_pass(|| box cargo_common_metadata::CargoCommonMetadata);
-    store.register_late_pass(|| box multiple_crate_versions::MultipleCrateVersions);
+    let allowed_duplicate_crates = conf.allowed_duplicate_crates.iter().cloned().collect::<FxHashSet<_>>();
+    store.register_late_pass(move || {
+        box multiple_crate_versions::MultipleCrateVersions::new(allowed_duplicate_crates.clone())
+    });
+    let allowed_feature_names = conf.allowed_feature_names.iter().cloned().collect::<FxHashSet<_>>();
+    store.register_late_pass(move || box feature_name::FeatureName::new(allowed_feature_names.clone()));
+    store.register_late_pass(|| box lint_groups_priority::LintGroupsPriority);
     store.register_late_pass(|| box wildcard_dependencies::WildcardDependencies);
     store.register_early_pass(|| box literal_representation::LiteralDigitGrouping);
     let literal_representation_threshold = conf.literal_representation_threshold;
     store.register_early_pass(move || box literal_representation::DecimalLiteralRepresentation::new(literal_representation_threshold));
     let enum_variant_name_threshold = conf.enum_variant_name_threshold;
-    store.register_early_pass(move || box enum_variants::EnumVariantNames::new(enum_variant_name_threshold));
+    let allowed_prefixes = conf.allowed_prefixes.clone();
+    let allowed_suffixes = conf.allowed_suffixes.clone();
+    store.register_early_pass(move || {
+        box enum_variants::EnumVariantNames::new(enum_variant_name_threshold, &allowed_prefixes, &allowed_suffixes)
+    });
     store.register_early_pass(|| box tabs_in_doc_comments::TabsInDocComments);
     store.register_late_pass(|| box default::Default::default());
     store.register_late_pass(|| box unused_self::UnusedSelf);
@@ -1150,13 +1305,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box large_stack_arrays::LargeStackArrays::new(array_size_threshold));
     store.register_late_pass(move || box large_const_arrays::LargeConstArrays::new(array_size_threshold));
     store.register_late_pass(|| box floating_point_arithmetic::FloatingPointArithmetic);
-    store.register_early_pass(|| box as_conversions::AsConversions);
     store.register_late_pass(|| box let_underscore::LetUnderscore);
     store.register_late_pass(|| box atomic_ordering::AtomicOrdering);
     store.register_early_pass(|| box single_component_path_imports::SingleComponentPathImports);
     let max_fn_params_bools = conf.max_fn_params_bools;
     let max_struct_bools = conf.max_struct_bools;
-    store.register_early_pass(move || box excessive_bools::ExcessiveBools::new(max_struct_bools, max_fn_params_bools));
+    store.register_late_pass(move || box excessive_bools::ExcessiveBools::new(max_struct_bools, max_fn_params_bools));
     store.register_early_pass(|| box option_env_unwrap::OptionEnvUnwrap);
     let warn_on_all_wildcard_imports = conf.warn_on_all_wildcard_imports;
     store.register_late_pass(move || box wildcard_imports::WildcardImports::new(warn_on_all_wildcard_imports));
@@ -1170,16 +1324,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box mut_mutex_lock::MutMutexLock);
     store.register_late_pass(|| box match_on_vec_items::MatchOnVecItems);
     store.register_late_pass(|| box manual_async_fn::ManualAsyncFn);
-    store.register_early_pass(|| box redundant_field_names::RedundantFieldNames);
+    store.register_early_pass(move || box redundant_field_names::RedundantFieldNames::new(msrv));
     store.register_late_pass(|| box vec_resize_to_zero::VecResizeToZero);
-    store.register_late_pass(|| box panic_in_result_fn::PanicInResultFn);
+    let reachable_panic_depth = conf.reachable_panic_depth;
+    store.register_late_pass(move || box panic_in_result_fn::PanicInResultFn::new(reachable_panic_depth));
+    let large_error_threshold = conf.large_error_threshold;
+    store.register_late_pass(move || box large_error_variant::LargeErrorVariant::new(large_error_threshold));
     let single_char_binding_names_threshold = conf.single_char_binding_names_threshold;
     store.register_early_pass(move || box non_expressive_names::NonExpressiveNames {
         single_char_binding_names_threshold,
     });
     store.register_early_pass(|| box unnested_or_patterns::UnnestedOrPatterns);
     store.register_late_pass(|| box macro_use::MacroUseImports::default());
-    store.register_late_pass(|| box map_identity::MapIdentity);
     store.register_late_pass(|| box pattern_type_mismatch::PatternTypeMismatch);
     store.register_late_pass(|| box stable_sort_primitive::StableSortPrimitive);
     store.register_late_pass(|| box repeat_once::RepeatOnce);
@@ -1189,15 +1345,106 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box manual_ok_or::ManualOkOr);
     store.register_late_pass(|| box float_equality_without_abs::FloatEqualityWithoutAbs);
     store.register_late_pass(|| box async_yields_async::AsyncYieldsAsync);
-    let disallowed_methods = conf.disallowed_methods.iter().cloned().collect::<FxHashSet<_>>();
+    let disallowed_methods = conf.disallowed_methods.clone();
     store.register_late_pass(move || box disallowed_method::DisallowedMethod::new(&disallowed_methods));
     store.register_early_pass(|| box asm_syntax::InlineAsmX86AttSyntax);
     store.register_early_pass(|| box asm_syntax::InlineAsmX86IntelSyntax);
     store.register_late_pass(|| box undropped_manually_drops::UndroppedManuallyDrops);
     store.register_late_pass(|| box strings::StrToString);
     store.register_late_pass(|| box strings::StringToString);
+    store.register_late_pass(|| box std_instead_of_core::StdReexports::default());
+    store.register_late_pass(|| box arc_with_non_send_sync::ArcWithNonSendSync);
+    store.register_late_pass(|| box empty_with_brackets::EmptyWithBrackets::default());
+    let max_exported_error_types = conf.max_exported_error_types;
+    store.register_late_pass(move || box error_impl_error::ErrorImplError::new(max_exported_error_types));
+    let allowed_exhaustive_types = conf.allowed_exhaustive_types.iter().cloned().collect::<FxHashSet<_>>();
+    store.register_late_pass(move || box exhaustive_items::ExhaustiveItems::new(allowed_exhaustive_types.clone()));
+    store.register_early_pass(|| box unnecessary_self_imports::UnnecessarySelfImports);
+    store.register_late_pass(|| box unused_trait_names::UnusedTraitNames::default());
+    store.register_early_pass(|| box visibility::Visibility);
+    let parse_item_categories = |names: &[String]| {
+        names
+            .iter()
+            .filter_map(|name| {
+                arbitrary_source_item_ordering::ItemCategory::from_name(name).or_else(|| {
+                    sess.err(&format!(
+                        "error reading Clippy's configuration file. `{}` is not a valid item kind",
+                        name
+                    ));
+                    None
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let module_item_order = parse_item_categories(&conf.module_item_order);
+    let alphabetical_ordering = parse_item_categories(&conf.alphabetical_ordering);
+    store.register_early_pass(move || {
+        box arbitrary_source_item_ordering::ArbitrarySourceItemOrdering::new(
+            module_item_order.clone(),
+            alphabetical_ordering.clone(),
+        )
+    });
+    store.register_late_pass(|| box default_numeric_fallback::DefaultNumericFallback);
+    let allowed_as_conversions = conf
+        .allowed_as_conversions
+        .iter()
+        .filter_map(|name| {
+            as_conversions::AllowedConversion::from_name(name).or_else(|| {
+                sess.err(&format!(
+                    "error reading Clippy's configuration file. `{}` is not a valid kind of `as` conversion",
+                    name
+                ));
+                None
+            })
+        })
+        .collect::<Vec<_>>();
+    store.register_late_pass(move || box as_conversions::AsConversions::new(allowed_as_conversions.clone()));
+    store.register_early_pass(|| box manual_range_patterns::ManualRangePatterns);
+    let user_facing_macros = conf.user_facing_macros.clone();
+    store.register_late_pass(move || {
+        box unnecessary_debug_formatting::UnnecessaryDebugFormatting::new(user_facing_macros.clone())
+    });
+    let template_functions = conf.template_functions.clone();
+    let check_named_placeholders = conf.check_named_placeholders;
+    store.register_late_pass(move || {
+        box literal_string_with_formatting_args::LiteralStringWithFormattingArgs::new(
+            &template_functions,
+            check_named_placeholders,
+        )
+    });
+    let blocking_call_paths = conf.blocking_call_paths.clone();
+    store.register_late_pass(move || box blocking_call_in_async::BlockingCallInAsync::new(&blocking_call_paths));
+    store.register_late_pass(|| box single_range_in_vec_init::SingleRangeInVecInit);
+    store.register_late_pass(|| box unbounded_channel::UnboundedChannel);
+    store.register_late_pass(|| box manual_retain::ManualRetain);
+    store.register_late_pass(|| box read_zero_byte_vec::ReadZeroByteVec);
+    store.register_late_pass(|| box format_args::FormatArgs);
+    store.register_late_pass(|| box unordered_iter_in_assert::UnorderedIterInAssert);
+    store.register_late_pass(|| box path_join_correctness::PathJoinCorrectness);
+    let disallowed_functions = conf.disallowed_functions.clone();
+    store.register_late_pass(move || box disallowed_function::DisallowedFunction::new(&disallowed_functions));
+    let disallowed_types = conf.disallowed_types.clone();
+    store.register_late_pass(move || box disallowed_type::DisallowedType::new(&disallowed_types));
+    store.register_late_pass(move || box once_cell_lazy::OnceCellLazy::new(msrv));
+    let significant_drop_types = conf.significant_drop_types.clone();
+    store.register_late_pass(move || {
+        box significant_drop_in_scrutinee::SignificantDropInScrutinee::new(&significant_drop_types)
+    });
+    store.register_late_pass(|| box impl_trait_in_params::ImplTraitInParams);
+    store.register_late_pass(|| box thread_spawn_without_join_or_detach_policy::ThreadSpawnWithoutJoinOrDetachPolicy);
+
+    // The unstable lints are only run with `cargo clippy --unstable-lints` or `enable-unstable-lints`
+    // in `clippy.toml`, while they are still experimental. They are registered like the other lints,
+    // so that the lint attributes mentioning them work anyway.
+    if conf.enable_unstable_lints || std::env::var_os("CLIPPY_UNSTABLE_LINTS").is_some() {
+        store.register_late_pass(|| box only_used_in_recursion::OnlyUsedInRecursion);
+    }
+
+    // `unfulfilled_allow` checks which lints were called by the other passes, so it's registered last
+    store.register_late_pass(|| box unfulfilled_allow::UnfulfilledAllow::default());
 
     store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
+        LintId::of(&arbitrary_source_item_ordering::ARBITRARY_SOURCE_ITEM_ORDERING),
         LintId::of(&arithmetic::FLOAT_ARITHMETIC),
         LintId::of(&arithmetic::INTEGER_ARITHMETIC),
         LintId::of(&as_conversions::AS_CONVERSIONS),
@@ -1205,14 +1452,22 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&asm_syntax::INLINE_ASM_X86_INTEL_SYNTAX),
         LintId::of(&create_dir::CREATE_DIR),
         LintId::of(&dbg_macro::DBG_MACRO),
+        LintId::of(&dbg_macro::DBG_MACRO_IN_RELEASE),
+        LintId::of(&default_numeric_fallback::DEFAULT_NUMERIC_FALLBACK),
         LintId::of(&else_if_without_else::ELSE_IF_WITHOUT_ELSE),
+        LintId::of(&empty_with_brackets::EMPTY_ENUM_VARIANTS_WITH_BRACKETS),
+        LintId::of(&error_impl_error::ERROR_IMPL_ERROR),
+        LintId::of(&exhaustive_items::EXHAUSTIVE_ENUMS),
+        LintId::of(&exhaustive_items::EXHAUSTIVE_STRUCTS),
         LintId::of(&exit::EXIT),
         LintId::of(&float_literal::LOSSY_FLOAT_LITERAL),
+        LintId::of(&impl_trait_in_params::IMPL_TRAIT_IN_PARAMS),
         LintId::of(&implicit_return::IMPLICIT_RETURN),
         LintId::of(&indexing_slicing::INDEXING_SLICING),
         LintId::of(&inherent_impl::MULTIPLE_INHERENT_IMPL),
         LintId::of(&integer_division::INTEGER_DIVISION),
         LintId::of(&let_underscore::LET_UNDERSCORE_MUST_USE),
+        LintId::of(&let_underscore::UNDERSCORE_ASSIGNMENT),
         LintId::of(&literal_representation::DECIMAL_LITERAL_REPRESENTATION),
         LintId::of(&matches::REST_PAT_IN_FULLY_BOUND_STRUCTS),
         LintId::of(&matches::WILDCARD_ENUM_MATCH_ARM),
@@ -1229,28 +1484,41 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS),
         LintId::of(&modulo_arithmetic::MODULO_ARITHMETIC),
         LintId::of(&panic_in_result_fn::PANIC_IN_RESULT_FN),
+        LintId::of(&panic_in_result_fn::REACHABLE_PANIC),
         LintId::of(&panic_unimplemented::PANIC),
         LintId::of(&panic_unimplemented::TODO),
         LintId::of(&panic_unimplemented::UNIMPLEMENTED),
         LintId::of(&panic_unimplemented::UNREACHABLE),
+        LintId::of(&panic_unimplemented::UNTRACKED_TODO),
         LintId::of(&pattern_type_mismatch::PATTERN_TYPE_MISMATCH),
         LintId::of(&shadow::SHADOW_REUSE),
         LintId::of(&shadow::SHADOW_SAME),
+        LintId::of(&std_instead_of_core::ALLOC_INSTEAD_OF_CORE),
+        LintId::of(&std_instead_of_core::STD_INSTEAD_OF_ALLOC),
+        LintId::of(&std_instead_of_core::STD_INSTEAD_OF_CORE),
         LintId::of(&strings::STRING_ADD),
         LintId::of(&strings::STRING_TO_STRING),
         LintId::of(&strings::STR_TO_STRING),
+        LintId::of(&thread_spawn_without_join_or_detach_policy::THREAD_SPAWN_WITHOUT_JOIN_OR_DETACH_POLICY),
         LintId::of(&types::RC_BUFFER),
+        LintId::of(&unnecessary_self_imports::UNNECESSARY_SELF_IMPORTS),
+        LintId::of(&unused_trait_names::UNUSED_TRAIT_NAMES),
         LintId::of(&unwrap_in_result::UNWRAP_IN_RESULT),
         LintId::of(&verbose_file_reads::VERBOSE_FILE_READS),
+        LintId::of(&visibility::PUB_WITHOUT_SHORTHAND),
+        LintId::of(&visibility::PUB_WITH_SHORTHAND),
         LintId::of(&write::PRINT_STDOUT),
         LintId::of(&write::USE_DEBUG),
     ]);
 
     store.register_group(true, "clippy::pedantic", Some("clippy_pedantic"), vec![
+        LintId::of(&arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC),
+        LintId::of(&atomic_ordering::SEQ_CST_ORDERING),
         LintId::of(&attrs::INLINE_ALWAYS),
         LintId::of(&await_holding_invalid::AWAIT_HOLDING_LOCK),
         LintId::of(&await_holding_invalid::AWAIT_HOLDING_REFCELL_REF),
         LintId::of(&bit_mask::VERBOSE_BIT_MASK),
+        LintId::of(&blocking_call_in_async::BLOCKING_CALL_IN_ASYNC),
         LintId::of(&checked_conversions::CHECKED_CONVERSIONS),
         LintId::of(&copies::SAME_FUNCTIONS_IN_IF_CONDITION),
         LintId::of(&copy_iterator::COPY_ITERATOR),
@@ -1274,8 +1542,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&items_after_statements::ITEMS_AFTER_STATEMENTS),
         LintId::of(&large_stack_arrays::LARGE_STACK_ARRAYS),
         LintId::of(&let_underscore::LET_UNDERSCORE_DROP),
+        LintId::of(&let_underscore::LET_UNDERSCORE_RESULT),
         LintId::of(&literal_representation::LARGE_DIGIT_GROUPS),
         LintId::of(&literal_representation::UNREADABLE_LITERAL),
+        LintId::of(&literal_string_with_formatting_args::LITERAL_STRING_WITH_FORMATTING_ARGS),
         LintId::of(&loops::EXPLICIT_INTO_ITER_LOOP),
         LintId::of(&loops::EXPLICIT_ITER_LOOP),
         LintId::of(&macro_use::MACRO_USE_IMPORTS),
@@ -1291,6 +1561,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::FILTER_MAP_NEXT),
         LintId::of(&methods::FIND_MAP),
         LintId::of(&methods::INEFFICIENT_TO_STRING),
+        LintId::of(&methods::LINES_FILTER_MAP_OK),
         LintId::of(&methods::MAP_FLATTEN),
         LintId::of(&methods::MAP_UNWRAP_OR),
         LintId::of(&misc::USED_UNDERSCORE_BINDING),
@@ -1299,6 +1570,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&needless_continue::NEEDLESS_CONTINUE),
         LintId::of(&needless_pass_by_value::NEEDLESS_PASS_BY_VALUE),
         LintId::of(&non_expressive_names::SIMILAR_NAMES),
+        LintId::of(&once_cell_lazy::ONCE_CELL_LAZY),
         LintId::of(&option_if_let_else::OPTION_IF_LET_ELSE),
         LintId::of(&pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE),
         LintId::of(&pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF),
@@ -1319,10 +1591,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::INVALID_UPCAST_COMPARISONS),
         LintId::of(&types::LET_UNIT_VALUE),
         LintId::of(&types::LINKEDLIST),
+        LintId::of(&types::NESTED_CONTAINERS),
         LintId::of(&types::OPTION_OPTION),
+        LintId::of(&unbounded_channel::UNBOUNDED_CHANNEL),
         LintId::of(&unicode::NON_ASCII_LITERAL),
         LintId::of(&unicode::UNICODE_NOT_NFC),
+        LintId::of(&unnecessary_debug_formatting::UNNECESSARY_DEBUG_FORMATTING),
         LintId::of(&unnested_or_patterns::UNNESTED_OR_PATTERNS),
+        LintId::of(&unordered_iter_in_assert::UNORDERED_ITER_IN_ASSERT),
         LintId::of(&unused_self::UNUSED_SELF),
         LintId::of(&wildcard_imports::ENUM_GLOB_USE),
         LintId::of(&wildcard_imports::WILDCARD_IMPORTS),
@@ -1334,9 +1610,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&utils::internal_lints::COLLAPSIBLE_SPAN_LINT_CALLS),
         LintId::of(&utils::internal_lints::COMPILER_LINT_FUNCTIONS),
         LintId::of(&utils::internal_lints::DEFAULT_LINT),
+        LintId::of(&utils::internal_lints::INTERNING_DEFINED_SYMBOL),
         LintId::of(&utils::internal_lints::INVALID_PATHS),
         LintId::of(&utils::internal_lints::LINT_WITHOUT_LINT_PASS),
         LintId::of(&utils::internal_lints::MATCH_TYPE_ON_DIAGNOSTIC_ITEM),
+        LintId::of(&utils::internal_lints::MISSING_EXPANSION_CHECK),
         LintId::of(&utils::internal_lints::OUTER_EXPN_EXPN_DATA),
         LintId::of(&utils::internal_lints::PRODUCE_ICE),
     ]);
@@ -1352,6 +1630,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&attrs::DEPRECATED_CFG_ATTR),
         LintId::of(&attrs::DEPRECATED_SEMVER),
         LintId::of(&attrs::MISMATCHED_TARGET_OS),
+        LintId::of(&attrs::MIXED_ATTRIBUTES_STYLE),
         LintId::of(&attrs::UNKNOWN_CLIPPY_LINTS),
         LintId::of(&attrs::USELESS_ATTRIBUTE),
         LintId::of(&bit_mask::BAD_BIT_MASK),
@@ -1367,6 +1646,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&copies::IFS_SAME_COND),
         LintId::of(&copies::IF_SAME_THEN_ELSE),
         LintId::of(&default::FIELD_REASSIGN_WITH_DEFAULT),
+        LintId::of(&default::STRUCT_FIELDS_REST_DEFAULT),
         LintId::of(&derive::DERIVE_HASH_XOR_EQ),
         LintId::of(&derive::DERIVE_ORD_XOR_PARTIAL_ORD),
         LintId::of(&doc::MISSING_SAFETY_DOC),
@@ -1374,8 +1654,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
         LintId::of(&double_parens::DOUBLE_PARENS),
         LintId::of(&drop_forget_ref::DROP_COPY),
+        LintId::of(&drop_forget_ref::DROP_NON_DROP),
         LintId::of(&drop_forget_ref::DROP_REF),
         LintId::of(&drop_forget_ref::FORGET_COPY),
+        LintId::of(&drop_forget_ref::FORGET_NON_DROP),
         LintId::of(&drop_forget_ref::FORGET_REF),
         LintId::of(&duration_subsec::DURATION_SUBSEC),
         LintId::of(&entry::MAP_ENTRY),
@@ -1393,6 +1675,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS),
         LintId::of(&float_literal::EXCESSIVE_PRECISION),
         LintId::of(&format::USELESS_FORMAT),
+        LintId::of(&format_args::FORMAT_IN_FORMAT_ARGS),
         LintId::of(&formatting::POSSIBLE_MISSING_COMMA),
         LintId::of(&formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING),
         LintId::of(&formatting::SUSPICIOUS_ELSE_FORMATTING),
@@ -1414,6 +1697,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&int_plus_one::INT_PLUS_ONE),
         LintId::of(&large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(&large_enum_variant::LARGE_ENUM_VARIANT),
+        LintId::of(&large_error_variant::LARGE_ERROR_VARIANT),
         LintId::of(&len_zero::COMPARISON_TO_EMPTY),
         LintId::of(&len_zero::LEN_WITHOUT_IS_EMPTY),
         LintId::of(&len_zero::LEN_ZERO),
@@ -1428,6 +1712,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&loops::FOR_KV_MAP),
         LintId::of(&loops::FOR_LOOPS_OVER_FALLIBLES),
         LintId::of(&loops::ITER_NEXT_LOOP),
+        LintId::of(&loops::MANUAL_FIND),
         LintId::of(&loops::MANUAL_MEMCPY),
         LintId::of(&loops::MUT_RANGE_BOUND),
         LintId::of(&loops::NEEDLESS_COLLECT),
@@ -1435,16 +1720,19 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&loops::NEVER_LOOP),
         LintId::of(&loops::SAME_ITEM_PUSH),
         LintId::of(&loops::SINGLE_ELEMENT_LOOP),
+        LintId::of(&loops::SPIN_LOOP_WITHOUT_HINT),
         LintId::of(&loops::WHILE_IMMUTABLE_CONDITION),
         LintId::of(&loops::WHILE_LET_LOOP),
         LintId::of(&loops::WHILE_LET_ON_ITERATOR),
         LintId::of(&main_recursion::MAIN_RECURSION),
         LintId::of(&manual_async_fn::MANUAL_ASYNC_FN),
         LintId::of(&manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
+        LintId::of(&manual_range_patterns::MANUAL_RANGE_PATTERNS),
+        LintId::of(&manual_retain::MANUAL_RETAIN),
         LintId::of(&manual_strip::MANUAL_STRIP),
         LintId::of(&manual_unwrap_or::MANUAL_UNWRAP_OR),
         LintId::of(&map_clone::MAP_CLONE),
-        LintId::of(&map_identity::MAP_IDENTITY),
+        LintId::of(&map_clone::MAP_IDENTITY),
         LintId::of(&map_unit_fn::OPTION_MAP_UNIT_FN),
         LintId::of(&map_unit_fn::RESULT_MAP_UNIT_FN),
         LintId::of(&matches::INFALLIBLE_DESTRUCTURING_MATCH),
@@ -1453,6 +1741,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&matches::MATCH_OVERLAPPING_ARM),
         LintId::of(&matches::MATCH_REF_PATS),
         LintId::of(&matches::MATCH_SINGLE_BINDING),
+        LintId::of(&matches::REDUNDANT_GUARDS),
         LintId::of(&matches::REDUNDANT_PATTERN_MATCHING),
         LintId::of(&matches::SINGLE_MATCH),
         LintId::of(&matches::WILDCARD_IN_OR_PATTERNS),
@@ -1476,14 +1765,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::ITER_NTH),
         LintId::of(&methods::ITER_NTH_ZERO),
         LintId::of(&methods::ITER_SKIP_NEXT),
+        LintId::of(&methods::MANUAL_CONTAINS),
+        LintId::of(&methods::MANUAL_INSPECT),
         LintId::of(&methods::MANUAL_SATURATING_ARITHMETIC),
         LintId::of(&methods::MAP_COLLECT_RESULT_UNIT),
         LintId::of(&methods::NEW_RET_NO_SELF),
         LintId::of(&methods::OK_EXPECT),
+        LintId::of(&methods::OPTION_AS_REF_CLONED),
         LintId::of(&methods::OPTION_AS_REF_DEREF),
         LintId::of(&methods::OPTION_MAP_OR_NONE),
         LintId::of(&methods::OR_FUN_CALL),
         LintId::of(&methods::RESULT_MAP_OR_INTO_OPTION),
+        LintId::of(&methods::RESULT_OK_OK_OR),
         LintId::of(&methods::SEARCH_IS_SOME),
         LintId::of(&methods::SHOULD_IMPLEMENT_TRAIT),
         LintId::of(&methods::SINGLE_CHAR_ADD_STR),
@@ -1517,6 +1810,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&mut_mutex_lock::MUT_MUTEX_LOCK),
         LintId::of(&mut_reference::UNNECESSARY_MUT_PASSED),
         LintId::of(&mutex_atomic::MUTEX_ATOMIC),
+        LintId::of(&mutex_atomic::MUTEX_INTEGER_USE_ATOMIC),
         LintId::of(&needless_arbitrary_self_type::NEEDLESS_ARBITRARY_SELF_TYPE),
         LintId::of(&needless_bool::BOOL_COMPARISON),
         LintId::of(&needless_bool::NEEDLESS_BOOL),
@@ -1531,6 +1825,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST),
         LintId::of(&non_expressive_names::JUST_UNDERSCORES_AND_DIGITS),
         LintId::of(&non_expressive_names::MANY_SINGLE_CHAR_NAMES),
+        LintId::of(&only_used_in_recursion::ONLY_USED_IN_RECURSION),
         LintId::of(&open_options::NONSENSICAL_OPEN_OPTIONS),
         LintId::of(&option_env_unwrap::OPTION_ENV_UNWRAP),
         LintId::of(&overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL),
@@ -1545,6 +1840,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&ranges::MANUAL_RANGE_CONTAINS),
         LintId::of(&ranges::RANGE_ZIP_WITH_LEN),
         LintId::of(&ranges::REVERSED_EMPTY_RANGES),
+        LintId::of(&read_zero_byte_vec::READ_ZERO_BYTE_VEC),
         LintId::of(&redundant_clone::REDUNDANT_CLONE),
         LintId::of(&redundant_closure_call::REDUNDANT_CLOSURE_CALL),
         LintId::of(&redundant_field_names::REDUNDANT_FIELD_NAMES),
@@ -1559,6 +1855,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&self_assignment::SELF_ASSIGNMENT),
         LintId::of(&serde_api::SERDE_API_MISUSE),
         LintId::of(&single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS),
+        LintId::of(&single_range_in_vec_init::SINGLE_RANGE_IN_VEC_INIT),
         LintId::of(&slow_vector_initialization::SLOW_VECTOR_INITIALIZATION),
         LintId::of(&stable_sort_primitive::STABLE_SORT_PRIMITIVE),
         LintId::of(&strings::STRING_FROM_UTF8_AS_BYTES),
@@ -1625,6 +1922,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&assertions_on_constants::ASSERTIONS_ON_CONSTANTS),
         LintId::of(&assign_ops::ASSIGN_OP_PATTERN),
         LintId::of(&attrs::BLANKET_CLIPPY_RESTRICTION_LINTS),
+        LintId::of(&attrs::MIXED_ATTRIBUTES_STYLE),
         LintId::of(&attrs::UNKNOWN_CLIPPY_LINTS),
         LintId::of(&blacklisted_name::BLACKLISTED_NAME),
         LintId::of(&blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS),
@@ -1632,6 +1930,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&collapsible_match::COLLAPSIBLE_MATCH),
         LintId::of(&comparison_chain::COMPARISON_CHAIN),
         LintId::of(&default::FIELD_REASSIGN_WITH_DEFAULT),
+        LintId::of(&default::STRUCT_FIELDS_REST_DEFAULT),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&enum_variants::ENUM_VARIANT_NAMES),
@@ -1681,8 +1980,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::MAP_COLLECT_RESULT_UNIT),
         LintId::of(&methods::NEW_RET_NO_SELF),
         LintId::of(&methods::OK_EXPECT),
+        LintId::of(&methods::OPTION_AS_REF_CLONED),
         LintId::of(&methods::OPTION_MAP_OR_NONE),
         LintId::of(&methods::RESULT_MAP_OR_INTO_OPTION),
+        LintId::of(&methods::RESULT_OK_OK_OR),
         LintId::of(&methods::SHOULD_IMPLEMENT_TRAIT),
         LintId::of(&methods::SINGLE_CHAR_ADD_STR),
         LintId::of(&methods::STRING_EXTEND_CHARS),
@@ -1715,6 +2016,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&returns::LET_AND_RETURN),
         LintId::of(&returns::NEEDLESS_RETURN),
         LintId::of(&single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS),
+        LintId::of(&single_range_in_vec_init::SINGLE_RANGE_IN_VEC_INIT),
         LintId::of(&suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS),
         LintId::of(&tabs_in_doc_comments::TABS_IN_DOC_COMMENTS),
         LintId::of(&to_digit_is_some::TO_DIGIT_IS_SOME),
@@ -1737,6 +2039,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&booleans::NONMINIMAL_BOOL),
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
         LintId::of(&double_parens::DOUBLE_PARENS),
+        LintId::of(&drop_forget_ref::DROP_NON_DROP),
+        LintId::of(&drop_forget_ref::FORGET_NON_DROP),
         LintId::of(&duration_subsec::DURATION_SUBSEC),
         LintId::of(&eval_order_dependence::DIVERGING_SUB_EXPRESSION),
         LintId::of(&eval_order_dependence::EVAL_ORDER_DEPENDENCE),
@@ -1749,21 +2053,25 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&lifetimes::EXTRA_UNUSED_LIFETIMES),
         LintId::of(&lifetimes::NEEDLESS_LIFETIMES),
         LintId::of(&loops::EXPLICIT_COUNTER_LOOP),
+        LintId::of(&loops::MANUAL_FIND),
         LintId::of(&loops::MUT_RANGE_BOUND),
         LintId::of(&loops::SINGLE_ELEMENT_LOOP),
         LintId::of(&loops::WHILE_LET_LOOP),
+        LintId::of(&manual_range_patterns::MANUAL_RANGE_PATTERNS),
         LintId::of(&manual_strip::MANUAL_STRIP),
         LintId::of(&manual_unwrap_or::MANUAL_UNWRAP_OR),
-        LintId::of(&map_identity::MAP_IDENTITY),
+        LintId::of(&map_clone::MAP_IDENTITY),
         LintId::of(&map_unit_fn::OPTION_MAP_UNIT_FN),
         LintId::of(&map_unit_fn::RESULT_MAP_UNIT_FN),
         LintId::of(&matches::MATCH_AS_REF),
         LintId::of(&matches::MATCH_SINGLE_BINDING),
+        LintId::of(&matches::REDUNDANT_GUARDS),
         LintId::of(&matches::WILDCARD_IN_OR_PATTERNS),
         LintId::of(&methods::BIND_INSTEAD_OF_MAP),
         LintId::of(&methods::CLONE_ON_COPY),
         LintId::of(&methods::FILTER_NEXT),
         LintId::of(&methods::FLAT_MAP_IDENTITY),
+        LintId::of(&methods::MANUAL_INSPECT),
         LintId::of(&methods::OPTION_AS_REF_DEREF),
         LintId::of(&methods::SEARCH_IS_SOME),
         LintId::of(&methods::SKIP_WHILE_NEXT),
@@ -1781,6 +2089,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD),
         LintId::of(&no_effect::NO_EFFECT),
         LintId::of(&no_effect::UNNECESSARY_OPERATION),
+        LintId::of(&only_used_in_recursion::ONLY_USED_IN_RECURSION),
         LintId::of(&overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL),
         LintId::of(&partialeq_ne_impl::PARTIALEQ_NE_IMPL),
         LintId::of(&precedence::PRECEDENCE),
@@ -1865,6 +2174,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&option_env_unwrap::OPTION_ENV_UNWRAP),
         LintId::of(&ptr::MUT_FROM_REF),
         LintId::of(&ranges::REVERSED_EMPTY_RANGES),
+        LintId::of(&read_zero_byte_vec::READ_ZERO_BYTE_VEC),
         LintId::of(&regex::INVALID_REGEX),
         LintId::of(&self_assignment::SELF_ASSIGNMENT),
         LintId::of(&serde_api::SERDE_API_MISUSE),
@@ -1892,16 +2202,22 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&bytecount::NAIVE_BYTECOUNT),
         LintId::of(&entry::MAP_ENTRY),
         LintId::of(&escape::BOXED_LOCAL),
+        LintId::of(&format_args::FORMAT_IN_FORMAT_ARGS),
         LintId::of(&large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(&large_enum_variant::LARGE_ENUM_VARIANT),
+        LintId::of(&large_error_variant::LARGE_ERROR_VARIANT),
         LintId::of(&loops::MANUAL_MEMCPY),
         LintId::of(&loops::NEEDLESS_COLLECT),
+        LintId::of(&loops::SPIN_LOOP_WITHOUT_HINT),
+        LintId::of(&manual_retain::MANUAL_RETAIN),
         LintId::of(&methods::EXPECT_FUN_CALL),
         LintId::of(&methods::ITER_NTH),
+        LintId::of(&methods::MANUAL_CONTAINS),
         LintId::of(&methods::OR_FUN_CALL),
         LintId::of(&methods::SINGLE_CHAR_PATTERN),
         LintId::of(&misc::CMP_OWNED),
         LintId::of(&mutex_atomic::MUTEX_ATOMIC),
+        LintId::of(&mutex_atomic::MUTEX_INTEGER_USE_ATOMIC),
         LintId::of(&redundant_clone::REDUNDANT_CLONE),
         LintId::of(&slow_vector_initialization::SLOW_VECTOR_INITIALIZATION),
         LintId::of(&stable_sort_primitive::STABLE_SORT_PRIMITIVE),
@@ -1912,29 +2228,60 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     store.register_group(true, "clippy::cargo", Some("clippy_cargo"), vec![
         LintId::of(&cargo_common_metadata::CARGO_COMMON_METADATA),
+        LintId::of(&feature_name::NEGATIVE_FEATURE_NAMES),
+        LintId::of(&feature_name::REDUNDANT_FEATURE_NAMES),
+        LintId::of(&lint_groups_priority::LINT_GROUPS_PRIORITY),
         LintId::of(&multiple_crate_versions::MULTIPLE_CRATE_VERSIONS),
+        LintId::of(&wildcard_dependencies::PATH_DEPENDENCIES),
+        LintId::of(&wildcard_dependencies::UNPINNED_GIT_DEPENDENCIES),
         LintId::of(&wildcard_dependencies::WILDCARD_DEPENDENCIES),
     ]);
 
     store.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
+        LintId::of(&atomic_ordering::ATOMIC_ORDERING_MISUSE),
         LintId::of(&attrs::EMPTY_LINE_AFTER_OUTER_ATTR),
         LintId::of(&cognitive_complexity::COGNITIVE_COMPLEXITY),
+        LintId::of(&disallowed_function::DISALLOWED_FUNCTION),
+        LintId::of(&disallowed_macro::DISALLOWED_MACRO),
         LintId::of(&disallowed_method::DISALLOWED_METHOD),
+        LintId::of(&disallowed_type::DISALLOWED_TYPE),
         LintId::of(&fallible_impl_from::FALLIBLE_IMPL_FROM),
         LintId::of(&floating_point_arithmetic::IMPRECISE_FLOPS),
         LintId::of(&floating_point_arithmetic::SUBOPTIMAL_FLOPS),
         LintId::of(&future_not_send::FUTURE_NOT_SEND),
         LintId::of(&let_if_seq::USELESS_LET_IF_SEQ),
+        LintId::of(&loops::WHILE_FLOAT),
         LintId::of(&missing_const_for_fn::MISSING_CONST_FOR_FN),
         LintId::of(&mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
         LintId::of(&mutex_atomic::MUTEX_INTEGER),
         LintId::of(&needless_borrow::NEEDLESS_BORROW),
+        LintId::of(&needless_borrow::NEEDLESS_BORROWS_FOR_GENERIC_ARGS),
         LintId::of(&path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE),
+        LintId::of(&path_join_correctness::JOIN_ABSOLUTE_PATHS),
         LintId::of(&redundant_pub_crate::REDUNDANT_PUB_CRATE),
+        LintId::of(&significant_drop_in_scrutinee::SIGNIFICANT_DROP_IN_SCRUTINEE),
         LintId::of(&strings::STRING_LIT_AS_BYTES),
         LintId::of(&transmute::USELESS_TRANSMUTE),
+        LintId::of(&unfulfilled_allow::UNFULFILLED_ALLOW),
         LintId::of(&use_self::USE_SELF),
     ]);
+
+    // Not a category: the lints keep their own category and default level. This list isn't
+    // generated by `update_lints`, new error handling lints need to be added by hand. Lints
+    // suggesting `map_err(|_| ..)`, like `result_ok_ok_or`, are left out since `map_err_ignore`
+    // flags it.
+    store.register_group(true, "clippy::error_handling", None, vec![
+        LintId::of(&functions::RESULT_UNIT_ERR),
+        LintId::of(&let_underscore::LET_UNDERSCORE_RESULT),
+        LintId::of(&map_err_ignore::MAP_ERR_IGNORE),
+        LintId::of(&methods::EXPECT_USED),
+        LintId::of(&methods::OK_EXPECT),
+        LintId::of(&methods::UNWRAP_USED),
+        LintId::of(&panic_in_result_fn::PANIC_IN_RESULT_FN),
+        LintId::of(&panic_in_result_fn::REACHABLE_PANIC),
+        LintId::of(&panic_unimplemented::PANIC),
+        LintId::of(&unwrap_in_result::UNWRAP_IN_RESULT),
+    ]);
 }
 
 #[rustfmt::skip]
@@ -1983,27 +2330,13 @@ fn register_removed_non_tool_lints(store: &mut rustc_lint::LintStore) {
 
 /// Register renamed lints.
 ///
+/// The renames are listed in `renamed_lints.rs`.
+///
 /// Used in `./src/driver.rs`.
 pub fn register_renamed(ls: &mut rustc_lint::LintStore) {
-    ls.register_renamed("clippy::stutter", "clippy::module_name_repetitions");
-    ls.register_renamed("clippy::new_without_default_derive", "clippy::new_without_default");
-    ls.register_renamed("clippy::cyclomatic_complexity", "clippy::cognitive_complexity");
-    ls.register_renamed("clippy::const_static_lifetime", "clippy::redundant_static_lifetimes");
-    ls.register_renamed("clippy::option_and_then_some", "clippy::bind_instead_of_map");
-    ls.register_renamed("clippy::block_in_if_condition_expr", "clippy::blocks_in_if_conditions");
-    ls.register_renamed("clippy::block_in_if_condition_stmt", "clippy::blocks_in_if_conditions");
-    ls.register_renamed("clippy::option_map_unwrap_or", "clippy::map_unwrap_or");
-    ls.register_renamed("clippy::option_map_unwrap_or_else", "clippy::map_unwrap_or");
-    ls.register_renamed("clippy::result_map_unwrap_or_else", "clippy::map_unwrap_or");
-    ls.register_renamed("clippy::option_unwrap_used", "clippy::unwrap_used");
-    ls.register_renamed("clippy::result_unwrap_used", "clippy::unwrap_used");
-    ls.register_renamed("clippy::option_expect_used", "clippy::expect_used");
-    ls.register_renamed("clippy::result_expect_used", "clippy::expect_used");
-    ls.register_renamed("clippy::for_loop_over_option", "clippy::for_loops_over_fallibles");
-    ls.register_renamed("clippy::for_loop_over_result", "clippy::for_loops_over_fallibles");
-    ls.register_renamed("clippy::identity_conversion", "clippy::useless_conversion");
-    ls.register_renamed("clippy::zero_width_space", "clippy::invisible_characters");
-    ls.register_renamed("clippy::single_char_push_str", "clippy::single_char_add_str");
+    for (old_name, new_name) in renamed_lints::RENAMED_LINTS {
+        ls.register_renamed(old_name, new_name);
+    }
 }
 
 // only exists to let the dogfood integration test works.
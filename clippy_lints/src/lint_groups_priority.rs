@@ -0,0 +1,170 @@
+//! lint on lint groups in `[lints.clippy]` which have the same priority as the lints they contain
+
+use std::fs;
+use std::path::Path;
+
+use crate::utils::{run_lints, span_lint, span_lint_and_then};
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_hir::{hir_id::CRATE_HIR_ID, Crate};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::DUMMY_SP;
+use toml::Value;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for lint groups in the `[lints.clippy]` table of the `Cargo.toml`
+    /// which have the same priority as lints they contain.
+    ///
+    /// **Why is this bad?** The entries of the table are applied in the order of their priority,
+    /// and in an unspecified order for entries with the same priority. A lint that is meant to
+    /// override the level of its group may end up being overridden by the group instead.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// [lints.clippy]
+    /// pedantic = "warn"
+    /// similar_names = "allow"
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// [lints.clippy]
+    /// pedantic = { level = "warn", priority = -1 }
+    /// similar_names = "allow"
+    /// ```
+    pub LINT_GROUPS_PRIORITY,
+    cargo,
+    "a lint group in `Cargo.toml` has the same priority as lints it contains"
+}
+
+declare_lint_pass!(LintGroupsPriority => [LINT_GROUPS_PRIORITY]);
+
+/// An entry of a `[lints.clippy]` table.
+struct LintEntry<'a> {
+    name: &'a str,
+    level: &'a str,
+    priority: i64,
+}
+
+impl<'a> LintEntry<'a> {
+    fn parse(name: &'a str, value: &'a Value) -> Option<Self> {
+        let (level, priority) = match value {
+            Value::String(level) => (level.as_str(), 0),
+            Value::Table(table) => (
+                table.get("level")?.as_str()?,
+                table.get("priority").and_then(Value::as_integer).unwrap_or(0),
+            ),
+            _ => return None,
+        };
+        Some(Self { name, level, priority })
+    }
+}
+
+impl LateLintPass<'_> for LintGroupsPriority {
+    fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
+        if !run_lints(cx, &[LINT_GROUPS_PRIORITY], CRATE_HIR_ID) {
+            return;
+        }
+
+        let metadata = unwrap_cargo_metadata!(cx, LINT_GROUPS_PRIORITY, false);
+        let local_name = cx.tcx.crate_name(LOCAL_CRATE).as_str();
+        if let Some(package) = metadata
+            .packages
+            .iter()
+            .find(|p| p.name.replace('-', "_") == *local_name)
+        {
+            check_manifest(cx, &package.manifest_path, &["lints", "clippy"]);
+        }
+        check_manifest(
+            cx,
+            &metadata.workspace_root.join("Cargo.toml"),
+            &["workspace", "lints", "clippy"],
+        );
+    }
+}
+
+fn check_manifest(cx: &LateContext<'_>, manifest_path: &Path, table_path: &[&str]) {
+    let manifest = match fs::read_to_string(manifest_path).map(|content| content.parse::<Value>()) {
+        Ok(Ok(manifest)) => manifest,
+        Ok(Err(e)) => {
+            let msg = format!("could not parse `{}`: {}", manifest_path.display(), e);
+            span_lint(cx, LINT_GROUPS_PRIORITY, DUMMY_SP, &msg);
+            return;
+        },
+        Err(e) => {
+            let msg = format!("could not read `{}`: {}", manifest_path.display(), e);
+            span_lint(cx, LINT_GROUPS_PRIORITY, DUMMY_SP, &msg);
+            return;
+        },
+    };
+    let table = match table_path
+        .iter()
+        .try_fold(&manifest, |value, key| value.get(key))
+        .and_then(Value::as_table)
+    {
+        Some(table) => table,
+        None => return,
+    };
+
+    let entries: Vec<LintEntry<'_>> = table
+        .iter()
+        .filter_map(|(name, value)| LintEntry::parse(name, value))
+        .collect();
+    let table_name = table_path.join(".");
+
+    for group in entries.iter().filter(|entry| is_group_name(entry.name)) {
+        let members = match cx.lints().find_lints(&format!("clippy::{}", group.name)) {
+            Ok(members) => members,
+            Err(()) => continue,
+        };
+
+        let conflicts: Vec<&LintEntry<'_>> = entries
+            .iter()
+            .filter(|lint| {
+                lint.priority == group.priority
+                    && lint.level != group.level
+                    && !is_group_name(lint.name)
+                    && cx
+                        .lints()
+                        .find_lints(&format!("clippy::{}", lint.name.replace('-', "_")))
+                        .map_or(false, |ids| ids.iter().all(|id| members.contains(id)))
+            })
+            .collect();
+        if conflicts.is_empty() {
+            continue;
+        }
+
+        span_lint_and_then(
+            cx,
+            LINT_GROUPS_PRIORITY,
+            DUMMY_SP,
+            &format!(
+                "lint group `{}` has the same priority ({}) as a lint in `[{}]`",
+                group.name, group.priority, table_name
+            ),
+            |diag| {
+                for lint in &conflicts {
+                    diag.note(&format!(
+                        "`{}` is set to `{}` and is part of the group",
+                        lint.name, lint.level
+                    ));
+                }
+                diag.help(&format!(
+                    "to have lints override the group set `{}` to a lower priority: `{} = {{ level = \"{}\", priority = {} }}`",
+                    group.name,
+                    group.name,
+                    group.level,
+                    group.priority - 1
+                ));
+            },
+        );
+    }
+}
+
+fn is_group_name(name: &str) -> bool {
+    matches!(
+        name,
+        "all" | "cargo" | "complexity" | "correctness" | "nursery" | "pedantic" | "perf" | "restriction" | "style"
+    )
+}
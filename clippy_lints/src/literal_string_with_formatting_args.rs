@@ -0,0 +1,153 @@
+use crate::utils::{in_macro, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for string literals containing formatting placeholders, like
+    /// `{}`, `{0}`, `{:?}` or `{name}`, which are passed as `&str` arguments of a function or
+    /// method instead of to a formatting macro.
+    ///
+    /// Functions which take template strings can be allowed with the `template-functions`
+    /// configuration, e.g. `template-functions = ["my_crate::log::info"]`. Named placeholders
+    /// are not checked if `check-named-placeholders` is set to `false`.
+    ///
+    /// **Why is this bad?** The placeholders will not be replaced. This usually means that a call
+    /// to `format!` was forgotten.
+    ///
+    /// **Known problems:** Functions taking template strings in other syntaxes with braces
+    /// need to be added to the configuration. Arguments of generic parameter types, like
+    /// `impl Into<String>`, are not checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name = "world";
+    /// let mut greeting = String::from("Hello, ");
+    /// greeting.push_str("{}!");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "world";
+    /// let mut greeting = String::from("Hello, ");
+    /// greeting.push_str(&format!("{}!", name));
+    /// ```
+    pub LITERAL_STRING_WITH_FORMATTING_ARGS,
+    pedantic,
+    "string literals with formatting placeholders passed outside of a formatting macro"
+}
+
+#[derive(Clone)]
+pub struct LiteralStringWithFormattingArgs {
+    template_functions: FxHashSet<Vec<Symbol>>,
+    check_named: bool,
+}
+
+impl LiteralStringWithFormattingArgs {
+    pub fn new(template_functions: &[String], check_named: bool) -> Self {
+        Self {
+            template_functions: template_functions
+                .iter()
+                .map(|s| s.split("::").map(|seg| Symbol::intern(seg)).collect::<Vec<_>>())
+                .collect(),
+            check_named,
+        }
+    }
+}
+
+impl_lint_pass!(LiteralStringWithFormattingArgs => [LITERAL_STRING_WITH_FORMATTING_ARGS]);
+
+impl<'tcx> LateLintPass<'tcx> for LiteralStringWithFormattingArgs {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+
+        let (def_id, args, skip) = match expr.kind {
+            ExprKind::Call(ref func, args) => match cx.typeck_results().expr_ty(func).kind() {
+                ty::FnDef(def_id, _) => (Some(*def_id), args, 0),
+                _ => return,
+            },
+            // the receiver is formatted by the method, if at all, e.g. `"{}".replace("{}", ..)`
+            ExprKind::MethodCall(_, _, args, _) => (cx.typeck_results().type_dependent_def_id(expr.hir_id), args, 1),
+            _ => return,
+        };
+        let def_id = match def_id {
+            Some(def_id) if !self.template_functions.contains(&cx.get_def_path(def_id)) => def_id,
+            _ => return,
+        };
+
+        let inputs = cx.tcx.fn_sig(def_id).inputs().skip_binder();
+        for (arg, input) in args.iter().zip(inputs.iter()).skip(skip) {
+            if_chain! {
+                // generic parameters, like the `Pattern` of `str::replace`, may not be displayed
+                if let ty::Ref(_, inner, _) = input.kind();
+                if *inner.kind() == ty::Str;
+                if let ExprKind::Lit(ref lit) = arg.kind;
+                if let LitKind::Str(s, _) = lit.node;
+                if has_placeholder(&s.as_str(), self.check_named);
+                then {
+                    span_lint_and_help(
+                        cx,
+                        LITERAL_STRING_WITH_FORMATTING_ARGS,
+                        arg.span,
+                        "this looks like a formatting argument but it is not part of a formatting macro",
+                        None,
+                        "if this is meant to be formatted, use `format!` or a similar macro",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the string contains a formatting placeholder, like `{}`, `{0}` or `{:?}`, and if
+/// `named` is set, `{name}`. `{{` is an escaped brace.
+fn has_placeholder(s: &str, named: bool) -> bool {
+    let mut rest = s;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        if rest.starts_with('{') {
+            rest = &rest[1..];
+            continue;
+        }
+        let close = match rest.find('}') {
+            Some(close) => close,
+            None => return false,
+        };
+        let (arg, spec) = match rest[..close].find(':') {
+            Some(colon) => (&rest[..colon], Some(&rest[colon + 1..close])),
+            None => (&rest[..close], None),
+        };
+        let is_arg = arg.is_empty() || arg.chars().all(|c| c.is_ascii_digit()) || (named && is_ident(arg));
+        if is_arg && spec.map_or(true, is_format_spec) {
+            return true;
+        }
+        rest = &rest[close + 1..];
+    }
+    false
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => s != "_" && chars.all(|c| c.is_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+/// Checks if the string is the part of a placeholder after the `:`, like `?` or `>8.2`.
+fn is_format_spec(spec: &str) -> bool {
+    let mut chars = spec.chars();
+    // an alignment, optionally preceded by a fill character
+    let rest = match (chars.next(), chars.next()) {
+        (Some(_), Some('<' | '^' | '>')) => chars.as_str(),
+        (Some('<' | '^' | '>'), _) => &spec[1..],
+        _ => spec,
+    };
+    rest.chars().all(|c| c.is_ascii_alphanumeric() || "+-#.$?_".contains(c))
+}
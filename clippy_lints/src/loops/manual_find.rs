@@ -0,0 +1,300 @@
+use super::summary::{Action, IterSource, LoopSummary};
+use super::{get_span_of_entire_for_loop, same_var, var_def_id, MANUAL_FIND};
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::mutated_variables;
+use crate::utils::visitors::LocalUsedVisitor;
+use crate::utils::{
+    contains_return, get_parent_expr, get_trait_def_id, has_iter_method, implements_trait, is_copy,
+    is_type_diagnostic_item, match_qpath, paths, snippet, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    BindingAnnotation, Block, BorrowKind, Expr, ExprKind, HirId, Local, Mutability, Pat, PatKind, Stmt, StmtKind,
+};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::hir::map::Map;
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_span::symbol::{sym, Symbol};
+
+/// The two shapes of loops `MANUAL_FIND` lints.
+enum FindLoop<'tcx> {
+    /// `for x in iter { if cond { return Some(x); } } None`, with the `None` or `return None`
+    /// expression following the loop
+    Return(&'tcx Expr<'tcx>),
+    /// `let mut res = None; for x in iter { if cond { res = Some(x); break; } }`, with the `let`
+    /// statement preceding the loop
+    Break(&'tcx Local<'tcx>, &'tcx Stmt<'tcx>, Symbol),
+}
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+    for (i, stmt) in block.stmts.iter().enumerate() {
+        let for_expr = match stmt.kind {
+            StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => match expr.kind {
+                ExprKind::DropTemps(ref inner) => inner,
+                _ => expr,
+            },
+            _ => continue,
+        };
+        let summary = match LoopSummary::new(cx, for_expr) {
+            Some(summary) => summary,
+            None => continue,
+        };
+        let arg = match summary.source {
+            IterSource::IntoIter(arg) => arg,
+            IterSource::Next(_) => continue,
+        };
+        if summary.body.span.from_expansion() || in_external_macro(cx.sess(), stmt.span) {
+            continue;
+        }
+        let (pat, binding_id, binding_name) = match summary.pat {
+            Some(pat) => match pat.kind {
+                PatKind::Binding(BindingAnnotation::Unannotated, id, ident, None) => (pat, id, ident.name),
+                _ => continue,
+            },
+            None => continue,
+        };
+
+        // The body must consist of a single `if` without `else`
+        let cond = match summary.effect.cond {
+            Some(cond) if !cond.span.from_expansion() && !contains_return(cond) => cond,
+            _ => continue,
+        };
+
+        let kind = match summary.effect.action {
+            // `return Some(x)`, followed by `None` or `return None`
+            Action::Return(ret) => {
+                if_chain! {
+                    if is_some_of(cx, ret, binding_id);
+                    if let Some(tail) = expr_after(block, i);
+                    if (is_none_path(cx, tail) && is_body_of_fn(cx, block))
+                        || matches!(tail.kind, ExprKind::Ret(Some(ref none)) if is_none_path(cx, none));
+                    then {
+                        FindLoop::Return(tail)
+                    } else {
+                        continue;
+                    }
+                }
+            },
+            // `res = Some(x); break;`, preceded by `let mut res = None;`
+            Action::AssignAndBreak(res_id, value) => {
+                if_chain! {
+                    if i > 0;
+                    if let StmtKind::Local(ref local) = block.stmts[i - 1].kind;
+                    if let PatKind::Binding(BindingAnnotation::Mutable, id, res_ident, None) = local.pat.kind;
+                    if id == res_id;
+                    if let Some(ref init) = local.init;
+                    if is_none_path(cx, init);
+                    if is_some_of(cx, value, binding_id);
+                    let mut visitor = LocalUsedVisitor::new(res_id);
+                    if !visitor.check_expr(cond);
+                    then {
+                        FindLoop::Break(local, &block.stmts[i - 1], res_ident.name)
+                    } else {
+                        continue;
+                    }
+                }
+            },
+            Action::Other(_) => continue,
+        };
+
+        let mut applicability = Applicability::MachineApplicable;
+        let closure = find_closure(cx, pat, binding_id, binding_name, cond, &mut applicability);
+        let iter = find_receiver(cx, arg, &mut applicability);
+        let loop_span = get_span_of_entire_for_loop(for_expr);
+        let (span, sugg) = match kind {
+            FindLoop::Return(tail) => {
+                let ret = if let ExprKind::Ret(..) = tail.kind {
+                    "return "
+                } else {
+                    ""
+                };
+                (
+                    loop_span.with_hi(tail.span.hi()),
+                    format!("{}{}.find({})", ret, iter, closure),
+                )
+            },
+            FindLoop::Break(local, local_stmt, name) => {
+                let is_mutated_later = block.stmts[i + 1..]
+                    .iter()
+                    .filter_map(|stmt| match stmt.kind {
+                        StmtKind::Local(ref local) => local.init.as_deref(),
+                        StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(expr),
+                        StmtKind::Item(..) => None,
+                    })
+                    .chain(block.expr.as_deref())
+                    .any(|expr| mutated_variables(expr, cx).map_or(true, |vars| vars.contains(&local.pat.hir_id)));
+                let mutability = if is_mutated_later { "mut " } else { "" };
+                let ty = local
+                    .ty
+                    .map_or_else(String::new, |ty| format!(": {}", snippet(cx, ty.span, "..")));
+                (
+                    local_stmt.span.with_hi(stmt.span.hi()),
+                    format!("let {}{}{} = {}.find({});", mutability, name, ty, iter, closure),
+                )
+            },
+        };
+
+        span_lint_and_sugg(
+            cx,
+            MANUAL_FIND,
+            span,
+            "manual implementation of `Iterator::find`",
+            "replace with an iterator",
+            sugg,
+            applicability,
+        );
+    }
+}
+
+/// Returns the expression which is evaluated last in the block, if it directly follows the
+/// statement at index `i`.
+fn expr_after<'tcx>(block: &'tcx Block<'tcx>, i: usize) -> Option<&'tcx Expr<'tcx>> {
+    match (&block.stmts[i + 1..], block.expr) {
+        ([], Some(expr)) => Some(expr),
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(expr),
+            StmtKind::Local(..) | StmtKind::Item(..) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks if the block is the body of a function or closure, so that its value is returned.
+fn is_body_of_fn(cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+    let map = cx.tcx.hir();
+    let body = map.body(map.body_owned_by(map.enclosing_body_owner(block.hir_id)));
+    matches!(body.value.kind, ExprKind::Block(ref body_block, _) if body_block.hir_id == block.hir_id)
+}
+
+fn is_none_path(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Path(ref qpath) if match_qpath(qpath, &paths::OPTION_NONE))
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::option_type)
+}
+
+/// Checks if the expression is `Some(var)`.
+fn is_some_of(cx: &LateContext<'_>, expr: &Expr<'_>, var: HirId) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref func, [ref arg]) = expr.kind;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if match_qpath(qpath, &paths::OPTION_SOME);
+        then {
+            same_var(cx, arg, var)
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the expression to call `find` on.
+fn find_receiver(cx: &LateContext<'_>, arg: &Expr<'_>, applicability: &mut Applicability) -> String {
+    // `&v` and `v: &Vec<_>` become `v.iter()`
+    let collection = match (&arg.kind, cx.typeck_results().expr_ty(arg).kind()) {
+        (ExprKind::AddrOf(BorrowKind::Ref, mutability, inner), _) => Some((*inner, *mutability)),
+        (_, ty::Ref(_, _, mutability)) => Some((arg, *mutability)),
+        _ => None,
+    };
+    if let Some((collection, mutability)) = collection {
+        if has_iter_method(cx, cx.typeck_results().expr_ty(collection)).is_some() {
+            let method = match mutability {
+                Mutability::Mut => "iter_mut",
+                Mutability::Not => "iter",
+            };
+            let collection = Sugg::hir_with_applicability(cx, collection, "..", applicability).maybe_par();
+            return format!("{}.{}()", collection, method);
+        }
+    }
+
+    let receiver = Sugg::hir_with_applicability(cx, arg, "..", applicability).maybe_par();
+    let is_iterator = get_trait_def_id(cx, &paths::ITERATOR).map_or(false, |id| {
+        implements_trait(cx, cx.typeck_results().expr_ty(arg), id, &[])
+    });
+    if is_iterator {
+        // `find` takes `&mut self`, so a local iterator has to be declared as mutable
+        if var_def_id(cx, arg).is_some() {
+            *applicability = Applicability::MaybeIncorrect;
+        }
+        receiver.to_string()
+    } else {
+        format!("{}.into_iter()", receiver)
+    }
+}
+
+/// Builds the closure passed to `find` from the condition of the loop. The closure takes the
+/// item by reference, so items which aren't `Copy` are dereferenced where needed.
+fn find_closure<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &Pat<'_>,
+    binding_id: HirId,
+    binding_name: Symbol,
+    cond: &'tcx Expr<'_>,
+    applicability: &mut Applicability,
+) -> String {
+    let cond_snippet = snippet_with_applicability(cx, cond.span, "..", applicability);
+    if is_copy(cx, cx.typeck_results().pat_ty(pat)) {
+        return format!("|&{}| {}", binding_name, cond_snippet);
+    }
+
+    let mut visitor = BindingUsesVisitor {
+        cx,
+        binding_id,
+        uses: Vec::new(),
+    };
+    visitor.visit_expr(cond);
+
+    let mut replacements = Vec::new();
+    for usage in visitor.uses {
+        if usage.span.from_expansion() {
+            *applicability = Applicability::MaybeIncorrect;
+            continue;
+        }
+        match get_parent_expr(cx, usage).map(|parent| (parent, &parent.kind)) {
+            Some((parent, ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _))) => {
+                replacements.push((parent.span, binding_name.to_string()));
+            },
+            Some((_, ExprKind::Binary(..))) => replacements.push((usage.span, format!("*{}", binding_name))),
+            // auto-(de)referenced
+            Some((_, ExprKind::MethodCall(_, _, [receiver, ..], _))) if receiver.hir_id == usage.hir_id => {},
+            Some((_, ExprKind::Field(..))) | Some((_, ExprKind::Index(..))) => {},
+            _ => *applicability = Applicability::MaybeIncorrect,
+        }
+    }
+
+    let mut closure_body = String::new();
+    let mut pos = 0;
+    replacements.sort_by_key(|(span, _)| span.lo());
+    for (span, replacement) in replacements {
+        let start = (span.lo() - cond.span.lo()).0 as usize;
+        let end = (span.hi() - cond.span.lo()).0 as usize;
+        closure_body.push_str(&cond_snippet[pos..start]);
+        closure_body.push_str(&replacement);
+        pos = end;
+    }
+    closure_body.push_str(&cond_snippet[pos..]);
+    format!("|{}| {}", binding_name, closure_body)
+}
+
+/// Collects the uses of a binding.
+struct BindingUsesVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    binding_id: HirId,
+    uses: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BindingUsesVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if same_var(self.cx, expr, self.binding_id) {
+            self.uses.push(expr);
+        } else {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
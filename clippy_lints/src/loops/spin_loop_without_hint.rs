@@ -0,0 +1,131 @@
+use super::{is_simple_break_expr, SPIN_LOOP_WITHOUT_HINT};
+use crate::atomic_ordering::ATOMIC_TYPES;
+use crate::utils::{higher, is_no_std_crate, match_def_path, meets_msrv, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::{Applicability, DiagnosticBuilder};
+use rustc_hir::{Block, Expr, ExprKind, LoopSource, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_semver::RustcVersion;
+
+const MSG: &str = "busy-wait loop without a spin loop hint";
+
+/// The atomic methods polling the value, the compare-exchanges being how spin locks poll.
+const POLLING_METHODS: [&str; 3] = ["load", "compare_exchange", "compare_exchange_weak"];
+
+/// The version stabilizing `hint::spin_loop`, replacing `atomic::spin_loop_hint`.
+const SPIN_LOOP_MSRV: RustcVersion = RustcVersion::new(1, 49, 0);
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, msrv: Option<&RustcVersion>) {
+    // `while cond {}`
+    if let Some((cond, body)) = higher::while_loop(expr) {
+        if_chain! {
+            if let ExprKind::Block(block, _) = body.kind;
+            if block.stmts.is_empty() && block.expr.is_none();
+            if polls_atomic(cx, cond);
+            then {
+                span_lint_and_then(cx, SPIN_LOOP_WITHOUT_HINT, expr.span, MSG, |diag| {
+                    let hint = spin_loop_hint(cx, msrv);
+                    diag.span_suggestion(
+                        body.span,
+                        &format!("call `{}` in the loop body", hint),
+                        format!("{{ {}; }}", hint),
+                        Applicability::MachineApplicable,
+                    );
+                    note_blocking(cx, diag);
+                });
+            }
+        }
+        return;
+    }
+
+    // `loop { if cond { break; } }`
+    if_chain! {
+        if let ExprKind::Loop(block, _, LoopSource::Loop) = expr.kind;
+        if let Some(inner) = only_expr(block);
+        if let Some((cond, then, None)) = higher::if_block(inner);
+        if let ExprKind::Block(then_block, _) = then.kind;
+        if only_expr(then_block).map_or(false, is_simple_break_expr);
+        if polls_atomic(cx, cond);
+        then {
+            span_lint_and_then(cx, SPIN_LOOP_WITHOUT_HINT, expr.span, MSG, |diag| {
+                diag.span_help(inner.span, &format!("call `{}` after this `if`", spin_loop_hint(cx, msrv)));
+                note_blocking(cx, diag);
+            });
+        }
+    }
+}
+
+fn spin_loop_hint(cx: &LateContext<'_>, msrv: Option<&RustcVersion>) -> &'static str {
+    let no_std = is_no_std_crate(cx.tcx.hir().krate());
+    match (meets_msrv(msrv, &SPIN_LOOP_MSRV), no_std) {
+        (true, false) => "std::hint::spin_loop()",
+        (true, true) => "core::hint::spin_loop()",
+        (false, false) => "std::sync::atomic::spin_loop_hint()",
+        (false, true) => "core::sync::atomic::spin_loop_hint()",
+    }
+}
+
+fn note_blocking(cx: &LateContext<'_>, diag: &mut DiagnosticBuilder<'_>) {
+    if !is_no_std_crate(cx.tcx.hir().krate()) {
+        diag.note("if the wait can be long, block with `std::sync::Condvar` or `std::thread::park` instead");
+    }
+}
+
+/// Returns the expression of a block made of a single expression or statement.
+fn only_expr<'tcx>(block: &Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match (block.stmts, block.expr) {
+        ([], Some(expr)) => Some(expr),
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+            StmtKind::Local(..) | StmtKind::Item(..) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the condition only polls atomics, with at least one atomic polled, so that the loop
+/// does nothing but wait for another thread.
+fn polls_atomic(cx: &LateContext<'_>, cond: &Expr<'_>) -> bool {
+    let mut polls = false;
+    is_polling(cx, cond, &mut polls) && polls
+}
+
+fn is_polling(cx: &LateContext<'_>, expr: &Expr<'_>, polls: &mut bool) -> bool {
+    match expr.kind {
+        ExprKind::Lit(..) | ExprKind::Path(..) => true,
+        ExprKind::Unary(_, inner)
+        | ExprKind::Field(inner, _)
+        | ExprKind::AddrOf(_, _, inner)
+        | ExprKind::DropTemps(inner) => is_polling(cx, inner, polls),
+        ExprKind::Binary(_, left, right) => is_polling(cx, left, polls) && is_polling(cx, right, polls),
+        ExprKind::MethodCall(path, _, args, _) => {
+            let name = path.ident.as_str();
+            if POLLING_METHODS.contains(&&*name) && is_atomic_method(cx, expr) {
+                *polls = true;
+                args.iter().all(|arg| is_polling(cx, arg, polls))
+            } else {
+                // e.g. `lock.compare_exchange(..).is_err()`
+                matches!(&*name, "is_ok" | "is_err") && is_polling(cx, &args[0], polls)
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Whether the method call is to an inherent method of an atomic type, the receiver being
+/// possibly behind references or smart pointers like `Arc`.
+fn is_atomic_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+        if let Some(impl_id) = cx.tcx.impl_of_method(def_id);
+        if let ty::Adt(adt, _) = cx.tcx.type_of(impl_id).kind();
+        then {
+            ATOMIC_TYPES
+                .iter()
+                .any(|ty| match_def_path(cx, adt.did, &["core", "sync", "atomic", ty]))
+        } else {
+            false
+        }
+    }
+}
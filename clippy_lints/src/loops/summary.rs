@@ -0,0 +1,144 @@
+//! A summary of the shape of a loop, shared by the lints which suggest replacing a loop by an
+//! iterator adapter.
+
+use super::is_simple_break_expr;
+use crate::utils::{higher, last_path_segment, match_trait_method, paths};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::{Expr, ExprKind, HirId, LoopSource, MatchSource, Pat, PatKind, QPath, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+use rustc_span::symbol::sym;
+
+/// Where the items of a loop come from.
+pub(super) enum IterSource<'tcx> {
+    /// `for pat in expr { .. }`, holding `expr`
+    IntoIter(&'tcx Expr<'tcx>),
+    /// `while let Some(pat) = iter.next() { .. }`, holding `iter`
+    Next(&'tcx Expr<'tcx>),
+}
+
+/// What the body of a loop does with an item, once it decided to act on it.
+pub(super) enum Action<'tcx> {
+    /// `return expr`
+    Return(&'tcx Expr<'tcx>),
+    /// `local = expr; break;`
+    AssignAndBreak(HirId, &'tcx Expr<'tcx>),
+    /// Anything else, holding the whole block
+    Other(&'tcx Expr<'tcx>),
+}
+
+/// The body of a loop, split into the condition of an `if` without `else` making up the whole
+/// body, and the action taken.
+pub(super) struct BodyEffect<'tcx> {
+    pub cond: Option<&'tcx Expr<'tcx>>,
+    pub action: Action<'tcx>,
+}
+
+pub(super) struct LoopSummary<'tcx> {
+    /// The `for` or `while let` expression
+    pub expr: &'tcx Expr<'tcx>,
+    /// The span of `for pat in expr` or `while let Some(pat) = iter.next()`
+    pub head: Span,
+    /// The pattern binding each item, if there is one
+    pub pat: Option<&'tcx Pat<'tcx>>,
+    pub source: IterSource<'tcx>,
+    pub body: &'tcx Expr<'tcx>,
+    pub effect: BodyEffect<'tcx>,
+}
+
+impl<'tcx> LoopSummary<'tcx> {
+    /// Summarizes a `for` loop or a `while let Some(..) = iter.next()` loop.
+    pub fn new(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        let (pat, source, body, head) = if let Some((pat, arg, body)) = higher::for_loop(expr) {
+            let head = super::get_span_of_entire_for_loop(expr).with_hi(arg.span.hi());
+            (Some(pat), IterSource::IntoIter(arg), body, head)
+        } else {
+            if_chain! {
+                if let ExprKind::Loop(ref block, _, LoopSource::WhileLet) = expr.kind;
+                if let Some(ref match_expr) = block.expr;
+                if let ExprKind::Match(ref next, ref arms, MatchSource::WhileLetDesugar) = match_expr.kind;
+                if let PatKind::TupleStruct(ref qpath, ref pat_args, _) = arms[0].pat.kind;
+                if last_path_segment(qpath).ident.name == sym::Some;
+                if let ExprKind::MethodCall(ref method_path, _, [ref iter], _) = next.kind;
+                if method_path.ident.name == sym::next && match_trait_method(cx, next, &paths::ITERATOR);
+                then {
+                    let head = expr.span.with_hi(next.span.hi());
+                    (pat_args.first(), IterSource::Next(iter), arms[0].body, head)
+                } else {
+                    return None;
+                }
+            }
+        };
+
+        Some(Self {
+            expr,
+            head,
+            pat,
+            source,
+            body,
+            effect: BodyEffect::new(body),
+        })
+    }
+}
+
+impl<'tcx> BodyEffect<'tcx> {
+    fn new(body: &'tcx Expr<'tcx>) -> Self {
+        if let Some((cond, then, None)) = single_expr(body).and_then(higher::if_block) {
+            Self {
+                cond: Some(cond),
+                action: Action::new(then),
+            }
+        } else {
+            Self {
+                cond: None,
+                action: Action::new(body),
+            }
+        }
+    }
+}
+
+impl<'tcx> Action<'tcx> {
+    fn new(block: &'tcx Expr<'tcx>) -> Self {
+        if_chain! {
+            if let Some(ret) = single_expr(block);
+            if let ExprKind::Ret(Some(ref value)) = ret.kind;
+            then {
+                return Action::Return(value);
+            }
+        }
+
+        if_chain! {
+            if let ExprKind::Block(ref block, _) = block.kind;
+            if let [ref assign, ref brk] = *block.stmts;
+            if block.expr.is_none();
+            if let StmtKind::Semi(ref assign) = assign.kind;
+            if let ExprKind::Assign(ref lhs, ref value, _) = assign.kind;
+            if let ExprKind::Path(QPath::Resolved(None, ref path)) = lhs.kind;
+            if let Res::Local(local_id) = path.res;
+            if let StmtKind::Semi(ref brk) | StmtKind::Expr(ref brk) = brk.kind;
+            if is_simple_break_expr(brk);
+            then {
+                return Action::AssignAndBreak(local_id, value);
+            }
+        }
+
+        Action::Other(block)
+    }
+}
+
+/// Returns the only expression of a block expression, with or without semicolon.
+pub(super) fn single_expr<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Block(ref block, _) = expr.kind {
+        match (&*block.stmts, block.expr) {
+            ([], Some(expr)) => Some(expr),
+            ([stmt], None) => match stmt.kind {
+                StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(expr),
+                StmtKind::Local(..) | StmtKind::Item(..) => None,
+            },
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
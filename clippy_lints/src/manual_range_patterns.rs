@@ -0,0 +1,149 @@
+use crate::utils::{in_macro, snippet_opt, span_lint_and_sugg};
+use rustc_ast::ast::{Expr, ExprKind, LitKind, Pat, PatKind, RangeEnd, UnOp};
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use std::convert::TryFrom;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for or-patterns which enumerate a contiguous range of integer or
+    /// character literals, like `1 | 2 | 3 | 4 | 5` or `1 | 2..=4 | 5`.
+    ///
+    /// **Why is this bad?** A range pattern is shorter, and makes it obvious that all values in
+    /// between are matched.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 3;
+    /// let is_small = matches!(x, 1 | 2 | 3 | 4 | 5);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 3;
+    /// let is_small = matches!(x, 1..=5);
+    /// ```
+    pub MANUAL_RANGE_PATTERNS,
+    complexity,
+    "or-patterns of contiguous literals which can be written as a range pattern"
+}
+
+declare_lint_pass!(ManualRangePatterns => [MANUAL_RANGE_PATTERNS]);
+
+/// The kind of the literals in the alternatives.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Int,
+    Char,
+}
+
+/// The inclusive range of values an alternative matches, and the expressions of its bounds.
+struct Alternative<'a> {
+    start: i128,
+    end: i128,
+    start_expr: &'a Expr,
+    end_expr: &'a Expr,
+}
+
+impl EarlyLintPass for ManualRangePatterns {
+    fn check_pat(&mut self, cx: &EarlyContext<'_>, pat: &Pat) {
+        if in_macro(pat.span) {
+            return;
+        }
+        let pats = match pat.kind {
+            PatKind::Or(ref pats) if pats.len() >= 2 => pats,
+            _ => return,
+        };
+
+        let mut kind = None;
+        let mut has_range = false;
+        let mut alternatives = Vec::with_capacity(pats.len());
+        for pat in pats {
+            let alternative = match pat.kind {
+                PatKind::Lit(ref expr) => {
+                    let (value, value_kind) = match lit_value(expr) {
+                        Some(value) => value,
+                        None => return,
+                    };
+                    if *kind.get_or_insert(value_kind) != value_kind {
+                        return;
+                    }
+                    Alternative {
+                        start: value,
+                        end: value,
+                        start_expr: expr,
+                        end_expr: expr,
+                    }
+                },
+                PatKind::Range(Some(ref start_expr), Some(ref end_expr), ref range_end)
+                    if matches!(range_end.node, RangeEnd::Included(_)) =>
+                {
+                    match (lit_value(start_expr), lit_value(end_expr)) {
+                        (Some((start, start_kind)), Some((end, end_kind)))
+                            if start_kind == end_kind && *kind.get_or_insert(start_kind) == start_kind =>
+                        {
+                            has_range = true;
+                            Alternative {
+                                start,
+                                end,
+                                start_expr,
+                                end_expr,
+                            }
+                        },
+                        _ => return,
+                    }
+                },
+                _ => return,
+            };
+            alternatives.push(alternative);
+        }
+
+        // `1 | 2` isn't any clearer as `1..=2`
+        if alternatives.len() < 3 && !has_range {
+            return;
+        }
+
+        alternatives.sort_by_key(|alternative| alternative.start);
+        let mut last = &alternatives[0];
+        for alternative in &alternatives[1..] {
+            if alternative.start > last.end.saturating_add(1) {
+                return;
+            }
+            if alternative.end > last.end {
+                last = alternative;
+            }
+        }
+
+        if let (Some(start), Some(end)) = (
+            snippet_opt(cx, alternatives[0].start_expr.span),
+            snippet_opt(cx, last.end_expr.span),
+        ) {
+            span_lint_and_sugg(
+                cx,
+                MANUAL_RANGE_PATTERNS,
+                pat.span,
+                "this or-pattern matches a contiguous range of values",
+                "try",
+                format!("{}..={}", start, end),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Returns the value of an integer or character literal, which may be negated.
+fn lit_value(expr: &Expr) -> Option<(i128, Kind)> {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.kind {
+            LitKind::Int(value, _) => Some((i128::try_from(value).ok()?, Kind::Int)),
+            LitKind::Char(c) => Some((i128::from(u32::from(c)), Kind::Char)),
+            _ => None,
+        },
+        ExprKind::Unary(UnOp::Neg, ref expr) => match lit_value(expr)? {
+            (value, Kind::Int) => Some((-value, Kind::Int)),
+            (_, Kind::Char) => None,
+        },
+        _ => None,
+    }
+}
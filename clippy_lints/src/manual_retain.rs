@@ -0,0 +1,83 @@
+use crate::utils::{
+    is_type_diagnostic_item, match_trait_method, method_chain_args, paths, snippet_with_applicability,
+    span_lint_and_sugg, SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `Vec` being reassigned with its own elements filtered, like
+    /// `v = v.into_iter().filter(|x| ..).collect()` or
+    /// `v = v.iter().cloned().filter(|x| ..).collect()`.
+    ///
+    /// **Why is this bad?** `Vec::retain` does the same in place, without allocating a new `Vec`
+    /// or cloning the elements.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let mut v = vec![1, 2, 3, 4];
+    /// v = v.into_iter().filter(|x| x % 2 == 0).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v = vec![1, 2, 3, 4];
+    /// v.retain(|x| x % 2 == 0);
+    /// ```
+    pub MANUAL_RETAIN,
+    perf,
+    "reassigning a `Vec` with its filtered elements instead of using `Vec::retain`"
+}
+
+declare_lint_pass!(ManualRetain => [MANUAL_RETAIN]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualRetain {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Assign(target, value, _) = expr.kind;
+            let ty = cx.typeck_results().expr_ty(target);
+            if is_type_diagnostic_item(cx, ty, sym::vec_type);
+            // collecting into another type, e.g. a `VecDeque`, can't be replaced
+            if cx.typeck_results().expr_ty(value) == ty;
+            if let Some(filter_args) = method_chain_args(value, &["filter", "collect"]).map(|args| args[0]);
+            if let Some(recv) = vec_iter_recv(&filter_args[0]);
+            if let ExprKind::MethodCall(_, _, [filter_call, ..], _) = value.kind;
+            if match_trait_method(cx, filter_call, &paths::ITERATOR);
+            if SpanlessEq::new(cx).eq_expr(target, recv);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this reassigns a `Vec` with its filtered elements",
+                    "use `retain` instead",
+                    format!(
+                        "{}.retain({})",
+                        snippet_with_applicability(cx, target.span, "..", &mut applicability),
+                        snippet_with_applicability(cx, filter_args[1].span, "..", &mut applicability),
+                    ),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the `Vec` of `v.into_iter()`, `v.iter().cloned()` or `v.iter().copied()`, which all
+/// pass `&T` to `filter` like `retain` does.
+fn vec_iter_recv<'a>(iter: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    if let Some(args) = method_chain_args(iter, &["into_iter"]) {
+        return Some(&args[0][0]);
+    }
+    method_chain_args(iter, &["iter", "cloned"])
+        .or_else(|| method_chain_args(iter, &["iter", "copied"]))
+        .map(|args| &args[0][0])
+}
@@ -74,6 +74,7 @@ enum StripKind {
 }
 
 impl<'tcx> LateLintPass<'tcx> for ManualStrip {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if !meets_msrv(self.msrv.as_ref(), &MANUAL_STRIP_MSRV) {
             return;
@@ -1,6 +1,7 @@
 use crate::utils::paths;
 use crate::utils::{
-    is_copy, is_type_diagnostic_item, match_trait_method, remove_blocks, snippet_with_applicability, span_lint_and_sugg,
+    is_adjusted, is_clone_method_path, is_copy, is_param_or_deref, is_type_diagnostic_item, match_path,
+    match_trait_method, match_var, remove_blocks, snippet_with_applicability, span_lint_and_sugg,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -8,7 +9,6 @@ use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty;
-use rustc_middle::ty::adjustment::Adjust;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::symbol::Ident;
 use rustc_span::{sym, Span};
@@ -35,14 +35,37 @@ declare_clippy_lint! {
     /// ```rust
     /// let x = vec![42, 43];
     /// let y = x.iter();
-    /// let z = y.cloned();
+    /// let z = y.copied();
     /// ```
     pub MAP_CLONE,
     style,
     "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types"
 }
 
-declare_lint_pass!(MapClone => [MAP_CLONE]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for instances of `map(f)` where `f` is the identity function.
+    ///
+    /// **Why is this bad?** It can be written more concisely without the call to `map`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let x = [1, 2, 3];
+    /// let y: Vec<_> = x.iter().map(|x| x).map(|x| 2*x).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x = [1, 2, 3];
+    /// let y: Vec<_> = x.iter().map(|x| 2*x).collect();
+    /// ```
+    pub MAP_IDENTITY,
+    complexity,
+    "using iterator.map(|x| x)"
+}
+
+declare_lint_pass!(MapClone => [MAP_CLONE, MAP_IDENTITY]);
 
 impl<'tcx> LateLintPass<'tcx> for MapClone {
     fn check_expr(&mut self, cx: &LateContext<'_>, e: &hir::Expr<'_>) {
@@ -53,58 +76,104 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
         if_chain! {
             if let hir::ExprKind::MethodCall(ref method, _, ref args, _) = e.kind;
             if args.len() == 2;
-            if method.ident.as_str() == "map";
+            if method.ident.name == sym::map;
             let ty = cx.typeck_results().expr_ty(&args[0]);
-            if is_type_diagnostic_item(cx, ty, sym::option_type) || match_trait_method(cx, e, &paths::ITERATOR);
-            if let hir::ExprKind::Closure(_, _, body_id, _, _) = args[1].kind;
-            let closure_body = cx.tcx.hir().body(body_id);
-            let closure_expr = remove_blocks(&closure_body.value);
+            let is_result = is_type_diagnostic_item(cx, ty, sym::result_type);
+            if is_result || is_type_diagnostic_item(cx, ty, sym::option_type)
+                || match_trait_method(cx, e, &paths::ITERATOR);
             then {
-                match closure_body.params[0].pat.kind {
-                    hir::PatKind::Ref(ref inner, hir::Mutability::Not) => if let hir::PatKind::Binding(
-                        hir::BindingAnnotation::Unannotated, .., name, None
-                    ) = inner.kind {
-                        if ident_eq(name, closure_expr) {
-                            lint(cx, e.span, args[0].span, true);
-                        }
-                    },
-                    hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, .., name, None) => {
-                        match closure_expr.kind {
-                            hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) => {
-                                if ident_eq(name, inner) {
-                                    if let ty::Ref(.., Mutability::Not) = cx.typeck_results().expr_ty(inner).kind() {
-                                        lint(cx, e.span, args[0].span, true);
-                                    }
-                                }
-                            },
-                            hir::ExprKind::MethodCall(ref method, _, [obj], _) => if_chain! {
-                                if ident_eq(name, obj) && method.ident.name == sym::clone;
-                                if match_trait_method(cx, closure_expr, &paths::CLONE_TRAIT);
-                                // no autoderefs
-                                if !cx.typeck_results().expr_adjustments(obj).iter()
-                                    .any(|a| matches!(a.kind, Adjust::Deref(Some(..))));
-                                then {
-                                    let obj_ty = cx.typeck_results().expr_ty(obj);
-                                    if let ty::Ref(_, ty, mutability) = obj_ty.kind() {
-                                        if matches!(mutability, Mutability::Not) {
-                                            let copy = is_copy(cx, ty);
-                                            lint(cx, e.span, args[0].span, copy);
-                                        }
-                                    } else {
-                                        lint_needless_cloning(cx, e.span, args[0].span);
-                                    }
-                                }
-                            },
-                            _ => {},
-                        }
-                    },
-                    _ => {},
+                // both lints are checked here, so that a `map` call gets at most one of them
+                if is_expr_identity_function(cx, &args[1]) {
+                    span_lint_and_sugg(
+                        cx,
+                        MAP_IDENTITY,
+                        e.span.trim_start(args[0].span).unwrap(),
+                        "unnecessary map of the identity function",
+                        "remove the call to `map`",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    );
+                } else if !is_result && !is_option_as_ref_cloned(cx, e, &args[0]) {
+                    check_clone_closure(cx, e, &args[0], &args[1]);
                 }
             }
         }
     }
 }
 
+/// Checks for `opt.as_ref().map(..)` giving back the type of `opt`, which is linted by
+/// `option_as_ref_cloned` instead.
+fn is_option_as_ref_cloned(cx: &LateContext<'_>, e: &hir::Expr<'_>, recv: &hir::Expr<'_>) -> bool {
+    if let hir::ExprKind::MethodCall(ref method, _, [ref opt], _) = recv.kind {
+        let opt_ty = cx.typeck_results().expr_ty(opt);
+        method.ident.as_str() == "as_ref"
+            && is_type_diagnostic_item(cx, opt_ty, sym::option_type)
+            && cx.typeck_results().expr_ty(e) == opt_ty
+    } else {
+        false
+    }
+}
+
+/// Checks for closures copying or cloning their parameter, like `|x| *x`, `|&x| x`,
+/// `|x| x.clone()` or `|x| Clone::clone(x)`.
+fn check_clone_closure(cx: &LateContext<'_>, e: &hir::Expr<'_>, recv: &hir::Expr<'_>, arg: &hir::Expr<'_>) {
+    if_chain! {
+        if let hir::ExprKind::Closure(_, _, body_id, _, _) = arg.kind;
+        let closure_body = cx.tcx.hir().body(body_id);
+        if let [ref param] = closure_body.params;
+        let closure_expr = remove_blocks(&closure_body.value);
+        if let Some(is_clone_call) = clones_param(cx, param.pat, closure_expr);
+        then {
+            // The types decide what is done, rather than the syntax, as dereferencing and method
+            // calls go through `Deref` impls. For example `|x| x.clone()` clones the reference
+            // itself if `x: &T` and `T` isn't `Clone`.
+            let param_ty = cx.typeck_results().pat_ty(param.pat);
+            let elem_ty = cx.typeck_results().expr_ty(closure_expr);
+            match *param_ty.kind() {
+                ty::Ref(_, referent_ty, Mutability::Not) if referent_ty == elem_ty => {
+                    lint(cx, e.span, recv.span, is_copy(cx, elem_ty));
+                },
+                _ if is_clone_call && param_ty == elem_ty => lint_needless_cloning(cx, e.span, recv.span),
+                _ => {},
+            }
+        }
+    }
+}
+
+/// Checks if the closure body copies or clones the parameter, returning whether it's a
+/// `clone` call.
+fn clones_param(cx: &LateContext<'_>, pat: &hir::Pat<'_>, closure_expr: &hir::Expr<'_>) -> Option<bool> {
+    match pat.kind {
+        // `|&x| x`
+        hir::PatKind::Ref(ref inner, hir::Mutability::Not) => match inner.kind {
+            hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, .., name, None)
+                if ident_eq(name, closure_expr) =>
+            {
+                Some(false)
+            },
+            _ => None,
+        },
+        hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, .., name, None) => match closure_expr.kind {
+            // `|x| *x`
+            hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) if ident_eq(name, inner) => Some(false),
+            // `|x| x.clone()` or `|x| (*x).clone()`
+            hir::ExprKind::MethodCall(ref method, _, [ref obj], _)
+                if method.ident.name == sym::clone
+                    && is_param_or_deref(obj, pat)
+                    && match_trait_method(cx, closure_expr, &paths::CLONE_TRAIT) =>
+            {
+                Some(true)
+            },
+            // `|x| Clone::clone(x)`
+            hir::ExprKind::Call(ref func, [ref obj]) if ident_eq(name, obj) && is_clone_method_path(cx, func) => {
+                Some(true)
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn ident_eq(name: Ident, path: &hir::Expr<'_>) -> bool {
     if let hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) = path.kind {
         path.segments.len() == 1 && path.segments[0].ident == name
@@ -113,6 +182,56 @@ fn ident_eq(name: Ident, path: &hir::Expr<'_>) -> bool {
     }
 }
 
+/// Checks if an expression represents the identity function
+/// Only examines closures and `std::convert::identity`
+fn is_expr_identity_function(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    match expr.kind {
+        hir::ExprKind::Closure(_, _, body_id, _, _) => is_body_identity_function(cx, cx.tcx.hir().body(body_id)),
+        hir::ExprKind::Path(hir::QPath::Resolved(_, ref path)) => match_path(path, &paths::STD_CONVERT_IDENTITY),
+        _ => false,
+    }
+}
+
+/// Checks if a function's body represents the identity function
+/// Looks for bodies of the form `|x| x`, `|x| return x`, `|x| { return x }` or `|x| {
+/// return x; }`
+fn is_body_identity_function(cx: &LateContext<'_>, func: &hir::Body<'_>) -> bool {
+    let params = func.params;
+    let body = remove_blocks(&func.value);
+
+    // if there's less/more than one parameter, then it is not the identity function
+    if params.len() != 1 {
+        return false;
+    }
+
+    match body.kind {
+        hir::ExprKind::Path(hir::QPath::Resolved(None, _)) => match_expr_param(cx, body, params[0].pat),
+        hir::ExprKind::Ret(Some(ref ret_val)) => match_expr_param(cx, ret_val, params[0].pat),
+        hir::ExprKind::Block(ref block, _) => {
+            if_chain! {
+                if block.stmts.len() == 1;
+                if let hir::StmtKind::Semi(ref expr) | hir::StmtKind::Expr(ref expr) = block.stmts[0].kind;
+                if let hir::ExprKind::Ret(Some(ref ret_val)) = expr.kind;
+                then {
+                    match_expr_param(cx, ret_val, params[0].pat)
+                } else {
+                    false
+                }
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Returns true iff an expression returns the same thing as a parameter's pattern
+fn match_expr_param(cx: &LateContext<'_>, expr: &hir::Expr<'_>, pat: &hir::Pat<'_>) -> bool {
+    if let hir::PatKind::Binding(_, _, ident, _) = pat.kind {
+        match_var(expr, ident.name) && !(cx.typeck_results().hir_owner == expr.hir_id.owner && is_adjusted(cx, expr))
+    } else {
+        false
+    }
+}
+
 fn lint_needless_cloning(cx: &LateContext<'_>, root: Span, receiver: Span) {
     span_lint_and_sugg(
         cx,
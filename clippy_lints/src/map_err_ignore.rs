@@ -1,14 +1,29 @@
-use crate::utils::span_lint_and_help;
+use crate::utils::{def_ids_of_paths, snippet_with_applicability, span_lint_and_help, span_lint_and_sugg};
 
-use rustc_hir::{CaptureBy, Expr, ExprKind, PatKind};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Crate, Expr, ExprKind, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::{self, TyS};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for instances of `map_err(|_| Some::Enum)`
+    /// **What it does:** Checks for instances of `map_err(|_| Some::Enum)`, or any other closure
+    /// ignoring the original error with a `_` parameter, like `map_err(|_| Error::Wrapper("context"))`,
+    /// `map_err(|_| MyError { kind: Kind::Io })` or `map_err(|_| make_error())`
     ///
     /// **Why is this bad?** This map_err throws away the original error rather than allowing the enum to contain and report the cause of the error
     ///
+    /// When the returned variant has a single field of the type of the original error, the
+    /// closure is replaced with the variant itself, like `map_err(Error::Parse)`.
+    ///
+    /// Error types which can be discarded, like `std::sync::PoisonError`, can be allowed with the
+    /// `map-err-ignore-allowed-types` configuration.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -103,11 +118,29 @@ declare_clippy_lint! {
     "`map_err` should not ignore the original error"
 }
 
-declare_lint_pass!(MapErrIgnore => [MAP_ERR_IGNORE]);
+pub struct MapErrIgnore {
+    allowed_types: Vec<String>,
+    allowed_type_ids: FxHashSet<DefId>,
+}
+
+impl MapErrIgnore {
+    pub fn new(allowed_types: Vec<String>) -> Self {
+        Self {
+            allowed_types,
+            allowed_type_ids: FxHashSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(MapErrIgnore => [MAP_ERR_IGNORE]);
 
 impl<'tcx> LateLintPass<'tcx> for MapErrIgnore {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        self.allowed_type_ids = def_ids_of_paths(cx, &self.allowed_types);
+    }
+
     // do not try to lint if this is from a macro or desugaring
-    fn check_expr(&mut self, cx: &LateContext<'_>, e: &Expr<'_>) {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if e.span.from_expansion() {
             return;
         }
@@ -117,27 +150,48 @@ impl<'tcx> LateLintPass<'tcx> for MapErrIgnore {
             // only work if the method name is `map_err` and there are only 2 arguments (e.g. x.map_err(|_|[1]
             // Enum::Variant[2]))
             if method.ident.as_str() == "map_err" && args.len() == 2 {
-                // make sure the first argument is a closure, and grab the CaptureRef, body_id, and body_span fields
-                if let ExprKind::Closure(capture, _, body_id, body_span, _) = args[1].kind {
-                    // check if this is by Reference (meaning there's no move statement)
-                    if capture == CaptureBy::Ref {
-                        // Get the closure body to check the parameters and values
-                        let closure_body = cx.tcx.hir().body(body_id);
-                        // make sure there's only one parameter (`|_|`)
-                        if closure_body.params.len() == 1 {
-                            // make sure that parameter is the wild token (`_`)
-                            if let PatKind::Wild = closure_body.params[0].pat.kind {
-                                // span the area of the closure capture and warn that the
-                                // original error will be thrown away
-                                span_lint_and_help(
+                // whatever the closure returns, be it a variant, a struct or the result of a call,
+                // can't contain the original error if the closure doesn't bind it
+                if let ExprKind::Closure(_, _, body_id, body_span, _) = args[1].kind {
+                    // Get the closure body to check the parameters and values
+                    let closure_body = cx.tcx.hir().body(body_id);
+                    // make sure there's only one parameter (`|_|`)
+                    if closure_body.params.len() == 1 {
+                        // make sure that parameter is the wild token (`_`)
+                        if let PatKind::Wild = closure_body.params[0].pat.kind {
+                            // some errors, like poisoned locks, are fine to discard
+                            if let ty::Adt(def, _) = cx.typeck_results().pat_ty(closure_body.params[0].pat).kind() {
+                                if self.allowed_type_ids.contains(&def.did) {
+                                    return;
+                                }
+                            }
+
+                            // the variant can take the original error instead, so replace the whole closure;
+                            // this drops the value the closure passed to the variant
+                            if let Some(ctor_span) = error_wrapping_variant(cx, closure_body) {
+                                let mut applicability = Applicability::MaybeIncorrect;
+                                span_lint_and_sugg(
                                     cx,
                                     MAP_ERR_IGNORE,
-                                    body_span,
+                                    args[1].span,
                                     "`map_err(|_|...` ignores the original error",
-                                    None,
-                                    "Consider wrapping the error in an enum variant",
+                                    "wrap the original error in the variant",
+                                    snippet_with_applicability(cx, ctor_span, "..", &mut applicability).into_owned(),
+                                    applicability,
                                 );
+                                return;
                             }
+
+                            // span the area of the closure capture and warn that the
+                            // original error will be thrown away
+                            span_lint_and_help(
+                                cx,
+                                MAP_ERR_IGNORE,
+                                body_span,
+                                "`map_err(|_|...` ignores the original error",
+                                None,
+                                "Consider wrapping the error in an enum variant",
+                            );
                         }
                     }
                 }
@@ -145,3 +199,26 @@ impl<'tcx> LateLintPass<'tcx> for MapErrIgnore {
         }
     }
 }
+
+/// Returns the span of the path to the enum variant the closure returns, if the variant has a
+/// single field of the type of the ignored error, like in `|_| Error::Parse(..)`.
+fn error_wrapping_variant<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> Option<Span> {
+    let ctor = match body.value.kind {
+        ExprKind::Call(func, [_]) => func,
+        ExprKind::Path(_) => &body.value,
+        _ => return None,
+    };
+
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = ctor.kind;
+        if let Res::Def(DefKind::Ctor(CtorOf::Variant, CtorKind::Fn), _) = cx.qpath_res(qpath, ctor.hir_id);
+        let ctor_sig = cx.typeck_results().expr_ty(ctor).fn_sig(cx.tcx);
+        if let [field_ty] = *ctor_sig.inputs().skip_binder();
+        if TyS::same_type(field_ty, cx.typeck_results().pat_ty(body.params[0].pat));
+        then {
+            Some(ctor.span)
+        } else {
+            None
+        }
+    }
+}
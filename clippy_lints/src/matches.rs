@@ -3,21 +3,23 @@ use crate::utils::sugg::Sugg;
 use crate::utils::usage::is_unused;
 use crate::utils::{
     expr_block, get_arg_name, get_parent_expr, in_macro, indent_of, is_allowed, is_expn_of, is_refutable,
-    is_type_diagnostic_item, is_wild, match_qpath, match_type, match_var, meets_msrv, multispan_sugg, remove_blocks,
-    snippet, snippet_block, snippet_with_applicability, span_lint_and_help, span_lint_and_note, span_lint_and_sugg,
-    span_lint_and_then,
+    is_type_diagnostic_item, is_wild, match_qpath, match_type, match_var, meets_msrv, multispan_sugg,
+    multispan_sugg_with_applicability, remove_blocks, snippet, snippet_block, snippet_with_applicability,
+    span_lint_and_help, span_lint_and_note, span_lint_and_sugg, span_lint_and_then,
 };
 use crate::utils::{paths, search_same, SpanlessEq, SpanlessHash};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
-use rustc_hir::def::CtorKind;
+use rustc_hir::def::{CtorKind, Res};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    Arm, BindingAnnotation, Block, BorrowKind, Expr, ExprKind, Guard, Local, MatchSource, Mutability, Node, Pat,
-    PatKind, QPath, RangeEnd,
+    Arm, BinOpKind, BindingAnnotation, Block, BorrowKind, Expr, ExprKind, Guard, HirId, Local, MatchSource, Mutability,
+    Node, Pat, PatKind, QPath, RangeEnd, UnOp,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Ty, TyS};
 use rustc_semver::RustcVersion;
@@ -533,6 +535,38 @@ declare_clippy_lint! {
     "`match` with identical arm bodies"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for match guards which only compare a binding of the pattern with
+    /// a literal, or match it against a pattern with `matches!`.
+    ///
+    /// **Why is this bad?** The literal or pattern can be written in the place of the binding,
+    /// which is shorter and lets the compiler check the match for exhaustiveness.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = Some(2);
+    /// match x {
+    ///     Some(y) if y == 2 => {},
+    ///     Some(y) if matches!(y, 3..=5) => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = Some(2);
+    /// match x {
+    ///     Some(2) => {},
+    ///     Some(3..=5) => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    pub REDUNDANT_GUARDS,
+    complexity,
+    "match guards which can be written as a pattern"
+}
+
 #[derive(Default)]
 pub struct Matches {
     msrv: Option<RustcVersion>,
@@ -566,6 +600,7 @@ impl_lint_pass!(Matches => [
     REDUNDANT_PATTERN_MATCHING,
     MATCH_LIKE_MATCHES_MACRO,
     MATCH_SAME_ARMS,
+    REDUNDANT_GUARDS,
 ]);
 
 const MATCH_LIKE_MATCHES_MACRO_MSRV: RustcVersion = RustcVersion::new(1, 42, 0);
@@ -594,6 +629,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
             check_wild_enum_match(cx, ex, arms);
             check_match_as_ref(cx, ex, arms, expr);
             check_wild_in_or_pats(cx, arms);
+            check_redundant_guards(cx, arms);
 
             if self.infallible_destructuring_match_linted {
                 self.infallible_destructuring_match_linted = false;
@@ -1122,6 +1158,155 @@ fn check_match_as_ref(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], exp
     }
 }
 
+fn check_redundant_guards<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
+    for arm in arms {
+        let guard = match arm.guard {
+            Some(Guard::If(guard)) if !in_macro(arm.pat.span) => guard,
+            _ => continue,
+        };
+        let (local_id, pat, guard_span, is_or) = if let Some(guard_span) = is_expn_of(guard.span, "matches") {
+            // `matches!(x, pat)` expands to `match x { pat => true, _ => false }`
+            if_chain! {
+                if let ExprKind::Match(scrutinee, [pat_arm, _], _) = guard.kind;
+                if pat_arm.guard.is_none();
+                if let Some(local_id) = path_to_local(scrutinee);
+                let mut has_bindings = false;
+                pat_arm.pat.each_binding(|_, _, _, _| has_bindings = true);
+                if !has_bindings;
+                then {
+                    (
+                        local_id,
+                        pat_arm.pat.span,
+                        guard_span,
+                        matches!(pat_arm.pat.kind, PatKind::Or(_)),
+                    )
+                } else {
+                    continue;
+                }
+            }
+        } else {
+            if_chain! {
+                if !guard.span.from_expansion();
+                if let ExprKind::Binary(op, left, right) = guard.kind;
+                if op.node == BinOpKind::Eq;
+                if let Some((local_id, lit)) = path_to_local(left)
+                    .map(|id| (id, right))
+                    .or_else(|| path_to_local(right).map(|id| (id, left)));
+                if is_pattern_lit(lit);
+                if TyS::same_type(cx.typeck_results().expr_ty(left), cx.typeck_results().expr_ty(right));
+                then {
+                    (local_id, lit.span, guard.span, false)
+                } else {
+                    continue;
+                }
+            }
+        };
+
+        // The binding must be bound by value once, not in an or-pattern
+        let mut binding = None;
+        let mut bindings_with_name = 0;
+        let local_name = cx.tcx.hir().name(local_id);
+        arm.pat.each_binding(|annotation, id, span, ident| {
+            if ident.name == local_name {
+                bindings_with_name += 1;
+            }
+            if id == local_id && annotation == BindingAnnotation::Unannotated {
+                binding = Some(span);
+            }
+        });
+        let binding_span = match binding {
+            Some(span) if bindings_with_name == 1 => span,
+            _ => continue,
+        };
+        if !matches!(
+            cx.typeck_results().pat_binding_modes().get(local_id),
+            Some(ty::BindingMode::BindByValue(_))
+        ) {
+            continue;
+        }
+
+        let is_top_level = arm.pat.hir_id == local_id;
+        let is_used = is_local_used(cx, &arm.body, local_id);
+        // Or-patterns can't be nested
+        if is_or && (is_used || !is_top_level) {
+            continue;
+        }
+
+        let mut applicability = Applicability::MachineApplicable;
+        let pat_snippet = snippet_with_applicability(cx, pat, "..", &mut applicability);
+        let replacement = if is_used {
+            format!("{} @ {}", local_name, pat_snippet)
+        } else {
+            pat_snippet.into_owned()
+        };
+        span_lint_and_then(cx, REDUNDANT_GUARDS, guard_span, "redundant guard", |diag| {
+            multispan_sugg_with_applicability(
+                diag,
+                "try",
+                applicability,
+                vec![
+                    (binding_span, replacement),
+                    (arm.pat.span.shrink_to_hi().with_hi(guard_span.hi()), String::new()),
+                ],
+            );
+        });
+    }
+}
+
+/// Returns the `HirId` of the local the expression is a path to.
+fn path_to_local(expr: &Expr<'_>) -> Option<HirId> {
+    if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+        if let Res::Local(id) = path.res {
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// Checks if the expression is a literal which can be used as a pattern.
+fn is_pattern_lit(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => !matches!(lit.node, LitKind::Float(..)),
+        ExprKind::Unary(UnOp::UnNeg, inner) => {
+            matches!(inner.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Int(..)))
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the local is used in the expression, including in closures.
+fn is_local_used<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, local_id: HirId) -> bool {
+    struct LocalUsedVisitor<'tcx> {
+        map: Map<'tcx>,
+        local_id: HirId,
+        used: bool,
+    }
+
+    impl<'tcx> Visitor<'tcx> for LocalUsedVisitor<'tcx> {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+            if path_to_local(expr) == Some(self.local_id) {
+                self.used = true;
+            } else {
+                walk_expr(self, expr);
+            }
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::OnlyBodies(self.map)
+        }
+    }
+
+    let mut visitor = LocalUsedVisitor {
+        map: cx.tcx.hir(),
+        local_id,
+        used: false,
+    };
+    visitor.visit_expr(expr);
+    visitor.used
+}
+
 fn check_wild_in_or_pats(cx: &LateContext<'_>, arms: &[Arm<'_>]) {
     for arm in arms {
         if let PatKind::Or(ref fields) = arm.pat.kind {
@@ -1,16 +1,22 @@
-use crate::utils::{match_def_path, paths, qpath_res, span_lint};
+use crate::utils::{is_type_diagnostic_item, match_def_path, paths, qpath_res, span_lint_and_then};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::Ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `std::mem::forget(t)` where `t` is
-    /// `Drop`.
+    /// `Drop` or contains a value which is.
     ///
     /// **Why is this bad?** `std::mem::forget(t)` prevents `t` from running its
-    /// destructor, possibly causing leaks.
+    /// destructor, possibly causing leaks. The other calls of `std::mem::forget`
+    /// are linted by `forget_ref`, `forget_copy` and `forget_non_drop`, so enabling
+    /// this lint bans it outright.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** Only the type arguments of the type are checked for
+    /// heap allocations, not the fields of structs.
     ///
     /// **Example:**
     /// ```rust
@@ -26,6 +32,7 @@ declare_clippy_lint! {
 declare_lint_pass!(MemForget => [MEM_FORGET]);
 
 impl<'tcx> LateLintPass<'tcx> for MemForget {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if let ExprKind::Call(ref path_expr, ref args) = e.kind {
             if let ExprKind::Path(ref qpath) = path_expr.kind {
@@ -33,8 +40,18 @@ impl<'tcx> LateLintPass<'tcx> for MemForget {
                     if match_def_path(cx, def_id, &paths::MEM_FORGET) {
                         let forgot_ty = cx.typeck_results().expr_ty(&args[0]);
 
-                        if forgot_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx)) {
-                            span_lint(cx, MEM_FORGET, e.span, "usage of `mem::forget` on `Drop` type");
+                        if forgot_ty.needs_drop(cx.tcx, cx.param_env) {
+                            span_lint_and_then(
+                                cx,
+                                MEM_FORGET,
+                                e.span,
+                                "usage of `mem::forget` on `Drop` type",
+                                |diag| {
+                                    if owns_heap_allocation(cx, forgot_ty) {
+                                        diag.note("the heap memory owned by the value is leaked");
+                                    }
+                                },
+                            );
                         }
                     }
                 }
@@ -42,3 +59,18 @@ impl<'tcx> LateLintPass<'tcx> for MemForget {
         }
     }
 }
+
+/// Checks if the type is or contains a `Box`, `Vec`, `String`, `Rc` or `Arc`.
+fn owns_heap_allocation<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|inner| match inner.unpack() {
+        GenericArgKind::Type(inner_ty) => {
+            inner_ty.is_box()
+                || is_type_diagnostic_item(cx, inner_ty, sym::vec_type)
+                || is_type_diagnostic_item(cx, inner_ty, sym::string_type)
+                || is_type_diagnostic_item(cx, inner_ty, sym::Rc)
+                || is_type_diagnostic_item(cx, inner_ty, sym::Arc)
+        },
+
+        GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
+    })
+}
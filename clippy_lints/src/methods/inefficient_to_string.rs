@@ -1,6 +1,7 @@
 use super::INEFFICIENT_TO_STRING;
 use crate::utils::{
-    is_type_diagnostic_item, match_def_path, paths, snippet_with_applicability, span_lint_and_then, walk_ptrs_ty_depth,
+    is_std_item, is_type_diagnostic_item, match_def_path, paths, snippet_with_applicability, span_lint_and_then,
+    std_items, walk_ptrs_ty_depth,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -56,7 +57,7 @@ fn specializes_tostring(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
     }
 
     if let ty::Adt(adt, substs) = ty.kind() {
-        match_def_path(cx, adt.did, &paths::COW) && substs.type_at(1).is_str()
+        is_std_item(cx, adt.did, &std_items::COW) && substs.type_at(1).is_str()
     } else {
         false
     }
@@ -0,0 +1,64 @@
+use super::LINES_FILTER_MAP_OK;
+use crate::utils::{match_def_path, match_type, paths, qpath_res, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+
+/// Checks for the `LINES_FILTER_MAP_OK` lint, on `lines.filter_map(Result::ok)`,
+/// `lines.flat_map(Result::ok)` and `lines.flatten()`. `args` are the arguments of the method
+/// called on the lines, `method_span` is the span of its name.
+pub fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, method: &str, args: &[hir::Expr<'_>], method_span: Span) {
+    let lines = &args[0];
+    if !match_type(cx, cx.typeck_results().expr_ty(lines), &paths::IO_LINES) {
+        return;
+    }
+    if let [_, ref f] = *args {
+        if !is_result_ok(cx, f) {
+            return;
+        }
+    }
+
+    span_lint_and_then(
+        cx,
+        LINES_FILTER_MAP_OK,
+        method_span.with_hi(expr.span.hi()),
+        &format!("`{}()` on the `Lines` of a reader ignores the IO errors", method),
+        |diag| {
+            diag.span_note(
+                lines.span,
+                "the reader may keep returning the same error, e.g. when it's a directory, and the iteration \
+                 never ends",
+            );
+            diag.help("use `take_while(Result::is_ok).flatten()` to stop at the first error, or handle the errors");
+        },
+    );
+}
+
+/// Checks if the expression is `Result::ok` or `|x| x.ok()`.
+fn is_result_ok(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    match expr.kind {
+        hir::ExprKind::Path(ref qpath) => qpath_res(cx, qpath, expr.hir_id)
+            .opt_def_id()
+            .map_or(false, |def_id| match_def_path(cx, def_id, &paths::RESULT_OK_METHOD)),
+        hir::ExprKind::Closure(_, _, body_id, ..) => {
+            let body = cx.tcx.hir().body(body_id);
+            if_chain! {
+                if let [ref param] = *body.params;
+                if let hir::PatKind::Binding(_, param_id, _, None) = param.pat.kind;
+                if let hir::ExprKind::MethodCall(ref method, _, [ref recv], _) = body.value.kind;
+                if method.ident.as_str() == "ok";
+                if let hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) = recv.kind;
+                if path.res == Res::Local(param_id);
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(body.value.hir_id);
+                then {
+                    match_def_path(cx, def_id, &paths::RESULT_OK_METHOD)
+                } else {
+                    false
+                }
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,125 @@
+use super::MANUAL_CONTAINS;
+use crate::utils::sugg::Sugg;
+use crate::utils::visitors::LocalUsedVisitor;
+use crate::utils::{match_trait_method, paths, remove_blocks, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, TyS};
+use rustc_span::sym;
+
+/// Checks for the `MANUAL_CONTAINS` lint, on `slice.iter().any(..)` and
+/// `slice.iter().position(..).is_some()`. `search` is the call of `any` or `position`.
+///
+/// Returns `true` if the lint was emitted.
+pub fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    search: &hir::Expr<'_>,
+    search_args: &[hir::Expr<'_>],
+) -> bool {
+    if_chain! {
+        if match_trait_method(cx, search, &paths::ITERATOR);
+        if let hir::ExprKind::MethodCall(ref iter_path, _, [ref slice], _) = search_args[0].kind;
+        if iter_path.ident.name == sym::iter;
+        if let ty::Ref(_, slice_ty, _) = cx.typeck_results().expr_ty_adjusted(slice).kind();
+        if let ty::Slice(elem_ty) = slice_ty.kind();
+        if let hir::ExprKind::Closure(_, _, body_id, ..) = search_args[1].kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [ref param] = *body.params;
+        if let Some(param_id) = param_binding(param.pat);
+        if let hir::ExprKind::Binary(op, ref lhs, ref rhs) = remove_blocks(&body.value).kind;
+        if op.node == hir::BinOpKind::Eq;
+        if let Some((param_side, needle)) = split_comparison(lhs, rhs, param_id);
+        let mut visitor = LocalUsedVisitor::new(param_id);
+        if !visitor.check_expr(needle);
+        let needle_ty = cx.typeck_results().expr_ty(needle);
+        if TyS::same_type(cx.typeck_results().expr_ty(param_side), needle_ty);
+        then {
+            // The closure runs once per item, while the argument of `contains` is evaluated once
+            let mut applicability = if is_plain_value(needle) {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+
+            // `contains` takes `&T`
+            let arg = if TyS::same_type(needle_ty, *elem_ty) {
+                match needle.kind {
+                    hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner)
+                        if cx.typeck_results().expr_ty(inner).is_ref() =>
+                    {
+                        snippet_with_applicability(cx, inner.span, "..", &mut applicability).into_owned()
+                    },
+                    _ => Sugg::hir_with_applicability(cx, needle, "..", &mut applicability)
+                        .addr()
+                        .to_string(),
+                }
+            } else if matches!(needle_ty.kind(), ty::Ref(_, inner_ty, _) if TyS::same_type(*inner_ty, *elem_ty)) {
+                snippet_with_applicability(cx, needle.span, "..", &mut applicability).into_owned()
+            } else {
+                return false;
+            };
+
+            let slice = Sugg::hir_with_applicability(cx, slice, "..", &mut applicability).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                MANUAL_CONTAINS,
+                expr.span,
+                "using a search on the iterator of a slice to check whether it contains a value",
+                "use `contains()` instead",
+                format!("{}.contains({})", slice, arg),
+                applicability,
+            );
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the binding of a closure parameter `x` or `&x`.
+fn param_binding(pat: &hir::Pat<'_>) -> Option<hir::HirId> {
+    match pat.kind {
+        hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, id, _, None) => Some(id),
+        hir::PatKind::Ref(ref inner, _) => param_binding(inner),
+        _ => None,
+    }
+}
+
+/// Splits the operands of the comparison into the one using the parameter and the other one.
+fn split_comparison<'a>(
+    lhs: &'a hir::Expr<'a>,
+    rhs: &'a hir::Expr<'a>,
+    param_id: hir::HirId,
+) -> Option<(&'a hir::Expr<'a>, &'a hir::Expr<'a>)> {
+    if is_param(lhs, param_id) {
+        Some((lhs, rhs))
+    } else if is_param(rhs, param_id) {
+        Some((rhs, lhs))
+    } else {
+        None
+    }
+}
+
+/// Checks if the expression is the parameter, or a dereference of it.
+fn is_param(expr: &hir::Expr<'_>, param_id: hir::HirId) -> bool {
+    match expr.kind {
+        hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) => path.res == Res::Local(param_id),
+        hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) => is_param(inner, param_id),
+        _ => false,
+    }
+}
+
+/// Checks if evaluating the expression once instead of once per item can't change the result.
+fn is_plain_value(expr: &hir::Expr<'_>) -> bool {
+    match expr.kind {
+        hir::ExprKind::Lit(..) | hir::ExprKind::Path(..) => true,
+        hir::ExprKind::Field(ref inner, _) | hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) => {
+            is_plain_value(inner)
+        },
+        _ => false,
+    }
+}
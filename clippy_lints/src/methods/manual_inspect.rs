@@ -0,0 +1,52 @@
+use super::MANUAL_INSPECT;
+use crate::utils::{
+    contains_return, is_copy, match_trait_method, paths, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+
+/// Checks for the `MANUAL_INSPECT` lint, on `iter.map(|x| { observe(&x); x })`. `map_span` is
+/// the span of the `map` method name.
+pub fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, map_args: &'tcx [hir::Expr<'_>], map_span: Span) {
+    if_chain! {
+        if match_trait_method(cx, expr, &paths::ITERATOR);
+        if let hir::ExprKind::Closure(_, _, body_id, ..) = map_args[1].kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [ref param] = *body.params;
+        // the parameter can't be mutated if its binding isn't `mut`
+        if let hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, param_id, ident, None) = param.pat.kind;
+        if let hir::ExprKind::Block(ref block, _) = body.value.kind;
+        if !block.stmts.is_empty();
+        if let Some(ref ret) = block.expr;
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) = ret.kind;
+        // not a `let` in the closure shadowing the parameter
+        if path.res == Res::Local(param_id);
+        if !contains_return(&body.value);
+        if !block.span.from_expansion();
+        then {
+            // `inspect` passes the items by reference. `Copy` items can be copied out of it, so the
+            // statements see the same value, other items have to be used through the reference.
+            let (mut applicability, param) = if is_copy(cx, cx.typeck_results().pat_ty(param.pat)) {
+                (Applicability::MachineApplicable, format!("&{}", ident))
+            } else {
+                (Applicability::MaybeIncorrect, ident.to_string())
+            };
+            // the block without the returned parameter
+            let stmts = snippet_with_applicability(cx, block.span.with_hi(ret.span.lo()), "{ ..", &mut applicability);
+            let end = snippet_with_applicability(cx, block.span.with_lo(ret.span.hi()), " }", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                MANUAL_INSPECT,
+                map_span.with_hi(expr.span.hi()),
+                "using `map` with a closure which returns its argument unchanged",
+                "use `inspect` instead",
+                format!("inspect(|{}| {}{})", param, stmts.trim_end(), end),
+                applicability,
+            );
+        }
+    }
+}
@@ -1,6 +1,10 @@
 mod bind_instead_of_map;
 mod inefficient_to_string;
+mod lines_filter_map_ok;
+mod manual_contains;
+mod manual_inspect;
 mod manual_saturating_arithmetic;
+mod option_as_ref_cloned;
 mod option_map_unwrap_or;
 mod unnecessary_filter_map;
 mod unnecessary_lazy_eval;
@@ -28,11 +32,11 @@ use crate::utils::eager_or_lazy::is_lazyness_candidate;
 use crate::utils::usage::mutated_variables;
 use crate::utils::{
     contains_return, contains_ty, get_arg_name, get_parent_expr, get_trait_def_id, has_iter_method, higher,
-    implements_trait, in_macro, is_copy, is_expn_of, is_type_diagnostic_item, iter_input_pats, last_path_segment,
-    match_def_path, match_qpath, match_trait_method, match_type, match_var, meets_msrv, method_calls,
-    method_chain_args, paths, remove_blocks, return_ty, single_segment_path, snippet, snippet_with_applicability,
-    snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, sugg,
-    walk_ptrs_ty_depth, SpanlessEq,
+    implements_trait, in_macro, is_copy, is_expn_of, is_type_diagnostic_item, is_type_std_item, iter_input_pats,
+    last_path_segment, match_def_path, match_qpath, match_trait_method, match_type, match_var, meets_msrv,
+    method_calls, method_chain_args, paths, remove_blocks, return_ty, single_segment_path, snippet,
+    snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg,
+    span_lint_and_then, std_items, sugg, walk_ptrs_ty_depth, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -212,7 +216,8 @@ declare_clippy_lint! {
     /// **What it does:** Checks for usage of `ok().expect(..)`.
     ///
     /// **Why is this bad?** Because you usually call `expect()` on the `Result`
-    /// directly to get a better error message.
+    /// directly to get a better error message. `ok()` discards the error, while
+    /// `Result::expect` includes it in the panic message.
     ///
     /// **Known problems:** The error type needs to implement `Debug`
     ///
@@ -317,6 +322,34 @@ declare_clippy_lint! {
     "using `Result.map_or(None, Some)`, which is more succinctly expressed as `ok()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.ok().ok_or(_)` on `Result`s.
+    ///
+    /// **Why is this bad?** Readability, the round trip through an `Option` only replaces the
+    /// error, which is more directly expressed as `_.map_err(|_| _)`.
+    ///
+    /// **Known problems:** Both forms discard the original error, which the `map_err_ignore`
+    /// lint warns about. It may be better to include the original error in the new one. The
+    /// suggested closure only evaluates the new error on `Err`, unlike `ok_or`.
+    ///
+    /// **Example:**
+    ///
+    /// Bad:
+    /// ```rust
+    /// # let r: Result<u32, &str> = Ok(1);
+    /// assert_eq!(Ok(1), r.ok().ok_or("failed"));
+    /// ```
+    ///
+    /// Good:
+    /// ```rust
+    /// # let r: Result<u32, &str> = Ok(1);
+    /// assert_eq!(Ok(1), r.map_err(|_| "failed"));
+    /// ```
+    pub RESULT_OK_OK_OR,
+    style,
+    "using `Result.ok().ok_or(_)`, which is more directly expressed as `map_err(|_| _)`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `_.and_then(|x| Some(y))`, `_.and_then(|x| Ok(y))` or
     /// `_.or_else(|x| Err(y))`.
@@ -1263,6 +1296,31 @@ declare_clippy_lint! {
     "using `as_ref().map(Deref::deref)`, which is more succinctly expressed as `as_deref()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `opt.as_ref().cloned()` and `opt.as_ref().map(|x| x.clone())`
+    /// on `Option`s, which can be written as `opt.clone()`, or `opt` if it's `Copy`, and
+    /// `opt.as_mut().map(|x| &*x)`, which can be written as `opt.as_ref()`.
+    ///
+    /// **Why is this bad?** Readability, the detour through a reference does nothing.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let opt = Some(String::from("a"));
+    /// let copy = opt.as_ref().cloned();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let opt = Some(String::from("a"));
+    /// let copy = opt.clone();
+    /// ```
+    pub OPTION_AS_REF_CLONED,
+    style,
+    "using `as_ref().cloned()` on an `Option`, which is more succinctly expressed as `clone()`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `iter().next()` on a Slice or an Array
     ///
@@ -1404,6 +1462,105 @@ declare_clippy_lint! {
     "use `.collect()` instead of `::from_iter()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for searching a slice for a value with `iter().any()` or
+    /// `iter().position(..).is_some()`.
+    ///
+    /// **Why is this bad?** `contains()` is shorter, and is specialized for some element types.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let values = [1, 2, 3];
+    /// let needle = 2;
+    /// let found = values.iter().any(|x| *x == needle);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let values = [1, 2, 3];
+    /// let needle = 2;
+    /// let found = values.contains(&needle);
+    /// ```
+    pub MANUAL_CONTAINS,
+    perf,
+    "using `iter().any()` or `iter().position(..).is_some()` to check whether a slice contains a value"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `map` on iterators with a closure which only has side
+    /// effects and returns its argument unchanged, like `.map(|x| { println!("{}", x); x })`.
+    ///
+    /// **Why is this bad?** `inspect` is made for this and says so, while a reader has to check
+    /// the closure passed to `map` to find out that the items aren't changed.
+    ///
+    /// **Known problems:** `inspect` passes the items by reference, so the suggestion may need
+    /// adjusting for items which aren't `Copy`.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let sum: i32 = v
+    ///     .into_iter()
+    ///     .map(|x| {
+    ///         println!("adding {}", x);
+    ///         x
+    ///     })
+    ///     .sum();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let sum: i32 = v
+    ///     .into_iter()
+    ///     .inspect(|&x| {
+    ///         println!("adding {}", x);
+    ///     })
+    ///     .sum();
+    /// ```
+    pub MANUAL_INSPECT,
+    complexity,
+    "using `map` with a closure which returns its argument unchanged instead of `inspect`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `lines.filter_map(Result::ok)`, `lines.flat_map(Result::ok)`
+    /// and `lines.flatten()` on the `Lines` of a `BufRead`.
+    ///
+    /// **Why is this bad?** The IO errors are silently ignored, and the iteration goes on. If the
+    /// reader returns the same error again, e.g. when reading a directory, the iteration never
+    /// ends.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::{fs::File, io::{self, BufRead, BufReader}};
+    /// # fn f() -> io::Result<()> {
+    /// let reader = BufReader::new(File::open("Cargo.toml")?);
+    /// for line in reader.lines().flatten() {
+    ///     println!("{}", line);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::{fs::File, io::{self, BufRead, BufReader}};
+    /// # fn f() -> io::Result<()> {
+    /// let reader = BufReader::new(File::open("Cargo.toml")?);
+    /// for line in reader.lines() {
+    ///     println!("{}", line?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub LINES_FILTER_MAP_OK,
+    pedantic,
+    "ignoring the errors of `BufRead::lines` with `filter_map(Result::ok)` or `flatten()`"
+}
+
 pub struct Methods {
     msrv: Option<RustcVersion>,
 }
@@ -1424,6 +1581,7 @@ impl_lint_pass!(Methods => [
     OK_EXPECT,
     MAP_UNWRAP_OR,
     RESULT_MAP_OR_INTO_OPTION,
+    RESULT_OK_OK_OR,
     OPTION_MAP_OR_NONE,
     BIND_INSTEAD_OF_MAP,
     OR_FUN_CALL,
@@ -1463,9 +1621,13 @@ impl_lint_pass!(Methods => [
     ZST_OFFSET,
     FILETYPE_IS_FILE,
     OPTION_AS_REF_DEREF,
+    OPTION_AS_REF_CLONED,
     UNNECESSARY_LAZY_EVALUATIONS,
     MAP_COLLECT_RESULT_UNIT,
     FROM_ITER_INSTEAD_OF_COLLECT,
+    MANUAL_CONTAINS,
+    MANUAL_INSPECT,
+    LINES_FILTER_MAP_OK,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Methods {
@@ -1483,7 +1645,8 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["unwrap", "get"] => lint_get_unwrap(cx, expr, arg_lists[1], false),
             ["unwrap", "get_mut"] => lint_get_unwrap(cx, expr, arg_lists[1], true),
             ["unwrap", ..] => lint_unwrap(cx, expr, arg_lists[0]),
-            ["expect", "ok"] => lint_ok_expect(cx, expr, arg_lists[1]),
+            ["expect", "ok"] => lint_ok_expect(cx, expr, arg_lists[1], arg_lists[0]),
+            ["ok_or", "ok"] => lint_ok_ok_or(cx, expr, arg_lists[1], arg_lists[0]),
             ["expect", ..] => lint_expect(cx, expr, arg_lists[0]),
             ["unwrap_or", "map"] => option_map_unwrap_or::lint(cx, expr, arg_lists[1], arg_lists[0], method_spans[1]),
             ["unwrap_or_else", "map"] => {
@@ -1517,11 +1680,16 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["flatten", "map"] => lint_map_flatten(cx, expr, arg_lists[1]),
             ["is_some", "find"] => lint_search_is_some(cx, expr, "find", arg_lists[1], arg_lists[0], method_spans[1]),
             ["is_some", "position"] => {
-                lint_search_is_some(cx, expr, "position", arg_lists[1], arg_lists[0], method_spans[1])
+                if !manual_contains::lint(cx, expr, &arg_lists[0][0], arg_lists[1]) {
+                    lint_search_is_some(cx, expr, "position", arg_lists[1], arg_lists[0], method_spans[1])
+                }
             },
             ["is_some", "rposition"] => {
                 lint_search_is_some(cx, expr, "rposition", arg_lists[1], arg_lists[0], method_spans[1])
             },
+            ["any", "iter"] => {
+                manual_contains::lint(cx, expr, expr, arg_lists[0]);
+            },
             ["extend", ..] => lint_extend(cx, expr, arg_lists[0]),
             ["nth", "iter"] => lint_iter_nth(cx, expr, &arg_lists, false),
             ["nth", "iter_mut"] => lint_iter_nth(cx, expr, &arg_lists, true),
@@ -1529,6 +1697,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["step_by", ..] => lint_step_by(cx, expr, arg_lists[0]),
             ["next", "skip"] => lint_iter_skip_next(cx, expr, arg_lists[1]),
             ["collect", "cloned"] => lint_iter_cloned_collect(cx, expr, arg_lists[1]),
+            ["cloned", "as_ref"] => option_as_ref_cloned::lint_cloned(cx, expr, arg_lists[1]),
             ["as_ref"] => lint_asref(cx, expr, "as_ref", arg_lists[0]),
             ["as_mut"] => lint_asref(cx, expr, "as_mut", arg_lists[0]),
             ["fold", ..] => lint_unnecessary_fold(cx, expr, arg_lists[0], method_spans[0]),
@@ -1543,10 +1712,12 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             },
             ["is_file", ..] => lint_filetype_is_file(cx, expr, arg_lists[0]),
             ["map", "as_ref"] => {
-                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false, self.msrv.as_ref())
+                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false, self.msrv.as_ref());
+                option_as_ref_cloned::lint_map_clone(cx, expr, arg_lists[1], arg_lists[0]);
             },
             ["map", "as_mut"] => {
-                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true, self.msrv.as_ref())
+                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true, self.msrv.as_ref());
+                option_as_ref_cloned::lint_as_mut_reborrow(cx, expr, arg_lists[1], arg_lists[0]);
             },
             ["unwrap_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "unwrap_or"),
             ["get_or_insert_with", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "get_or_insert"),
@@ -1555,6 +1726,14 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             _ => {},
         }
 
+        // checked on its own, as the other lints on `map` match on the method before it
+        if let ["map", ..] = method_names.as_slice() {
+            manual_inspect::lint(cx, expr, arg_lists[0], method_spans[0]);
+        }
+        if let [method @ ("filter_map" | "flat_map" | "flatten"), "lines"] = method_names.as_slice() {
+            lines_filter_map_ok::lint(cx, expr, method, arg_lists[0], method_spans[0]);
+        }
+
         match expr.kind {
             hir::ExprKind::Call(ref func, ref args) => {
                 if let hir::ExprKind::Path(path) = &func.kind {
@@ -1568,7 +1747,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 lint_expect_fun_call(cx, expr, *method_span, &method_call.ident.as_str(), args);
 
                 let self_ty = cx.typeck_results().expr_ty_adjusted(&args[0]);
-                if args.len() == 1 && method_call.ident.name == sym!(clone) {
+                if args.len() == 1 && method_call.ident.name == sym::clone {
                     lint_clone_on_copy(cx, expr, &args[0], self_ty);
                     lint_clone_on_ref_ptr(cx, expr, &args[0]);
                 }
@@ -1592,7 +1771,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                             }
                         }
                     },
-                    ty::Ref(..) if method_call.ident.name == sym!(into_iter) => {
+                    ty::Ref(..) if method_call.ident.name == sym::into_iter => {
                         lint_into_iter(cx, expr, self_ty, *method_span);
                     },
                     _ => (),
@@ -1829,7 +2008,7 @@ fn lint_or_fun_call<'tcx>(
         ];
 
         if let hir::ExprKind::MethodCall(ref path, _, ref args, _) = &arg.kind {
-            if path.ident.as_str() == "len" {
+            if path.ident.name == sym::len {
                 let ty = cx.typeck_results().expr_ty(&args[0]).peel_refs();
 
                 match ty.kind() {
@@ -2190,7 +2369,9 @@ fn lint_clone_on_ref_ptr(cx: &LateContext<'_>, expr: &hir::Expr<'_>, arg: &hir::
             "Rc"
         } else if is_type_diagnostic_item(cx, obj_ty, sym::Arc) {
             "Arc"
-        } else if match_type(cx, obj_ty, &paths::WEAK_RC) || match_type(cx, obj_ty, &paths::WEAK_ARC) {
+        } else if is_type_std_item(cx, obj_ty, &std_items::WEAK_RC)
+            || is_type_std_item(cx, obj_ty, &std_items::WEAK_ARC)
+        {
             "Weak"
         } else {
             return;
@@ -2438,7 +2619,7 @@ fn lint_iter_nth<'tcx>(
         "slice"
     } else if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&iter_args[0]), sym::vec_type) {
         "Vec"
-    } else if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&iter_args[0]), sym!(vecdeque_type)) {
+    } else if is_type_std_item(cx, cx.typeck_results().expr_ty(&iter_args[0]), &std_items::VEC_DEQUE) {
         "VecDeque"
     } else {
         let nth_args = nth_and_iter_args[0];
@@ -2492,13 +2673,13 @@ fn lint_get_unwrap<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, get_args:
     } else if is_type_diagnostic_item(cx, expr_ty, sym::vec_type) {
         needs_ref = get_args_str.parse::<usize>().is_ok();
         "Vec"
-    } else if is_type_diagnostic_item(cx, expr_ty, sym!(vecdeque_type)) {
+    } else if is_type_std_item(cx, expr_ty, &std_items::VEC_DEQUE) {
         needs_ref = get_args_str.parse::<usize>().is_ok();
         "VecDeque"
-    } else if !is_mut && is_type_diagnostic_item(cx, expr_ty, sym!(hashmap_type)) {
+    } else if !is_mut && is_type_std_item(cx, expr_ty, &std_items::HASHMAP) {
         needs_ref = true;
         "HashMap"
-    } else if !is_mut && match_type(cx, expr_ty, &paths::BTREEMAP) {
+    } else if !is_mut && is_type_std_item(cx, expr_ty, &std_items::BTREEMAP) {
         needs_ref = true;
         "BTreeMap"
     } else {
@@ -2638,9 +2819,9 @@ fn lint_unwrap(cx: &LateContext<'_>, expr: &hir::Expr<'_>, unwrap_args: &[hir::E
 fn lint_expect(cx: &LateContext<'_>, expr: &hir::Expr<'_>, expect_args: &[hir::Expr<'_>]) {
     let obj_ty = cx.typeck_results().expr_ty(&expect_args[0]).peel_refs();
 
-    let mess = if is_type_diagnostic_item(cx, obj_ty, sym!(option_type)) {
+    let mess = if is_type_diagnostic_item(cx, obj_ty, sym::option_type) {
         Some((EXPECT_USED, "an Option", "None"))
-    } else if is_type_diagnostic_item(cx, obj_ty, sym!(result_type)) {
+    } else if is_type_diagnostic_item(cx, obj_ty, sym::result_type) {
         Some((EXPECT_USED, "a Result", "Err"))
     } else {
         None
@@ -2659,7 +2840,12 @@ fn lint_expect(cx: &LateContext<'_>, expr: &hir::Expr<'_>, expect_args: &[hir::E
 }
 
 /// lint use of `ok().expect()` for `Result`s
-fn lint_ok_expect(cx: &LateContext<'_>, expr: &hir::Expr<'_>, ok_args: &[hir::Expr<'_>]) {
+fn lint_ok_expect(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    ok_args: &[hir::Expr<'_>],
+    expect_args: &[hir::Expr<'_>],
+) {
     if_chain! {
         // lint if the caller of `ok()` is a `Result`
         if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&ok_args[0]), sym::result_type);
@@ -2668,18 +2854,51 @@ fn lint_ok_expect(cx: &LateContext<'_>, expr: &hir::Expr<'_>, ok_args: &[hir::Ex
         if has_debug_impl(error_type, cx);
 
         then {
-            span_lint_and_help(
+            let mut applicability = Applicability::MachineApplicable;
+            let result = snippet_with_applicability(cx, ok_args[0].span, "..", &mut applicability);
+            let message = snippet_with_applicability(cx, expect_args[1].span, "..", &mut applicability);
+            span_lint_and_sugg(
                 cx,
                 OK_EXPECT,
                 expr.span,
                 "called `ok().expect()` on a `Result` value",
-                None,
-                "you can call `expect()` directly on the `Result`",
+                "call `expect()` directly on the `Result`",
+                format!("{}.expect({})", result, message),
+                applicability,
             );
         }
     }
 }
 
+/// lint use of `ok().ok_or()` for `Result`s
+fn lint_ok_ok_or(cx: &LateContext<'_>, expr: &hir::Expr<'_>, ok_args: &[hir::Expr<'_>], ok_or_args: &[hir::Expr<'_>]) {
+    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&ok_args[0]), sym::result_type) {
+        // The closure only evaluates the error on `Err`, which changes the behavior if its
+        // evaluation has side effects
+        let mut applicability = match ok_or_args[1].kind {
+            hir::ExprKind::Path(_) | hir::ExprKind::Lit(_) => Applicability::MachineApplicable,
+            _ => Applicability::MaybeIncorrect,
+        };
+        let result = snippet_with_applicability(cx, ok_args[0].span, "..", &mut applicability);
+        let error = snippet_with_applicability(cx, ok_or_args[1].span, "..", &mut applicability);
+        span_lint_and_then(
+            cx,
+            RESULT_OK_OK_OR,
+            expr.span,
+            "called `ok().ok_or(..)` on a `Result` value",
+            |diag| {
+                diag.span_suggestion(
+                    expr.span,
+                    "call `map_err()` directly on the `Result`",
+                    format!("{}.map_err(|_| {})", result, error),
+                    applicability,
+                );
+                diag.note("the original error is discarded, consider including it in the new error");
+            },
+        );
+    }
+}
+
 /// lint use of `map().flatten()` for `Iterators` and 'Options'
 fn lint_map_flatten<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, map_args: &'tcx [hir::Expr<'_>]) {
     // lint if caller of `.map().flatten()` is an Iterator
@@ -3116,7 +3335,7 @@ fn lint_search_is_some<'tcx>(
     else if search_method == "find" {
         let is_string_or_str_slice = |e| {
             let self_ty = cx.typeck_results().expr_ty(e).peel_refs();
-            if is_type_diagnostic_item(cx, self_ty, sym!(string_type)) {
+            if is_type_diagnostic_item(cx, self_ty, sym::string_type) {
                 true
             } else {
                 *self_ty.kind() == ty::Str
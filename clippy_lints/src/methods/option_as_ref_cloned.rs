@@ -0,0 +1,124 @@
+use super::OPTION_AS_REF_CLONED;
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    is_clone_method_path, is_copy, is_param, is_param_or_deref, is_type_diagnostic_item, match_trait_method, paths,
+    remove_blocks, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, TyS};
+use rustc_span::sym;
+
+/// Checks for the `OPTION_AS_REF_CLONED` lint, on `opt.as_ref().cloned()`.
+pub fn lint_cloned(cx: &LateContext<'_>, expr: &hir::Expr<'_>, as_ref_args: &[hir::Expr<'_>]) {
+    lint_clone(cx, expr, &as_ref_args[0], "cloned()");
+}
+
+/// Checks for the `OPTION_AS_REF_CLONED` lint, on `opt.as_ref().map(|x| x.clone())`,
+/// `opt.as_ref().map(Clone::clone)` and the other closures linted by `map_clone`, like
+/// `opt.as_ref().map(|x| *x)`.
+pub fn lint_map_clone(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    as_ref_args: &[hir::Expr<'_>],
+    map_args: &[hir::Expr<'_>],
+) {
+    if is_clone_fn(cx, &map_args[1]) {
+        lint_clone(cx, expr, &as_ref_args[0], "map(..)");
+    }
+}
+
+/// Checks for the `OPTION_AS_REF_CLONED` lint, on `opt.as_mut().map(|x| &*x)`.
+pub fn lint_as_mut_reborrow(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    as_mut_args: &[hir::Expr<'_>],
+    map_args: &[hir::Expr<'_>],
+) {
+    let recv = &as_mut_args[0];
+    if_chain! {
+        if let ty::Adt(_, substs) = cx.typeck_results().expr_ty(recv).peel_refs().kind();
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::option_type);
+        if let hir::ExprKind::Closure(_, _, body_id, ..) = map_args[1].kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [ref param] = *body.params;
+        if let hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, ref inner) = body.value.kind;
+        if let hir::ExprKind::Unary(hir::UnOp::UnDeref, ref reborrowed) = inner.kind;
+        if is_param(reborrowed, param.pat);
+        // `&*x` may also go through a `Deref` impl, then it's not what `as_ref` returns
+        if let ty::Adt(_, result_substs) = cx.typeck_results().expr_ty(expr).kind();
+        if let ty::Ref(_, result_ty, hir::Mutability::Not) = result_substs.type_at(0).kind();
+        if TyS::same_type(result_ty, substs.type_at(0));
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                OPTION_AS_REF_CLONED,
+                expr.span,
+                "reborrowing the result of `as_mut()` as a shared reference",
+                "use `as_ref()` instead",
+                format!("{}.as_ref()", Sugg::hir_with_applicability(cx, recv, "..", &mut applicability).maybe_par()),
+                applicability,
+            );
+        }
+    }
+}
+
+fn lint_clone(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, method: &str) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    // the element may be cloned into another type through `Deref`, and cloning a reference to an
+    // `Option` clones the reference
+    if is_type_diagnostic_item(cx, recv_ty, sym::option_type)
+        && TyS::same_type(cx.typeck_results().expr_ty(expr), recv_ty)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability).maybe_par();
+        // a `Copy` `Option` is copied by using it
+        let (help, sugg) = if is_copy(cx, recv_ty) {
+            ("use the `Option` instead, it's `Copy`", recv.to_string())
+        } else {
+            ("use `clone()` instead", format!("{}.clone()", recv))
+        };
+        span_lint_and_sugg(
+            cx,
+            OPTION_AS_REF_CLONED,
+            expr.span,
+            &format!("cloning an `Option` with `as_ref().{}`", method),
+            help,
+            sugg,
+            applicability,
+        );
+    }
+}
+
+/// Checks if the expression is `Clone::clone` or a closure copying or cloning its parameter, like
+/// `|x| x.clone()`, `|x| *x` or `|&x| x`. The types are checked by the caller.
+fn is_clone_fn(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    match expr.kind {
+        hir::ExprKind::Path(_) => is_clone_method_path(cx, expr),
+        hir::ExprKind::Closure(_, _, body_id, ..) => {
+            let body = cx.tcx.hir().body(body_id);
+            let param = match *body.params {
+                [ref param] => param,
+                _ => return false,
+            };
+            let value = remove_blocks(&body.value);
+            match (&param.pat.kind, &value.kind) {
+                (hir::PatKind::Ref(ref inner, hir::Mutability::Not), _) => is_param(value, inner),
+                (_, hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner)) => is_param(inner, param.pat),
+                (_, hir::ExprKind::MethodCall(ref method, _, [ref obj], _)) => {
+                    method.ident.name == sym::clone
+                        && is_param_or_deref(obj, param.pat)
+                        && match_trait_method(cx, value, &paths::CLONE_TRAIT)
+                },
+                (_, hir::ExprKind::Call(ref func, [ref obj])) => {
+                    is_param(obj, param.pat) && is_clone_method_path(cx, func)
+                },
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
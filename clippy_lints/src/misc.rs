@@ -17,8 +17,8 @@ use crate::consts::{constant, Constant};
 use crate::utils::sugg::Sugg;
 use crate::utils::{
     get_item_name, get_parent_expr, higher, implements_trait, in_constant, is_integer_const, iter_input_pats,
-    last_path_segment, match_qpath, match_trait_method, paths, snippet, snippet_opt, span_lint, span_lint_and_sugg,
-    span_lint_and_then, span_lint_hir_and_then, unsext, SpanlessEq,
+    last_path_segment, match_qpath, match_trait_method, paths, significant_tys, snippet, snippet_opt, span_lint,
+    span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then, unsext, SignificantTy, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -374,6 +374,7 @@ impl<'tcx> LateLintPass<'tcx> for MiscLints {
         };
     }
 
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         match expr.kind {
             ExprKind::Cast(ref e, ref ty) => {
@@ -416,7 +417,11 @@ impl<'tcx> LateLintPass<'tcx> for MiscLints {
             _ => None,
         };
         if let Some(binding) = binding {
-            span_lint(
+            // a guard is often bound to `_guard` to keep the lock until the end of the scope, renaming it
+            // to `_` would release the lock immediately
+            let is_guard = matches!(expr.kind, ExprKind::Path(_))
+                && significant_tys(cx, cx.typeck_results().expr_ty(expr)).contains(&SignificantTy::SyncGuard);
+            span_lint_and_then(
                 cx,
                 USED_UNDERSCORE_BINDING,
                 expr.span,
@@ -425,6 +430,14 @@ impl<'tcx> LateLintPass<'tcx> for MiscLints {
                      underscore signals that a binding will not be used.",
                     binding
                 ),
+                |diag| {
+                    if is_guard {
+                        diag.help(&format!(
+                            "rename it to `{}`, binding the guard to `_` would release the lock immediately",
+                            binding.trim_start_matches('_')
+                        ));
+                    }
+                },
             );
         }
     }
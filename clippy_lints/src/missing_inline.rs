@@ -1,8 +1,13 @@
 use crate::utils::span_lint;
 use rustc_ast::ast;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_stmt, NestedVisitorMap, Visitor};
+use rustc_hir::{BodyId, HirId};
 use rustc_lint::{self, LateContext, LateLintPass, LintContext};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::GenericParamDefKind;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 
@@ -18,6 +23,10 @@ declare_clippy_lint! {
     /// sense. It allows the crate to require all exported methods to be `#[inline]` by default, and
     /// then opt out for specific methods where this might not make sense.
     ///
+    /// The lint only checks library crates. Generic functions are skipped, because they are
+    /// instantiated in the crates using them and can be inlined there anyway. Functions with more
+    /// statements than configured by `missing-inline-max-statements` (default: 50) are skipped, too.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -69,21 +78,74 @@ fn check_missing_inline_attrs(cx: &LateContext<'_>, attrs: &[ast::Attribute], sp
     }
 }
 
-fn is_executable(cx: &LateContext<'_>) -> bool {
+fn is_library(cx: &LateContext<'_>) -> bool {
     use rustc_session::config::CrateType;
 
     cx.tcx
         .sess
         .crate_types()
         .iter()
-        .any(|t: &CrateType| matches!(t, CrateType::Executable))
+        .any(|t: &CrateType| matches!(t, CrateType::Rlib | CrateType::Dylib))
+}
+
+/// Returns true if the function has type or const parameters. If `check_parent` is set, the
+/// parameters of the parent item (e.g. the `impl` block of a method) are checked as well.
+fn is_generic(cx: &LateContext<'_>, def_id: DefId, check_parent: bool) -> bool {
+    let generics = cx.tcx.generics_of(def_id);
+    generics
+        .params
+        .iter()
+        .any(|param| !matches!(param.kind, GenericParamDefKind::Lifetime))
+        || (check_parent && generics.parent.map_or(false, |parent| is_generic(cx, parent, false)))
+}
+
+/// Counts the statements of a function body, including the ones in nested blocks.
+struct StatementCounter {
+    count: u64,
+}
+
+impl<'tcx> Visitor<'tcx> for StatementCounter {
+    type Map = Map<'tcx>;
+
+    fn visit_stmt(&mut self, stmt: &'tcx hir::Stmt<'_>) {
+        self.count += 1;
+        walk_stmt(self, stmt);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
 }
 
-declare_lint_pass!(MissingInline => [MISSING_INLINE_IN_PUBLIC_ITEMS]);
+pub struct MissingInline {
+    max_statements: u64,
+}
+
+impl MissingInline {
+    #[must_use]
+    pub fn new(max_statements: u64) -> Self {
+        Self { max_statements }
+    }
+
+    /// Checks whether the function would benefit from `#[inline]`, i.e. it isn't generic and not
+    /// too large.
+    fn is_candidate(&self, cx: &LateContext<'_>, hir_id: HirId, body_id: BodyId, check_parent: bool) -> bool {
+        let def_id = cx.tcx.hir().local_def_id(hir_id).to_def_id();
+        if is_generic(cx, def_id, check_parent) {
+            return false;
+        }
+
+        let mut counter = StatementCounter { count: 0 };
+        counter.visit_body(cx.tcx.hir().body(body_id));
+        counter.count <= self.max_statements
+    }
+}
+
+impl_lint_pass!(MissingInline => [MISSING_INLINE_IN_PUBLIC_ITEMS]);
 
 impl<'tcx> LateLintPass<'tcx> for MissingInline {
     fn check_item(&mut self, cx: &LateContext<'tcx>, it: &'tcx hir::Item<'_>) {
-        if rustc_middle::lint::in_external_macro(cx.sess(), it.span) || is_executable(cx) {
+        if rustc_middle::lint::in_external_macro(cx.sess(), it.span) || !is_library(cx) {
             return;
         }
 
@@ -91,9 +153,11 @@ impl<'tcx> LateLintPass<'tcx> for MissingInline {
             return;
         }
         match it.kind {
-            hir::ItemKind::Fn(..) => {
-                let desc = "a function";
-                check_missing_inline_attrs(cx, &it.attrs, it.span, desc);
+            hir::ItemKind::Fn(_, _, body_id) => {
+                if self.is_candidate(cx, it.hir_id, body_id, false) {
+                    let desc = "a function";
+                    check_missing_inline_attrs(cx, &it.attrs, it.span, desc);
+                }
             },
             hir::ItemKind::Trait(ref _is_auto, ref _unsafe, ref _generics, ref _bounds, trait_items) => {
                 // note: we need to check if the trait is exported so we can't use
@@ -102,15 +166,15 @@ impl<'tcx> LateLintPass<'tcx> for MissingInline {
                     let tit_ = cx.tcx.hir().trait_item(tit.id);
                     match tit_.kind {
                         hir::TraitItemKind::Const(..) | hir::TraitItemKind::Type(..) => {},
-                        hir::TraitItemKind::Fn(..) => {
-                            if tit.defaultness.has_value() {
-                                // trait method with default body needs inline in case
-                                // an impl is not provided
+                        hir::TraitItemKind::Fn(_, hir::TraitFn::Provided(body_id)) => {
+                            // trait method with default body needs inline in case
+                            // an impl is not provided
+                            if self.is_candidate(cx, tit_.hir_id, body_id, false) {
                                 let desc = "a default trait method";
-                                let item = cx.tcx.hir().expect_trait_item(tit.id.hir_id);
-                                check_missing_inline_attrs(cx, &item.attrs, item.span, desc);
+                                check_missing_inline_attrs(cx, &tit_.attrs, tit_.span, desc);
                             }
                         },
+                        hir::TraitItemKind::Fn(_, hir::TraitFn::Required(_)) => {},
                     }
                 }
             },
@@ -133,7 +197,7 @@ impl<'tcx> LateLintPass<'tcx> for MissingInline {
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx hir::ImplItem<'_>) {
         use rustc_middle::ty::{ImplContainer, TraitContainer};
-        if rustc_middle::lint::in_external_macro(cx.sess(), impl_item.span) || is_executable(cx) {
+        if rustc_middle::lint::in_external_macro(cx.sess(), impl_item.span) || !is_library(cx) {
             return;
         }
 
@@ -142,10 +206,13 @@ impl<'tcx> LateLintPass<'tcx> for MissingInline {
             return;
         }
 
-        let desc = match impl_item.kind {
-            hir::ImplItemKind::Fn(..) => "a method",
+        let body_id = match impl_item.kind {
+            hir::ImplItemKind::Fn(_, body_id) => body_id,
             hir::ImplItemKind::Const(..) | hir::ImplItemKind::TyAlias(_) => return,
         };
+        if !self.is_candidate(cx, impl_item.hir_id, body_id, true) {
+            return;
+        }
 
         let def_id = cx.tcx.hir().local_def_id(impl_item.hir_id);
         let trait_def_id = match cx.tcx.associated_item(def_id).container {
@@ -161,6 +228,6 @@ impl<'tcx> LateLintPass<'tcx> for MissingInline {
             }
         }
 
-        check_missing_inline_attrs(cx, &impl_item.attrs, impl_item.span, desc);
+        check_missing_inline_attrs(cx, &impl_item.attrs, impl_item.span, "a method");
     }
 }
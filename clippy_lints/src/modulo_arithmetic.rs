@@ -17,6 +17,9 @@ declare_clippy_lint! {
     ///
     /// For example, in Rust `17 % -3 = 2`, but in Python `17 % -3 = -1`.
     ///
+    /// The lint is not emitted if both operands are known to be non-negative, e.g. constants,
+    /// casts from narrower unsigned types, or calls of `abs` on floats and of `rem_euclid`.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -105,9 +108,32 @@ fn check_const_operands<'tcx>(
     }
 }
 
-fn check_non_const_operands<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, operand: &Expr<'_>) {
-    let operand_type = cx.typeck_results().expr_ty(operand);
-    if might_have_negative_value(operand_type) {
+/// Checks if the operand is known to be non-negative.
+fn is_non_negative(cx: &LateContext<'_>, operand: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(operand);
+    if !might_have_negative_value(ty) {
+        return true;
+    }
+    if let Some(info) = analyze_operand(operand, cx, operand) {
+        return !info.is_negative;
+    }
+    match operand.kind {
+        ExprKind::Cast(ref inner, _) => {
+            let inner_ty = cx.typeck_results().expr_ty(inner);
+            inner_ty.is_integral()
+                && !inner_ty.is_signed()
+                && (ty.is_floating_point() || inner_ty.primitive_size(cx.tcx) < ty.primitive_size(cx.tcx))
+        },
+        ExprKind::MethodCall(ref path, _, _, _) => {
+            path.ident.name.as_str() == "rem_euclid" || (ty.is_floating_point() && path.ident.name.as_str() == "abs")
+        },
+        _ => false,
+    }
+}
+
+fn check_non_const_operands<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+    let operand_type = cx.typeck_results().expr_ty(lhs);
+    if might_have_negative_value(operand_type) && !(is_non_negative(cx, lhs) && is_non_negative(cx, rhs)) {
         span_lint_and_then(
             cx,
             MODULO_ARITHMETIC,
@@ -137,7 +163,7 @@ impl<'tcx> LateLintPass<'tcx> for ModuloArithmetic {
                             check_const_operands(cx, expr, &lhs_operand, &rhs_operand);
                         }
                         else {
-                            check_non_const_operands(cx, expr, lhs);
+                            check_non_const_operands(cx, expr, lhs, rhs);
                         }
                     }
                 };
@@ -1,13 +1,14 @@
 //! lint on multiple versions of a crate being used
 
-use crate::utils::{run_lints, span_lint};
+use crate::utils::{run_lints, span_lint_and_then};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::{Crate, CRATE_HIR_ID};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::DUMMY_SP;
 
-use cargo_metadata::{DependencyKind, Node, Package, PackageId};
+use cargo_metadata::{DependencyKind, Node, NodeDep, Package, PackageId};
 use if_chain::if_chain;
 use itertools::Itertools;
 
@@ -20,7 +21,9 @@ declare_clippy_lint! {
     /// between different versions of a crate.
     ///
     /// **Known problems:** Because this can be caused purely by the dependencies
-    /// themselves, it's not always possible to fix this issue.
+    /// themselves, it's not always possible to fix this issue. The lint names the direct
+    /// dependencies pulling in each version, and crates that can't be deduplicated can be
+    /// allowed with the `allowed-duplicate-crates` configuration option.
     ///
     /// **Example:**
     /// ```toml
@@ -34,7 +37,20 @@ declare_clippy_lint! {
     "multiple versions of the same crate being used"
 }
 
-declare_lint_pass!(MultipleCrateVersions => [MULTIPLE_CRATE_VERSIONS]);
+#[derive(Clone)]
+pub struct MultipleCrateVersions {
+    allowed_duplicate_crates: FxHashSet<String>,
+}
+
+impl MultipleCrateVersions {
+    pub fn new(allowed_duplicate_crates: FxHashSet<String>) -> Self {
+        Self {
+            allowed_duplicate_crates,
+        }
+    }
+}
+
+impl_lint_pass!(MultipleCrateVersions => [MULTIPLE_CRATE_VERSIONS]);
 
 impl LateLintPass<'_> for MultipleCrateVersions {
     fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
@@ -56,20 +72,41 @@ impl LateLintPass<'_> for MultipleCrateVersions {
                 for (name, group) in &packages.iter().group_by(|p| p.name.clone()) {
                     let group: Vec<&Package> = group.collect();
 
-                    if group.len() <= 1 {
+                    if group.len() <= 1 || self.allowed_duplicate_crates.contains(&name) {
                         continue;
                     }
 
                     if group.iter().all(|p| is_normal_dep(&resolve.nodes, local_id, &p.id)) {
-                        let mut versions: Vec<_> = group.into_iter().map(|p| &p.version).collect();
-                        versions.sort();
-                        let versions = versions.iter().join(", ");
+                        let mut group = group;
+                        group.sort_by(|a, b| a.version.cmp(&b.version));
+                        let versions = group.iter().map(|p| &p.version).join(", ");
 
-                        span_lint(
+                        span_lint_and_then(
                             cx,
                             MULTIPLE_CRATE_VERSIONS,
                             DUMMY_SP,
                             &format!("multiple versions for dependency `{}`: {}", name, versions),
+                            |diag| {
+                                for package in &group {
+                                    let pulled_in_by = pulled_in_by(&resolve.nodes, &packages, local_id, &package.id);
+                                    let (direct, indirect): (Vec<_>, Vec<_>) =
+                                        pulled_in_by.into_iter().partition(|dep| dep.id == package.id);
+                                    if !direct.is_empty() {
+                                        diag.note(&format!(
+                                            "`{} {}` is a direct dependency",
+                                            name, package.version
+                                        ));
+                                    }
+                                    if !indirect.is_empty() {
+                                        diag.note(&format!(
+                                            "`{} {}` is pulled in by {}",
+                                            name,
+                                            package.version,
+                                            indirect.iter().map(|dep| format!("`{}`", dep.name)).join(", ")
+                                        ));
+                                    }
+                                }
+                            },
                         );
                     }
                 }
@@ -78,15 +115,15 @@ impl LateLintPass<'_> for MultipleCrateVersions {
     }
 }
 
+fn is_normal(dep: &NodeDep) -> bool {
+    dep.dep_kinds
+        .iter()
+        .any(|info| matches!(info.kind, DependencyKind::Normal))
+}
+
 fn is_normal_dep(nodes: &[Node], local_id: &PackageId, dep_id: &PackageId) -> bool {
     fn depends_on(node: &Node, dep_id: &PackageId) -> bool {
-        node.deps.iter().any(|dep| {
-            dep.pkg == *dep_id
-                && dep
-                    .dep_kinds
-                    .iter()
-                    .any(|info| matches!(info.kind, DependencyKind::Normal))
-        })
+        node.deps.iter().any(|dep| dep.pkg == *dep_id && is_normal(dep))
     }
 
     nodes
@@ -94,3 +131,45 @@ fn is_normal_dep(nodes: &[Node], local_id: &PackageId, dep_id: &PackageId) -> bo
         .filter(|node| depends_on(node, dep_id))
         .any(|node| node.id == *local_id || is_normal_dep(nodes, local_id, &node.id))
 }
+
+/// Returns the direct normal dependencies of the local crate that pull in `dep_id`. The dependency
+/// itself is part of the result if the local crate depends on it directly.
+fn pulled_in_by<'a>(
+    nodes: &[Node],
+    packages: &'a [Package],
+    local_id: &PackageId,
+    dep_id: &PackageId,
+) -> Vec<&'a Package> {
+    let local_node = match nodes.iter().find(|node| node.id == *local_id) {
+        Some(node) => node,
+        None => return Vec::new(),
+    };
+
+    let mut deps: Vec<&Package> = local_node
+        .deps
+        .iter()
+        .filter(|dep| is_normal(dep) && depends_transitively(nodes, &dep.pkg, dep_id))
+        .filter_map(|dep| packages.iter().find(|p| p.id == dep.pkg))
+        .collect();
+    deps.sort_by(|a, b| a.name.cmp(&b.name));
+    deps.dedup_by(|a, b| a.id == b.id);
+    deps
+}
+
+/// Checks whether `from` is `to` or depends on it through normal dependencies.
+fn depends_transitively(nodes: &[Node], from: &PackageId, to: &PackageId) -> bool {
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![from];
+    while let Some(id) = stack.pop() {
+        if id == to {
+            return true;
+        }
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Some(node) = nodes.iter().find(|node| node.id == *id) {
+            stack.extend(node.deps.iter().filter(|dep| is_normal(dep)).map(|dep| &dep.pkg));
+        }
+    }
+    false
+}
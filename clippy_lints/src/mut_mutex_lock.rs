@@ -1,20 +1,24 @@
-use crate::utils::{is_type_diagnostic_item, span_lint_and_sugg};
+use crate::utils::{is_type_std_item, span_lint_and_sugg, std_items};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Mutability};
+use rustc_hir::{Expr, ExprKind, Mutability, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for `&mut Mutex::lock` calls
+    /// **What it does:** Checks for `&mut Mutex::lock` calls, i.e. calls of `lock` on a mutex
+    /// which is accessed through a `&mut` reference, like `self.mutex` in a `&mut self` method.
     ///
     /// **Why is this bad?** `Mutex::lock` is less efficient than
     /// calling `Mutex::get_mut`. In addition you also have a statically
     /// guarantee that the mutex isn't locked, instead of just a runtime
     /// guarantee.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** `get_mut` returns a `&mut` reference instead of a `MutexGuard`, so
+    /// the suggestion doesn't compile if the guard is used as such, e.g. passed to `drop`.
+    /// Mutexes owned by a local binding aren't linted, even if no other reference to them exists.
     ///
     /// **Example:**
     ///
@@ -45,13 +49,20 @@ declare_clippy_lint! {
 declare_lint_pass!(MutMutexLock => [MUT_MUTEX_LOCK]);
 
 impl<'tcx> LateLintPass<'tcx> for MutMutexLock {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, ex: &'tcx Expr<'tcx>) {
         if_chain! {
             if let ExprKind::MethodCall(path, method_span, args, _) = &ex.kind;
             if path.ident.name == sym!(lock);
-            let ty = cx.typeck_results().expr_ty(&args[0]);
-            if let ty::Ref(_, inner_ty, Mutability::Mut) = ty.kind();
-            if is_type_diagnostic_item(cx, inner_ty, sym!(mutex_type));
+            // the mutex must be auto-referenced, `(&*mutex).lock()` can't be changed to `get_mut`
+            if let Some(Adjust::Borrow(AutoBorrow::Ref(..))) = cx
+                .typeck_results()
+                .expr_adjustments(&args[0])
+                .last()
+                .map(|adjustment| &adjustment.kind);
+            if let ty::Ref(_, inner_ty, _) = cx.typeck_results().expr_ty_adjusted(&args[0]).kind();
+            if is_type_std_item(cx, inner_ty, &std_items::MUTEX);
+            if is_behind_mut_ref(cx, &args[0]);
             then {
                 span_lint_and_sugg(
                     cx,
@@ -66,3 +77,38 @@ impl<'tcx> LateLintPass<'tcx> for MutMutexLock {
         }
     }
 }
+
+/// Checks that the place is reached through a `&mut` reference, without a shared reference or an
+/// overloaded deref on the way, so the borrow checker guarantees exclusive access to it.
+fn is_behind_mut_ref(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> bool {
+    let mut behind_mut_ref = false;
+    loop {
+        // the adjustments include the auto-derefs of the method receiver and of the base of a
+        // field access, e.g. of `self` in `self.mutex`
+        let mut ty = cx.typeck_results().expr_ty(expr);
+        for adjustment in cx.typeck_results().expr_adjustments(expr) {
+            if let Adjust::Deref(overloaded) = adjustment.kind {
+                match ty.kind() {
+                    _ if overloaded.is_some() => return false,
+                    ty::Ref(_, _, Mutability::Mut) => behind_mut_ref = true,
+                    ty::Ref(_, _, Mutability::Not) => return false,
+                    _ => {},
+                }
+            }
+            ty = adjustment.target;
+        }
+
+        match expr.kind {
+            ExprKind::Field(base, _) => expr = base,
+            ExprKind::Unary(UnOp::UnDeref, base) => {
+                match cx.typeck_results().expr_ty_adjusted(base).kind() {
+                    ty::Ref(_, _, Mutability::Mut) => behind_mut_ref = true,
+                    ty::Adt(def, _) if def.is_box() => {},
+                    _ => return false,
+                }
+                expr = base;
+            },
+            _ => return behind_mut_ref,
+        }
+    }
+}
@@ -2,12 +2,25 @@
 //!
 //! This lint is **warn** by default
 
-use crate::utils::{is_type_diagnostic_item, span_lint};
+use crate::utils::{
+    in_macro, is_type_diagnostic_item, is_type_std_item, match_def_path, multispan_sugg_with_applicability, paths,
+    qpath_res, snippet_with_applicability, span_lint, span_lint_and_then, std_items,
+};
+use if_chain::if_chain;
 use rustc_ast::ast;
-use rustc_hir::Expr;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    BinOpKind, BindingAnnotation, Block, Expr, ExprKind, HirId, Mutability, Node, PatKind, QPath, StmtKind, UnOp,
+};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usages of `Mutex<X>` where an atomic will do.
@@ -62,13 +75,117 @@ declare_clippy_lint! {
     "using a mutex for an integer type"
 }
 
-declare_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for local `Mutex`es of a `bool` or an integer, whose value is
+    /// only read and written with `*m.lock().unwrap()`, and suggests replacing them and all their
+    /// uses with an atomic.
+    ///
+    /// **Why is this bad?** The lock is only held for a single read or write, which an atomic
+    /// does without locking.
+    ///
+    /// **Known problems:** The suggested `fetch_add` and `fetch_sub` wrap around on overflow,
+    /// while `+=` and `-=` panic in debug builds. The suggested orderings are `SeqCst`, weaker
+    /// orderings may be enough.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// let count = Mutex::new(0usize);
+    /// *count.lock().unwrap() += 1;
+    /// println!("{}", *count.lock().unwrap());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// let count = AtomicUsize::new(0usize);
+    /// count.fetch_add(1, Ordering::SeqCst);
+    /// println!("{}", count.load(Ordering::SeqCst));
+    /// ```
+    pub MUTEX_INTEGER_USE_ATOMIC,
+    perf,
+    "a local `Mutex` of a `bool` or an integer which is only read and written, instead of an atomic"
+}
+
+#[derive(Default)]
+pub struct Mutex {
+    // Spans of the `Mutex::new` calls linted by `mutex_integer_use_atomic`.
+    linted: FxHashSet<Span>,
+}
+
+impl_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER, MUTEX_INTEGER_USE_ATOMIC]);
 
 impl<'tcx> LateLintPass<'tcx> for Mutex {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Local(local) = stmt.kind;
+                if local.ty.is_none();
+                if let PatKind::Binding(BindingAnnotation::Unannotated, mutex_id, ident, None) = local.pat.kind;
+                if let Some(init) = local.init;
+                if !in_macro(init.span);
+                if let ExprKind::Call(func, [value]) = init.kind;
+                if let ExprKind::Path(ref qpath) = func.kind;
+                if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+                if match_def_path(cx, def_id, &paths::MUTEX_NEW);
+                if let Some(atomic_name) = get_exact_atomic_name(cx.typeck_results().expr_ty(value));
+                then {
+                    let mut visitor = AtomicUseVisitor {
+                        cx,
+                        mutex_id,
+                        name: ident.to_string(),
+                        is_bool: atomic_name == "AtomicBool",
+                        applicability: Applicability::MachineApplicable,
+                        suggestions: Vec::new(),
+                        failed: false,
+                    };
+                    for stmt in &block.stmts[i + 1..] {
+                        visitor.visit_stmt(stmt);
+                    }
+                    if let Some(expr) = block.expr {
+                        visitor.visit_expr(expr);
+                    }
+                    if visitor.failed || visitor.suggestions.is_empty() {
+                        continue;
+                    }
+
+                    let mut applicability = visitor.applicability;
+                    let mut suggestions = vec![(
+                        init.span,
+                        format!(
+                            "std::sync::atomic::{}::new({})",
+                            atomic_name,
+                            snippet_with_applicability(cx, value.span, "..", &mut applicability)
+                        ),
+                    )];
+                    suggestions.extend(visitor.suggestions);
+                    self.linted.insert(init.span);
+                    span_lint_and_then(
+                        cx,
+                        MUTEX_INTEGER_USE_ATOMIC,
+                        init.span,
+                        "this `Mutex` is only used to read and write its value",
+                        |diag| {
+                            multispan_sugg_with_applicability(
+                                diag,
+                                &format!("use an `{}` instead", atomic_name),
+                                applicability,
+                                suggestions,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if self.linted.contains(&expr.span) {
+            return;
+        }
         let ty = cx.typeck_results().expr_ty(expr);
         if let ty::Adt(_, subst) = ty.kind() {
-            if is_type_diagnostic_item(cx, ty, sym!(mutex_type)) {
+            if is_type_std_item(cx, ty, &std_items::MUTEX) {
                 let mutex_param = subst.type_at(0);
                 if let Some(atomic_name) = get_atomic_name(mutex_param) {
                     let msg = format!(
@@ -96,3 +213,143 @@ fn get_atomic_name(ty: Ty<'_>) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// The `Ordering` used by the suggestions, which keeps the guarantees of the `Mutex`.
+const ORDERING: &str = "std::sync::atomic::Ordering::SeqCst";
+
+/// Returns the atomic type of the same size as `ty`.
+fn get_exact_atomic_name(ty: Ty<'_>) -> Option<&'static str> {
+    match ty.kind() {
+        ty::Bool => Some("AtomicBool"),
+        ty::Uint(ast::UintTy::U8) => Some("AtomicU8"),
+        ty::Uint(ast::UintTy::U16) => Some("AtomicU16"),
+        ty::Uint(ast::UintTy::U32) => Some("AtomicU32"),
+        ty::Uint(ast::UintTy::U64) => Some("AtomicU64"),
+        ty::Uint(ast::UintTy::Usize) => Some("AtomicUsize"),
+        ty::Int(ast::IntTy::I8) => Some("AtomicI8"),
+        ty::Int(ast::IntTy::I16) => Some("AtomicI16"),
+        ty::Int(ast::IntTy::I32) => Some("AtomicI32"),
+        ty::Int(ast::IntTy::I64) => Some("AtomicI64"),
+        ty::Int(ast::IntTy::Isize) => Some("AtomicIsize"),
+        _ => None,
+    }
+}
+
+/// Collects the replacements of the uses of the `Mutex` by atomic operations, and fails on the
+/// other uses.
+struct AtomicUseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    mutex_id: HirId,
+    /// The name of the `Mutex` binding
+    name: String,
+    is_bool: bool,
+    applicability: Applicability,
+    suggestions: Vec<(Span, String)>,
+    failed: bool,
+}
+
+impl<'a, 'tcx> AtomicUseVisitor<'a, 'tcx> {
+    /// Checks if `expr` is `*m.lock().unwrap()`, with `m` the `Mutex`.
+    fn is_locked_value(&self, expr: &Expr<'_>) -> bool {
+        if_chain! {
+            if let ExprKind::Unary(UnOp::UnDeref, unwrap) = expr.kind;
+            if let ExprKind::MethodCall(unwrap_name, _, [lock], _) = unwrap.kind;
+            if unwrap_name.ident.name == sym::unwrap;
+            if is_type_diagnostic_item(self.cx, self.cx.typeck_results().expr_ty(lock), sym::result_type);
+            if let ExprKind::MethodCall(_, _, [mutex], _) = lock.kind;
+            if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(lock.hir_id);
+            if match_def_path(self.cx, def_id, &paths::MUTEX_LOCK);
+            if let ExprKind::Path(QPath::Resolved(None, path)) = mutex.kind;
+            then {
+                path.res == Res::Local(self.mutex_id)
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Returns the atomic method doing the same as the compound assignment `op`.
+    fn fetch_method(&self, op: BinOpKind) -> Option<&'static str> {
+        match op {
+            BinOpKind::Add if !self.is_bool => Some("fetch_add"),
+            BinOpKind::Sub if !self.is_bool => Some("fetch_sub"),
+            BinOpKind::BitAnd => Some("fetch_and"),
+            BinOpKind::BitOr => Some("fetch_or"),
+            BinOpKind::BitXor => Some("fetch_xor"),
+            _ => None,
+        }
+    }
+
+    /// Visits the assigned value, which must not use the `Mutex`, as it's moved into the
+    /// replacement of the assignment.
+    fn visit_assigned_value(&mut self, value: &'tcx Expr<'tcx>) -> String {
+        let suggestions = self.suggestions.len();
+        self.visit_expr(value);
+        if self.suggestions.len() > suggestions {
+            self.failed = true;
+        }
+        snippet_with_applicability(self.cx, value.span, "..", &mut self.applicability).into_owned()
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AtomicUseVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.failed {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Assign(place, value, _) if self.is_locked_value(place) => {
+                let value = self.visit_assigned_value(value);
+                self.suggestions
+                    .push((expr.span, format!("{}.store({}, {})", self.name, value, ORDERING)));
+            },
+            ExprKind::AssignOp(op, place, value) if self.is_locked_value(place) => {
+                // the `fetch_*` methods return the previous value instead of `()`
+                let in_stmt = matches!(
+                    self.cx.tcx.hir().find(self.cx.tcx.hir().get_parent_node(expr.hir_id)),
+                    Some(Node::Stmt(_))
+                );
+                match self.fetch_method(op.node) {
+                    Some(method) if in_stmt => {
+                        // `+=` and `-=` panic on overflow in debug builds, the atomic methods wrap
+                        if matches!(op.node, BinOpKind::Add | BinOpKind::Sub) {
+                            self.applicability = Applicability::MaybeIncorrect;
+                        }
+                        let value = self.visit_assigned_value(value);
+                        self.suggestions
+                            .push((expr.span, format!("{}.{}({}, {})", self.name, method, value, ORDERING)));
+                    },
+                    _ => self.failed = true,
+                }
+            },
+            // the value is borrowed mutably, or bound by reference in a pattern
+            ExprKind::AddrOf(_, Mutability::Mut, inner) if self.is_locked_value(inner) => self.failed = true,
+            ExprKind::Match(scrutinee, arms, _)
+                if self.is_locked_value(scrutinee)
+                    && arms.iter().any(|arm| arm.pat.contains_explicit_ref_binding().is_some()) =>
+            {
+                self.failed = true;
+            },
+            // a read of the value, which isn't auto-borrowed as the receiver of a method
+            ExprKind::Unary(UnOp::UnDeref, _) if self.is_locked_value(expr) => {
+                if self.cx.typeck_results().expr_adjustments(expr).is_empty() {
+                    self.suggestions
+                        .push((expr.span, format!("{}.load({})", self.name, ORDERING)));
+                } else {
+                    self.failed = true;
+                }
+            },
+            // any other use of the `Mutex`
+            ExprKind::Path(QPath::Resolved(None, path)) if path.res == Res::Local(self.mutex_id) => {
+                self.failed = true;
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -69,6 +69,7 @@ declare_clippy_lint! {
 declare_lint_pass!(NeedlessBool => [NEEDLESS_BOOL]);
 
 impl<'tcx> LateLintPass<'tcx> for NeedlessBool {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         use self::Expression::{Bool, RetBool};
         if let Some((ref pred, ref then_block, Some(ref else_expr))) = higher::if_block(&e) {
@@ -2,13 +2,23 @@
 //!
 //! This lint is **warn** by default
 
-use crate::utils::{snippet_opt, span_lint_and_then};
+use crate::utils::mir::{used_after_block, PossibleBorrowerMap};
+use crate::utils::{
+    fn_has_unsatisfiable_preds, implements_trait, is_copy, last_path_segment, snippet_opt, snippet_with_applicability,
+    span_lint_and_sugg, span_lint_and_then,
+};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::{BindingAnnotation, BorrowKind, Expr, ExprKind, HirId, Item, Mutability, Pat, PatKind};
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{
+    BindingAnnotation, BodyOwnerKind, BorrowKind, Expr, ExprKind, GenericArg, HirId, Item, Mutability, Pat, PatKind,
+    PathSegment, QPath,
+};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
+use rustc_middle::mir;
 use rustc_middle::ty::adjustment::{Adjust, Adjustment};
+use rustc_middle::ty::{self, PredicateAtom, Ty};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::sym;
 
@@ -34,18 +44,79 @@ declare_clippy_lint! {
     "taking a reference that is going to be automatically dereferenced"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for borrowed arguments of generic functions, where the
+    /// borrowed value itself satisfies the bounds of the generic parameter.
+    ///
+    /// **Why is this bad?** The borrow is not needed, the value can be passed as it is.
+    ///
+    /// **Known problems:** To make sure that removing the borrow still compiles and behaves the
+    /// same, the lint only checks bounds whose traits forward the impl for `&T` to the impl for
+    /// `T`, like `AsRef` or `Display`, and only arguments which are `Copy`, temporaries, or local
+    /// variables which are neither used nor borrowed after the call.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::path::{Path, PathBuf};
+    /// fn join(base: &Path, file: impl AsRef<Path>) -> PathBuf {
+    ///     base.join(file)
+    /// }
+    ///
+    /// let name = String::from("Cargo.toml");
+    /// join(Path::new("/"), &name);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::{Path, PathBuf};
+    /// # fn join(base: &Path, file: impl AsRef<Path>) -> PathBuf {
+    /// #     base.join(file)
+    /// # }
+    /// let name = String::from("Cargo.toml");
+    /// join(Path::new("/"), name);
+    /// ```
+    pub NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
+    nursery,
+    "taking a reference that is not needed to satisfy the bounds of a generic argument"
+}
+
 #[derive(Default)]
 pub struct NeedlessBorrow {
     derived_item: Option<HirId>,
 }
 
-impl_lint_pass!(NeedlessBorrow => [NEEDLESS_BORROW]);
+impl_lint_pass!(NeedlessBorrow => [NEEDLESS_BORROW, NEEDLESS_BORROWS_FOR_GENERIC_ARGS]);
 
 impl<'tcx> LateLintPass<'tcx> for NeedlessBorrow {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if e.span.from_expansion() || self.derived_item.is_some() {
             return;
         }
+        match e.kind {
+            ExprKind::Call(callee, args) => {
+                if_chain! {
+                    if let ty::FnDef(fn_id, _) = *cx.typeck_results().expr_ty(callee).kind();
+                    if let ExprKind::Path(ref qpath) = callee.kind;
+                    if !matches!(qpath, QPath::LangItem(..));
+                    if !has_explicit_type_args(last_path_segment(qpath));
+                    then {
+                        for (index, arg) in args.iter().enumerate() {
+                            check_generic_arg(cx, e, fn_id, index, arg);
+                        }
+                    }
+                }
+            },
+            ExprKind::MethodCall(segment, _, args, _) => {
+                if let Some(fn_id) = cx.typeck_results().type_dependent_def_id(e.hir_id) {
+                    if !has_explicit_type_args(segment) {
+                        // the receiver is auto-referenced anyway
+                        for (index, arg) in args.iter().enumerate().skip(1) {
+                            check_generic_arg(cx, e, fn_id, index, arg);
+                        }
+                    }
+                }
+            },
+            _ => {},
+        }
         if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, ref inner) = e.kind {
             if let ty::Ref(..) = cx.typeck_results().expr_ty(inner).kind() {
                 for adj3 in cx.typeck_results().expr_adjustments(e).windows(3) {
@@ -127,3 +198,201 @@ impl<'tcx> LateLintPass<'tcx> for NeedlessBorrow {
         }
     }
 }
+
+/// Checks for `::<..>` type arguments, which would have to be changed together with the argument.
+fn has_explicit_type_args(segment: &PathSegment<'_>) -> bool {
+    segment.args.map_or(false, |args| {
+        args.args.iter().any(|arg| matches!(arg, GenericArg::Type(_)))
+    })
+}
+
+/// Checks if the argument `index` of the call is `&x`, which can be replaced by `x`.
+fn check_generic_arg<'tcx>(cx: &LateContext<'tcx>, call: &Expr<'_>, fn_id: DefId, index: usize, arg: &Expr<'_>) {
+    if_chain! {
+        if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, ref inner) = arg.kind;
+        if !arg.span.from_expansion();
+        // auto-derefs are checked by `NEEDLESS_BORROW`
+        if cx.typeck_results().expr_adjustments(arg).is_empty();
+        let fn_sig = cx.tcx.fn_sig(fn_id).skip_binder();
+        if let Some(&param_ty) = fn_sig.inputs().get(index);
+        if let ty::Param(param) = *param_ty.kind();
+        // parameters of the impl or the trait are determined by the receiver
+        if cx.tcx.parent(cx.tcx.generics_of(fn_id).type_param(&param, cx.tcx).def_id) == Some(fn_id);
+        // the type of the other arguments and of the result must not change
+        if fn_sig
+            .inputs_and_output
+            .iter()
+            .filter(|ty| ty.walk().any(|arg| arg == param_ty.into()))
+            .count()
+            == 1;
+        let referent_ty = cx.typeck_results().expr_ty(inner);
+        if bounds_hold_for_referent(cx, fn_id, param_ty, referent_ty);
+        if referent_can_be_moved(cx, call, arg, inner, referent_ty);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_BORROWS_FOR_GENERIC_ARGS,
+                arg.span,
+                "the borrowed expression implements the required traits",
+                "change this to",
+                snippet_with_applicability(cx, inner.span, "..", &mut applicability).to_string(),
+                applicability,
+            );
+        }
+    }
+}
+
+fn mentions_param<'tcx>(substs: &[ty::subst::GenericArg<'tcx>], param_ty: Ty<'tcx>) -> bool {
+    substs.iter().any(|arg| arg.walk().any(|arg| arg == param_ty.into()))
+}
+
+/// Checks that the referent satisfies all the bounds of `param_ty`, and that removing the borrow
+/// doesn't change which impls are used. There has to be at least one bound besides `Sized`,
+/// otherwise the argument is probably borrowed on purpose.
+fn bounds_hold_for_referent<'tcx>(
+    cx: &LateContext<'tcx>,
+    fn_id: DefId,
+    param_ty: Ty<'tcx>,
+    referent_ty: Ty<'tcx>,
+) -> bool {
+    let sized_trait = cx.tcx.lang_items().sized_trait();
+    let mut has_bounds = false;
+    for (predicate, _) in cx.tcx.predicates_of(fn_id).predicates {
+        match predicate.skip_binders() {
+            PredicateAtom::Trait(trait_pred, _) if trait_pred.self_ty() == param_ty => {
+                let trait_id = trait_pred.def_id();
+                let trait_params = &trait_pred.trait_ref.substs[1..];
+                if mentions_param(trait_params, param_ty) || !implements_trait(cx, referent_ty, trait_id, trait_params)
+                {
+                    return false;
+                }
+                if Some(trait_id) != sized_trait {
+                    if !has_forwarding_ref_impl(cx, trait_id) {
+                        return false;
+                    }
+                    has_bounds = true;
+                }
+            },
+            PredicateAtom::Trait(trait_pred, _) => {
+                if mentions_param(trait_pred.trait_ref.substs, param_ty) {
+                    return false;
+                }
+            },
+            PredicateAtom::Projection(proj_pred) => {
+                if mentions_param(proj_pred.projection_ty.substs, param_ty)
+                    || proj_pred.ty.walk().any(|arg| arg == param_ty.into())
+                {
+                    return false;
+                }
+            },
+            PredicateAtom::TypeOutlives(ty::OutlivesPredicate(ty, _)) => {
+                if ty.walk().any(|arg| arg == param_ty.into()) {
+                    return false;
+                }
+            },
+            _ => {},
+        }
+    }
+    has_bounds
+}
+
+/// Checks for a blanket `impl<T: Trait> Trait for &T`, which is used for all references and by
+/// convention forwards to the impl of the referent. `Clone for &T` for example doesn't.
+fn has_forwarding_ref_impl(cx: &LateContext<'_>, trait_id: DefId) -> bool {
+    cx.tcx.all_impls(trait_id).any(|impl_id| {
+        if let ty::Ref(_, referent, Mutability::Not) = *cx.tcx.type_of(impl_id).kind() {
+            matches!(referent.kind(), ty::Param(_))
+                && cx.tcx.predicates_of(impl_id).predicates.iter().any(|(predicate, _)| {
+                    matches!(
+                        predicate.skip_binders(),
+                        PredicateAtom::Trait(trait_pred, _)
+                            if trait_pred.def_id() == trait_id && trait_pred.self_ty() == referent
+                    )
+                })
+        } else {
+            false
+        }
+    })
+}
+
+/// Checks that passing the referent by value doesn't move a value which is used later.
+fn referent_can_be_moved<'tcx>(
+    cx: &LateContext<'tcx>,
+    call: &Expr<'_>,
+    arg: &Expr<'_>,
+    inner: &Expr<'_>,
+    referent_ty: Ty<'tcx>,
+) -> bool {
+    if is_copy(cx, referent_ty) {
+        return true;
+    }
+    match inner.kind {
+        // temporaries are not used anywhere else
+        ExprKind::Call(..)
+        | ExprKind::MethodCall(..)
+        | ExprKind::Struct(..)
+        | ExprKind::Tup(..)
+        | ExprKind::Array(..) => true,
+        ExprKind::Path(QPath::Resolved(None, path)) if matches!(path.res, Res::Local(_)) => {
+            local_can_be_moved(cx, call, arg)
+        },
+        _ => false,
+    }
+}
+
+/// Checks in the MIR that the local borrowed by `arg` is neither used after the call nor
+/// borrowed anywhere else at the call.
+fn local_can_be_moved(cx: &LateContext<'_>, call: &Expr<'_>, arg: &Expr<'_>) -> bool {
+    let owner = cx.tcx.hir().enclosing_body_owner(call.hir_id);
+    if !matches!(
+        cx.tcx.hir().body_owner_kind(owner),
+        BodyOwnerKind::Fn | BodyOwnerKind::Closure
+    ) {
+        return false;
+    }
+    let def_id = cx.tcx.hir().local_def_id(owner).to_def_id();
+    // Building MIR for `fn`s with unsatisfiable preds results in ICE.
+    if fn_has_unsatisfiable_preds(cx, def_id) {
+        return false;
+    }
+    let mir = cx.tcx.optimized_mir(def_id);
+
+    for (bb, bbdata) in mir.basic_blocks().iter_enumerated() {
+        let terminator = bbdata.terminator();
+        if terminator.source_info.span != call.span {
+            continue;
+        }
+        let args = match &terminator.kind {
+            mir::TerminatorKind::Call { args, .. } => args,
+            _ => continue,
+        };
+
+        // `arg_local = &local; call(.., move arg_local, ..)`
+        let borrow = bbdata.statements.iter().find_map(|stmt| match &stmt.kind {
+            mir::StatementKind::Assign(box (place, mir::Rvalue::Ref(_, _, borrowed)))
+                if stmt.source_info.span == arg.span =>
+            {
+                Some((place.local, *borrowed))
+            },
+            _ => None,
+        });
+        if_chain! {
+            if let Some((arg_local, borrowed)) = borrow;
+            if borrowed.projection.is_empty();
+            if args
+                .iter()
+                .any(|op| matches!(op, mir::Operand::Move(place) if place.local == arg_local));
+            then {
+                let loc = mir::Location {
+                    block: bb,
+                    statement_index: bbdata.statements.len(),
+                };
+                return PossibleBorrowerMap::new(cx, mir).only_borrowers(&[arg_local], borrowed.local, loc)
+                    && !used_after_block(mir, borrowed.local, bb);
+            }
+        }
+        return false;
+    }
+    false
+}
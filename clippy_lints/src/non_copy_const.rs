@@ -4,21 +4,22 @@
 
 use std::ptr;
 
-use rustc_hir::def::{DefKind, Res};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{CtorOf, DefKind, Res};
 use rustc_hir::def_id::DefId;
 use rustc_hir::{
-    BodyId, Expr, ExprKind, HirId, ImplItem, ImplItemKind, Item, ItemKind, Node, TraitItem, TraitItemKind, UnOp,
+    BodyId, Crate, Expr, ExprKind, HirId, ImplItem, ImplItemKind, Item, ItemKind, Node, TraitItem, TraitItemKind, UnOp,
 };
 use rustc_infer::traits::specialization_graph;
 use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::mir::interpret::{ConstValue, ErrorHandled};
 use rustc_middle::ty::adjustment::Adjust;
-use rustc_middle::ty::{self, AssocKind, Const, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::{self, AssocKind, Const, Ty, TypeFoldable};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{InnerSpan, Span, DUMMY_SP};
 use rustc_typeck::hir_ty_to_ty;
 
-use crate::utils::{in_constant, qpath_res, span_lint_and_then};
+use crate::utils::{def_ids_of_paths, in_constant, is_interior_mut_ty, qpath_res, span_lint_and_then};
 use if_chain::if_chain;
 
 // FIXME: this is a correctness problem but there's no suitable
@@ -112,7 +113,7 @@ declare_clippy_lint! {
     "referencing `const` with interior mutability"
 }
 
-fn is_unfrozen<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+fn is_unfrozen<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, ignored: &FxHashSet<DefId>) -> bool {
     // Ignore types whose layout is unknown since `is_freeze` reports every generic types as `!Freeze`,
     // making it indistinguishable from `UnsafeCell`. i.e. it isn't a tool to prove a type is
     // 'unfrozen'. However, this code causes a false negative in which
@@ -121,22 +122,27 @@ fn is_unfrozen<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     // since it works when a pointer indirection involves (`Cell<*const T>`).
     // Making up a `ParamEnv` where every generic params and assoc types are `Freeze`is another option;
     // but I'm not sure whether it's a decent way, if possible.
-    cx.tcx.layout_of(cx.param_env.and(ty)).is_ok() && !ty.is_freeze(cx.tcx.at(DUMMY_SP), cx.param_env)
+    // Types from the `ignore-interior-mutability` configuration are treated as `Freeze`.
+    cx.tcx.layout_of(cx.param_env.and(ty)).is_ok()
+        && !ty.is_freeze(cx.tcx.at(DUMMY_SP), cx.param_env)
+        && is_interior_mut_ty(cx, ty, DUMMY_SP, ignored)
 }
 
 fn is_value_unfrozen_raw<'tcx>(
     cx: &LateContext<'tcx>,
     result: Result<ConstValue<'tcx>, ErrorHandled>,
     ty: Ty<'tcx>,
+    ignored: &FxHashSet<DefId>,
 ) -> bool {
-    fn inner<'tcx>(cx: &LateContext<'tcx>, val: &'tcx Const<'tcx>) -> bool {
+    fn inner<'tcx>(cx: &LateContext<'tcx>, val: &'tcx Const<'tcx>, ignored: &FxHashSet<DefId>) -> bool {
         match val.ty.kind() {
+            ty::Adt(ty_def, ..) if ignored.contains(&ty_def.did) => false,
             // the fact that we have to dig into every structs to search enums
             // leads us to the point checking `UnsafeCell` directly is the only option.
             ty::Adt(ty_def, ..) if Some(ty_def.did) == cx.tcx.lang_items().unsafe_cell_type() => true,
             ty::Array(..) | ty::Adt(..) | ty::Tuple(..) => {
                 let val = cx.tcx.destructure_const(cx.param_env.and(val));
-                val.fields.iter().any(|field| inner(cx, field))
+                val.fields.iter().any(|field| inner(cx, field, ignored))
             },
             _ => false,
         }
@@ -145,44 +151,86 @@ fn is_value_unfrozen_raw<'tcx>(
     result.map_or_else(
         |err| {
             // Consider `TooGeneric` cases as being unfrozen.
-            // This causes a false positive where an assoc const whose type is unfrozen
-            // have a value that is a frozen variant with a generic param (an example is
-            // `declare_interior_mutable_const::enums::BothOfCellAndGeneric::GENERIC_VARIANT`).
+            // Declarations don't get here since they look at the variant their value constructs
+            // instead (see `is_generic_value_unfrozen`). For borrows, this causes a false positive
+            // where an assoc const whose type is unfrozen have a value that is a frozen variant
+            // with a generic param (an example is borrowing
+            // `borrow_interior_mutable_const::enums::BothOfCellAndGeneric::GENERIC_VARIANT`).
             // However, it prevents a number of false negatives that is, I think, important:
-            // 1. assoc consts in trait defs referring to consts of themselves
-            //    (an example is `declare_interior_mutable_const::traits::ConcreteTypes::ANOTHER_ATOMIC`).
-            // 2. a path expr referring to assoc consts whose type is doesn't have
+            // 1. a path expr referring to assoc consts whose type is doesn't have
             //    any frozen variants in trait defs (i.e. without substitute for `Self`).
             //    (e.g. borrowing `borrow_interior_mutable_const::trait::ConcreteTypes::ATOMIC`)
-            // 3. similar to the false positive above;
+            // 2. similar to the false positive above;
             //    but the value is an unfrozen variant, or the type has no enums. (An example is
-            //    `declare_interior_mutable_const::enums::BothOfCellAndGeneric::UNFROZEN_VARIANT`
-            //    and `declare_interior_mutable_const::enums::BothOfCellAndGeneric::NO_ENUM`).
+            //    borrowing `borrow_interior_mutable_const::enums::BothOfCellAndGeneric::UNFROZEN_VARIANT`).
             // One might be able to prevent these FNs correctly, and replace this with `false`;
-            // e.g. implementing `has_frozen_variant` described above, and not running this function
-            // when the type doesn't have any frozen variants would be the 'correct' way for the 2nd
+            // e.g. looking at the value of local consts like declarations do would be the 'correct'
+            // way for the 2nd case, and checking whether the type has any frozen variants for the 1st
             // case (that actually removes another suboptimal behavior (I won't say 'false positive') where,
-            // similar to 2., but with the a frozen variant) (e.g. borrowing
+            // similar to 1., but with the a frozen variant) (e.g. borrowing
             // `borrow_interior_mutable_const::enums::AssocConsts::TO_BE_FROZEN_VARIANT`).
             // I chose this way because unfrozen enums as assoc consts are rare (or, hopefully, none).
             err == ErrorHandled::TooGeneric
         },
-        |val| inner(cx, Const::from_value(cx.tcx, val, ty)),
+        |val| inner(cx, Const::from_value(cx.tcx, val, ty), ignored),
     )
 }
 
-fn is_value_unfrozen_poly<'tcx>(cx: &LateContext<'tcx>, body_id: BodyId, ty: Ty<'tcx>) -> bool {
+fn is_value_unfrozen_poly<'tcx>(
+    cx: &LateContext<'tcx>,
+    body_id: BodyId,
+    ty: Ty<'tcx>,
+    ignored: &FxHashSet<DefId>,
+) -> bool {
     let result = cx.tcx.const_eval_poly(body_id.hir_id.owner.to_def_id());
-    is_value_unfrozen_raw(cx, result, ty)
+    if let Err(ErrorHandled::TooGeneric) = result {
+        return is_generic_value_unfrozen(cx, body_id, ignored);
+    }
+    is_value_unfrozen_raw(cx, result, ty, ignored)
 }
 
-fn is_value_unfrozen_expr<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId, def_id: DefId, ty: Ty<'tcx>) -> bool {
+/// Checks if the value of a const which is too generic to be evaluated may be unfrozen. A value
+/// constructing an enum variant without interior mutable fields, like
+/// `BothOfCellAndGeneric::Generic(std::ptr::null())`, is frozen even though the enum isn't.
+fn is_generic_value_unfrozen(cx: &LateContext<'_>, body_id: BodyId, ignored: &FxHashSet<DefId>) -> bool {
+    let value = &cx.tcx.hir().body(body_id).value;
+    let typeck_results = cx.tcx.typeck_body(body_id);
+    let res = match &value.kind {
+        ExprKind::Call(func, _) => match &func.kind {
+            ExprKind::Path(qpath) => typeck_results.qpath_res(qpath, func.hir_id),
+            _ => return true,
+        },
+        ExprKind::Path(qpath) | ExprKind::Struct(qpath, ..) => typeck_results.qpath_res(qpath, value.hir_id),
+        _ => return true,
+    };
+    if let ty::Adt(adt_def, substs) = typeck_results.expr_ty(value).kind() {
+        let variant = match res {
+            Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_id) => adt_def.variant_with_ctor_id(ctor_id),
+            Res::Def(DefKind::Variant, variant_id) => adt_def.variant_with_id(variant_id),
+            _ => return true,
+        };
+        variant
+            .fields
+            .iter()
+            .any(|field| is_interior_mut_ty(cx, field.ty(cx.tcx, substs), value.span, ignored))
+    } else {
+        true
+    }
+}
+
+fn is_value_unfrozen_expr<'tcx>(
+    cx: &LateContext<'tcx>,
+    hir_id: HirId,
+    def_id: DefId,
+    ty: Ty<'tcx>,
+    ignored: &FxHashSet<DefId>,
+) -> bool {
     let substs = cx.typeck_results().node_substs(hir_id);
 
     let result = cx
         .tcx
         .const_eval_resolve(cx.param_env, ty::WithOptConstParam::unknown(def_id), substs, None, None);
-    is_value_unfrozen_raw(cx, result, ty)
+    is_value_unfrozen_raw(cx, result, ty, ignored)
 }
 
 #[derive(Copy, Clone)]
@@ -229,14 +277,34 @@ fn lint(cx: &LateContext<'_>, source: Source) {
     });
 }
 
-declare_lint_pass!(NonCopyConst => [DECLARE_INTERIOR_MUTABLE_CONST, BORROW_INTERIOR_MUTABLE_CONST]);
+pub struct NonCopyConst {
+    ignore_interior_mutability: Vec<String>,
+    ignore_mut_def_ids: FxHashSet<DefId>,
+}
+
+impl NonCopyConst {
+    pub fn new(ignore_interior_mutability: Vec<String>) -> Self {
+        Self {
+            ignore_interior_mutability,
+            ignore_mut_def_ids: FxHashSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(NonCopyConst => [DECLARE_INTERIOR_MUTABLE_CONST, BORROW_INTERIOR_MUTABLE_CONST]);
 
 impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        self.ignore_mut_def_ids = def_ids_of_paths(cx, &self.ignore_interior_mutability);
+    }
+
     fn check_item(&mut self, cx: &LateContext<'tcx>, it: &'tcx Item<'_>) {
         if let ItemKind::Const(hir_ty, body_id) = it.kind {
             let ty = hir_ty_to_ty(cx.tcx, hir_ty);
 
-            if is_unfrozen(cx, ty) && is_value_unfrozen_poly(cx, body_id, ty) {
+            if is_unfrozen(cx, ty, &self.ignore_mut_def_ids)
+                && is_value_unfrozen_poly(cx, body_id, ty, &self.ignore_mut_def_ids)
+            {
                 lint(cx, Source::Item { item: it.span });
             }
         }
@@ -249,7 +317,7 @@ impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
             // Normalize assoc types because ones originated from generic params
             // bounded other traits could have their bound.
             let normalized = cx.tcx.normalize_erasing_regions(cx.param_env, ty);
-            if is_unfrozen(cx, normalized)
+            if is_unfrozen(cx, normalized, &self.ignore_mut_def_ids)
                 // When there's no default value, lint it only according to its type;
                 // in other words, lint consts whose value *could* be unfrozen, not definitely is.
                 // This feels inconsistent with how the lint treats generic types,
@@ -262,7 +330,9 @@ impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
                 // i.e. having an enum doesn't necessary mean a type has a frozen variant.
                 // And, implementing it isn't a trivial task; it'll probably end up
                 // re-implementing the trait predicate evaluation specific to `Freeze`.
-                && body_id_opt.map_or(true, |body_id| is_value_unfrozen_poly(cx, body_id, normalized))
+                && body_id_opt.map_or(true, |body_id| {
+                    is_value_unfrozen_poly(cx, body_id, normalized, &self.ignore_mut_def_ids)
+                })
             {
                 lint(cx, Source::Assoc { item: trait_item.span });
             }
@@ -282,7 +352,10 @@ impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
                     if_chain! {
                         // Lint a trait impl item only when the definition is a generic type,
                         // assuming a assoc const is not meant to be a interior mutable type.
+                        // The consts of traits from other crates, like `lock_api::RawMutex::INIT`,
+                        // can't be changed by the implementor, so they aren't linted at all.
                         if let Some(of_trait_def_id) = of_trait_ref.trait_def_id();
+                        if of_trait_def_id.is_local();
                         if let Some(of_assoc_item) = specialization_graph::Node::Trait(of_trait_def_id)
                             .item(cx.tcx, impl_item.ident, AssocKind::Const, of_trait_def_id);
                         if cx
@@ -304,8 +377,8 @@ impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
                         then {
                             let ty = hir_ty_to_ty(cx.tcx, hir_ty);
                             let normalized = cx.tcx.normalize_erasing_regions(cx.param_env, ty);
-                            if is_unfrozen(cx, normalized)
-                                && is_value_unfrozen_poly(cx, *body_id, normalized)
+                            if is_unfrozen(cx, normalized, &self.ignore_mut_def_ids)
+                                && is_value_unfrozen_poly(cx, *body_id, normalized, &self.ignore_mut_def_ids)
                             {
                                 lint(
                                    cx,
@@ -322,7 +395,9 @@ impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
                     // Normalize assoc types originated from generic params.
                     let normalized = cx.tcx.normalize_erasing_regions(cx.param_env, ty);
 
-                    if is_unfrozen(cx, ty) && is_value_unfrozen_poly(cx, *body_id, normalized) {
+                    if is_unfrozen(cx, normalized, &self.ignore_mut_def_ids)
+                        && is_value_unfrozen_poly(cx, *body_id, normalized, &self.ignore_mut_def_ids)
+                    {
                         lint(cx, Source::Assoc { item: impl_item.span });
                     }
                 },
@@ -417,7 +492,9 @@ impl<'tcx> LateLintPass<'tcx> for NonCopyConst {
                 cx.typeck_results().expr_ty(dereferenced_expr)
             };
 
-            if is_unfrozen(cx, ty) && is_value_unfrozen_expr(cx, expr.hir_id, item_def_id, ty) {
+            if is_unfrozen(cx, ty, &self.ignore_mut_def_ids)
+                && is_value_unfrozen_expr(cx, expr.hir_id, item_def_id, ty, &self.ignore_mut_def_ids)
+            {
                 lint(cx, Source::Expr { expr: expr.span });
             }
         }
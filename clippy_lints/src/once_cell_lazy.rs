@@ -0,0 +1,146 @@
+use crate::utils::{
+    in_macro, is_expn_of, match_def_path, meets_msrv, multispan_sugg_with_applicability, qpath_res, span_lint_and_help,
+    span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BodyId, ExprKind, Item, ItemKind, QPath, Ty, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+const LAZY_LOCK_MSRV: RustcVersion = RustcVersion::new(1, 80, 0);
+const ONCE_LOCK_MSRV: RustcVersion = RustcVersion::new(1, 70, 0);
+
+// `once_cell` isn't a dependency of Clippy, so these paths can't be in the `paths` module, whose
+// paths are checked by resolving them
+const ONCE_CELL_SYNC_LAZY: [&str; 3] = ["once_cell", "sync", "Lazy"];
+const ONCE_CELL_SYNC_LAZY_NEW: [&str; 4] = ["once_cell", "sync", "Lazy", "new"];
+const ONCE_CELL_SYNC_ONCE_CELL: [&str; 3] = ["once_cell", "sync", "OnceCell"];
+const ONCE_CELL_SYNC_ONCE_CELL_NEW: [&str; 4] = ["once_cell", "sync", "OnceCell", "new"];
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for statics using `once_cell::sync::Lazy` or
+    /// `once_cell::sync::OnceCell`, and for statics declared with `lazy_static!`, which can use
+    /// `std::sync::LazyLock` or `std::sync::OnceLock` instead.
+    ///
+    /// **Why is this bad?** The standard library types do the same, without depending on
+    /// another crate.
+    ///
+    /// **Known problems:** `std::sync::OnceLock` doesn't have all the methods of
+    /// `once_cell::sync::OnceCell`, e.g. `get_or_try_init`. The imports of the `once_cell` types
+    /// aren't removed. The statics declared with `lazy_static!` aren't rewritten. Without an `msrv`
+    /// configuration, the standard library types are suggested even if the toolchain is too old to
+    /// have them.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// use once_cell::sync::Lazy;
+    ///
+    /// static CONFIG: Lazy<Config> = Lazy::new(Config::load);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::sync::LazyLock;
+    ///
+    /// static CONFIG: LazyLock<Config> = LazyLock::new(Config::load);
+    /// ```
+    pub ONCE_CELL_LAZY,
+    pedantic,
+    "statics using `once_cell` or `lazy_static!` instead of `std::sync::{LazyLock, OnceLock}`"
+}
+
+pub struct OnceCellLazy {
+    msrv: Option<RustcVersion>,
+}
+
+impl OnceCellLazy {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+
+    fn check_static(&self, cx: &LateContext<'_>, ty: &Ty<'_>, body_id: BodyId) {
+        if_chain! {
+            if let TyKind::Path(QPath::Resolved(None, path)) = ty.kind;
+            if let Res::Def(DefKind::Struct, def_id) = path.res;
+            if let Some(last_segment) = path.segments.last();
+            then {
+                let (name, new_path, std_name, msrv) = if match_def_path(cx, def_id, &ONCE_CELL_SYNC_LAZY) {
+                    ("Lazy", &ONCE_CELL_SYNC_LAZY_NEW, "LazyLock", &LAZY_LOCK_MSRV)
+                } else if match_def_path(cx, def_id, &ONCE_CELL_SYNC_ONCE_CELL) {
+                    ("OnceCell", &ONCE_CELL_SYNC_ONCE_CELL_NEW, "OnceLock", &ONCE_LOCK_MSRV)
+                } else {
+                    return;
+                };
+                if !meets_msrv(self.msrv.as_ref(), msrv) {
+                    return;
+                }
+
+                let msg = format!("`once_cell::sync::{}` can be replaced with `std::sync::{}`", name, std_name);
+                let help = format!("use `std::sync::{}` instead", std_name);
+
+                // the initializer is rewritten too if it's `Lazy::new(..)` or `OnceCell::new()`
+                let init = &cx.tcx.hir().body(body_id).value;
+                if_chain! {
+                    if let ExprKind::Call(func, _) = init.kind;
+                    if !in_macro(init.span);
+                    if let ExprKind::Path(ref qpath) = func.kind;
+                    if let Res::Def(DefKind::AssocFn, fn_def_id) = qpath_res(cx, qpath, func.hir_id);
+                    if match_def_path(cx, fn_def_id, new_path);
+                    then {
+                        // the path of the type, without the generic arguments
+                        let ty_path_span = path.span.with_hi(last_segment.ident.span.hi());
+                        span_lint_and_then(cx, ONCE_CELL_LAZY, ty.span, &msg, |diag| {
+                            multispan_sugg_with_applicability(
+                                diag,
+                                &help,
+                                Applicability::MaybeIncorrect,
+                                vec![
+                                    (ty_path_span, format!("std::sync::{}", std_name)),
+                                    (func.span, format!("std::sync::{}::new", std_name)),
+                                ],
+                            );
+                        });
+                        return;
+                    }
+                }
+
+                span_lint_and_help(cx, ONCE_CELL_LAZY, ty.span, &msg, None, &help);
+            }
+        }
+    }
+}
+
+impl_lint_pass!(OnceCellLazy => [ONCE_CELL_LAZY]);
+
+impl<'tcx> LateLintPass<'tcx> for OnceCellLazy {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        match item.kind {
+            ItemKind::Static(ty, _, body_id) if !in_macro(item.span) => self.check_static(cx, ty, body_id),
+            // `lazy_static!` declares a struct named like each static, dereferencing to its value
+            ItemKind::Struct(..)
+                if is_expn_of(item.span, "lazy_static").is_some()
+                    && !in_macro(item.ident.span)
+                    && meets_msrv(self.msrv.as_ref(), &LAZY_LOCK_MSRV) =>
+            {
+                span_lint_and_help(
+                    cx,
+                    ONCE_CELL_LAZY,
+                    item.ident.span,
+                    "`lazy_static!` statics can be replaced with `std::sync::LazyLock`",
+                    None,
+                    &format!(
+                        "declare it as `static {}: std::sync::LazyLock<..> = std::sync::LazyLock::new(|| ..);`",
+                        item.ident
+                    ),
+                );
+            },
+            _ => {},
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
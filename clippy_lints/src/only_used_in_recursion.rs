@@ -0,0 +1,205 @@
+use crate::utils::{in_macro, qpath_res, span_lint_and_then};
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, ItemKind, Node, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::kw;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for function parameters which are only used to be passed on to
+    /// recursive calls of the function.
+    ///
+    /// **Why is this bad?** The parameter doesn't affect the result of the function, so it can
+    /// be removed.
+    ///
+    /// **Known problems:** Methods of trait impls and default methods of traits are ignored, as
+    /// their signature can't be changed. Parameters which are passed on in a different position
+    /// are only linted if the parameter they're passed as is unused, too.
+    /// The lint is unstable, it only runs with `cargo clippy --unstable-lints`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn f(a: usize, b: usize) -> usize {
+    ///     if a == 0 {
+    ///         1
+    ///     } else {
+    ///         f(a - 1, b + 1)
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f(a: usize) -> usize {
+    ///     if a == 0 {
+    ///         1
+    ///     } else {
+    ///         f(a - 1)
+    ///     }
+    /// }
+    /// ```
+    pub ONLY_USED_IN_RECURSION,
+    complexity,
+    "function parameters which are only used in recursive calls"
+}
+
+declare_lint_pass!(OnlyUsedInRecursion => [ONLY_USED_IN_RECURSION]);
+
+impl<'tcx> LateLintPass<'tcx> for OnlyUsedInRecursion {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if matches!(kind, FnKind::Closure(..)) || in_macro(span) {
+            return;
+        }
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(hir_id)) {
+            if matches!(
+                item.kind,
+                ItemKind::Impl { of_trait: Some(_), .. } | ItemKind::Trait(..)
+            ) {
+                return;
+            }
+        }
+
+        let params: Vec<Option<HirId>> = body
+            .params
+            .iter()
+            .map(|param| match param.pat.kind {
+                PatKind::Binding(_, id, _, None) => Some(id),
+                _ => None,
+            })
+            .collect();
+        let mut visitor = RecursionVisitor {
+            cx,
+            fn_def_id: cx.tcx.hir().local_def_id(hir_id).to_def_id(),
+            params: &params,
+            // Parameters with patterns are treated as used
+            used: params.iter().map(Option::is_none).collect(),
+            passed_on: vec![Vec::new(); params.len()],
+        };
+        visitor.visit_body(body);
+
+        // A parameter is used if it's passed on as a parameter which is used
+        let RecursionVisitor {
+            mut used, passed_on, ..
+        } = visitor;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, uses) in passed_on.iter().enumerate() {
+                if !used[i] && uses.iter().any(|&(position, _)| used[position]) {
+                    used[i] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        for (i, uses) in passed_on.iter().enumerate() {
+            if used[i] || uses.is_empty() {
+                continue;
+            }
+            let param = &body.params[i];
+            let name = match param.pat.kind {
+                PatKind::Binding(_, _, ident, _) => ident.name,
+                _ => continue,
+            };
+            if name.as_str().starts_with('_') {
+                continue;
+            }
+            span_lint_and_then(
+                cx,
+                ONLY_USED_IN_RECURSION,
+                param.pat.span,
+                "parameter is only used in recursion",
+                |diag| {
+                    diag.span_note(
+                        uses.iter().map(|&(_, span)| span).collect::<Vec<_>>(),
+                        "parameter used here",
+                    );
+                    if name == kw::SelfLower {
+                        diag.help("consider turning the method into an associated function");
+                    } else {
+                        diag.help("consider removing the parameter");
+                    }
+                },
+            );
+        }
+    }
+}
+
+struct RecursionVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    fn_def_id: DefId,
+    /// The bindings of the parameters, if they're simple bindings.
+    params: &'a [Option<HirId>],
+    /// Whether the parameters are used outside of recursive calls.
+    used: Vec<bool>,
+    /// The positions the parameters are passed on as in recursive calls, and the spans of the
+    /// arguments.
+    passed_on: Vec<Vec<(usize, Span)>>,
+}
+
+impl<'a, 'tcx> RecursionVisitor<'a, 'tcx> {
+    /// Returns the position of the parameter the expression is a path to.
+    fn param_position(&self, expr: &Expr<'_>) -> Option<usize> {
+        if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+            if let Res::Local(id) = path.res {
+                return self.params.iter().position(|param| *param == Some(id));
+            }
+        }
+        None
+    }
+
+    fn is_recursive_call(&self, expr: &Expr<'_>) -> bool {
+        let callee = match expr.kind {
+            ExprKind::Call(func, _) => match func.kind {
+                ExprKind::Path(ref qpath) => match qpath_res(self.cx, qpath, func.hir_id) {
+                    Res::Def(_, def_id) => Some(def_id),
+                    _ => None,
+                },
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => self.cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            _ => None,
+        };
+        callee == Some(self.fn_def_id)
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for RecursionVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let Some(position) = self.param_position(expr) {
+            self.used[position] = true;
+            return;
+        }
+
+        if self.is_recursive_call(expr) {
+            if let ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _) = expr.kind {
+                for (arg_position, arg) in args.iter().enumerate() {
+                    match self.param_position(arg) {
+                        Some(position) => self.passed_on[position].push((arg_position, arg.span)),
+                        None => self.visit_expr(arg),
+                    }
+                }
+                return;
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -193,6 +193,7 @@ fn detect_option_if_let_else<'tcx>(
 }
 
 impl<'tcx> LateLintPass<'tcx> for OptionIfLetElse {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
         if let Some(detection) = detect_option_if_let_else(cx, expr) {
             span_lint_and_sugg(
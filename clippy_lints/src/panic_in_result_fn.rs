@@ -1,16 +1,20 @@
-use crate::utils::{is_expn_of, is_type_diagnostic_item, return_ty, span_lint_and_then};
+use crate::utils::{is_allowed, is_expn_of, is_type_diagnostic_item, return_ty, span_lint_and_then};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{self, FnKind, NestedVisitorMap, Visitor};
-use rustc_hir::Expr;
+use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{sym, Span};
+use std::collections::VecDeque;
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for usage of `panic!`, `unimplemented!`, `todo!` or `unreachable!` in a function of type result.
+    /// **What it does:** Checks for usage of `panic!`, `unimplemented!`, `todo!`, `unreachable!` or assertions in a function of type result or option.
     ///
-    /// **Why is this bad?** For some codebases, it is desirable for functions of type result to return an error instead of crashing. Hence unimplemented, panic and unreachable should be avoided.
+    /// **Why is this bad?** For some codebases, it is desirable for functions of type result or option to return an error or `None` instead of crashing. Hence unimplemented, panic, unreachable and assertions should be avoided.
     ///
     /// **Known problems:** None.
     ///
@@ -24,10 +28,51 @@ declare_clippy_lint! {
     /// ```
     pub PANIC_IN_RESULT_FN,
     restriction,
-    "functions of type `Result<..>` that contain `panic!()`, `todo!()` or `unreachable()` or `unimplemented()` "
+    "functions of type `Result<..>` or `Option<..>` that contain `panic!()`, `todo!()`, `unreachable()`, `unimplemented()` or assertions"
 }
 
-declare_lint_pass!(PanicInResultFn  => [PANIC_IN_RESULT_FN]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for exported functions which may panic, directly or through the
+    /// functions of the crate they call, and lists the places where they may panic. Calls are
+    /// followed up to the depth configured with `reachable-panic-depth`.
+    ///
+    /// **Why is this bad?** Libraries which promise not to panic need to review every panic
+    /// their public API can reach.
+    ///
+    /// **Known problems:** Only calls of functions and methods of the crate whose definition is
+    /// known statically are followed, calls through trait objects, generic parameters and
+    /// function pointers are not. Panics in closures and in other crates, like the one of
+    /// `Option::unwrap`, are not listed.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// pub fn parse(input: &str) -> u32 {
+    ///     digits(input)
+    /// }
+    ///
+    /// fn digits(input: &str) -> u32 {
+    ///     assert!(!input.is_empty());
+    ///     // ..
+    /// #   0
+    /// }
+    /// ```
+    pub REACHABLE_PANIC,
+    restriction,
+    "exported functions which may reach a `panic!()`, `todo!()`, `unreachable()`, `unimplemented()` or assertion"
+}
+
+pub struct PanicInResultFn {
+    reachable_panic_depth: u64,
+}
+
+impl PanicInResultFn {
+    pub fn new(reachable_panic_depth: u64) -> Self {
+        Self { reachable_panic_depth }
+    }
+}
+
+impl_lint_pass!(PanicInResultFn  => [PANIC_IN_RESULT_FN, REACHABLE_PANIC]);
 
 impl<'tcx> LateLintPass<'tcx> for PanicInResultFn {
     fn check_fn(
@@ -39,28 +84,74 @@ impl<'tcx> LateLintPass<'tcx> for PanicInResultFn {
         span: Span,
         hir_id: hir::HirId,
     ) {
-        if !matches!(fn_kind, FnKind::Closure(_))
-            && is_type_diagnostic_item(cx, return_ty(cx, hir_id), sym::result_type)
-        {
-            lint_impl_body(cx, span, body);
+        if matches!(fn_kind, FnKind::Closure(_)) {
+            return;
+        }
+
+        let ret_ty = return_ty(cx, hir_id);
+        if is_type_diagnostic_item(cx, ret_ty, sym::result_type) {
+            lint_impl_body(cx, span, body, "Result", "return Err() instead of panicking");
+        } else if is_type_diagnostic_item(cx, ret_ty, sym::option_type) {
+            lint_impl_body(cx, span, body, "Option", "return None instead of panicking");
+        }
+
+        // following the calls is expensive, and the lint is allowed by default
+        if cx.access_levels.is_exported(hir_id) && !is_allowed(cx, REACHABLE_PANIC, hir_id) {
+            lint_reachable_panics(cx, span, body, self.reachable_panic_depth);
         }
     }
 }
 
-struct FindPanicUnimplementedUnreachable {
+/// The panicking macros, `panic` last as the others expand to it.
+const PANIC_MACROS: [&str; 7] = [
+    "unimplemented",
+    "unreachable",
+    "todo",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "panic",
+];
+
+struct FindPanicUnimplementedUnreachable<'a, 'tcx> {
     result: Vec<Span>,
+    /// The typeck results of the body, to collect the functions it calls
+    typeck_results: Option<&'a TypeckResults<'tcx>>,
+    calls: Vec<DefId>,
+}
+
+impl<'a, 'tcx> FindPanicUnimplementedUnreachable<'a, 'tcx> {
+    fn new(typeck_results: Option<&'a TypeckResults<'tcx>>) -> Self {
+        Self {
+            result: Vec::new(),
+            typeck_results,
+            calls: Vec::new(),
+        }
+    }
 }
 
-impl<'tcx> Visitor<'tcx> for FindPanicUnimplementedUnreachable {
+impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnimplementedUnreachable<'a, 'tcx> {
     type Map = Map<'tcx>;
 
     fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-        if ["unimplemented", "unreachable", "panic", "todo"]
-            .iter()
-            .any(|fun| is_expn_of(expr.span, fun).is_some())
-        {
+        if PANIC_MACROS.iter().any(|fun| is_expn_of(expr.span, fun).is_some()) {
+            // the rest of the expansion belongs to the same panic
             self.result.push(expr.span);
+            return;
+        }
+
+        if let Some(typeck_results) = self.typeck_results {
+            let callee = match expr.kind {
+                ExprKind::Call(ref func, _) => match func.kind {
+                    ExprKind::Path(ref qpath) => typeck_results.qpath_res(qpath, func.hir_id).opt_def_id(),
+                    _ => None,
+                },
+                ExprKind::MethodCall(..) => typeck_results.type_dependent_def_id(expr.hir_id),
+                _ => None,
+            };
+            self.calls.extend(callee);
         }
+
         // and check sub-expressions
         intravisit::walk_expr(self, expr);
     }
@@ -70,21 +161,75 @@ impl<'tcx> Visitor<'tcx> for FindPanicUnimplementedUnreachable {
     }
 }
 
-fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, body: &'tcx hir::Body<'tcx>) {
-    let mut panics = FindPanicUnimplementedUnreachable { result: Vec::new() };
+fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, body: &'tcx hir::Body<'tcx>, ty: &str, note: &str) {
+    let mut panics = FindPanicUnimplementedUnreachable::new(None);
     panics.visit_expr(&body.value);
     if !panics.result.is_empty() {
         span_lint_and_then(
             cx,
             PANIC_IN_RESULT_FN,
             impl_span,
-            "used `unimplemented!()`, `unreachable!()`, `todo!()` or `panic!()` in a function that returns `Result`",
+            &format!(
+                "used `unimplemented!()`, `unreachable!()`, `todo!()`, `panic!()` or assertion in a function that returns `{}`",
+                ty
+            ),
             move |diag| {
-                diag.help(
-                    "`unimplemented!()`, `unreachable!()`, `todo!()` or `panic!()` should not be used in a function that returns `Result` as `Result` is expected to return an error instead of crashing",
-                );
-                diag.span_note(panics.result, "return Err() instead of panicking");
+                diag.help(&format!(
+                    "`unimplemented!()`, `unreachable!()`, `todo!()`, `panic!()` or assertions should not be used in a function that returns `{0}` as `{0}` is expected to return an error instead of crashing",
+                    ty
+                ));
+                diag.span_note(panics.result, note);
             },
         );
     }
 }
+
+/// Lints the exported function if it can reach panics, following the calls of functions of the
+/// crate up to `max_depth` levels deep.
+fn lint_reachable_panics<'tcx>(cx: &LateContext<'tcx>, fn_span: Span, body: &'tcx hir::Body<'tcx>, max_depth: u64) {
+    let mut panics = Vec::new();
+    let mut visited = FxHashSet::default();
+    visited.insert(cx.tcx.hir().body_owner_def_id(body.id()).to_def_id());
+
+    // breadth-first, so that each function is reached by its shortest path and its calls are
+    // followed as deep as possible
+    let mut bodies = VecDeque::new();
+    bodies.push_back((body, 0));
+    while let Some((body, depth)) = bodies.pop_front() {
+        let owner = cx.tcx.hir().body_owner_def_id(body.id());
+        let mut finder = FindPanicUnimplementedUnreachable::new(Some(cx.tcx.typeck(owner)));
+        finder.visit_expr(&body.value);
+        panics.extend(finder.result.into_iter().map(|span| (span, owner.to_def_id())));
+
+        if depth < max_depth {
+            for callee in finder.calls {
+                if !visited.insert(callee) {
+                    continue;
+                }
+                let callee_body = callee
+                    .as_local()
+                    .map(|local_id| cx.tcx.hir().local_def_id_to_hir_id(local_id))
+                    .and_then(|hir_id| cx.tcx.hir().maybe_body_owned_by(hir_id));
+                if let Some(body_id) = callee_body {
+                    bodies.push_back((cx.tcx.hir().body(body_id), depth + 1));
+                }
+            }
+        }
+    }
+
+    if panics.is_empty() {
+        return;
+    }
+    panics.sort_by_key(|(span, _)| *span);
+    span_lint_and_then(
+        cx,
+        REACHABLE_PANIC,
+        fn_span,
+        "this exported function may panic",
+        |diag| {
+            for (span, owner) in panics {
+                diag.span_note(span, &format!("`{}` may panic here", cx.tcx.def_path_str(owner)));
+            }
+        },
+    );
+}
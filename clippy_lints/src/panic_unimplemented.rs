@@ -1,8 +1,9 @@
-use crate::utils::{is_expn_of, match_panic_call, span_lint};
+use crate::utils::{is_expn_of, match_panic_call, snippet_opt, span_lint, span_lint_and_help};
 use if_chain::if_chain;
+use regex::Regex;
 use rustc_hir::Expr;
-use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::Span;
 
 declare_clippy_lint! {
@@ -69,7 +70,46 @@ declare_clippy_lint! {
     "usage of the `unreachable!` macro"
 }
 
-declare_lint_pass!(PanicUnimplemented => [UNIMPLEMENTED, UNREACHABLE, TODO, PANIC]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for `todo!` and `unimplemented!` calls without a reference to
+    /// the issue tracking the missing code, in the message, in a comment after the call on its
+    /// last line or in the `//` comments on the lines right above the call. The reference must
+    /// match the `todo-tracking-regex` configuration, `TODO\(#\d+\)` by default.
+    ///
+    /// **Why is this bad?** Unfinished code without a tracking issue is easily forgotten.
+    ///
+    /// **Known problems:** The `todo` and `unimplemented` lints still lint tracked calls, so they
+    /// should not be enabled together with this one.
+    ///
+    /// **Example:**
+    /// ```no_run
+    /// fn parse_config() {
+    ///     todo!("parse the config file");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn parse_config() {
+    ///     todo!("TODO(#42): parse the config file");
+    /// }
+    /// ```
+    pub UNTRACKED_TODO,
+    restriction,
+    "`todo!` or `unimplemented!` without a reference to a tracking issue"
+}
+
+pub struct PanicUnimplemented {
+    /// The pattern of tracking references, `None` if the configured one is invalid
+    tracking_regex: Option<Regex>,
+}
+
+impl PanicUnimplemented {
+    pub fn new(tracking_regex: Option<Regex>) -> Self {
+        Self { tracking_regex }
+    }
+}
+
+impl_lint_pass!(PanicUnimplemented => [UNIMPLEMENTED, UNREACHABLE, TODO, PANIC, UNTRACKED_TODO]);
 
 impl<'tcx> LateLintPass<'tcx> for PanicUnimplemented {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -82,8 +122,10 @@ impl<'tcx> LateLintPass<'tcx> for PanicUnimplemented {
                     span,
                     "`unimplemented` should not be present in production code",
                 );
+                self.check_tracking_reference(cx, span, "unimplemented");
             } else if is_expn_of(expr.span, "todo").is_some() {
                 span_lint(cx, TODO, span, "`todo` should not be present in production code");
+                self.check_tracking_reference(cx, span, "todo");
             } else if is_expn_of(expr.span, "unreachable").is_some() {
                 span_lint(cx, UNREACHABLE, span, "usage of the `unreachable!` macro");
             } else if is_expn_of(expr.span, "panic").is_some() {
@@ -93,6 +135,58 @@ impl<'tcx> LateLintPass<'tcx> for PanicUnimplemented {
     }
 }
 
+impl PanicUnimplemented {
+    fn check_tracking_reference(&self, cx: &LateContext<'_>, span: Span, name: &str) {
+        if let Some(ref regex) = self.tracking_regex {
+            if !has_tracking_reference(cx, span, regex) {
+                span_lint_and_help(
+                    cx,
+                    UNTRACKED_TODO,
+                    span,
+                    &format!("`{}!` without a reference to a tracking issue", name),
+                    None,
+                    &format!(
+                        "add a reference matching `{}` to the message or to a comment next to the call",
+                        regex
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Checks the call, the rest of its last line and the line comments right above it for a
+/// match of the regex.
+fn has_tracking_reference(cx: &LateContext<'_>, span: Span, regex: &Regex) -> bool {
+    if snippet_opt(cx, span).map_or(false, |snippet| regex.is_match(&snippet)) {
+        return true;
+    }
+
+    let source_map = cx.sess().source_map();
+    let hi = source_map.lookup_char_pos(span.hi());
+    if let Some(line) = hi.file.get_line(hi.line - 1) {
+        let rest: String = line.chars().skip(hi.col.0).collect();
+        if regex.is_match(&rest) {
+            return true;
+        }
+    }
+
+    let lo = source_map.lookup_char_pos(span.lo());
+    let mut line_index = lo.line - 1;
+    while line_index > 0 {
+        line_index -= 1;
+        match lo.file.get_line(line_index) {
+            Some(line) if line.trim_start().starts_with("//") => {
+                if regex.is_match(&line) {
+                    return true;
+                }
+            },
+            _ => break,
+        }
+    }
+    false
+}
+
 fn get_outer_span(expr: &Expr<'_>) -> Span {
     if_chain! {
         if expr.span.from_expansion();
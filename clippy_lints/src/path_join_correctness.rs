@@ -0,0 +1,96 @@
+use crate::utils::{match_def_path, paths, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for string literals starting with `/`, `\` or a Windows drive
+    /// prefix like `C:` passed to `Path::join`, and for literals starting with a drive prefix
+    /// passed to `PathBuf::push`.
+    ///
+    /// **Why is this bad?** Joining or pushing an absolute path replaces the whole path
+    /// instead of appending to it, which is rarely intended. A leading `\` and a drive prefix
+    /// only make the path absolute on Windows.
+    ///
+    /// **Known problems:** None. `PathBuf::push` with a literal starting with a separator is
+    /// checked by `path_buf_push_overwrite`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let x = Path::new("/foo").join("/bar");
+    /// assert_eq!(x, Path::new("/bar"));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let x = Path::new("/foo").join("bar");
+    /// assert_eq!(x, Path::new("/foo/bar"));
+    /// ```
+    pub JOIN_ABSOLUTE_PATHS,
+    nursery,
+    "joining an absolute path replaces the whole path"
+}
+
+declare_lint_pass!(PathJoinCorrectness => [JOIN_ABSOLUTE_PATHS]);
+
+impl<'tcx> LateLintPass<'tcx> for PathJoinCorrectness {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, [_, arg], _) = expr.kind;
+            if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            let is_join = match_def_path(cx, method_id, &paths::PATH_JOIN);
+            if is_join || match_def_path(cx, method_id, &paths::PATH_BUF_PUSH);
+            if let ExprKind::Lit(ref lit) = arg.kind;
+            if let LitKind::Str(ref path_lit, _) = lit.node;
+            then {
+                let path_lit = path_lit.as_str();
+                let method = if is_join { "Path::join" } else { "PathBuf::push" };
+                if has_drive_prefix(&path_lit) {
+                    span_lint_and_then(
+                        cx,
+                        JOIN_ABSOLUTE_PATHS,
+                        lit.span,
+                        &format!("argument to `{}` starts with a drive prefix", method),
+                        |diag| {
+                            diag.note("on Windows, the argument replaces the whole path instead of being appended");
+                        },
+                    );
+                } else if is_join && path_lit.starts_with(|c| c == '/' || c == '\\') {
+                    span_lint_and_then(
+                        cx,
+                        JOIN_ABSOLUTE_PATHS,
+                        lit.span,
+                        "argument to `Path::join` starts with a path separator",
+                        |diag| {
+                            // `\` is only a separator on Windows, elsewhere it's part of the file name
+                            if path_lit.starts_with('\\') {
+                                diag.note("on Windows, the argument replaces the whole path instead of being appended");
+                            } else {
+                                diag.note("the argument replaces the whole path instead of being appended");
+                            }
+                            diag.span_suggestion(
+                                lit.span,
+                                "if this is unintentional, remove the leading separator",
+                                format!("{:?}", path_lit.trim_start_matches(|c| c == '/' || c == '\\')),
+                                Applicability::MaybeIncorrect,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the path starts with a Windows drive prefix, like `C:` or `c:\`.
+fn has_drive_prefix(path: &str) -> bool {
+    let mut chars = path.chars();
+    matches!((chars.next(), chars.next()), (Some(drive), Some(':')) if drive.is_ascii_alphabetic())
+}
@@ -2,10 +2,12 @@ use crate::consts::{constant, Constant};
 use if_chain::if_chain;
 use rustc_ast::ast::RangeLimits;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, PathSegment, QPath};
+use rustc_hir::def::Res;
+use rustc_hir::{BinOpKind, BindingAnnotation, Expr, ExprKind, Node, PatKind, PathSegment, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::{Span, Spanned};
 use rustc_span::sym;
 use rustc_span::symbol::Ident;
@@ -13,11 +15,13 @@ use std::cmp::Ordering;
 
 use crate::utils::sugg::Sugg;
 use crate::utils::{
-    get_parent_expr, is_integer_const, single_segment_path, snippet, snippet_opt, snippet_with_applicability,
-    span_lint, span_lint_and_sugg, span_lint_and_then,
+    get_parent_expr, is_integer_const, meets_msrv, single_segment_path, snippet, snippet_opt,
+    snippet_with_applicability, span_lint, span_lint_and_sugg, span_lint_and_then,
 };
 use crate::utils::{higher, SpanlessEq};
 
+const MANUAL_RANGE_CONTAINS_MSRV: RustcVersion = RustcVersion::new(1, 35, 0);
+
 declare_clippy_lint! {
     /// **What it does:** Checks for zipping a collection with the range of
     /// `0.._.len()`.
@@ -102,7 +106,10 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for range expressions `x..y` where both `x` and `y`
-    /// are constant and `x` is greater or equal to `y`.
+    /// are constant and `x` is greater or equal to `y`. Immutable locals initialized with a
+    /// constant count as constants.
+    ///
+    /// It also checks for `n..0` ranges of unsigned integers, which are always empty.
     ///
     /// **Why is this bad?** Empty ranges yield no values so iterating them is a no-op.
     /// Moreover, trying to use a reversed range to index a slice will panic at run-time.
@@ -160,7 +167,18 @@ declare_clippy_lint! {
     "manually reimplementing {`Range`, `RangeInclusive`}`::contains`"
 }
 
-declare_lint_pass!(Ranges => [
+pub struct Ranges {
+    msrv: Option<RustcVersion>,
+}
+
+impl Ranges {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Ranges => [
     RANGE_ZIP_WITH_LEN,
     RANGE_PLUS_ONE,
     RANGE_MINUS_ONE,
@@ -175,7 +193,9 @@ impl<'tcx> LateLintPass<'tcx> for Ranges {
                 check_range_zip_with_len(cx, path, args, expr.span);
             },
             ExprKind::Binary(ref op, ref l, ref r) => {
-                check_possible_range_contains(cx, op.node, l, r, expr.span);
+                if meets_msrv(self.msrv.as_ref(), &MANUAL_RANGE_CONTAINS_MSRV) {
+                    check_possible_range_contains(cx, op.node, l, r, expr.span);
+                }
             },
             _ => {},
         }
@@ -184,6 +204,8 @@ impl<'tcx> LateLintPass<'tcx> for Ranges {
         check_inclusive_range_minus_one(cx, expr);
         check_reversed_empty_range(cx, expr);
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 fn check_possible_range_contains(cx: &LateContext<'_>, op: BinOpKind, l: &Expr<'_>, r: &Expr<'_>, span: Span) {
@@ -435,51 +457,94 @@ fn check_reversed_empty_range(cx: &LateContext<'_>, expr: &Expr<'_>) {
         }
     }
 
+    /// Evaluates the bound as a constant, looking through immutable locals initialized with a
+    /// constant.
+    fn bound_constant(cx: &LateContext<'_>, bound: &Expr<'_>) -> Option<Constant> {
+        if let Some((value, _)) = constant(cx, cx.typeck_results(), bound) {
+            return Some(value);
+        }
+        if_chain! {
+            if let ExprKind::Path(QPath::Resolved(None, path)) = bound.kind;
+            if let Res::Local(hir_id) = path.res;
+            if let Some(Node::Binding(pat)) = cx.tcx.hir().find(hir_id);
+            if let PatKind::Binding(BindingAnnotation::Unannotated, ..) = pat.kind;
+            if let Some(Node::Local(local)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(hir_id));
+            if let Some(init) = local.init;
+            then {
+                constant(cx, cx.typeck_results(), init).map(|(value, _)| value)
+            } else {
+                None
+            }
+        }
+    }
+
+    fn lint_empty_range(cx: &LateContext<'_>, expr: &Expr<'_>, start: &Expr<'_>, end: &Expr<'_>, limits: RangeLimits) {
+        span_lint_and_then(
+            cx,
+            REVERSED_EMPTY_RANGES,
+            expr.span,
+            "this range is empty so it will yield no values",
+            |diag| {
+                let start_snippet = snippet(cx, start.span, "_");
+                let end_snippet = snippet(cx, end.span, "_");
+                let dots = match limits {
+                    RangeLimits::HalfOpen => "..",
+                    RangeLimits::Closed => "..=",
+                };
+
+                diag.span_suggestion(
+                    expr.span,
+                    "consider using the following if you are attempting to iterate over this \
+                     range in reverse",
+                    format!("({}{}{}).rev()", end_snippet, dots, start_snippet),
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        );
+    }
+
     if_chain! {
         if let Some(higher::Range { start: Some(start), end: Some(end), limits }) = higher::range(expr);
         let ty = cx.typeck_results().expr_ty(start);
         if let ty::Int(_) | ty::Uint(_) = ty.kind();
-        if let Some((start_idx, _)) = constant(cx, cx.typeck_results(), start);
-        if let Some((end_idx, _)) = constant(cx, cx.typeck_results(), end);
-        if let Some(ordering) = Constant::partial_cmp(cx.tcx, ty, &start_idx, &end_idx);
-        if is_empty_range(limits, ordering);
         then {
-            if inside_indexing_expr(cx, expr) {
-                // Avoid linting `N..N` as it has proven to be useful, see #5689 and #5628 ...
-                if ordering != Ordering::Equal {
-                    span_lint(
-                        cx,
-                        REVERSED_EMPTY_RANGES,
-                        expr.span,
-                        "this range is reversed and using it to index a slice will panic at run-time",
-                    );
-                }
-            // ... except in for loop arguments for backwards compatibility with `reverse_range_loop`
-            } else if ordering != Ordering::Equal || is_for_loop_arg(cx, expr) {
-                span_lint_and_then(
-                    cx,
-                    REVERSED_EMPTY_RANGES,
-                    expr.span,
-                    "this range is empty so it will yield no values",
-                    |diag| {
+            match (bound_constant(cx, start), bound_constant(cx, end)) {
+                (Some(start_idx), Some(end_idx)) => {
+                    let ordering = match Constant::partial_cmp(cx.tcx, ty, &start_idx, &end_idx) {
+                        Some(ordering) if is_empty_range(limits, ordering) => ordering,
+                        _ => return,
+                    };
+                    if inside_indexing_expr(cx, expr) {
+                        // Avoid linting `N..N` as it has proven to be useful, see #5689 and #5628 ...
                         if ordering != Ordering::Equal {
-                            let start_snippet = snippet(cx, start.span, "_");
-                            let end_snippet = snippet(cx, end.span, "_");
-                            let dots = match limits {
-                                RangeLimits::HalfOpen => "..",
-                                RangeLimits::Closed => "..="
-                            };
-
-                            diag.span_suggestion(
+                            span_lint(
+                                cx,
+                                REVERSED_EMPTY_RANGES,
                                 expr.span,
-                                "consider using the following if you are attempting to iterate over this \
-                                 range in reverse",
-                                format!("({}{}{}).rev()", end_snippet, dots, start_snippet),
-                                Applicability::MaybeIncorrect,
+                                "this range is reversed and using it to index a slice will panic at run-time",
                             );
                         }
-                    },
-                );
+                    // ... except in for loop arguments for backwards compatibility with `reverse_range_loop`
+                    } else if ordering != Ordering::Equal {
+                        lint_empty_range(cx, expr, start, end, limits);
+                    } else if is_for_loop_arg(cx, expr) {
+                        span_lint(
+                            cx,
+                            REVERSED_EMPTY_RANGES,
+                            expr.span,
+                            "this range is empty so it will yield no values",
+                        );
+                    }
+                },
+                // `n..0` is empty for any unsigned `n`, and `&slice[n..0]` is empty or panics
+                (None, Some(Constant::Int(0)))
+                    if limits == RangeLimits::HalfOpen
+                        && matches!(ty.kind(), ty::Uint(_))
+                        && !inside_indexing_expr(cx, expr) =>
+                {
+                    lint_empty_range(cx, expr, start, end, limits);
+                },
+                _ => {},
             }
         }
     }
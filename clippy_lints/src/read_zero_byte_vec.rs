@@ -0,0 +1,180 @@
+use crate::utils::{match_def_path, match_trait_method, paths, qpath_res, snippet_opt, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, Mutability, PatKind, QPath, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for empty `Vec`s, created with `Vec::new()`, `vec![]` or
+    /// `Vec::with_capacity(n)`, which are passed to `Read::read` or `Read::read_exact` before
+    /// being used otherwise.
+    ///
+    /// **Why is this bad?** `read` reads at most as many bytes as the length of the buffer, not
+    /// its capacity. With an empty `Vec` it reads nothing, and `read_exact` returns right away.
+    ///
+    /// **Known problems:** Only the uses of the `Vec` in the same block are checked, e.g. a
+    /// `resize` through another function isn't seen.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::io::Read;
+    /// # fn f(mut reader: impl Read) -> std::io::Result<()> {
+    /// let mut buf = Vec::with_capacity(1024);
+    /// reader.read_exact(&mut buf)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::io::Read;
+    /// # fn f(mut reader: impl Read) -> std::io::Result<()> {
+    /// let mut buf = vec![0; 1024];
+    /// reader.read_exact(&mut buf)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub READ_ZERO_BYTE_VEC,
+    correctness,
+    "reading into an empty `Vec`, which reads zero bytes"
+}
+
+declare_lint_pass!(ReadZeroByteVec => [READ_ZERO_BYTE_VEC]);
+
+impl<'tcx> LateLintPass<'tcx> for ReadZeroByteVec {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Local(local) = stmt.kind;
+                if let PatKind::Binding(_, buf_id, ident, None) = local.pat.kind;
+                if let Some(init) = local.init;
+                if let Some(capacity) = empty_vec_capacity(cx, init);
+                if let Some(read) = first_use_read(cx, buf_id, block, i + 1);
+                if match_trait_method(cx, read, &paths::IO_READ);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        READ_ZERO_BYTE_VEC,
+                        read.span,
+                        "reading zero byte data to `Vec`",
+                        |diag| match capacity.and_then(|capacity| snippet_opt(cx, capacity.span)) {
+                            Some(capacity) => {
+                                diag.span_suggestion(
+                                    init.span,
+                                    "to read as many bytes as the capacity, create the `Vec` with that length",
+                                    format!("vec![0; {}]", capacity),
+                                    Applicability::MaybeIncorrect,
+                                );
+                            },
+                            None => {
+                                diag.help(&format!(
+                                    "use `read_to_end` to read all the data, or create `{}` with a length, \
+                                     like `vec![0; len]`",
+                                    ident
+                                ));
+                            },
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the expression creates an empty `Vec`, returning the argument of `Vec::with_capacity`
+/// if there's one.
+fn empty_vec_capacity<'tcx>(cx: &LateContext<'_>, init: &'tcx Expr<'tcx>) -> Option<Option<&'tcx Expr<'tcx>>> {
+    if_chain! {
+        if let ExprKind::Call(func, args) = init.kind;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+        then {
+            match args {
+                // `vec![]` expands to `Vec::new()`
+                [] if match_def_path(cx, def_id, &paths::VEC_NEW) => Some(None),
+                [capacity] if match_def_path(cx, def_id, &paths::VEC_WITH_CAPACITY) => Some(Some(capacity)),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the `read(&mut buf)` or `read_exact(&mut buf)` call if it's the first use of `buf` in
+/// the statements of the block from `start`.
+fn first_use_read<'tcx>(
+    cx: &LateContext<'tcx>,
+    buf_id: HirId,
+    block: &'tcx Block<'tcx>,
+    start: usize,
+) -> Option<&'tcx Expr<'tcx>> {
+    let mut visitor = FirstUseVisitor {
+        cx,
+        buf_id,
+        read: None,
+        found: false,
+    };
+    for stmt in &block.stmts[start..] {
+        visitor.visit_stmt(stmt);
+        if visitor.found {
+            return visitor.read;
+        }
+    }
+    if let Some(expr) = block.expr {
+        visitor.visit_expr(expr);
+    }
+    visitor.read
+}
+
+struct FirstUseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    buf_id: HirId,
+    /// The `read` call, if it's the first use of the buffer
+    read: Option<&'tcx Expr<'tcx>>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FirstUseVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt<'tcx>) {
+        if !self.found {
+            walk_stmt(self, stmt);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.found {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(method, _, [_, arg], _)
+                if matches!(&*method.ident.as_str(), "read" | "read_exact") && is_mut_ref_to(arg, self.buf_id) =>
+            {
+                self.read = Some(expr);
+                self.found = true;
+            },
+            // a use in a closure counts too, it may be called before the `read`
+            ExprKind::Path(QPath::Resolved(None, path)) if path.res == Res::Local(self.buf_id) => self.found = true,
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+fn is_mut_ref_to(expr: &Expr<'_>, id: HirId) -> bool {
+    match expr.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, inner) => {
+            matches!(inner.kind, ExprKind::Path(QPath::Resolved(None, path)) if path.res == Res::Local(id))
+        },
+        _ => false,
+    }
+}
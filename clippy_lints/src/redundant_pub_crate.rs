@@ -1,12 +1,13 @@
 use crate::utils::span_lint_and_then;
 use rustc_errors::Applicability;
-use rustc_hir::{Item, ItemKind, VisibilityKind};
+use rustc_hir::{HirId, ImplItem, Item, ItemKind, StructField, Visibility, VisibilityKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for items declared `pub(crate)` that are not crate visible because they
-    /// are inside a private module.
+    /// **What it does:** Checks for items, associated items of inherent impls and struct fields
+    /// declared `pub(crate)` that are not crate visible because they are inside a private module.
     ///
     /// **Why is this bad?** Writing `pub(crate)` is misleading when it's redundant due to the parent
     /// module's visibility.
@@ -41,11 +42,54 @@ impl_lint_pass!(RedundantPubCrate => [REDUNDANT_PUB_CRATE]);
 
 impl<'tcx> LateLintPass<'tcx> for RedundantPubCrate {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
-        if let VisibilityKind::Crate { .. } = item.vis.node {
-            if !cx.access_levels.is_exported(item.hir_id) {
+        self.check_vis(cx, &item.vis, item.hir_id, item.span.with_hi(item.ident.span.hi()));
+
+        if let ItemKind::Mod { .. } = item.kind {
+            self.is_exported.push(cx.access_levels.is_exported(item.hir_id));
+        }
+    }
+
+    fn check_item_post(&mut self, _cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Mod { .. } = item.kind {
+            self.is_exported.pop().expect("unbalanced check_item/check_item_post");
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // `pub` associated items of types used outside of the crate would be exported
+        let impl_def_id = cx.tcx.hir().local_def_id(cx.tcx.hir().get_parent_item(item.hir_id));
+        let self_ty_reachable = match cx.tcx.type_of(impl_def_id).ty_adt_def() {
+            Some(adt) => adt.did.as_local().map_or(true, |did| {
+                cx.access_levels.is_reachable(cx.tcx.hir().local_def_id_to_hir_id(did))
+            }),
+            None => true,
+        };
+        if !self_ty_reachable {
+            self.check_vis(cx, &item.vis, item.hir_id, item.span.with_hi(item.ident.span.hi()));
+        }
+    }
+
+    fn check_struct_field(&mut self, cx: &LateContext<'tcx>, field: &'tcx StructField<'tcx>) {
+        // the span of the name of positional fields is the whole field, and `pub` fields of types
+        // used outside of the crate would be exported
+        if !field.is_positional()
+            && !cx
+                .access_levels
+                .is_reachable(cx.tcx.hir().get_parent_item(field.hir_id))
+        {
+            self.check_vis(cx, &field.vis, field.hir_id, field.span.with_hi(field.ident.span.hi()));
+        }
+    }
+}
+
+impl RedundantPubCrate {
+    /// Lints the `pub(crate)` visibility of the item, field or associated item. `span` ends with
+    /// its name.
+    fn check_vis(&self, cx: &LateContext<'_>, vis: &Visibility<'_>, hir_id: HirId, span: Span) {
+        if let VisibilityKind::Crate { .. } = vis.node {
+            if !cx.access_levels.is_exported(hir_id) {
                 if let Some(false) = self.is_exported.last() {
-                    let span = item.span.with_hi(item.ident.span.hi());
-                    let def_id = cx.tcx.hir().local_def_id(item.hir_id);
+                    let def_id = cx.tcx.hir().local_def_id(hir_id);
                     let descr = cx.tcx.def_kind(def_id).descr(def_id.to_def_id());
                     span_lint_and_then(
                         cx,
@@ -54,7 +98,7 @@ impl<'tcx> LateLintPass<'tcx> for RedundantPubCrate {
                         &format!("pub(crate) {} inside private module", descr),
                         |diag| {
                             diag.span_suggestion(
-                                item.vis.span,
+                                vis.span,
                                 "consider using",
                                 "pub".to_string(),
                                 Applicability::MachineApplicable,
@@ -64,15 +108,5 @@ impl<'tcx> LateLintPass<'tcx> for RedundantPubCrate {
                 }
             }
         }
-
-        if let ItemKind::Mod { .. } = item.kind {
-            self.is_exported.push(cx.access_levels.is_exported(item.hir_id));
-        }
-    }
-
-    fn check_item_post(&mut self, _cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
-        if let ItemKind::Mod { .. } = item.kind {
-            self.is_exported.pop().expect("unbalanced check_item/check_item_post");
-        }
     }
 }
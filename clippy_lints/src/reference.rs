@@ -118,6 +118,7 @@ declare_clippy_lint! {
 declare_lint_pass!(RefInDeref => [REF_IN_DEREF]);
 
 impl EarlyLintPass for RefInDeref {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &EarlyContext<'_>, e: &Expr) {
         if_chain! {
             if let ExprKind::Field(ref object, _) = e.kind;
@@ -0,0 +1,28 @@
+// This file is managed by `cargo dev rename_lint`. Prefer using that when possible.
+
+/// Lints that have been renamed, as pairs of the old and the new name.
+///
+/// The old names keep working in lint attributes and on the command line, but emit a warning
+/// that suggests to use the new name instead.
+#[rustfmt::skip]
+pub static RENAMED_LINTS: &[(&str, &str)] = &[
+    ("clippy::stutter", "clippy::module_name_repetitions"),
+    ("clippy::new_without_default_derive", "clippy::new_without_default"),
+    ("clippy::cyclomatic_complexity", "clippy::cognitive_complexity"),
+    ("clippy::const_static_lifetime", "clippy::redundant_static_lifetimes"),
+    ("clippy::option_and_then_some", "clippy::bind_instead_of_map"),
+    ("clippy::block_in_if_condition_expr", "clippy::blocks_in_if_conditions"),
+    ("clippy::block_in_if_condition_stmt", "clippy::blocks_in_if_conditions"),
+    ("clippy::option_map_unwrap_or", "clippy::map_unwrap_or"),
+    ("clippy::option_map_unwrap_or_else", "clippy::map_unwrap_or"),
+    ("clippy::result_map_unwrap_or_else", "clippy::map_unwrap_or"),
+    ("clippy::option_unwrap_used", "clippy::unwrap_used"),
+    ("clippy::result_unwrap_used", "clippy::unwrap_used"),
+    ("clippy::option_expect_used", "clippy::expect_used"),
+    ("clippy::result_expect_used", "clippy::expect_used"),
+    ("clippy::for_loop_over_option", "clippy::for_loops_over_fallibles"),
+    ("clippy::for_loop_over_result", "clippy::for_loops_over_fallibles"),
+    ("clippy::identity_conversion", "clippy::useless_conversion"),
+    ("clippy::zero_width_space", "clippy::invisible_characters"),
+    ("clippy::single_char_push_str", "clippy::single_char_add_str"),
+];
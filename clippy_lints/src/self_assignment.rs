@@ -34,6 +34,7 @@ declare_clippy_lint! {
 declare_lint_pass!(SelfAssignment => [SELF_ASSIGNMENT]);
 
 impl<'tcx> LateLintPass<'tcx> for SelfAssignment {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::Assign(lhs, rhs, _) = &expr.kind {
             if eq_expr_value(cx, lhs, rhs) {
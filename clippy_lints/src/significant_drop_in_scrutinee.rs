@@ -0,0 +1,182 @@
+use crate::utils::{in_macro, match_type, significant_tys, span_lint_and_help, SignificantTy};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, MatchSource, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for temporaries with a significant `Drop` in the scrutinee of a
+    /// `match`, `if let` or `while let`, like the `MutexGuard` of `mutex.lock().unwrap().len()`.
+    ///
+    /// The types with a significant `Drop` are the guards of `std::sync::Mutex` and
+    /// `std::sync::RwLock`, and the types of the `significant-drop-types` configuration.
+    ///
+    /// **Why is this bad?** The temporaries of the scrutinee are only dropped at the end of the
+    /// whole expression, so a lock taken in the scrutinee is still held in the arms, which
+    /// deadlocks if an arm locks it again.
+    ///
+    /// **Known problems:** The temporaries in the tail expression of a block in the scrutinee
+    /// also live until the end of the expression, but aren't linted.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Mutex;
+    /// let mutex = Mutex::new(vec![1, 2, 3]);
+    /// match mutex.lock().unwrap().len() {
+    ///     0 => mutex.lock().unwrap().push(1),
+    ///     _ => {},
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// let mutex = Mutex::new(vec![1, 2, 3]);
+    /// let len = mutex.lock().unwrap().len();
+    /// match len {
+    ///     0 => mutex.lock().unwrap().push(1),
+    ///     _ => {},
+    /// }
+    /// ```
+    pub SIGNIFICANT_DROP_IN_SCRUTINEE,
+    nursery,
+    "temporary with a significant `Drop` in a `match` scrutinee, living until the end of the `match`"
+}
+
+pub struct SignificantDropInScrutinee {
+    /// The types of the `significant-drop-types` configuration
+    significant_drop_types: Vec<Vec<String>>,
+}
+
+impl SignificantDropInScrutinee {
+    #[must_use]
+    pub fn new(significant_drop_types: &[String]) -> Self {
+        Self {
+            significant_drop_types: significant_drop_types
+                .iter()
+                .map(|path| path.split("::").map(ToString::to_string).collect())
+                .collect(),
+        }
+    }
+
+    fn has_significant_drop<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+        // dropping a reference doesn't drop what it points to
+        if let ty::Ref(..) = ty.kind() {
+            return false;
+        }
+        significant_tys(cx, ty).contains(&SignificantTy::SyncGuard)
+            || ty.walk().any(|inner| match inner.unpack() {
+                GenericArgKind::Type(inner_ty) => self.significant_drop_types.iter().any(|path| {
+                    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+                    match_type(cx, inner_ty, &path)
+                }),
+                GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
+            })
+    }
+}
+
+impl_lint_pass!(SignificantDropInScrutinee => [SIGNIFICANT_DROP_IN_SCRUTINEE]);
+
+impl<'tcx> LateLintPass<'tcx> for SignificantDropInScrutinee {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // e.g. the `match` of `assert_eq!`
+        if in_macro(expr.span) {
+            return;
+        }
+
+        let (scrutinee, keyword) = match expr.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::Normal) => (scrutinee, "match"),
+            ExprKind::Match(scrutinee, _, MatchSource::IfLetDesugar { .. }) => (scrutinee, "if let"),
+            ExprKind::Match(scrutinee, _, MatchSource::WhileLetDesugar) => (scrutinee, "while let"),
+            _ => return,
+        };
+
+        let mut visitor = TemporaryVisitor {
+            cx,
+            lint: self,
+            temporaries: Vec::new(),
+        };
+        visitor.visit_expr(scrutinee);
+
+        for temporary in visitor.temporaries {
+            span_lint_and_help(
+                cx,
+                SIGNIFICANT_DROP_IN_SCRUTINEE,
+                temporary.span,
+                &format!(
+                    "temporary with significant `Drop` in `{}` scrutinee will live until the end of the `{}` expression",
+                    keyword, keyword
+                ),
+                None,
+                &format!(
+                    "compute the value in a `let` statement before the `{}`, the temporary is then dropped at the end of the `let`",
+                    keyword
+                ),
+            );
+        }
+    }
+}
+
+/// Collects the temporaries with a significant `Drop` which are borrowed in the scrutinee, the
+/// ones moved somewhere else, e.g. into the pattern, aren't dropped at the end of the `match`.
+struct TemporaryVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    lint: &'a SignificantDropInScrutinee,
+    temporaries: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'tcx> TemporaryVisitor<'_, 'tcx> {
+    fn check_temporary(&mut self, expr: &'tcx Expr<'tcx>) {
+        if matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            && !expr.span.from_expansion()
+            && !self.contains(expr.hir_id)
+            && self
+                .lint
+                .has_significant_drop(self.cx, self.cx.typeck_results().expr_ty(expr))
+        {
+            self.temporaries.push(expr);
+        }
+    }
+
+    fn contains(&self, hir_id: HirId) -> bool {
+        self.temporaries.iter().any(|temporary| temporary.hir_id == hir_id)
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for TemporaryVisitor<'_, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            // the closures and the nested `match`es have their own temporaries
+            ExprKind::Closure(..) | ExprKind::Block(..) | ExprKind::Match(..) | ExprKind::Loop(..) => return,
+            ExprKind::AddrOf(_, _, inner) => self.check_temporary(inner),
+            // an overloaded `Deref` borrows the temporary, e.g. `*mutex.lock().unwrap()`
+            ExprKind::Unary(UnOp::UnDeref, inner) if self.cx.typeck_results().is_method_call(expr) => {
+                self.check_temporary(inner)
+            },
+            _ => {},
+        }
+
+        // the auto-borrows and overloaded auto-derefs, e.g. of the method receivers
+        if self
+            .cx
+            .typeck_results()
+            .expr_adjustments(expr)
+            .iter()
+            .any(|adjustment| matches!(adjustment.kind, Adjust::Borrow(_) | Adjust::Deref(Some(_))))
+        {
+            self.check_temporary(expr);
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
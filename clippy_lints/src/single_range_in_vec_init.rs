@@ -0,0 +1,95 @@
+use crate::consts::constant;
+use crate::utils::{higher, is_expn_of, snippet_opt, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_ast::ast::{RangeLimits, UintTy};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `vec![start..end]` and `[start..end]`, which create a `Vec`
+    /// or an array with a single `Range` element.
+    ///
+    /// **Why is this bad?** This is most likely meant to be a `Vec` of all the values of the
+    /// range, `(start..end).collect()`, or a `Vec` or array of `end` elements, `vec![start; end]`.
+    ///
+    /// **Known problems:** A single range in a `Vec` may be intended, e.g. if more ranges are
+    /// pushed later.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let indices = vec![0..10];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let indices: Vec<usize> = (0..10).collect();
+    /// ```
+    pub SINGLE_RANGE_IN_VEC_INIT,
+    style,
+    "`vec![start..end]` or `[start..end]`, which contain a single `Range`"
+}
+
+declare_lint_pass!(SingleRangeInVecInit => [SINGLE_RANGE_IN_VEC_INIT]);
+
+impl<'tcx> LateLintPass<'tcx> for SingleRangeInVecInit {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (arg, span, is_vec) = match higher::vec_macro(cx, expr) {
+            Some(higher::VecArgs::Vec([arg])) => match is_expn_of(expr.span, "vec") {
+                Some(span) => (arg, span, true),
+                None => return,
+            },
+            Some(_) => return,
+            None => match expr.kind {
+                ExprKind::Array([ref arg]) if !expr.span.from_expansion() => (arg, expr.span, false),
+                _ => return,
+            },
+        };
+
+        if_chain! {
+            if let Some(higher::Range {
+                start: Some(start),
+                end: Some(end),
+                limits: RangeLimits::HalfOpen,
+            }) = higher::range(arg);
+            let ty = cx.typeck_results().expr_ty(start);
+            if ty.is_integral();
+            if let Some(start_snippet) = snippet_opt(cx, start.span);
+            if let Some(end_snippet) = snippet_opt(cx, end.span);
+            then {
+                let (msg, container) = if is_vec {
+                    ("a `Vec` of `Range` that is only one element", "a `Vec`")
+                } else {
+                    ("an array of `Range` that is only one element", "an array")
+                };
+                // the length has to be a `usize`, and a constant for arrays
+                let of_len = matches!(end.kind, ExprKind::Lit(..))
+                    || (matches!(cx.typeck_results().expr_ty(end).kind(), ty::Uint(UintTy::Usize))
+                        && (is_vec || constant(cx, cx.typeck_results(), end).is_some()));
+
+                span_lint_and_then(cx, SINGLE_RANGE_IN_VEC_INIT, span, msg, |diag| {
+                    diag.span_suggestion(
+                        span,
+                        "if you wanted a `Vec` that contains the entire range, try",
+                        format!("({}..{}).collect::<std::vec::Vec<{}>>()", start_snippet, end_snippet, ty),
+                        Applicability::MaybeIncorrect,
+                    );
+                    if of_len {
+                        diag.span_suggestion(
+                            span,
+                            &format!("if you wanted {} of len {}, try", container, end_snippet),
+                            if is_vec {
+                                format!("vec![{}; {}]", start_snippet, end_snippet)
+                            } else {
+                                format!("[{}; {}]", start_snippet, end_snippet)
+                            },
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                });
+            }
+        }
+    }
+}
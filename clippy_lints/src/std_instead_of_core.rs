@@ -0,0 +1,184 @@
+use crate::utils::{in_macro, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{CrateNum, DefId, CRATE_DEF_INDEX};
+use rustc_hir::{HirId, Path, PathSegment};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::edition::Edition;
+use rustc_span::symbol::{kw, sym};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Finds items imported through `std` when available through `core`.
+    ///
+    /// **Why is this bad?** Crates which have `no_std` compatibility may wish to ensure types are
+    /// imported from core to ensure disabling `std` does not cause the crate to fail to compile. This
+    /// lint is also useful for crates migrating to become `no_std` compatible.
+    ///
+    /// **Known problems:** Items that are unstable in `core` are not linted, even if they are stable
+    /// in `std`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::hash::Hasher;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use core::hash::Hasher;
+    /// ```
+    pub STD_INSTEAD_OF_CORE,
+    restriction,
+    "type is imported from std when available in core"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Finds items imported through `std` when available through `alloc`.
+    ///
+    /// **Why is this bad?** Crates which have `no_std` compatibility and require alloc may wish to
+    /// ensure types are imported from alloc to ensure disabling `std` does not cause the crate to fail
+    /// to compile. This lint is also useful for crates migrating to become `no_std` compatible.
+    ///
+    /// **Known problems:** The crate needs an `extern crate alloc;` declaration for the suggestion to
+    /// compile, so it is not applied automatically.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::vec::Vec;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # extern crate alloc;
+    /// use alloc::vec::Vec;
+    /// ```
+    pub STD_INSTEAD_OF_ALLOC,
+    restriction,
+    "type is imported from std when available in alloc"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Finds items imported through `alloc` when available through `core`.
+    ///
+    /// **Why is this bad?** Crates which have `no_std` compatibility and may optionally require alloc
+    /// may wish to ensure types are imported from core to ensure disabling `alloc` does not cause the
+    /// crate to fail to compile. This lint is also useful for crates migrating to become `no_std`
+    /// compatible.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # extern crate alloc;
+    /// use alloc::slice::from_ref;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use core::slice::from_ref;
+    /// ```
+    pub ALLOC_INSTEAD_OF_CORE,
+    restriction,
+    "type is imported from alloc when available in core"
+}
+
+#[derive(Default)]
+pub struct StdReexports {
+    // Paths which can be either a module or a macro (e.g. `std::env`) will cause this check to happen
+    // twice. First for the mod, second for the macro. This is used to avoid the lint reporting for
+    // the same span twice.
+    prev_span: Option<Span>,
+}
+
+impl_lint_pass!(StdReexports => [STD_INSTEAD_OF_CORE, STD_INSTEAD_OF_ALLOC, ALLOC_INSTEAD_OF_CORE]);
+
+impl<'tcx> LateLintPass<'tcx> for StdReexports {
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &Path<'tcx>, _: HirId) {
+        if_chain! {
+            if !in_macro(path.span);
+            if let Res::Def(_, def_id) = path.res;
+            if let Some((first_segment, rest)) = split_first_segment(path);
+            if Some(first_segment.ident.span) != self.prev_span;
+            then {
+                // `core` is only in the extern prelude since the 2018 edition
+                let core_applicability = if cx.sess().edition() == Edition::Edition2015 {
+                    Applicability::MaybeIncorrect
+                } else {
+                    Applicability::MachineApplicable
+                };
+                let (lint, used_mod, replace_with, applicability) = match first_segment.ident.name {
+                    sym::std => match cx.tcx.crate_name(def_id.krate) {
+                        sym::core => (STD_INSTEAD_OF_CORE, "std", "core", core_applicability),
+                        sym::alloc => (STD_INSTEAD_OF_ALLOC, "std", "alloc", Applicability::MaybeIncorrect),
+                        _ => return,
+                    },
+                    sym::alloc if cx.tcx.crate_name(def_id.krate) == sym::core => {
+                        (ALLOC_INSTEAD_OF_CORE, "alloc", "core", core_applicability)
+                    },
+                    _ => return,
+                };
+                if !is_stable(cx, def_id) || !resolves_to(cx, def_id.krate, rest, def_id) {
+                    return;
+                }
+
+                span_lint_and_sugg(
+                    cx,
+                    lint,
+                    first_segment.ident.span,
+                    &format!("used import from `{}` instead of `{}`", used_mod, replace_with),
+                    &format!("consider importing the item from `{}`", replace_with),
+                    replace_with.to_string(),
+                    applicability,
+                );
+                self.prev_span = Some(first_segment.ident.span);
+            }
+        }
+    }
+}
+
+/// Returns the first segment of the path, skipping the root of absolute paths like `::std::vec`,
+/// together with the remaining segments.
+fn split_first_segment<'a, 'tcx>(path: &'a Path<'tcx>) -> Option<(&'a PathSegment<'tcx>, &'a [PathSegment<'tcx>])> {
+    match path.segments {
+        [root, first, rest @ ..] if root.ident.name == kw::PathRoot => Some((first, rest)),
+        [first, rest @ ..] => Some((first, rest)),
+        [] => None,
+    }
+}
+
+/// Checks that neither the item nor any of its parents are unstable, e.g. items which are stable in
+/// `std` but still behind a feature gate in `core`.
+fn is_stable(cx: &LateContext<'_>, mut def_id: DefId) -> bool {
+    loop {
+        if cx
+            .tcx
+            .lookup_stability(def_id)
+            .map_or(false, |stability| stability.level.is_unstable())
+        {
+            return false;
+        }
+
+        match cx.tcx.parent(def_id) {
+            Some(parent) => def_id = parent,
+            None => return true,
+        }
+    }
+}
+
+/// Checks that `segments`, looked up from the root of `krate`, lead to `def_id`. `std` re-exports
+/// don't always mirror the module structure of `core` and `alloc` (e.g. `std::os::raw::c_void` is
+/// `core::ffi::c_void`), so the rewritten path has to be checked.
+fn resolves_to(cx: &LateContext<'_>, krate: CrateNum, segments: &[PathSegment<'_>], def_id: DefId) -> bool {
+    let mut candidates = vec![DefId {
+        krate,
+        index: CRATE_DEF_INDEX,
+    }];
+    for segment in segments {
+        candidates = candidates
+            .into_iter()
+            .flat_map(|parent| cx.tcx.item_children(parent).iter())
+            .filter(|child| child.ident.name == segment.ident.name)
+            .filter_map(|child| child.res.opt_def_id())
+            .collect();
+    }
+    candidates.contains(&def_id)
+}
@@ -318,6 +318,7 @@ declare_clippy_lint! {
 declare_lint_pass!(StrToString => [STR_TO_STRING]);
 
 impl LateLintPass<'_> for StrToString {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'_>) {
         if_chain! {
             if let ExprKind::MethodCall(path, _, args, _) = &expr.kind;
@@ -367,12 +368,13 @@ declare_clippy_lint! {
 declare_lint_pass!(StringToString => [STRING_TO_STRING]);
 
 impl LateLintPass<'_> for StringToString {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'_>) {
         if_chain! {
             if let ExprKind::MethodCall(path, _, args, _) = &expr.kind;
             if path.ident.name == sym!(to_string);
             let ty = cx.typeck_results().expr_ty(&args[0]);
-            if is_type_diagnostic_item(cx, ty, sym!(string_type));
+            if is_type_diagnostic_item(cx, ty, sym::string_type);
             then {
                 span_lint_and_help(
                     cx,
@@ -1,6 +1,7 @@
 use crate::utils::sugg::Sugg;
 use crate::utils::{
-    differing_macro_contexts, eq_expr_value, is_type_diagnostic_item, snippet_with_applicability, span_lint_and_then,
+    differing_macro_contexts, eq_expr_value, is_type_diagnostic_item, is_type_std_item, snippet_with_applicability,
+    span_lint_and_then, std_items,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -199,7 +200,7 @@ fn check_for_slice<'a>(cx: &LateContext<'_>, lhs1: &'a Expr<'_>, lhs2: &'a Expr<
                 if matches!(ty.kind(), ty::Slice(_))
                     || matches!(ty.kind(), ty::Array(_, _))
                     || is_type_diagnostic_item(cx, ty, sym::vec_type)
-                    || is_type_diagnostic_item(cx, ty, sym!(vecdeque_type))
+                    || is_type_std_item(cx, ty, &std_items::VEC_DEQUE)
                 {
                     return Slice::Swappable(lhs1, idx1, idx2);
                 }
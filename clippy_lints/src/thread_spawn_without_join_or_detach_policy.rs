@@ -0,0 +1,100 @@
+use crate::utils::visitors::LocalUsedVisitor;
+use crate::utils::{fn_def_id, match_def_path, paths, span_lint_and_help};
+use rustc_hir::{Block, Expr, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `std::thread::spawn` calls whose `JoinHandle` is discarded,
+    /// or bound to a variable which is never used.
+    ///
+    /// **Why is this bad?** Dropping the `JoinHandle` detaches the thread: nothing waits for
+    /// it to finish, and its panics are lost. Some codebases require each thread to be either
+    /// joined, or explicitly detached with `drop(handle)`, so that detaching is a visible
+    /// decision.
+    ///
+    /// **Known problems:** Any use of the handle counts as joining or detaching it, e.g.
+    /// passing it to a function. The threads spawned with `std::thread::Builder` aren't
+    /// checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// std::thread::spawn(|| println!("working"));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let handle = std::thread::spawn(|| println!("working"));
+    /// handle.join().unwrap();
+    /// ```
+    pub THREAD_SPAWN_WITHOUT_JOIN_OR_DETACH_POLICY,
+    restriction,
+    "`std::thread::spawn` whose `JoinHandle` is neither joined nor explicitly detached"
+}
+
+declare_lint_pass!(ThreadSpawnWithoutJoinOrDetachPolicy => [THREAD_SPAWN_WITHOUT_JOIN_OR_DETACH_POLICY]);
+
+const HELP: &str = "join the thread with `.join()`, or detach it explicitly with `drop(..)`";
+
+impl<'tcx> LateLintPass<'tcx> for ThreadSpawnWithoutJoinOrDetachPolicy {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if in_external_macro(cx.sess(), stmt.span) {
+                continue;
+            }
+            match stmt.kind {
+                // `thread::spawn(..);`
+                StmtKind::Semi(expr) if is_thread_spawn(cx, expr) => lint_discarded(cx, expr),
+                StmtKind::Local(local) => {
+                    let init = match local.init {
+                        Some(init) if is_thread_spawn(cx, init) => init,
+                        _ => continue,
+                    };
+                    match local.pat.kind {
+                        // `let _ = thread::spawn(..);`
+                        PatKind::Wild => lint_discarded(cx, init),
+                        // `let handle = thread::spawn(..);`, the handle being dropped at the
+                        // end of the block if it's never used
+                        PatKind::Binding(_, binding_id, ident, None) => {
+                            let mut visitor = LocalUsedVisitor::new(binding_id);
+                            let used = block.stmts[i + 1..].iter().any(|stmt| visitor.check_stmt(stmt))
+                                || block.expr.map_or(false, |expr| visitor.check_expr(expr));
+                            if !used {
+                                span_lint_and_help(
+                                    cx,
+                                    THREAD_SPAWN_WITHOUT_JOIN_OR_DETACH_POLICY,
+                                    local.span,
+                                    &format!(
+                                        "the `JoinHandle` bound to `{}` is never used, which detaches the thread",
+                                        ident
+                                    ),
+                                    None,
+                                    HELP,
+                                );
+                            }
+                        },
+                        _ => {},
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+fn lint_discarded(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    span_lint_and_help(
+        cx,
+        THREAD_SPAWN_WITHOUT_JOIN_OR_DETACH_POLICY,
+        expr.span,
+        "the `JoinHandle` of this thread is discarded, which detaches the thread",
+        None,
+        HELP,
+    );
+}
+
+fn is_thread_spawn(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    !expr.span.from_expansion()
+        && fn_def_id(cx, expr).map_or(false, |def_id| match_def_path(cx, def_id, &paths::STD_THREAD_SPAWN))
+}
@@ -87,6 +87,7 @@ impl LateLintPass<'_> for ToStringInDisplay {
         }
     }
 
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
         if_chain! {
             if let ExprKind::MethodCall(ref path, _, args, _) = expr.kind;
@@ -9,6 +9,7 @@ use rustc_ast::{FloatTy, IntTy, LitFloatType, LitIntType, LitKind, UintTy};
 use rustc_errors::{Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{walk_body, walk_expr, walk_ty, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{
     BinOpKind, Block, Body, Expr, ExprKind, FnDecl, FnRetTy, FnSig, GenericArg, GenericBounds, GenericParamKind, HirId,
@@ -31,10 +32,12 @@ use rustc_typeck::hir_ty_to_ty;
 use crate::consts::{constant, Constant};
 use crate::utils::paths;
 use crate::utils::{
-    clip, comparisons, differing_macro_contexts, higher, in_constant, indent_of, int_bits, is_type_diagnostic_item,
-    last_path_segment, match_def_path, match_path, method_chain_args, multispan_sugg, numeric_literal::NumericLiteral,
-    qpath_res, reindent_multiline, sext, snippet, snippet_opt, snippet_with_applicability, snippet_with_macro_callsite,
-    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, unsext,
+    clip, comparisons, differing_macro_contexts, get_trait_def_id, higher, implements_trait, in_constant, in_macro,
+    indent_of, int_bits, is_std_item, is_type_diagnostic_item, is_type_std_item, last_path_segment, match_def_path,
+    match_path, method_chain_args, multispan_sugg, multispan_sugg_with_applicability, numeric_literal::NumericLiteral,
+    path_to_res, qpath_res, reindent_multiline, return_ty, sext, snippet, snippet_opt, snippet_with_applicability,
+    snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, std_items,
+    unsext,
 };
 
 declare_clippy_lint! {
@@ -219,6 +222,8 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `Rc<T>` and `Arc<T>` when `T` is a mutable buffer type such as `String` or `Vec`.
+    /// Calls to `Rc::new` and `Arc::new` with a buffer are linted as well, since the buffer can be converted
+    /// with `From` instead.
     ///
     /// **Why is this bad?** Expressions such as `Rc<String>` usually have no advantage over `Rc<str>`, since
     /// it is larger and involves an extra level of indirection, and doesn't implement `Borrow<str>`.
@@ -243,16 +248,69 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// fn foo(interned: Rc<str>) { ... }
     /// ```
+    ///
+    /// The buffers are converted with `From`:
+    ///
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// let interned = Rc::<str>::from(String::from("foo"));
+    /// ```
     pub RC_BUFFER,
     restriction,
     "shared ownership of a buffer type"
 }
 
-pub struct Types {
-    vec_box_size_threshold: u64,
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Result` and `Vec` types which are nested deeper than
+    /// configured, e.g. `Result<Result<T, E>, E>` or `Vec<Vec<Vec<T>>>`. The allowed depth
+    /// can be set with the `max-result-nesting` and `max-vec-nesting` configuration options.
+    ///
+    /// **Why is this bad?** Deeply nested containers are hard to read and to work with. A
+    /// `Result` of a `Result` usually means that the error types should be merged, and several
+    /// levels of `Vec`s are often better represented by a single flat `Vec` or by a newtype.
+    ///
+    /// **Known problems:** Nested `Option`s are linted by `option_option` instead.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn parse(input: &str) -> Result<Result<u32, std::num::ParseIntError>, String> {
+    ///     Err(input.to_string())
+    /// }
+    /// ```
+    ///
+    /// Better:
+    ///
+    /// ```rust
+    /// enum ParseError {
+    ///     Int(std::num::ParseIntError),
+    ///     Other(String),
+    /// }
+    ///
+    /// fn parse(input: &str) -> Result<u32, ParseError> {
+    ///     Err(ParseError::Other(input.to_string()))
+    /// }
+    /// ```
+    pub NESTED_CONTAINERS,
+    pedantic,
+    "usage of deeply nested `Result` or `Vec` types"
 }
 
-impl_lint_pass!(Types => [BOX_VEC, VEC_BOX, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, REDUNDANT_ALLOCATION, RC_BUFFER]);
+pub struct Types {
+    vec_box_size_threshold: u64,
+    max_result_nesting: u64,
+    max_vec_nesting: u64,
+}
+
+impl_lint_pass!(Types => [
+    BOX_VEC,
+    VEC_BOX,
+    OPTION_OPTION,
+    LINKEDLIST,
+    BORROWED_BOX,
+    REDUNDANT_ALLOCATION,
+    RC_BUFFER,
+    NESTED_CONTAINERS,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for Types {
     fn check_fn(&mut self, cx: &LateContext<'_>, _: FnKind<'_>, decl: &FnDecl<'_>, _: &Body<'_>, _: Span, id: HirId) {
@@ -283,6 +341,10 @@ impl<'tcx> LateLintPass<'tcx> for Types {
             self.check_ty(cx, ty, true);
         }
     }
+
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        check_rc_buffer_construction(cx, expr);
+    }
 }
 
 /// Checks if `qpath` has last segment with type parameter matching `path`
@@ -318,6 +380,97 @@ fn match_buffer_type(cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<&'static
     None
 }
 
+/// Checks for `Rc<T>` and `Arc<T>` when `T` is a buffer type, `name` is the name of the pointer
+/// type. Returns `true` if the lint was emitted.
+fn check_rc_buffer(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, qpath: &QPath<'_>, name: &str) -> bool {
+    let msg = format!("usage of `{}<T>` when T is a buffer type", name);
+    if let Some(alternate) = match_buffer_type(cx, qpath) {
+        span_lint_and_sugg(
+            cx,
+            RC_BUFFER,
+            hir_ty.span,
+            &msg,
+            "try",
+            format!("{}<{}>", name, alternate),
+            Applicability::MachineApplicable,
+        );
+        return true;
+    }
+    if match_type_parameter(cx, qpath, &paths::VEC).is_some() {
+        let inner_ty = first_type_parameter(qpath).and_then(|vec_ty| match vec_ty.kind {
+            TyKind::Path(ref vec_qpath) => first_type_parameter(vec_qpath),
+            _ => None,
+        });
+        let inner_span = match inner_ty {
+            Some(ty) => ty.span,
+            None => return false,
+        };
+        let mut applicability = Applicability::MachineApplicable;
+        span_lint_and_sugg(
+            cx,
+            RC_BUFFER,
+            hir_ty.span,
+            &msg,
+            "try",
+            format!(
+                "{}<[{}]>",
+                name,
+                snippet_with_applicability(cx, inner_span, "..", &mut applicability)
+            ),
+            applicability,
+        );
+        return true;
+    }
+    false
+}
+
+/// Checks for `Rc::new(buffer)` and `Arc::new(buffer)`. The buffer can be converted into an
+/// `Rc<str>`, `Arc<[T]>` etc. with `From` instead.
+fn check_rc_buffer_construction(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if_chain! {
+        if !expr.span.from_expansion();
+        if let ExprKind::Call(ref func, ref args) = expr.kind;
+        if args.len() == 1;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+        if match_def_path(cx, def_id, &paths::RC_NEW) || match_def_path(cx, def_id, &paths::ARC_NEW);
+        if let Some(func_snippet) = snippet_opt(cx, func.span);
+        if let Some(ptr) = func_snippet.strip_suffix("::new");
+        // `Rc::<String>::new` would need to be rewritten as well
+        if !ptr.contains('<');
+        then {
+            let arg_ty = cx.typeck_results().expr_ty(&args[0]);
+            let target = if is_type_diagnostic_item(cx, arg_ty, sym::string_type) {
+                "str"
+            } else if is_type_diagnostic_item(cx, arg_ty, sym::vec_type) {
+                "[_]"
+            } else if is_type_std_item(cx, arg_ty, &std_items::PATH_BUF) {
+                "std::path::Path"
+            } else if is_type_std_item(cx, arg_ty, &std_items::OS_STRING) {
+                "std::ffi::OsStr"
+            } else {
+                return;
+            };
+            // Changes the type of the pointer, which has to be adjusted where it's used
+            let mut applicability = Applicability::MaybeIncorrect;
+            span_lint_and_sugg(
+                cx,
+                RC_BUFFER,
+                expr.span,
+                &format!("usage of `{}::new` with a buffer type", ptr.rsplit("::").next().unwrap_or(ptr)),
+                "convert the buffer instead",
+                format!(
+                    "{}::<{}>::from({})",
+                    ptr,
+                    target,
+                    snippet_with_applicability(cx, args[0].span, "..", &mut applicability)
+                ),
+                applicability,
+            );
+        }
+    }
+}
+
 fn match_borrows_parameter(_cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<Span> {
     let last = last_path_segment(qpath);
     if_chain! {
@@ -335,9 +488,76 @@ fn match_borrows_parameter(_cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<S
     None
 }
 
+/// Returns the first type parameter of the last segment of `qpath`.
+fn first_type_parameter<'tcx>(qpath: &QPath<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    last_path_segment(qpath).args?.args.iter().find_map(|arg| match arg {
+        GenericArg::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// Returns how many times the container `def_id` is nested through its first type parameter,
+/// e.g. 3 for `Vec<Vec<Vec<u8>>>`.
+fn nesting_depth(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, def_id: DefId) -> u64 {
+    let mut depth = 0;
+    let mut ty = hir_ty;
+    while let TyKind::Path(ref qpath) = ty.kind {
+        if qpath_res(cx, qpath, ty.hir_id).opt_def_id() != Some(def_id) {
+            break;
+        }
+        depth += 1;
+        match first_type_parameter(qpath) {
+            Some(inner) => ty = inner,
+            None => break,
+        }
+    }
+    depth
+}
+
 impl Types {
-    pub fn new(vec_box_size_threshold: u64) -> Self {
-        Self { vec_box_size_threshold }
+    pub fn new(vec_box_size_threshold: u64, max_result_nesting: u64, max_vec_nesting: u64) -> Self {
+        Self {
+            vec_box_size_threshold,
+            max_result_nesting,
+            max_vec_nesting,
+        }
+    }
+
+    /// Checks if the container `def_id` is nested deeper than allowed. Returns `true` if the
+    /// lint was emitted.
+    fn check_nesting(&self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, def_id: DefId) -> bool {
+        let (name, max_depth, help) = if cx.tcx.is_diagnostic_item(sym::result_type, def_id) {
+            (
+                "Result",
+                self.max_result_nesting,
+                "consider merging the error types into a single `Result`, or using a newtype for the inner `Result`",
+            )
+        } else if cx.tcx.is_diagnostic_item(sym::vec_type, def_id) {
+            (
+                "Vec",
+                self.max_vec_nesting,
+                "consider flattening the data into a single `Vec`, or using a newtype for the inner levels",
+            )
+        } else {
+            return false;
+        };
+
+        let depth = nesting_depth(cx, hir_ty, def_id);
+        if depth <= max_depth {
+            return false;
+        }
+        span_lint_and_help(
+            cx,
+            NESTED_CONTAINERS,
+            hir_ty.span,
+            &format!(
+                "`{}` is nested {} levels deep, the maximum is {}",
+                name, depth, max_depth
+            ),
+            None,
+            help,
+        );
+        true
     }
 
     fn check_fn_decl(&mut self, cx: &LateContext<'_>, decl: &FnDecl<'_>) {
@@ -354,7 +574,7 @@ impl Types {
     /// lint found.
     ///
     /// The parameter `is_local` distinguishes the context of the type; types from
-    /// local bindings should only be checked for the `BORROWED_BOX` lint.
+    /// local bindings should only be checked for the `BORROWED_BOX` and `RC_BUFFER` lints.
     #[allow(clippy::too_many_lines)]
     fn check_ty(&mut self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, is_local: bool) {
         if hir_ty.span.from_expansion() {
@@ -365,6 +585,9 @@ impl Types {
                 let hir_id = hir_ty.hir_id;
                 let res = qpath_res(cx, qpath, hir_id);
                 if let Some(def_id) = res.opt_def_id() {
+                    if self.check_nesting(cx, hir_ty, def_id) {
+                        return; // don't recurse into the type
+                    }
                     if Some(def_id) == cx.tcx.lang_items().owned_box() {
                         if let Some(span) = match_borrows_parameter(cx, qpath) {
                             let mut applicability = Applicability::MachineApplicable;
@@ -431,43 +654,7 @@ impl Types {
                             );
                             return; // don't recurse into the type
                         }
-                        if let Some(alternate) = match_buffer_type(cx, qpath) {
-                            span_lint_and_sugg(
-                                cx,
-                                RC_BUFFER,
-                                hir_ty.span,
-                                "usage of `Rc<T>` when T is a buffer type",
-                                "try",
-                                format!("Rc<{}>", alternate),
-                                Applicability::MachineApplicable,
-                            );
-                            return; // don't recurse into the type
-                        }
-                        if match_type_parameter(cx, qpath, &paths::VEC).is_some() {
-                            let vec_ty = match &last_path_segment(qpath).args.unwrap().args[0] {
-                                GenericArg::Type(ty) => match &ty.kind {
-                                    TyKind::Path(qpath) => qpath,
-                                    _ => return,
-                                },
-                                _ => return,
-                            };
-                            let inner_span = match &last_path_segment(&vec_ty).args.unwrap().args[0] {
-                                GenericArg::Type(ty) => ty.span,
-                                _ => return,
-                            };
-                            let mut applicability = Applicability::MachineApplicable;
-                            span_lint_and_sugg(
-                                cx,
-                                RC_BUFFER,
-                                hir_ty.span,
-                                "usage of `Rc<T>` when T is a buffer type",
-                                "try",
-                                format!(
-                                    "Rc<[{}]>",
-                                    snippet_with_applicability(cx, inner_span, "..", &mut applicability)
-                                ),
-                                Applicability::MachineApplicable,
-                            );
+                        if check_rc_buffer(cx, hir_ty, qpath, "Rc") {
                             return; // don't recurse into the type
                         }
                         if let Some(span) = match_borrows_parameter(cx, qpath) {
@@ -484,43 +671,7 @@ impl Types {
                             return; // don't recurse into the type
                         }
                     } else if cx.tcx.is_diagnostic_item(sym::Arc, def_id) {
-                        if let Some(alternate) = match_buffer_type(cx, qpath) {
-                            span_lint_and_sugg(
-                                cx,
-                                RC_BUFFER,
-                                hir_ty.span,
-                                "usage of `Arc<T>` when T is a buffer type",
-                                "try",
-                                format!("Arc<{}>", alternate),
-                                Applicability::MachineApplicable,
-                            );
-                            return; // don't recurse into the type
-                        }
-                        if match_type_parameter(cx, qpath, &paths::VEC).is_some() {
-                            let vec_ty = match &last_path_segment(qpath).args.unwrap().args[0] {
-                                GenericArg::Type(ty) => match &ty.kind {
-                                    TyKind::Path(qpath) => qpath,
-                                    _ => return,
-                                },
-                                _ => return,
-                            };
-                            let inner_span = match &last_path_segment(&vec_ty).args.unwrap().args[0] {
-                                GenericArg::Type(ty) => ty.span,
-                                _ => return,
-                            };
-                            let mut applicability = Applicability::MachineApplicable;
-                            span_lint_and_sugg(
-                                cx,
-                                RC_BUFFER,
-                                hir_ty.span,
-                                "usage of `Arc<T>` when T is a buffer type",
-                                "try",
-                                format!(
-                                    "Arc<[{}]>",
-                                    snippet_with_applicability(cx, inner_span, "..", &mut applicability)
-                                ),
-                                Applicability::MachineApplicable,
-                            );
+                        if check_rc_buffer(cx, hir_ty, qpath, "Arc") {
                             return; // don't recurse into the type
                         }
                     } else if cx.tcx.is_diagnostic_item(sym::vec_type, def_id) {
@@ -571,7 +722,7 @@ impl Types {
                             );
                             return; // don't recurse into the type
                         }
-                    } else if match_def_path(cx, def_id, &paths::LINKED_LIST) {
+                    } else if is_std_item(cx, def_id, &std_items::LINKED_LIST) {
                         span_lint_and_help(
                             cx,
                             LINKEDLIST,
@@ -625,6 +776,15 @@ impl Types {
                     QPath::LangItem(..) => {},
                 }
             },
+            TyKind::Path(ref qpath) => {
+                if let Some(def_id) = qpath_res(cx, qpath, hir_ty.hir_id).opt_def_id() {
+                    if cx.tcx.is_diagnostic_item(sym::Rc, def_id) {
+                        check_rc_buffer(cx, hir_ty, qpath, "Rc");
+                    } else if cx.tcx.is_diagnostic_item(sym::Arc, def_id) {
+                        check_rc_buffer(cx, hir_ty, qpath, "Arc");
+                    }
+                }
+            },
             TyKind::Rptr(ref lt, ref mut_ty) => self.check_ty_rptr(cx, hir_ty, is_local, lt, mut_ty),
             // recurse
             TyKind::Slice(ref ty) | TyKind::Array(ref ty, _) | TyKind::Ptr(MutTy { ref ty, .. }) => {
@@ -1356,7 +1516,7 @@ declare_clippy_lint! {
 
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
-fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_>) -> u64 {
+pub fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_>) -> u64 {
     match typ.kind() {
         ty::Int(i) => match i {
             IntTy::Isize => tcx.data_layout.pointer_size.bits(),
@@ -1378,7 +1538,7 @@ fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_>) -> u64 {
     }
 }
 
-fn is_isize_or_usize(typ: Ty<'_>) -> bool {
+pub fn is_isize_or_usize(typ: Ty<'_>) -> bool {
     matches!(typ.kind(), ty::Int(IntTy::Isize) | ty::Uint(UintTy::Usize))
 }
 
@@ -1461,9 +1621,16 @@ enum ArchSuffix {
     None,
 }
 
-fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+/// Returns whether the lint suggested `try_from`, which is only suggested once per cast.
+fn check_loss_of_sign(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    op: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+) -> bool {
     if !cast_from.is_signed() || cast_to.is_signed() {
-        return;
+        return false;
     }
 
     // don't lint for positive constants
@@ -1473,7 +1640,7 @@ fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast
         if let ty::Int(ity) = *cast_from.kind();
         if sext(cx.tcx, n, ity) >= 0;
         then {
-            return
+            return false;
         }
     }
 
@@ -1492,11 +1659,12 @@ fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast
         }
 
         if allowed_methods.iter().any(|&name| method_name == name) {
-            return;
+            return false;
         }
     }
 
-    span_lint(
+    let mut suggested = false;
+    span_lint_and_then(
         cx,
         CAST_SIGN_LOSS,
         expr.span,
@@ -1504,10 +1672,72 @@ fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast
             "casting `{}` to `{}` may lose the sign of the value",
             cast_from, cast_to
         ),
+        |diag| suggested = suggest_try_from(cx, diag, expr, op, cast_to),
     );
+    suggested
 }
 
-fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+/// Suggests replacing an integer cast with `T::try_from`, followed by `?` if the enclosing
+/// function returns a `Result` whose error can be converted from `TryFromIntError`, and by
+/// `.expect(..)` otherwise. Returns whether the suggestion was added.
+fn suggest_try_from(
+    cx: &LateContext<'_>,
+    diag: &mut DiagnosticBuilder<'_>,
+    expr: &Expr<'_>,
+    op: &Expr<'_>,
+    cast_to: Ty<'_>,
+) -> bool {
+    if !cast_to.is_integral() || in_macro(expr.span) || in_constant(cx, expr.hir_id) {
+        return false;
+    }
+
+    let error_handling = if returns_try_from_int_error(cx, expr.hir_id) {
+        ")?".to_string()
+    } else {
+        format!(").expect(\"the value doesn't fit into `{}`\")", cast_to)
+    };
+    // `TryFrom` may not be in scope, and `.expect(..)` panics where the cast didn't
+    multispan_sugg_with_applicability(
+        diag,
+        &format!("consider checking the conversion with `{}::try_from`", cast_to),
+        Applicability::MaybeIncorrect,
+        vec![
+            (expr.span.with_hi(op.span.lo()), format!("{}::try_from(", cast_to)),
+            (op.span.shrink_to_hi().with_hi(expr.span.hi()), error_handling),
+        ],
+    );
+    true
+}
+
+/// Checks if the function enclosing the expression returns a `Result`, whose error type
+/// implements `From<TryFromIntError>`.
+fn returns_try_from_int_error(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let owner = cx.tcx.hir().enclosing_body_owner(hir_id);
+    if !matches!(cx.tcx.hir().body_owner_kind(owner), hir::BodyOwnerKind::Fn) {
+        return false;
+    }
+    let ret_ty = return_ty(cx, owner);
+    if_chain! {
+        if is_type_diagnostic_item(cx, ret_ty, sym::result_type);
+        if let ty::Adt(_, substs) = ret_ty.kind();
+        if let Some(from_trait) = get_trait_def_id(cx, &paths::FROM_TRAIT);
+        if let Some(Res::Def(_, error_id)) = path_to_res(cx, &paths::TRY_FROM_INT_ERROR);
+        then {
+            implements_trait(cx, substs.type_at(1), from_trait, &[cx.tcx.type_of(error_id).into()])
+        } else {
+            false
+        }
+    }
+}
+
+fn check_truncation_and_wrapping(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    op: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+    try_from_suggested: bool,
+) {
     let arch_64_suffix = " on targets with 64-bit wide pointers";
     let arch_32_suffix = " on targets with 32-bit wide pointers";
     let cast_unsigned_to_signed = !cast_from.is_signed() && cast_to.is_signed();
@@ -1543,7 +1773,7 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_fro
             ),
         };
     if span_truncation {
-        span_lint(
+        span_lint_and_then(
             cx,
             CAST_POSSIBLE_TRUNCATION,
             expr.span,
@@ -1557,6 +1787,11 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_fro
                     ArchSuffix::None => "",
                 }
             ),
+            |diag| {
+                if !try_from_suggested {
+                    suggest_try_from(cx, diag, expr, op, cast_to);
+                }
+            },
         );
     }
     if span_wrap {
@@ -1764,8 +1999,8 @@ fn lint_numeric_casts<'tcx>(
             }
         },
         (true, true) => {
-            check_loss_of_sign(cx, expr, cast_expr, cast_from, cast_to);
-            check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
+            let try_from_suggested = check_loss_of_sign(cx, expr, cast_expr, cast_from, cast_to);
+            check_truncation_and_wrapping(cx, expr, cast_expr, cast_from, cast_to, try_from_suggested);
             check_lossless(cx, expr, cast_expr, cast_from, cast_to);
         },
         (false, false) => {
@@ -2669,14 +2904,14 @@ impl<'tcx> ImplicitHasherType<'tcx> {
 
             let ty = hir_ty_to_ty(cx.tcx, hir_ty);
 
-            if is_type_diagnostic_item(cx, ty, sym!(hashmap_type)) && params_len == 2 {
+            if is_type_std_item(cx, ty, &std_items::HASHMAP) && params_len == 2 {
                 Some(ImplicitHasherType::HashMap(
                     hir_ty.span,
                     ty,
                     snippet(cx, params[0].span, "K"),
                     snippet(cx, params[1].span, "V"),
                 ))
-            } else if is_type_diagnostic_item(cx, ty, sym!(hashset_type)) && params_len == 1 {
+            } else if is_type_std_item(cx, ty, &std_items::HASHSET) && params_len == 1 {
                 Some(ImplicitHasherType::HashSet(
                     hir_ty.span,
                     ty,
@@ -2857,6 +3092,7 @@ declare_clippy_lint! {
 declare_lint_pass!(RefToMut => [CAST_REF_TO_MUT]);
 
 impl<'tcx> LateLintPass<'tcx> for RefToMut {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             if let ExprKind::Unary(UnOp::UnDeref, e) = &expr.kind;
@@ -0,0 +1,70 @@
+use crate::utils::{match_def_path, paths, qpath_res, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the creation of unbounded channels with
+    /// `std::sync::mpsc::channel`, `tokio::sync::mpsc::unbounded_channel` or
+    /// `crossbeam_channel::unbounded`.
+    ///
+    /// **Why is this bad?** An unbounded channel queues all the messages the receiver didn't take
+    /// yet. If the senders are faster than the receiver, the queue grows until the memory runs
+    /// out. A bounded channel makes the senders wait instead.
+    ///
+    /// **Known problems:** A bounded channel can deadlock if a thread both sends and receives on
+    /// it, and unbounded channels are fine when the number of messages is bounded by other means.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let (sender, receiver) = std::sync::mpsc::channel::<u32>();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let (sender, receiver) = std::sync::mpsc::sync_channel::<u32>(100);
+    /// ```
+    pub UNBOUNDED_CHANNEL,
+    pedantic,
+    "creating an unbounded channel"
+}
+
+// These crates aren't dependencies of Clippy, so their paths can't be checked by the
+// `invalid_paths` lint of `utils::paths`.
+const TOKIO_UNBOUNDED_CHANNEL: [&str; 5] = ["tokio", "sync", "mpsc", "unbounded", "unbounded_channel"];
+const CROSSBEAM_UNBOUNDED: [&str; 3] = ["crossbeam_channel", "channel", "unbounded"];
+
+/// The unbounded channel constructors, with the bounded alternatives to suggest
+const UNBOUNDED_CHANNELS: [(&[&str], &str); 3] = [
+    (&paths::MPSC_CHANNEL, "`std::sync::mpsc::sync_channel`"),
+    (&TOKIO_UNBOUNDED_CHANNEL, "`tokio::sync::mpsc::channel`"),
+    (&CROSSBEAM_UNBOUNDED, "`crossbeam_channel::bounded`"),
+];
+
+declare_lint_pass!(UnboundedChannel => [UNBOUNDED_CHANNEL]);
+
+impl<'tcx> LateLintPass<'tcx> for UnboundedChannel {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        if_chain! {
+            if let ExprKind::Call(func, _) = expr.kind;
+            if let ExprKind::Path(ref qpath) = func.kind;
+            if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+            if let Some((_, alternative)) = UNBOUNDED_CHANNELS.iter().find(|(p, _)| match_def_path(cx, def_id, p));
+            then {
+                span_lint_and_help(
+                    cx,
+                    UNBOUNDED_CHANNEL,
+                    expr.span,
+                    "creating an unbounded channel",
+                    None,
+                    &format!("use a bounded channel, like {}, to limit the queued messages", alternative),
+                );
+            }
+        }
+    }
+}
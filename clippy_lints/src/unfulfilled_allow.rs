@@ -0,0 +1,129 @@
+use crate::attrs::extract_clippy_lint;
+use crate::utils::{is_allowed, record_lint_calls, span_lint_hir_and_then, take_lint_calls};
+use rustc_ast::ast::{self, Attribute};
+use rustc_hir::{Crate, HirId};
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, Level, LintContext, LintId};
+use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[allow(clippy::..)]` attributes allowing a lint which isn't
+    /// emitted in the code they apply to.
+    ///
+    /// **Why is this bad?** The attribute is stale, e.g. the code was changed or the lint was
+    /// fixed since it was added. It may hide a new warning of the lint later.
+    ///
+    /// **Known problems:** Only the code compiled in this run is checked, a lint may still be
+    /// emitted with other features or on another target. Some lints don't look for their pattern
+    /// at all where they're allowed, so their attributes are always reported. The lint must be
+    /// enabled for the whole crate, e.g. on the command line or with `#![warn(..)]` at the crate
+    /// root.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[allow(clippy::needless_return)]
+    /// fn f() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f() -> u32 {
+    ///     42
+    /// }
+    /// ```
+    pub UNFULFILLED_ALLOW,
+    nursery,
+    "`#[allow]` attribute for a Clippy lint which isn't emitted"
+}
+
+/// Starts recording the Clippy lint calls before any pass runs, so that the calls of the early
+/// and pre-expansion passes are recorded too. It's registered as the first pre-expansion pass.
+pub struct RecordLintCalls;
+
+declare_lint_pass!(RecordLintCalls => []);
+
+impl EarlyLintPass for RecordLintCalls {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &ast::Crate) {
+        if cx.builder.lint_level(UNFULFILLED_ALLOW).0 != Level::Allow {
+            record_lint_calls();
+        }
+    }
+}
+
+/// An `#[allow(clippy::..)]` attribute.
+struct Allow {
+    lint: LintId,
+    /// The span of the lint in the attribute
+    span: Span,
+    /// The node the attribute applies to
+    hir_id: HirId,
+}
+
+#[derive(Default)]
+pub struct UnfulfilledAllow {
+    allows: Vec<Allow>,
+}
+
+impl_lint_pass!(UnfulfilledAllow => [UNFULFILLED_ALLOW]);
+
+impl<'tcx> LateLintPass<'tcx> for UnfulfilledAllow {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        if is_allowed(cx, UNFULFILLED_ALLOW, cx.last_node_with_lint_attrs) {
+            return;
+        }
+
+        for attr in attrs {
+            if !attr.has_name(sym::allow) || attr.span.from_expansion() {
+                continue;
+            }
+            for item in attr.meta_item_list().unwrap_or_default() {
+                let name = match extract_clippy_lint(&item) {
+                    Some(name) => name,
+                    None => continue,
+                };
+                // lint groups allow several lints, and the lint can't check its own attributes
+                match cx.lints().find_lints(&format!("clippy::{}", name)).as_deref() {
+                    Ok(&[lint]) if lint != LintId::of(UNFULFILLED_ALLOW) => self.allows.push(Allow {
+                        lint,
+                        span: item.span(),
+                        hir_id: cx.last_node_with_lint_attrs,
+                    }),
+                    _ => {},
+                }
+            }
+        }
+    }
+
+    // the pass is registered last, so that the lints emitted at the end of the crate by the other
+    // passes are already called
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        // the calls aren't recorded if the lint is allowed for the crate
+        let calls = match take_lint_calls() {
+            Some(calls) => calls,
+            None => return,
+        };
+        for allow in &self.allows {
+            let node_span = cx.tcx.hir().span(allow.hir_id);
+            let fulfilled = calls
+                .iter()
+                .any(|&(lint, span)| LintId::of(lint) == allow.lint && node_span.contains(span.source_callsite()));
+            if !fulfilled {
+                span_lint_hir_and_then(
+                    cx,
+                    UNFULFILLED_ALLOW,
+                    allow.hir_id,
+                    allow.span,
+                    &format!(
+                        "`{}` isn't emitted in the code it's allowed for",
+                        allow.lint.lint.name_lower()
+                    ),
+                    |diag| {
+                        diag.help("remove it from the attribute");
+                    },
+                );
+            }
+        }
+    }
+}
@@ -136,6 +136,7 @@ fn check_arg<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'tcx>) -> Option<(Spa
 }
 
 impl<'tcx> LateLintPass<'tcx> for UnitReturnExpectingOrd {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind {
             let arg_indices = get_args_to_check(cx, expr);
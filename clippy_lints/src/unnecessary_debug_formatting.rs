@@ -0,0 +1,173 @@
+use crate::utils::{
+    is_expn_of, is_type_diagnostic_item, is_type_std_item, match_def_path, match_function_call, paths,
+    span_lint_and_help, std_items,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Field, MatchSource, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `{:?}` formatting of strings, integers and paths in the
+    /// arguments of macros producing user-facing messages, like `println!` or `panic!`. The
+    /// macros to check can be configured with `user-facing-macros`.
+    ///
+    /// **Why is this bad?** These types implement `Display`, which is meant for users. `Debug`
+    /// output is meant for programmers: strings are quoted and escaped, and paths are shown as
+    /// quoted strings instead of with `.display()`.
+    ///
+    /// **Known problems:** The quotes of the `Debug` output are sometimes wanted, e.g. to make
+    /// leading or trailing whitespace visible. The `Debug` formatting with flags, like `{:#?}`
+    /// or `{:x?}`, isn't checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::path::Path;
+    /// # let name = "config.toml";
+    /// # let path = Path::new("/etc");
+    /// println!("reading {:?} in {:?}", name, path);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::Path;
+    /// # let name = "config.toml";
+    /// # let path = Path::new("/etc");
+    /// println!("reading {} in {}", name, path.display());
+    /// ```
+    pub UNNECESSARY_DEBUG_FORMATTING,
+    pedantic,
+    "`Debug` formatting of a type with a user-facing `Display` in a user-facing message"
+}
+
+#[derive(Clone)]
+pub struct UnnecessaryDebugFormatting {
+    macros: Vec<String>,
+}
+
+impl UnnecessaryDebugFormatting {
+    pub fn new(macros: Vec<String>) -> Self {
+        Self { macros }
+    }
+}
+
+impl_lint_pass!(UnnecessaryDebugFormatting => [UNNECESSARY_DEBUG_FORMATTING]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryDebugFormatting {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let args = match match_function_call(cx, expr, &paths::FMT_ARGUMENTS_NEW_V1)
+            .or_else(|| match_function_call(cx, expr, &paths::FMT_ARGUMENTS_NEW_V1_FORMATTED))
+        {
+            Some(args) => args,
+            None => return,
+        };
+        let (macro_name, call_site) = match self
+            .macros
+            .iter()
+            .find_map(|name| is_expn_of(expr.span, name).map(|span| (name, span)))
+        {
+            Some(found) => found,
+            None => return,
+        };
+        if in_external_macro(cx.sess(), call_site) {
+            return;
+        }
+
+        // The format specs, only passed to `new_v1_formatted`
+        let specs = match args.get(2).map(|specs| &specs.kind) {
+            Some(ExprKind::AddrOf(BorrowKind::Ref, _, specs)) => match specs.kind {
+                ExprKind::Array(specs) => specs,
+                _ => return,
+            },
+            _ => &[],
+        };
+
+        // The arguments are expanded to
+        // `&match (&a, &b) { (arg0, arg1) => [ArgumentV1::new(arg0, Debug::fmt), ..] }`
+        if_chain! {
+            if args.len() >= 2;
+            if let ExprKind::AddrOf(BorrowKind::Ref, _, ref arg_match) = args[1].kind;
+            if let ExprKind::Match(ref matchee, ref arms, MatchSource::Normal) = arg_match.kind;
+            if let [ref arm] = **arms;
+            if let ExprKind::Tup(ref values) = matchee.kind;
+            if let PatKind::Tuple(ref pats, None) = arm.pat.kind;
+            if let ExprKind::Array(ref arguments) = arm.body.kind;
+            then {
+                for (i, argument) in arguments.iter().enumerate() {
+                    if_chain! {
+                        if let Some([value, fmt]) = match_function_call(cx, argument, &paths::FMT_ARGUMENTV1_NEW);
+                        if let ExprKind::Path(ref fmt_path) = fmt.kind;
+                        if let Some(fmt_id) = cx.qpath_res(fmt_path, fmt.hir_id).opt_def_id();
+                        if match_def_path(cx, fmt_id, &paths::DEBUG_FMT_METHOD);
+                        if !has_flags(specs, i);
+                        if let ExprKind::Path(QPath::Resolved(None, ref path)) = value.kind;
+                        if let Res::Local(binding_id) = path.res;
+                        if let Some(index) = pats
+                            .iter()
+                            .position(|pat| matches!(pat.kind, PatKind::Binding(_, id, ..) if id == binding_id));
+                        if let ExprKind::AddrOf(BorrowKind::Ref, _, ref value) = values[index].kind;
+                        then {
+                            check_value(cx, macro_name, value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks if a format spec of the argument at `index` has flags, like `#` in `{:#?}` or `x` in
+/// `{:x?}`, which `{}` wouldn't keep.
+fn has_flags(specs: &[Expr<'_>], index: usize) -> bool {
+    specs.iter().any(|spec| {
+        if_chain! {
+            // struct `core::fmt::rt::v1::Argument`
+            if let ExprKind::Struct(_, ref fields, _) = spec.kind;
+            if int_field(fields, sym!(position)) == Some(index as u128);
+            if let Some(format_field) = fields.iter().find(|f| f.ident.name == sym::format);
+            // struct `core::fmt::rt::v1::FormatSpec`
+            if let ExprKind::Struct(_, ref fields, _) = format_field.expr.kind;
+            then {
+                int_field(fields, sym!(flags)).map_or(true, |flags| flags != 0)
+            } else {
+                false
+            }
+        }
+    })
+}
+
+fn int_field(fields: &[Field<'_>], name: Symbol) -> Option<u128> {
+    let field = fields.iter().find(|f| f.ident.name == name)?;
+    match field.expr.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Int(value, _) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn check_value(cx: &LateContext<'_>, macro_name: &str, value: &Expr<'_>) {
+    let ty = cx.typeck_results().expr_ty(value).peel_refs();
+    let help = match ty.kind() {
+        ty::Int(_) | ty::Uint(_) | ty::Str => "use `{}` instead of `{:?}`",
+        ty::Adt(..) if is_type_diagnostic_item(cx, ty, sym::string_type) => "use `{}` instead of `{:?}`",
+        ty::Adt(..) if is_type_std_item(cx, ty, &std_items::PATH) || is_type_std_item(cx, ty, &std_items::PATH_BUF) => {
+            "use `{}` instead of `{:?}`, and call `.display()` on the path"
+        },
+        _ => return,
+    };
+
+    span_lint_and_help(
+        cx,
+        UNNECESSARY_DEBUG_FORMATTING,
+        value.span,
+        &format!("unnecessary `Debug` formatting in `{}!` args", macro_name),
+        None,
+        help,
+    );
+}
@@ -0,0 +1,77 @@
+use crate::utils::{in_macro, span_lint_and_sugg};
+use rustc_ast::{Item, ItemKind, UseTree, UseTreeKind};
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::kw;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for imports ending in `::{self}`.
+    ///
+    /// **Why is this bad?** In most cases, this can be written much more cleanly by omitting
+    /// `::{self}`.
+    ///
+    /// **Known problems:** Removing `::{self}` will cause any non-module items at the same path to
+    /// also be imported, e.g. a function with the same name as the module. This might cause a
+    /// naming conflict.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use std::io::{self};
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::io;
+    /// ```
+    pub UNNECESSARY_SELF_IMPORTS,
+    restriction,
+    "imports ending in `::{self}`, which can be omitted"
+}
+
+declare_lint_pass!(UnnecessarySelfImports => [UNNECESSARY_SELF_IMPORTS]);
+
+impl EarlyLintPass for UnnecessarySelfImports {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if let ItemKind::Use(use_tree) = &item.kind {
+            check_use_tree(cx, use_tree);
+        }
+    }
+}
+
+/// Checks the use tree and the trees nested in it for `prefix::{self}`.
+fn check_use_tree(cx: &EarlyContext<'_>, use_tree: &UseTree) {
+    if let UseTreeKind::Nested(nested) = &use_tree.kind {
+        match nested.as_slice() {
+            [(self_tree, _)] if !use_tree.prefix.segments.is_empty() && is_self_import(self_tree) => {
+                let rename = match self_tree.kind {
+                    UseTreeKind::Simple(Some(rename), ..) => format!(" as {}", rename),
+                    _ => String::new(),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    UNNECESSARY_SELF_IMPORTS,
+                    use_tree.span.with_lo(use_tree.prefix.span.hi()),
+                    "import ending with `::{self}`",
+                    "consider omitting `::{self}`",
+                    rename,
+                    // the other items named like the module would be imported too
+                    Applicability::MaybeIncorrect,
+                );
+            },
+            _ => {
+                for (tree, _) in nested {
+                    check_use_tree(cx, tree);
+                }
+            },
+        }
+    }
+}
+
+fn is_self_import(use_tree: &UseTree) -> bool {
+    matches!(use_tree.kind, UseTreeKind::Simple(..))
+        && matches!(use_tree.prefix.segments.as_slice(), [segment] if segment.ident.name == kw::SelfLower)
+}
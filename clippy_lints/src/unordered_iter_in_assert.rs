@@ -0,0 +1,174 @@
+use crate::utils::{
+    higher, is_expn_of, is_test_fn, is_type_diagnostic_item, is_type_std_item, match_trait_method, paths,
+    span_lint_and_help, std_items,
+};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Mutability, Node, QPath, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `assert_eq!` and `assert_ne!` in tests comparing a `Vec`
+    /// collected from the iteration over a `HashMap` or a `HashSet`, without sorting it first.
+    ///
+    /// **Why is this bad?** The iteration order of `HashMap` and `HashSet` is unspecified and
+    /// changes between runs, so the test is flaky.
+    ///
+    /// **Known problems:** The `Vec` may be sorted in another function than the test, or have a
+    /// single element.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// let set: HashSet<_> = [1, 2].iter().copied().collect();
+    /// let v: Vec<_> = set.into_iter().collect();
+    /// assert_eq!(v, vec![1, 2]);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// let set: HashSet<_> = [1, 2].iter().copied().collect();
+    /// let mut v: Vec<_> = set.into_iter().collect();
+    /// v.sort_unstable();
+    /// assert_eq!(v, vec![1, 2]);
+    /// ```
+    pub UNORDERED_ITER_IN_ASSERT,
+    pedantic,
+    "asserting the order of the elements of a `HashMap` or a `HashSet` in a test"
+}
+
+declare_lint_pass!(UnorderedIterInAssert => [UNORDERED_ITER_IN_ASSERT]);
+
+/// `debug_assert_eq!` and `debug_assert_ne!` expand to these macros.
+const ASSERT_MACRO_NAMES: [&str; 2] = ["assert_eq", "assert_ne"];
+
+impl<'tcx> LateLintPass<'tcx> for UnorderedIterInAssert {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        if let ExprKind::Block(ref block, _) = e.kind {
+            for stmt in block.stmts {
+                if_chain! {
+                    if ASSERT_MACRO_NAMES.iter().any(|amn| is_expn_of(stmt.span, amn).is_some());
+                    if let StmtKind::Semi(ref matchexpr) = stmt.kind;
+                    if let Some(macro_args) = higher::extract_assert_macro_args(matchexpr);
+                    if macro_args.len() == 2;
+                    if is_test_fn(cx, cx.tcx.hir().get_parent_item(e.hir_id));
+                    then {
+                        for arg in macro_args {
+                            if let Some(collection) = unordered_collection(cx, arg) {
+                                span_lint_and_help(
+                                    cx,
+                                    UNORDERED_ITER_IN_ASSERT,
+                                    arg.span,
+                                    &format!(
+                                        "the order of this `Vec` depends on the iteration order of a `{}`",
+                                        collection
+                                    ),
+                                    None,
+                                    "sort it before the assertion, or use a `BTreeMap` or a `BTreeSet`",
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks if `expr` is a `Vec` collected from a `HashMap` or a `HashSet`, or a local initialized
+/// with one and not sorted afterwards. Returns the name of the collection type.
+fn unordered_collection(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if let Some(collection) = collected_from(cx, expr) {
+        return Some(collection);
+    }
+    if_chain! {
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.kind;
+        if let Res::Local(local_id) = path.res;
+        if let Some(Node::Local(local)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(local_id));
+        if let Some(init) = local.init;
+        if let Some(collection) = collected_from(cx, init);
+        let enclosing_body = cx.tcx.hir().body_owned_by(cx.tcx.hir().get_parent_item(local_id));
+        then {
+            let mut visitor = SortVisitor {
+                cx,
+                local_id,
+                may_be_sorted: false,
+            };
+            visitor.visit_expr(&cx.tcx.hir().body(enclosing_body).value);
+            if visitor.may_be_sorted { None } else { Some(collection) }
+        } else {
+            None
+        }
+    }
+}
+
+/// The methods iterating over a `HashMap` or a `HashSet`.
+const ITER_METHODS: [&str; 7] = ["iter", "iter_mut", "keys", "values", "values_mut", "into_iter", "drain"];
+
+/// Checks if `expr` is a `collect()` call into a `Vec`, on an iterator chain starting from a
+/// `HashMap` or a `HashSet`, like `map.keys().cloned().collect::<Vec<_>>()`.
+fn collected_from(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref method, _, ref args, _) = expr.kind;
+        if method.ident.as_str() == "collect";
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::vec_type);
+        then {
+            // the chain of `Iterator` adapters, down to the call creating the iterator
+            let mut iter = &args[0];
+            while let ExprKind::MethodCall(ref method, _, ref args, _) = iter.kind {
+                if ITER_METHODS.contains(&&*method.ident.as_str()) {
+                    let recv_ty = cx.typeck_results().expr_ty(&args[0]).peel_refs();
+                    if is_type_std_item(cx, recv_ty, &std_items::HASHMAP) {
+                        return Some("HashMap");
+                    } else if is_type_std_item(cx, recv_ty, &std_items::HASHSET) {
+                        return Some("HashSet");
+                    }
+                }
+                if !match_trait_method(cx, iter, &paths::ITERATOR) {
+                    return None;
+                }
+                iter = &args[0];
+            }
+        }
+    }
+    None
+}
+
+/// Checks if a local may be sorted, by a `sort*` method call on it or by borrowing it mutably.
+struct SortVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    may_be_sorted: bool,
+}
+
+impl<'a, 'tcx> SortVisitor<'a, 'tcx> {
+    fn is_local(&self, expr: &Expr<'_>) -> bool {
+        matches!(expr.kind, ExprKind::Path(QPath::Resolved(None, ref path)) if path.res == Res::Local(self.local_id))
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SortVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::MethodCall(ref method, _, ref args, _)
+                if method.ident.as_str().starts_with("sort") && self.is_local(&args[0]) =>
+            {
+                self.may_be_sorted = true;
+            },
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, ref inner) if self.is_local(inner) => {
+                self.may_be_sorted = true;
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
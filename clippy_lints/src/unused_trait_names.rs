@@ -0,0 +1,154 @@
+use crate::utils::{in_macro, span_lint_hir_and_then};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{Crate, Expr, ExprKind, HirId, Item, ItemKind, Node, Path, QPath, UseKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::{kw, Ident, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `use Trait` where the trait is only used for its methods and
+    /// never referred to by name.
+    ///
+    /// **Why is this bad?** Traits imported as `_` bring their methods into scope without adding
+    /// their name to the namespace of the module, so the name can't conflict with other items.
+    ///
+    /// **Known problems:** Only uses in the module of the `use` item are taken into account, so the
+    /// name may still be needed by other modules importing it with a glob, like `use super::*;`.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use std::fmt::Write;
+    ///
+    /// fn main() {
+    ///     let mut s = String::new();
+    ///     let _ = write!(s, "hello, world!");
+    ///     println!("{}", s);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt::Write as _;
+    ///
+    /// fn main() {
+    ///     let mut s = String::new();
+    ///     let _ = write!(s, "hello, world!");
+    ///     println!("{}", s);
+    /// }
+    /// ```
+    pub UNUSED_TRAIT_NAMES,
+    restriction,
+    "use items that import a trait but only use it anonymously"
+}
+
+/// A private `use` item importing a trait under a name.
+struct TraitImport {
+    hir_id: HirId,
+    module: LocalDefId,
+    trait_id: DefId,
+    ident: Ident,
+    is_renamed: bool,
+}
+
+#[derive(Default)]
+pub struct UnusedTraitNames {
+    imports: Vec<TraitImport>,
+    /// The names of traits used in paths, and the modules they're used in.
+    named: FxHashSet<(LocalDefId, Symbol, DefId)>,
+    /// The traits whose methods or associated functions are called, and the modules they're
+    /// called in.
+    method_uses: FxHashSet<(LocalDefId, DefId)>,
+}
+
+impl_lint_pass!(UnusedTraitNames => [UNUSED_TRAIT_NAMES]);
+
+impl<'tcx> LateLintPass<'tcx> for UnusedTraitNames {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if_chain! {
+            if let ItemKind::Use(path, UseKind::Single) = item.kind;
+            if let Res::Def(DefKind::Trait, trait_id) = path.res;
+            if item.ident.name != kw::Underscore;
+            if !item.vis.node.is_pub() && !item.vis.node.is_pub_restricted();
+            if !in_macro(item.span);
+            if let Some(last) = path.segments.last();
+            then {
+                self.imports.push(TraitImport {
+                    hir_id: item.hir_id,
+                    module: cx.tcx.parent_module(item.hir_id),
+                    trait_id,
+                    ident: item.ident,
+                    is_renamed: last.ident.name != item.ident.name,
+                });
+            }
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &'tcx Path<'tcx>, hir_id: HirId) {
+        // The path of the `use` item itself doesn't make use of the imported name
+        if let Some(Node::Item(Item {
+            kind: ItemKind::Use(..),
+            ..
+        })) = cx.tcx.hir().find(hir_id)
+        {
+            return;
+        }
+        if let Some(segment) = path.segments.first() {
+            if let Res::Def(DefKind::Trait, trait_id) = segment.res.unwrap_or(path.res) {
+                self.named
+                    .insert((cx.tcx.parent_module(hir_id), segment.ident.name, trait_id));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !matches!(
+            expr.kind,
+            ExprKind::MethodCall(..) | ExprKind::Path(QPath::TypeRelative(..))
+        ) {
+            return;
+        }
+        if let Some(trait_id) = cx
+            .typeck_results()
+            .type_dependent_def_id(expr.hir_id)
+            .and_then(|def_id| cx.tcx.trait_of_item(def_id))
+        {
+            self.method_uses.insert((cx.tcx.parent_module(expr.hir_id), trait_id));
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        for import in &self.imports {
+            let is_named = self
+                .named
+                .contains(&(import.module, import.ident.name, import.trait_id));
+            if is_named || !self.method_uses.contains(&(import.module, import.trait_id)) {
+                continue;
+            }
+
+            span_lint_hir_and_then(
+                cx,
+                UNUSED_TRAIT_NAMES,
+                import.hir_id,
+                import.ident.span,
+                "importing trait that is only used anonymously",
+                |diag| {
+                    let sugg = if import.is_renamed {
+                        "_".to_string()
+                    } else {
+                        format!("{} as _", import.ident)
+                    };
+                    diag.span_suggestion(
+                        import.ident.span,
+                        "import the trait as `_`",
+                        sugg,
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
@@ -1,14 +1,21 @@
-use crate::utils::{is_type_diagnostic_item, method_chain_args, return_ty, span_lint_and_then};
-use if_chain::if_chain;
+use crate::utils::{
+    get_trait_def_id, implements_trait, is_test_fn, is_type_diagnostic_item, method_chain_args, paths, return_ty,
+    snippet, span_lint_and_then,
+};
+use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
+use rustc_hir::{BodyId, Expr, HirId};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_middle::ty;
+use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for functions of type Result that contain `expect()` or `unwrap()`
+    /// **What it does:** Checks for functions of type Result that contain `expect()` or `unwrap()`,
+    /// except for tests. It suggests the `?` operator where the error can be converted to the
+    /// error type of the function.
     ///
     /// **Why is this bad?** These functions promote recoverable errors to non-recoverable errors which may be undesirable in code bases which wish to avoid panics.
     ///
@@ -52,50 +59,51 @@ declare_clippy_lint! {
 declare_lint_pass!(UnwrapInResult=> [UNWRAP_IN_RESULT]);
 
 impl<'tcx> LateLintPass<'tcx> for UnwrapInResult {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
+        if let hir::ItemKind::Fn(_, _, body_id) = item.kind {
+            check_fn(cx, item.span, item.hir_id, body_id);
+        }
+    }
+
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx hir::ImplItem<'_>) {
-        if_chain! {
-            // first check if it's a method or function
-            if let hir::ImplItemKind::Fn(ref _signature, _) = impl_item.kind;
-            // checking if its return type is `result` or `option`
-            if is_type_diagnostic_item(cx, return_ty(cx, impl_item.hir_id), sym::result_type)
-                || is_type_diagnostic_item(cx, return_ty(cx, impl_item.hir_id), sym::option_type);
-            then {
-                lint_impl_body(cx, impl_item.span, impl_item);
-            }
+        if let hir::ImplItemKind::Fn(_, body_id) = impl_item.kind {
+            check_fn(cx, impl_item.span, impl_item.hir_id, body_id);
         }
     }
 }
 
-use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
-use rustc_hir::{Expr, ImplItemKind};
+fn check_fn(cx: &LateContext<'_>, span: Span, hir_id: HirId, body_id: BodyId) {
+    // checking if its return type is `result` or `option`
+    let ret_ty = return_ty(cx, hir_id);
+    if (is_type_diagnostic_item(cx, ret_ty, sym::result_type) || is_type_diagnostic_item(cx, ret_ty, sym::option_type))
+        && !is_test_fn(cx, hir_id)
+    {
+        lint_fn_body(cx, span, hir_id, body_id, ret_ty);
+    }
+}
 
 struct FindExpectUnwrap<'a, 'tcx> {
     lcx: &'a LateContext<'tcx>,
     typeck_results: &'tcx ty::TypeckResults<'tcx>,
     result: Vec<Span>,
+    /// The calls with their receivers
+    calls: Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for FindExpectUnwrap<'a, 'tcx> {
     type Map = Map<'tcx>;
 
-    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-        // check for `expect`
-        if let Some(arglists) = method_chain_args(expr, &["expect"]) {
-            let reciever_ty = self.typeck_results.expr_ty(&arglists[0][0]).peel_refs();
-            if is_type_diagnostic_item(self.lcx, reciever_ty, sym::option_type)
-                || is_type_diagnostic_item(self.lcx, reciever_ty, sym::result_type)
-            {
-                self.result.push(expr.span);
-            }
-        }
-
-        // check for `unwrap`
-        if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-            let reciever_ty = self.typeck_results.expr_ty(&arglists[0][0]).peel_refs();
-            if is_type_diagnostic_item(self.lcx, reciever_ty, sym::option_type)
-                || is_type_diagnostic_item(self.lcx, reciever_ty, sym::result_type)
-            {
-                self.result.push(expr.span);
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        // check for `expect` and `unwrap`
+        for method in &["expect", "unwrap"] {
+            if let Some(arglists) = method_chain_args(expr, &[*method]) {
+                let reciever_ty = self.typeck_results.expr_ty(&arglists[0][0]).peel_refs();
+                if is_type_diagnostic_item(self.lcx, reciever_ty, sym::option_type)
+                    || is_type_diagnostic_item(self.lcx, reciever_ty, sym::result_type)
+                {
+                    self.result.push(expr.span);
+                    self.calls.push((expr, &arglists[0][0]));
+                }
             }
         }
 
@@ -108,33 +116,60 @@ impl<'a, 'tcx> Visitor<'tcx> for FindExpectUnwrap<'a, 'tcx> {
     }
 }
 
-fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, impl_item: &'tcx hir::ImplItem<'_>) {
-    if_chain! {
+/// Checks if `receiver?` works in a function returning `ret_ty`, i.e. if both are options, or
+/// both are results and the error of the receiver converts to the error of the function.
+fn can_propagate<'tcx>(cx: &LateContext<'tcx>, receiver_ty: Ty<'tcx>, ret_ty: Ty<'tcx>) -> bool {
+    match (receiver_ty.kind(), ret_ty.kind()) {
+        (ty::Adt(_, receiver_substs), ty::Adt(_, ret_substs))
+            if is_type_diagnostic_item(cx, receiver_ty, sym::result_type)
+                && is_type_diagnostic_item(cx, ret_ty, sym::result_type) =>
+        {
+            let (receiver_err, ret_err) = (receiver_substs.type_at(1), ret_substs.type_at(1));
+            ty::TyS::same_type(receiver_err, ret_err)
+                || get_trait_def_id(cx, &paths::FROM_TRAIT).map_or(false, |from_trait| {
+                    implements_trait(cx, ret_err, from_trait, &[receiver_err.into()])
+                })
+        },
+        _ => {
+            is_type_diagnostic_item(cx, receiver_ty, sym::option_type)
+                && is_type_diagnostic_item(cx, ret_ty, sym::option_type)
+        },
+    }
+}
 
-        if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
-        then {
-            let body = cx.tcx.hir().body(body_id);
-            let impl_item_def_id = cx.tcx.hir().local_def_id(impl_item.hir_id);
-            let mut fpu = FindExpectUnwrap {
-                lcx: cx,
-                typeck_results: cx.tcx.typeck(impl_item_def_id),
-                result: Vec::new(),
-            };
-            fpu.visit_expr(&body.value);
+fn lint_fn_body<'tcx>(cx: &LateContext<'tcx>, fn_span: Span, hir_id: HirId, body_id: BodyId, ret_ty: Ty<'tcx>) {
+    let body = cx.tcx.hir().body(body_id);
+    let typeck_results = cx.tcx.typeck(cx.tcx.hir().local_def_id(hir_id));
+    let mut fpu = FindExpectUnwrap {
+        lcx: cx,
+        typeck_results,
+        result: Vec::new(),
+        calls: Vec::new(),
+    };
+    fpu.visit_expr(&body.value);
 
-            // if we've found one, lint
-            if  !fpu.result.is_empty()  {
-                span_lint_and_then(
-                    cx,
-                    UNWRAP_IN_RESULT,
-                    impl_span,
-                    "used unwrap or expect in a function that returns result or option",
-                    move |diag| {
-                        diag.help(
-                            "unwrap and expect should not be used in a function that returns result or option" );
-                        diag.span_note(fpu.result, "potential non-recoverable error(s)");
-                    });
-            }
-        }
+    // if we've found one, lint
+    if !fpu.result.is_empty() {
+        span_lint_and_then(
+            cx,
+            UNWRAP_IN_RESULT,
+            fn_span,
+            "used unwrap or expect in a function that returns result or option",
+            move |diag| {
+                diag.help("unwrap and expect should not be used in a function that returns result or option");
+                diag.span_note(fpu.result, "potential non-recoverable error(s)");
+                for (call, receiver) in fpu.calls {
+                    // only direct receivers, not references
+                    if can_propagate(cx, typeck_results.expr_ty(receiver), ret_ty) {
+                        diag.span_suggestion(
+                            call.span,
+                            "use `?` to return the error to the caller instead of panicking",
+                            format!("{}?", snippet(cx, receiver.span, "..")),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                }
+            },
+        );
     }
 }
@@ -1,6 +1,7 @@
 use rustc_ast::ast;
 use rustc_errors::Applicability;
 use rustc_session::Session;
+use rustc_span::sym;
 use std::str::FromStr;
 
 /// Deprecation status of attributes known by Clippy.
@@ -147,3 +148,13 @@ pub fn get_unique_inner_attr(sess: &Session, attrs: &[ast::Attribute], name: &'s
 pub fn is_proc_macro(sess: &Session, attrs: &[ast::Attribute]) -> bool {
     attrs.iter().any(|attr| sess.is_proc_macro_attr(attr))
 }
+
+/// Checks if the attributes contain `#[cfg(test)]`.
+pub fn is_cfg_test(attrs: &[ast::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.has_name(sym::cfg)
+            && attr
+                .meta_item_list()
+                .map_or(false, |list| list.iter().any(|meta| meta.has_name(sym::test)))
+    })
+}
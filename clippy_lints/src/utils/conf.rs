@@ -3,8 +3,14 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use rustc_ast::ast::{LitKind, MetaItemKind, NestedMetaItem};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_session::Session;
+use rustc_span::lev_distance::find_best_match_for_name;
 use rustc_span::source_map;
+use rustc_span::symbol::Symbol;
+use serde::{Deserialize, Serialize};
 use source_map::Span;
+use std::collections::BTreeMap;
 use std::lazy::SyncLazy;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
@@ -37,6 +43,41 @@ pub enum Error {
     Io(io::Error),
     /// Not valid toml or doesn't fit the expected config format
     Toml(String),
+    /// A key which is not a configuration option.
+    UnknownKey {
+        /// The unknown key.
+        key: String,
+        /// A known key with a similar name.
+        suggestion: Option<String>,
+    },
+    /// A value which doesn't have the type of the option.
+    TypeMismatch {
+        /// The key of the option.
+        key: String,
+        /// The type of the option.
+        expected: &'static str,
+        /// The deserialization error.
+        error: String,
+    },
+    /// A key other than `allow` in a configuration file of a subdirectory of the crate.
+    NestedKey(String),
+}
+
+impl Error {
+    /// Returns a help message for the error, if there is one.
+    pub fn help(&self) -> Option<String> {
+        match self {
+            Self::UnknownKey {
+                suggestion: Some(suggestion),
+                ..
+            } => Some(format!("did you mean `{}`?", suggestion)),
+            Self::TypeMismatch { key, expected, .. } => {
+                Some(format!("`{}` expects a value of type `{}`", key, expected))
+            },
+            Self::NestedKey(_) => Some("set it in the configuration file of the crate or workspace".to_string()),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -44,6 +85,20 @@ impl fmt::Display for Error {
         match self {
             Self::Io(err) => err.fmt(f),
             Self::Toml(err) => err.fmt(f),
+            Self::UnknownKey {
+                key,
+                suggestion: Some(_),
+            } => write!(f, "unknown field `{}`", key),
+            Self::UnknownKey { key, suggestion: None } => {
+                let known_keys: Vec<String> = known_keys().map(|key| format!("`{}`", key)).collect();
+                write!(f, "unknown field `{}`, expected one of {}", key, known_keys.join(", "))
+            },
+            Self::TypeMismatch { key, error, .. } => write!(f, "invalid value for `{}`: {}", key, error),
+            Self::NestedKey(key) => write!(
+                f,
+                "`{}` can't be set in the configuration file of a subdirectory, only `allow` can",
+                key
+            ),
         }
     }
 }
@@ -54,31 +109,75 @@ impl From<io::Error> for Error {
     }
 }
 
+/// A path in the `disallowed-*` options, e.g. `"std::env::var"`, or
+/// `{ path = "std::env::var", reason = "use the `Config` instead" }` to show the reason in the
+/// diagnostics.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DisallowedPath {
+    /// The path alone.
+    Simple(String),
+    /// The path with the reason why the item is disallowed.
+    WithReason {
+        /// The path of the item.
+        path: String,
+        /// The reason shown in the diagnostics.
+        reason: Option<String>,
+    },
+}
+
+impl DisallowedPath {
+    /// Returns the `::`-separated segments of the path.
+    pub fn segments(&self) -> Vec<Symbol> {
+        let path = match self {
+            Self::Simple(path) | Self::WithReason { path, .. } => path,
+        };
+        path.split("::").map(Symbol::intern).collect()
+    }
+
+    /// Returns the reason why the item is disallowed, if there is one.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+}
+
 /// Vec of errors that might be collected during config toml parsing
 static ERRORS: SyncLazy<Mutex<Vec<Error>>> = SyncLazy::new(|| Mutex::new(Vec::new()));
 
 macro_rules! define_Conf {
-    ($(#[$doc:meta] ($config:ident, $config_str:literal: $Ty:ty, $default:expr),)+) => {
+    ($(#[doc = $doc:literal] ($config:ident, $config_str:literal: $Ty:ty, $default:expr),)+) => {
+        /// The names of the configuration options together with their documentation.
+        const CONF_KEYS: &[(&str, &str)] = &[$(($config_str, $doc),)+];
+
         mod helpers {
-            use serde::Deserialize;
+            use super::DisallowedPath;
+            use serde::{Deserialize, Serialize};
             /// Type used to store lint configuration.
-            #[derive(Deserialize)]
-            #[serde(rename_all = "kebab-case", deny_unknown_fields)]
+            #[derive(Deserialize, Serialize)]
+            #[serde(rename_all = "kebab-case")]
             pub struct Conf {
                 $(
-                    #[$doc]
+                    #[doc = $doc]
                     #[serde(default = $config_str)]
                     #[serde(with = $config_str)]
                     pub $config: $Ty,
                 )+
                 #[allow(dead_code)]
-                #[serde(default)]
+                #[serde(default, skip_serializing)]
                 third_party: Option<::toml::Value>,
+                /// The keys set in the configuration files, together with the file setting them.
+                #[serde(skip)]
+                pub set_keys: Vec<(String, ::std::path::PathBuf)>,
             }
 
             $(
                 mod $config {
-                    use serde::Deserialize;
+                    #[allow(unused_imports)]
+                    use super::DisallowedPath;
+                    use serde::{Deserialize, Serialize};
                     pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<$Ty, D::Error> {
                         use super::super::{ERRORS, Error};
 
@@ -87,11 +186,19 @@ macro_rules! define_Conf {
                                 ERRORS
                                     .lock()
                                     .expect("no threading here")
-                                    .push(Error::Toml(e.to_string()));
+                                    .push(Error::TypeMismatch {
+                                        key: $config_str.replace('_', "-"),
+                                        expected: stringify!($Ty),
+                                        error: e.to_string(),
+                                    });
                                 super::$config()
                             })
                         )
                     }
+
+                    pub fn serialize<S: serde::Serializer>(value: &$Ty, serializer: S) -> Result<S::Ok, S::Error> {
+                        value.serialize(serializer)
+                    }
                 }
 
                 #[must_use]
@@ -106,7 +213,7 @@ macro_rules! define_Conf {
 
 pub use self::helpers::Conf;
 define_Conf! {
-    /// Lint: MANUAL_NON_EXHAUSTIVE, MANUAL_STRIP, OPTION_AS_REF_DEREF, MATCH_LIKE_MATCHES_MACRO. The minimum rust version that the project supports
+    /// Lint: MANUAL_NON_EXHAUSTIVE, MANUAL_STRIP, OPTION_AS_REF_DEREF, MATCH_LIKE_MATCHES_MACRO, MISSING_CONST_FOR_FN, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, MEM_REPLACE_WITH_DEFAULT, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, USE_SELF, ONCE_CELL_LAZY, SPIN_LOOP_WITHOUT_HINT. The minimum rust version that the project supports
     (msrv, "msrv": Option<String>, None),
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about. NB: `bar` is not here since it has legitimate uses
     (blacklisted_names, "blacklisted_names": Vec<String>, ["foo", "baz", "quux"].iter().map(ToString::to_string).collect()),
@@ -168,8 +275,72 @@ define_Conf! {
     (max_fn_params_bools, "max_fn_params_bools": u64, 3),
     /// Lint: WILDCARD_IMPORTS. Whether to allow certain wildcard imports (prelude, super in tests).
     (warn_on_all_wildcard_imports, "warn_on_all_wildcard_imports": bool, false),
-    /// Lint: DISALLOWED_METHOD. The list of blacklisted methods to lint about. NB: `bar` is not here since it has legitimate uses
-    (disallowed_methods, "disallowed_methods": Vec<String>, Vec::<String>::new()),
+    /// Lint: DISALLOWED_METHOD. The paths where the disallowed methods are defined, e.g. `regex::re_unicode::Regex::is_match`, or `{ path = "..", reason = ".." }` tables to show a reason
+    (disallowed_methods, "disallowed_methods": Vec<DisallowedPath>, Vec::<DisallowedPath>::new()),
+    /// Lint: DISALLOWED_FUNCTION. The paths where the disallowed functions are defined, e.g. `std::env::var`, or `{ path = "..", reason = ".." }` tables to show a reason
+    (disallowed_functions, "disallowed_functions": Vec<DisallowedPath>, Vec::<DisallowedPath>::new()),
+    /// Lint: DISALLOWED_TYPE. The paths where the disallowed types are defined, e.g. `std::collections::hash::map::HashMap`, or `{ path = "..", reason = ".." }` tables to show a reason
+    (disallowed_types, "disallowed_types": Vec<DisallowedPath>, Vec::<DisallowedPath>::new()),
+    /// Lint: DISALLOWED_MACRO. The paths of the disallowed macros, e.g. `std::dbg`, or `{ path = "..", reason = ".." }` tables to show a reason
+    (disallowed_macros, "disallowed_macros": Vec<DisallowedPath>, Vec::<DisallowedPath>::new()),
+    /// Lint: MISSING_INLINE_IN_PUBLIC_ITEMS. The maximum number of statements a function can have to be linted for a missing `#[inline]`
+    (missing_inline_max_statements, "missing_inline_max_statements": u64, 50),
+    /// Lint: MULTIPLE_CRATE_VERSIONS. The list of crates that are allowed to be used in multiple versions
+    (allowed_duplicate_crates, "allowed_duplicate_crates": Vec<String>, Vec::<String>::new()),
+    /// Lint: NEGATIVE_FEATURE_NAMES, REDUNDANT_FEATURE_NAMES. The list of feature names that are allowed to have a negative or redundant prefix
+    (allowed_feature_names, "allowed_feature_names": Vec<String>, Vec::<String>::new()),
+    /// Lint: NESTED_CONTAINERS. The maximum number of nested `Result`s, e.g. `Result<Result<T, E>, E>` is nested 2 levels deep
+    (max_result_nesting, "max_result_nesting": u64, 1),
+    /// Lint: NESTED_CONTAINERS. The maximum number of nested `Vec`s, e.g. `Vec<Vec<Vec<T>>>` is nested 3 levels deep
+    (max_vec_nesting, "max_vec_nesting": u64, 2),
+    /// Lint: MUTABLE_KEY_TYPE, DECLARE_INTERIOR_MUTABLE_CONST, BORROW_INTERIOR_MUTABLE_CONST. The list of paths to types with interior mutability which should be treated as immutable, e.g. `Hash` implementations which don't use the interior mutable parts or lazily initialized values like `once_cell::sync::Lazy`
+    (ignore_interior_mutability, "ignore_interior_mutability": Vec<String>, ["bytes::Bytes"].iter().map(ToString::to_string).collect()),
+    /// Lint: ERROR_IMPL_ERROR. The maximum number of exported types named `Error` implementing `std::error::Error` a crate can have, set it to 0 to lint all of them
+    (max_exported_error_types, "max_exported_error_types": u64, 1),
+    /// Lint: EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS. The names or paths of exported types which are allowed to be exhaustive, e.g. `Color` or `style::Color`
+    (allowed_exhaustive_types, "allowed_exhaustive_types": Vec<String>, Vec::<String>::new()),
+    /// Lint: ARBITRARY_SOURCE_ITEM_ORDERING. The order of the kinds of items in a module, kinds which aren't listed can be placed anywhere
+    (module_item_order, "module_item_order": Vec<String>, ["extern_crate", "mod", "use", "macro", "const", "static", "type", "struct", "enum", "union", "trait", "trait_alias", "foreign_mod", "impl", "fn"].iter().map(ToString::to_string).collect()),
+    /// Lint: ARBITRARY_SOURCE_ITEM_ORDERING. The kinds of items whose members are sorted alphabetically, out of `enum` (variants), `struct` and `union` (fields)
+    (alphabetical_ordering, "alphabetical_ordering": Vec<String>, Vec::<String>::new()),
+    /// Lint: MODULE_NAME_REPETITIONS. The words an item name may start with when the rest of it is the name of its module, e.g. `to` allows `to_cake` in `mod cake`
+    (allowed_prefixes, "allowed_prefixes": Vec<String>, ["to", "as", "into", "from", "try_into", "try_from"].iter().map(ToString::to_string).collect()),
+    /// Lint: MODULE_NAME_REPETITIONS. The words an item name may end with when the rest of it is the name of its module, e.g. `error` allows `CakeError` in `mod cake`
+    (allowed_suffixes, "allowed_suffixes": Vec<String>, Vec::<String>::new()),
+    /// Lint: INFINITE_ITER, MAYBE_INFINITE_ITER. The paths of iterator types which are infinite, and of functions or methods which return infinite iterators, e.g. `my_mod::Counter` or `itertools::repeat_n`
+    (infinite_iterators, "infinite_iterators": Vec<String>, Vec::<String>::new()),
+    /// Lint: MAYBE_INFINITE_ITER. Whether iterators from `iter::successors` and `iter::from_fn` whose closures may return `None` are considered possibly infinite
+    (maybe_infinite_iter_sources, "maybe_infinite_iter_sources": bool, true),
+    /// Lint: AS_CONVERSIONS. The kinds of `as` conversions which are allowed: `lossless`, `index`, `pointer` and `enum`
+    (allowed_as_conversions, "allowed_as_conversions": Vec<String>, Vec::<String>::new()),
+    /// Lint: UNNECESSARY_DEBUG_FORMATTING. The macros whose output is shown to users
+    (user_facing_macros, "user_facing_macros": Vec<String>, ["print", "println", "eprint", "eprintln", "format", "panic"].iter().map(ToString::to_string).collect()),
+    /// Lint: LITERAL_STRING_WITH_FORMATTING_ARGS. The paths of functions and methods taking template strings, whose literal arguments are not checked
+    (template_functions, "template_functions": Vec<String>, Vec::<String>::new()),
+    /// Lint: LITERAL_STRING_WITH_FORMATTING_ARGS. Whether to check for named placeholders like `{name}`
+    (check_named_placeholders, "check_named_placeholders": bool, true),
+    /// Lint: MAP_ERR_IGNORE. The paths of error types which may be discarded by `map_err(|_| ..)`, e.g. `std::sync::PoisonError`
+    (map_err_ignore_allowed_types, "map_err_ignore_allowed_types": Vec<String>, Vec::<String>::new()),
+    /// Lint: PRINT_LITERAL, USE_DEBUG. The paths of third-party macros taking a format string and its arguments, like `log::info`
+    (format_macro_paths, "format_macro_paths": Vec<String>, Vec::<String>::new()),
+    /// Lint: PRINT_STDOUT. The logging macro to suggest instead of printing, like `tracing::info`
+    (logging_macro, "logging_macro": Option<String>, None),
+    /// Lint: UNTRACKED_TODO. The regex matching references to tracking issues, which `todo!` and `unimplemented!` calls must have
+    (todo_tracking_regex, "todo_tracking_regex": String, r"TODO\(#\d+\)".to_string()),
+    /// Lint: REACHABLE_PANIC. How many levels of calls to follow from exported functions to find panics
+    (reachable_panic_depth, "reachable_panic_depth": u64, 3),
+    /// Lint: LARGE_ERROR_VARIANT. The maximum size of the error type of a returned `Result`, in bytes
+    (large_error_threshold, "large_error_threshold": u64, 128),
+    /// Lint: BLOCKING_CALL_IN_ASYNC. The paths of more functions or methods which block the thread, e.g. `reqwest::blocking::get` or `std::thread::JoinHandle::join`
+    (blocking_call_paths, "blocking_call_paths": Vec<String>, Vec::<String>::new()),
+    /// Lint: SIGNIFICANT_DROP_IN_SCRUTINEE. The paths of more types whose `Drop` has a significant effect, like releasing a lock, e.g. `parking_lot::lock_api::MutexGuard`
+    (significant_drop_types, "significant_drop_types": Vec<String>, Vec::<String>::new()),
+    /// The Clippy lints which are allowed in the files of the crate, e.g. `["unwrap_used"]`. The configuration files in the subdirectories of the crate can allow more lints for the files below them
+    (allow, "allow": Vec<String>, Vec::<String>::new()),
+    /// The Clippy lints which are allowed in the `#[test]` functions and the `#[cfg(test)]` modules, e.g. `["unwrap_used", "expect_used"]`
+    (allow_in_tests, "allow_in_tests": Vec<String>, Vec::<String>::new()),
+    /// Whether to run the unstable lints, which are still experimental, like `only_used_in_recursion`. The same as `cargo clippy --unstable-lints`
+    (enable_unstable_lints, "enable_unstable_lints": bool, false),
 }
 
 impl Default for Conf {
@@ -179,74 +350,310 @@ impl Default for Conf {
     }
 }
 
-/// Search for the configuration file.
-pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+/// Possible filename to search for.
+const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
 
-    // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
-    // If neither of those exist, use ".".
-    let mut current = env::var_os("CLIPPY_CONF_DIR")
-        .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
-        .map_or_else(|| PathBuf::from("."), PathBuf::from);
+/// Search for the configuration files.
+///
+/// The search starts in `CLIPPY_CONF_DIR`, `CARGO_MANIFEST_DIR` or the current directory and
+/// walks up to the workspace root, the first directory whose `Cargo.toml` has a `[workspace]`
+/// table. Every configuration file found on the way is returned, starting with the one of the
+/// workspace root, so that the files closer to the crate take precedence. Outside of a workspace
+/// only the closest configuration file is returned.
+pub fn lookup_conf_files() -> io::Result<Vec<PathBuf>> {
+    let mut current = conf_dir();
+    let mut files = Vec::new();
     loop {
-        for config_file_name in &CONFIG_FILE_NAMES {
-            let config_file = current.join(config_file_name);
-            match fs::metadata(&config_file) {
-                // Only return if it's a file to handle the unlikely situation of a directory named
-                // `clippy.toml`.
-                Ok(ref md) if !md.is_dir() => return Ok(Some(config_file)),
-                // Return the error if it's something other than `NotFound`; otherwise we didn't
-                // find the project file yet, and continue searching.
-                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
-                _ => {},
-            }
+        if let Some(config_file) = conf_file_in(&current)? {
+            files.push(config_file);
+        }
+
+        if is_workspace_root(&current) {
+            files.reverse();
+            return Ok(files);
         }
 
         // If the current directory has no parent, we're done searching.
         if !current.pop() {
-            return Ok(None);
+            files.truncate(1);
+            return Ok(files);
         }
     }
 }
 
-/// Produces a `Conf` filled with the default values and forwards the errors
-///
-/// Used internally for convenience
-fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
-    (Conf::default(), errors)
+/// Returns the directory the search for the configuration files starts in, `CLIPPY_CONF_DIR`,
+/// `CARGO_MANIFEST_DIR` or the current directory.
+fn conf_dir() -> PathBuf {
+    env::var_os("CLIPPY_CONF_DIR")
+        .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
+        .map_or_else(|| PathBuf::from("."), PathBuf::from)
+}
+
+/// Returns the configuration file in `dir`, if there is one.
+fn conf_file_in(dir: &Path) -> io::Result<Option<PathBuf>> {
+    for config_file_name in &CONFIG_FILE_NAMES {
+        let config_file = dir.join(config_file_name);
+        match fs::metadata(&config_file) {
+            // Only return if it's a file to handle the unlikely situation of a directory named
+            // `clippy.toml`.
+            Ok(ref md) if !md.is_dir() => return Ok(Some(config_file)),
+            // Return the error if it's something other than `NotFound`; otherwise we didn't
+            // find the project file yet, and continue searching.
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {},
+        }
+    }
+
+    Ok(None)
+}
+
+/// Checks if `dir` contains a `Cargo.toml` with a `[workspace]` table.
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|content| content.parse::<toml::Value>().ok())
+        .map_or(false, |manifest| manifest.get("workspace").is_some())
+}
+
+/// The keys of the configuration options, in kebab-case.
+fn known_keys() -> impl Iterator<Item = String> {
+    CONF_KEYS
+        .iter()
+        .map(|(key, _)| key.replace('_', "-"))
+        .chain(Some("third-party".to_string()))
+}
+
+/// Returns the names of the lints using the configuration option `key`, e.g. `BLACKLISTED_NAME`
+/// for `blacklisted-names`.
+pub fn lints_using_key(key: &str) -> Vec<&'static str> {
+    CONF_KEYS
+        .iter()
+        .find(|(name, _)| name.replace('_', "-") == key)
+        .and_then(|(_, doc)| doc.trim().strip_prefix("Lint: "))
+        .and_then(|doc| doc.split('.').next())
+        .map_or_else(Vec::new, |lints| lints.split(',').map(str::trim).collect())
 }
 
-/// Read the `toml` configuration file.
+/// Read the `toml` configuration files and merge them, the keys of later files override the
+/// ones of earlier files. The errors are returned together with the file they were found in.
 ///
-/// In case of error, the function tries to continue as much as possible.
-pub fn read(path: &Path) -> (Conf, Vec<Error>) {
-    let content = match fs::read_to_string(path) {
-        Ok(content) => content,
-        Err(err) => return default(vec![err.into()]),
+/// In case of error, the function tries to continue as much as possible. Files which can't be
+/// parsed and keys which are unknown or have an invalid value are ignored.
+pub fn read(paths: &[PathBuf]) -> (Conf, Vec<(PathBuf, Error)>) {
+    let mut merged = toml::value::Table::new();
+    let mut set_keys = BTreeMap::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) => {
+                errors.push((path.clone(), err.into()));
+                continue;
+            },
+        };
+
+        // Each file is checked on its own, so that errors are reported for the file containing them
+        let (table, file_errors) = parse(&content);
+        errors.extend(file_errors.into_iter().map(|err| (path.clone(), err)));
+        if let Some(table) = table {
+            for key in table.keys() {
+                set_keys.insert(key.clone(), path.clone());
+            }
+            merged.extend(table);
+        }
+    }
+
+    // The errors of the merged table were already reported for the individual files
+    let mut conf: Conf = toml::Value::Table(merged).try_into().unwrap_or_default();
+    ERRORS.lock().expect("no threading -> mutex always safe").clear();
+    conf.set_keys = set_keys.into_iter().collect();
+
+    (conf, errors)
+}
+
+/// Parses the content of a single configuration file. Unknown keys and keys with an invalid
+/// value are reported and removed from the returned table.
+fn parse(content: &str) -> (Option<toml::value::Table>, Vec<Error>) {
+    let mut table = match toml::from_str::<toml::value::Table>(content) {
+        Ok(table) => table,
+        Err(e) => return (None, vec![Error::Toml(e.to_string())]),
     };
 
+    let known_keys: Vec<String> = known_keys().collect();
+    let unknown_keys: Vec<String> = table.keys().filter(|key| !known_keys.contains(key)).cloned().collect();
+    let mut errors = Vec::new();
+    for key in unknown_keys {
+        table.remove(&key);
+        let known_keys: Vec<Symbol> = known_keys.iter().map(|key| Symbol::intern(key)).collect();
+        let suggestion = find_best_match_for_name(&known_keys, Symbol::intern(&key), None).map(|s| s.to_string());
+        errors.push(Error::UnknownKey { key, suggestion });
+    }
+
     assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
-    match toml::from_str(&content) {
-        Ok(toml) => {
-            let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
+    let conf = toml::Value::Table(table.clone()).try_into::<Conf>();
+    for error in ERRORS.lock().expect("no threading -> mutex always safe").split_off(0) {
+        if let Error::TypeMismatch { ref key, .. } = error {
+            table.remove(key);
+        }
+        errors.push(error);
+    }
+    if let Err(e) = conf {
+        errors.push(Error::Toml(e.to_string()));
+        return (None, errors);
+    }
 
-            let toml_ref: &Conf = &toml;
+    if table.contains_key("cyclomatic-complexity-threshold") {
+        let cyc_err = "found deprecated field `cyclomatic-complexity-threshold`. Please use `cognitive-complexity-threshold` instead.".to_string();
+        errors.push(Error::Toml(cyc_err));
+    }
 
-            let cyc_field: Option<u64> = toml_ref.cyclomatic_complexity_threshold;
+    (Some(table), errors)
+}
 
-            if cyc_field.is_some() {
-                let cyc_err = "found deprecated field `cyclomatic-complexity-threshold`. Please use `cognitive-complexity-threshold` instead.".to_string();
-                errors.push(Error::Toml(cyc_err));
-            }
+/// Reports an error of the configuration file `file_name`.
+pub fn report_error(sess: &Session, file_name: &Path, error: &Error) {
+    let mut diag = sess.struct_err(&format!(
+        "error reading Clippy's configuration file `{}`: {}",
+        file_name.display(),
+        error
+    ));
+    if let Some(help) = error.help() {
+        diag.help(&help);
+    }
+    diag.emit();
+}
+
+/// The lints allowed in the directories of the crate, see `is_allowed_in_file`.
+static NESTED_CONFS: SyncLazy<Mutex<NestedConfs>> = SyncLazy::new(|| Mutex::new(NestedConfs::default()));
+
+/// The lints allowed by the configuration of the crate and the configuration files of its
+/// subdirectories.
+#[derive(Default)]
+struct NestedConfs {
+    /// The directory the configuration of the crate was searched in, the configuration files of
+    /// the subdirectories are searched below it.
+    root: PathBuf,
+    /// The lints allowed by the configuration of the crate.
+    allow: Vec<String>,
+    /// The lints allowed in the test code by the configuration of the crate.
+    allow_in_tests: Vec<String>,
+    /// The lints allowed in the directories searched so far, including the ones allowed by the
+    /// parent directories.
+    dirs: FxHashMap<PathBuf, Vec<String>>,
+}
 
-            (toml, errors)
+/// Sets the lints allowed by the configuration of the crate, everywhere and in the test code.
+/// The configuration files of its subdirectories are read later by `is_allowed_in_file`, when a
+/// lint is emitted in them.
+pub fn init_nested(allow: &[String], allow_in_tests: &[String]) {
+    *NESTED_CONFS.lock().expect("no threading -> mutex always safe") = NestedConfs {
+        root: absolute(conf_dir()),
+        allow: allow.iter().map(|name| normalize_lint_name(name)).collect(),
+        allow_in_tests: allow_in_tests.iter().map(|name| normalize_lint_name(name)).collect(),
+        dirs: FxHashMap::default(),
+    };
+}
+
+/// Checks if the lint, without its `clippy::` prefix, is allowed in the test code by the
+/// `allow-in-tests` key of the configuration of the crate.
+pub fn is_allowed_in_tests(name: &str) -> bool {
+    NESTED_CONFS
+        .lock()
+        .expect("no threading -> mutex always safe")
+        .allow_in_tests
+        .iter()
+        .any(|allowed| allowed == name)
+}
+
+/// Checks if the lint, without its `clippy::` prefix, is allowed in `file`.
+///
+/// The configuration files in the directories between the crate and `file` inherit the lints
+/// allowed by the configuration of the crate and their parent directories, and can only add to
+/// them. The errors of the configuration files read for the first time are returned too.
+pub fn is_allowed_in_file(file: Option<&Path>, name: &str) -> (bool, Vec<(PathBuf, Error)>) {
+    let mut nested = NESTED_CONFS.lock().expect("no threading -> mutex always safe");
+    let mut errors = Vec::new();
+    let relative = file.and_then(|file| {
+        let file = absolute(file.to_path_buf());
+        file.parent()
+            .and_then(|dir| dir.strip_prefix(&nested.root).ok())
+            .map(Path::to_path_buf)
+    });
+
+    let mut dir = nested.root.clone();
+    for component in relative.iter().flat_map(|relative| relative.components()) {
+        let parent = dir.clone();
+        dir.push(component);
+        if nested.dirs.contains_key(&dir) {
+            continue;
+        }
+
+        let mut allow = nested.dirs.get(&parent).unwrap_or(&nested.allow).clone();
+        match conf_file_in(&dir) {
+            Ok(Some(file_name)) => allow.extend(read_nested(&file_name, &mut errors)),
+            Ok(None) => {},
+            Err(error) => errors.push((dir.clone(), error.into())),
+        }
+        nested.dirs.insert(dir.clone(), allow);
+    }
+
+    let allow = nested.dirs.get(&dir).unwrap_or(&nested.allow);
+    (allow.iter().any(|allowed| allowed == name), errors)
+}
+
+/// Reads the lints allowed by the configuration file of a subdirectory of the crate, which
+/// can't contain other keys than `allow`.
+fn read_nested(file_name: &Path, errors: &mut Vec<(PathBuf, Error)>) -> Vec<String> {
+    let content = match fs::read_to_string(file_name) {
+        Ok(content) => content,
+        Err(error) => {
+            errors.push((file_name.to_path_buf(), error.into()));
+            return Vec::new();
         },
-        Err(e) => {
-            let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
-            errors.push(Error::Toml(e.to_string()));
+    };
 
-            default(errors)
+    let (table, file_errors) = parse(&content);
+    errors.extend(file_errors.into_iter().map(|error| (file_name.to_path_buf(), error)));
+    let mut table = match table {
+        Some(table) => table,
+        None => return Vec::new(),
+    };
+    let allow = table.remove("allow");
+    errors.extend(
+        table
+            .into_iter()
+            .map(|(key, _)| (file_name.to_path_buf(), Error::NestedKey(key))),
+    );
+
+    match allow.map(toml::Value::try_into::<Vec<String>>) {
+        Some(Ok(allow)) => allow.iter().map(|name| normalize_lint_name(name)).collect(),
+        Some(Err(error)) => {
+            errors.push((
+                file_name.to_path_buf(),
+                Error::TypeMismatch {
+                    key: "allow".to_string(),
+                    expected: "Vec<String>",
+                    error: error.to_string(),
+                },
+            ));
+            Vec::new()
         },
+        None => Vec::new(),
+    }
+}
+
+/// Removes the `clippy::` prefix of a lint name and replaces the dashes with underscores, like
+/// the names of `Lint::name_lower`.
+fn normalize_lint_name(name: &str) -> String {
+    let name = name.trim();
+    name.strip_prefix("clippy::").unwrap_or(name).replace('-', "_")
+}
+
+/// Makes a relative path absolute, relative to the current directory, which is the one of the
+/// paths of the source files.
+fn absolute(path: PathBuf) -> PathBuf {
+    match env::current_dir() {
+        Ok(current) if path.is_relative() => current.join(path),
+        _ => path,
     }
 }
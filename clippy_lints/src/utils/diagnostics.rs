@@ -1,10 +1,18 @@
 //! Clippy wrappers around rustc's diagnostic functions.
 
+use crate::utils::{conf, is_in_test};
 use rustc_errors::{Applicability, DiagnosticBuilder};
+use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::HirId;
-use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_lint::{EarlyContext, LateContext, Lint, LintContext, LintId};
+use rustc_middle::lint::LintSource;
+use rustc_session::Session;
 use rustc_span::source_map::{MultiSpan, Span};
+use rustc_span::FileName;
+use serde::Serialize;
 use std::env;
+use std::lazy::SyncLazy;
+use std::sync::Mutex;
 
 fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
@@ -19,6 +27,248 @@ fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
     }
 }
 
+/// Makes the suggestions of the lint not machine applicable, unless it's one of the lints of
+/// `cargo clippy --fix --only <lints>`, so that `cargo fix` doesn't apply them.
+fn fix_only(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
+    if let Ok(lints) = env::var("CLIPPY_FIX_ONLY") {
+        let name = lint.name_lower();
+        let selected = lints.split(',').any(|selected| {
+            let selected = selected.trim().replace('-', "_");
+            selected == name || name.strip_prefix("clippy::") == Some(selected.as_str())
+        });
+        if !selected {
+            for suggestion in &mut diag.suggestions {
+                if suggestion.applicability == Applicability::MachineApplicable {
+                    suggestion.applicability = Applicability::MaybeIncorrect;
+                }
+            }
+        }
+    }
+}
+
+/// The diagnostics emitted by the Clippy lints, if they are recorded for
+/// `clippy-driver --clippy-json-out`.
+static RECORDED: SyncLazy<Mutex<Option<Vec<RecordedDiagnostic>>>> = SyncLazy::new(|| Mutex::new(None));
+
+/// A Clippy diagnostic as it's written by `clippy-driver --clippy-json-out`. The fields are named
+/// like in the JSON diagnostics of rustc.
+#[derive(Serialize)]
+pub struct RecordedDiagnostic {
+    /// The name of the lint, e.g. `clippy::needless_return`, only set on the top-level diagnostic
+    pub lint: Option<String>,
+    pub level: String,
+    pub message: String,
+    pub spans: Vec<RecordedSpan>,
+    pub children: Vec<RecordedDiagnostic>,
+}
+
+#[derive(Serialize)]
+pub struct RecordedSpan {
+    pub file_name: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    /// The columns are 1-based, like the lines
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    pub label: Option<String>,
+    pub suggested_replacement: Option<String>,
+    pub suggestion_applicability: Option<String>,
+}
+
+/// Starts recording the diagnostics of the Clippy lints, which are then returned by
+/// `take_recorded_diagnostics`.
+pub fn record_diagnostics() {
+    *RECORDED.lock().unwrap() = Some(Vec::new());
+}
+
+/// Returns the diagnostics recorded since `record_diagnostics` was called.
+pub fn take_recorded_diagnostics() -> Vec<RecordedDiagnostic> {
+    RECORDED.lock().unwrap().take().unwrap_or_default()
+}
+
+fn record(sess: &Session, diag: &DiagnosticBuilder<'_>, lint: &'static Lint) {
+    let mut recorded = RECORDED.lock().unwrap();
+    let recorded = match *recorded {
+        Some(ref mut recorded) => recorded,
+        None => return,
+    };
+
+    let record_span = |span: Span, is_primary: bool, label: Option<String>| {
+        let lo = sess.source_map().lookup_char_pos(span.lo());
+        let hi = sess.source_map().lookup_char_pos(span.hi());
+        RecordedSpan {
+            file_name: lo.file.name.to_string(),
+            line_start: lo.line,
+            line_end: hi.line,
+            column_start: lo.col.0 + 1,
+            column_end: hi.col.0 + 1,
+            is_primary,
+            label,
+            suggested_replacement: None,
+            suggestion_applicability: None,
+        }
+    };
+    // the labels include the primary spans without a label
+    let record_spans = |span: &MultiSpan| -> Vec<RecordedSpan> {
+        span.span_labels()
+            .into_iter()
+            .map(|label| record_span(label.span, label.is_primary, label.label))
+            .collect()
+    };
+
+    let mut children: Vec<RecordedDiagnostic> = diag
+        .children
+        .iter()
+        .map(|child| RecordedDiagnostic {
+            lint: None,
+            level: child.level.to_string(),
+            message: child.message(),
+            spans: record_spans(&child.span),
+            children: Vec::new(),
+        })
+        .collect();
+    children.extend(diag.suggestions.iter().map(|suggestion| {
+        RecordedDiagnostic {
+            lint: None,
+            level: "help".to_string(),
+            message: suggestion.msg.clone(),
+            spans: suggestion
+                .substitutions
+                .iter()
+                .flat_map(|substitution| &substitution.parts)
+                .map(|part| RecordedSpan {
+                    suggested_replacement: Some(part.snippet.clone()),
+                    suggestion_applicability: Some(format!("{:?}", suggestion.applicability)),
+                    ..record_span(part.span, true, None)
+                })
+                .collect(),
+            children: Vec::new(),
+        }
+    }));
+    recorded.push(RecordedDiagnostic {
+        lint: Some(lint.name_lower()),
+        level: diag.level.to_string(),
+        message: diag.message(),
+        spans: record_spans(&diag.span),
+        children,
+    });
+}
+
+/// The Clippy lints which were called and the span of the call, whether they were allowed or
+/// not, for `unfulfilled_allow`. The calls are only recorded once it's enabled.
+static CALLS: SyncLazy<Mutex<Option<Vec<(&'static Lint, Span)>>>> = SyncLazy::new(|| Mutex::new(None));
+
+/// Starts recording the Clippy lints called, which are then returned by `take_lint_calls`.
+pub fn record_lint_calls() {
+    let mut calls = CALLS.lock().unwrap();
+    if calls.is_none() {
+        *calls = Some(Vec::new());
+    }
+}
+
+fn record_call(lint: &'static Lint, span: Option<Span>) {
+    if let (Some(calls), Some(span)) = (&mut *CALLS.lock().unwrap(), span) {
+        calls.push((lint, span));
+    }
+}
+
+/// Returns the Clippy lints called since `record_lint_calls` was called, with the span of the
+/// call, or `None` if the calls weren't recorded. The lints called while they're allowed are
+/// included.
+pub fn take_lint_calls() -> Option<Vec<(&'static Lint, Span)>> {
+    CALLS.lock().unwrap().take()
+}
+
+/// The lint contexts, telling if a lint is emitted in the test code for the `allow-in-tests`
+/// configuration, and where the level of a lint comes from.
+///
+/// The early passes never emit in the test code, and the late passes use the node being checked
+/// unless the lint is emitted with a `HirId`. That node is the crate root in `check_crate_post`
+/// and the owner of the body in `check_body_post`, not the node of the linted code, so the lints
+/// emitted there need `span_lint_hir` to be allowed in tests.
+pub trait LintScope {
+    /// Checks if the node, or the node being checked if `None`, is in the test code.
+    fn in_test(&self, hir_id: Option<HirId>) -> bool;
+
+    /// Returns where the level of the lint at the node, or at the node being checked if `None`,
+    /// is set.
+    fn lint_level_source(&self, lint: &'static Lint, hir_id: Option<HirId>) -> LintSource;
+}
+
+impl LintScope for LateContext<'_> {
+    fn in_test(&self, hir_id: Option<HirId>) -> bool {
+        is_in_test(self, hir_id.unwrap_or(self.last_node_with_lint_attrs))
+    }
+
+    // `lint_level_at_node` gives the source of `-D warnings` for the warnings it turns into errors,
+    // the level sets are searched directly to find the attribute of the lint
+    fn lint_level_source(&self, lint: &'static Lint, hir_id: Option<HirId>) -> LintSource {
+        let levels = self.tcx.lint_levels(LOCAL_CRATE);
+        let mut id = hir_id.unwrap_or(self.last_node_with_lint_attrs);
+        loop {
+            if let Some(&idx) = levels.id_to_set.get(&id) {
+                return levels.sets.get_lint_id_level(LintId::of(lint), idx, None).1;
+            }
+            let parent = self.tcx.hir().get_parent_node(id);
+            if parent == id {
+                return LintSource::Default;
+            }
+            id = parent;
+        }
+    }
+}
+
+impl LintScope for EarlyContext<'_> {
+    // the test functions and modules are only known once the HIR is built
+    fn in_test(&self, _: Option<HirId>) -> bool {
+        false
+    }
+
+    // unlike the late lints, an attribute turned into an error by `-D warnings` is seen as
+    // `-D warnings`
+    fn lint_level_source(&self, lint: &'static Lint, _: Option<HirId>) -> LintSource {
+        self.builder.lint_level(lint).1
+    }
+}
+
+/// Checks if the lint is allowed by the `allow` key of the configuration files for the file of
+/// `span`, or by the `allow-in-tests` key if it's emitted in the test code, reporting the errors
+/// of the configuration files read for it.
+///
+/// A level set for this very lint on the command line, or by an attribute of the same file,
+/// overrides the configuration files, unlike the levels set for its group or by `-D warnings`.
+fn allowed_by_conf<T: LintContext + LintScope>(
+    cx: &T,
+    lint: &'static Lint,
+    span: Option<Span>,
+    hir_id: Option<HirId>,
+) -> bool {
+    let source_map = cx.sess().source_map();
+    let file_name = span.map(|span| source_map.span_to_filename(span));
+    let full_name = lint.name_lower();
+    match cx.lint_level_source(lint, hir_id) {
+        LintSource::CommandLine(source, ..) if source.as_str().replace('-', "_") == full_name => return false,
+        LintSource::Node(source, attr_span, ..)
+            if *source.as_str() == full_name && file_name == Some(source_map.span_to_filename(attr_span)) =>
+        {
+            return false;
+        },
+        _ => {},
+    }
+
+    let file = match file_name {
+        Some(FileName::Real(ref name)) => Some(name.local_path()),
+        _ => None,
+    };
+    let name = full_name.strip_prefix("clippy::").unwrap_or(&full_name);
+    let (allowed, errors) = conf::is_allowed_in_file(file, name);
+    for (file_name, error) in errors {
+        conf::report_error(cx.sess(), &file_name, &error);
+    }
+    allowed || (conf::is_allowed_in_tests(name) && cx.in_test(hir_id))
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -36,10 +286,18 @@ fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
 /// 17 |     std::mem::forget(seven);
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
-pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+pub fn span_lint<T: LintContext + LintScope>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    let sp = sp.into();
+    let span = sp.primary_span();
+    record_call(lint, span);
     cx.struct_span_lint(lint, sp, |diag| {
+        if allowed_by_conf(cx, lint, span, None) {
+            diag.build(msg).cancel();
+            return;
+        }
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
+        record(cx.sess(), &diag, lint);
         diag.emit();
     });
 }
@@ -64,7 +322,7 @@ pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<Mult
 ///    |
 ///    = help: Consider using `f64::NAN` if you would like a constant representing NaN
 /// ```
-pub fn span_lint_and_help<'a, T: LintContext>(
+pub fn span_lint_and_help<'a, T: LintContext + LintScope>(
     cx: &'a T,
     lint: &'static Lint,
     span: Span,
@@ -72,7 +330,12 @@ pub fn span_lint_and_help<'a, T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
+    record_call(lint, Some(span));
     cx.struct_span_lint(lint, span, |diag| {
+        if allowed_by_conf(cx, lint, Some(span), None) {
+            diag.build(msg).cancel();
+            return;
+        }
         let mut diag = diag.build(msg);
         if let Some(help_span) = help_span {
             diag.span_help(help_span, help);
@@ -80,6 +343,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
             diag.help(help);
         }
         docs_link(&mut diag, lint);
+        record(cx.sess(), &diag, lint);
         diag.emit();
     });
 }
@@ -107,7 +371,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
 /// 10 |     forget(&SomeStruct);
 ///    |            ^^^^^^^^^^^
 /// ```
-pub fn span_lint_and_note<'a, T: LintContext>(
+pub fn span_lint_and_note<'a, T: LintContext + LintScope>(
     cx: &'a T,
     lint: &'static Lint,
     span: Span,
@@ -115,7 +379,12 @@ pub fn span_lint_and_note<'a, T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
+    record_call(lint, Some(span));
     cx.struct_span_lint(lint, span, |diag| {
+        if allowed_by_conf(cx, lint, Some(span), None) {
+            diag.build(msg).cancel();
+            return;
+        }
         let mut diag = diag.build(msg);
         if let Some(note_span) = note_span {
             diag.span_note(note_span, note);
@@ -123,6 +392,7 @@ pub fn span_lint_and_note<'a, T: LintContext>(
             diag.note(note);
         }
         docs_link(&mut diag, lint);
+        record(cx.sess(), &diag, lint);
         diag.emit();
     });
 }
@@ -131,22 +401,35 @@ pub fn span_lint_and_note<'a, T: LintContext>(
 ///
 /// If you need to customize your lint output a lot, use this function.
 /// If you change the signature, remember to update the internal lint `CollapsibleCalls`
-pub fn span_lint_and_then<'a, T: LintContext, F>(cx: &'a T, lint: &'static Lint, sp: Span, msg: &str, f: F)
+pub fn span_lint_and_then<'a, T: LintContext + LintScope, F>(cx: &'a T, lint: &'static Lint, sp: Span, msg: &str, f: F)
 where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
+    record_call(lint, Some(sp));
     cx.struct_span_lint(lint, sp, |diag| {
+        if allowed_by_conf(cx, lint, Some(sp), None) {
+            diag.build(msg).cancel();
+            return;
+        }
         let mut diag = diag.build(msg);
         f(&mut diag);
+        fix_only(&mut diag, lint);
         docs_link(&mut diag, lint);
+        record(cx.sess(), &diag, lint);
         diag.emit();
     });
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
+    record_call(lint, Some(sp));
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
+        if allowed_by_conf(cx, lint, Some(sp), Some(hir_id)) {
+            diag.build(msg).cancel();
+            return;
+        }
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
+        record(cx.sess(), &diag, lint);
         diag.emit();
     });
 }
@@ -159,10 +442,17 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut DiagnosticBuilder<'_>),
 ) {
+    record_call(lint, Some(sp));
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
+        if allowed_by_conf(cx, lint, Some(sp), Some(hir_id)) {
+            diag.build(msg).cancel();
+            return;
+        }
         let mut diag = diag.build(msg);
         f(&mut diag);
+        fix_only(&mut diag, lint);
         docs_link(&mut diag, lint);
+        record(cx.sess(), &diag, lint);
         diag.emit();
     });
 }
@@ -189,7 +479,7 @@ pub fn span_lint_hir_and_then(
 
 #[allow(clippy::unknown_clippy_lints)]
 #[cfg_attr(feature = "internal-lints", allow(clippy::collapsible_span_lint_calls))]
-pub fn span_lint_and_sugg<'a, T: LintContext>(
+pub fn span_lint_and_sugg<'a, T: LintContext + LintScope>(
     cx: &'a T,
     lint: &'static Lint,
     sp: Span,
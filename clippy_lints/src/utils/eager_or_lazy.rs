@@ -9,7 +9,7 @@
 //!  - or-fun-call
 //!  - option-if-let-else
 
-use crate::utils::{is_ctor_or_promotable_const_function, is_type_diagnostic_item, match_type, paths};
+use crate::utils::{is_ctor_or_promotable_const_function, is_type_std_item, std_items};
 use rustc_hir::def::{DefKind, Res};
 
 use rustc_hir::intravisit;
@@ -98,8 +98,8 @@ fn identify_some_potentially_expensive_patterns<'tcx>(cx: &LateContext<'tcx>, ex
                 ExprKind::Call(..) => !is_ctor_or_promotable_const_function(self.cx, expr),
                 ExprKind::Index(obj, _) => {
                     let ty = self.cx.typeck_results().expr_ty(obj);
-                    is_type_diagnostic_item(self.cx, ty, sym!(hashmap_type))
-                        || match_type(self.cx, ty, &paths::BTREEMAP)
+                    is_type_std_item(self.cx, ty, &std_items::HASHMAP)
+                        || is_type_std_item(self.cx, ty, &std_items::BTREEMAP)
                 },
                 ExprKind::MethodCall(..) => true,
                 _ => false,
@@ -1,7 +1,8 @@
 use crate::consts::{constant_simple, Constant};
 use crate::utils::{
-    is_expn_of, match_def_path, match_qpath, match_type, method_calls, path_to_res, paths, qpath_res, run_lints,
-    snippet, span_lint, span_lint_and_help, span_lint_and_sugg, SpanlessEq,
+    is_expn_of, last_path_segment, match_def_path, match_qpath, match_type, method_calls, path_to_res, paths,
+    qpath_res, run_lints, snippet, snippet_with_applicability, span_lint, span_lint_and_help, span_lint_and_sugg,
+    SpanlessEq,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::{Crate as AstCrate, ItemKind, LitKind, NodeId};
@@ -11,10 +12,14 @@ use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::hir_id::CRATE_HIR_ID;
-use rustc_hir::intravisit::{NestedVisitorMap, Visitor};
-use rustc_hir::{Crate, Expr, ExprKind, HirId, Item, MutTy, Mutability, Node, Path, StmtKind, Ty, TyKind};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    BinOpKind, BorrowKind, Crate, Expr, ExprKind, HirId, ImplItem, ImplItemKind, Item, MutTy, Mutability, Node, Path,
+    StmtKind, Ty, TyKind, UnOp,
+};
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
+use rustc_middle::mir::interpret::ConstValue;
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::{Span, Spanned};
@@ -234,10 +239,14 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:**
-    /// Checks the paths module for invalid paths.
+    /// Checks the paths module for invalid paths, by resolving each path in the current
+    /// standard library. Also checks that the fallback paths of the `std_items` module name
+    /// their diagnostic items.
     ///
     /// **Why is this bad?**
-    /// It indicates a bug in the code.
+    /// It indicates a bug in the code. The lints using the path silently stop working, for
+    /// example when an item is moved or renamed in a new toolchain. The dogfood test denies
+    /// this lint, so these paths are verified on every toolchain bump.
     ///
     /// **Known problems:** None.
     ///
@@ -247,6 +256,69 @@ declare_clippy_lint! {
     "invalid path"
 }
 
+declare_clippy_lint! {
+    /// **What it does:**
+    /// Checks for interning a symbol that is pre-interned and defined as a constant, and for
+    /// comparing the string of a symbol with such a symbol.
+    ///
+    /// **Why is this bad?**
+    /// It's faster and easier to use the symbol constant.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// Bad:
+    /// ```rust,ignore
+    /// let _ = sym!(f32);
+    /// if ident.as_str() == "clone" {}
+    /// ```
+    ///
+    /// Good:
+    /// ```rust,ignore
+    /// let _ = sym::f32;
+    /// if ident.name == sym::clone {}
+    /// ```
+    pub INTERNING_DEFINED_SYMBOL,
+    internal,
+    "interning a symbol that is pre-interned and defined as a constant"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `check_expr` and `check_stmt` methods of lint passes which
+    /// emit a lint without checking if the code comes from a macro expansion, with
+    /// `span.from_expansion()`, `in_macro`, `in_external_macro`, `is_expn_of` or a similar
+    /// function.
+    ///
+    /// **Why is this bad?** Lints emitted in macro expansions are the most common source of false
+    /// positives, the user often can't change the code of the macro.
+    ///
+    /// **Known problems:** Only the body of the method itself is checked, not the functions it
+    /// calls. Some passes handle expansions deliberately, the lint can be allowed on the method
+    /// for them.
+    ///
+    /// **Example:**
+    /// Bad:
+    /// ```rust,ignore
+    /// fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    ///     if is_foo(expr) {
+    ///         span_lint(cx, FOO, expr.span, "foo");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Good:
+    /// ```rust,ignore
+    /// fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    ///     if !in_macro(expr.span) && is_foo(expr) {
+    ///         span_lint(cx, FOO, expr.span, "foo");
+    ///     }
+    /// }
+    /// ```
+    pub MISSING_EXPANSION_CHECK,
+    internal,
+    "lint emitted by `check_expr` or `check_stmt` without checking for macro expansions"
+}
+
 declare_lint_pass!(ClippyLintsInternal => [CLIPPY_LINTS_INTERNAL]);
 
 impl EarlyLintPass for ClippyLintsInternal {
@@ -436,6 +508,7 @@ impl CompilerLintFunctions {
 impl_lint_pass!(CompilerLintFunctions => [COMPILER_LINT_FUNCTIONS]);
 
 impl<'tcx> LateLintPass<'tcx> for CompilerLintFunctions {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if !run_lints(cx, &[COMPILER_LINT_FUNCTIONS], expr.hir_id) {
             return;
@@ -697,6 +770,7 @@ fn suggest_note(
 declare_lint_pass!(MatchTypeOnDiagItem => [MATCH_TYPE_ON_DIAGNOSTIC_ITEM]);
 
 impl<'tcx> LateLintPass<'tcx> for MatchTypeOnDiagItem {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         if !run_lints(cx, &[MATCH_TYPE_ON_DIAGNOSTIC_ITEM], expr.hir_id) {
             return;
@@ -815,6 +889,12 @@ impl<'tcx> LateLintPass<'tcx> for InvalidPaths {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
         let local_def_id = &cx.tcx.parent_module(item.hir_id);
         let mod_name = &cx.tcx.item_name(local_def_id.to_def_id());
+        if mod_name.as_str() == "std_items" {
+            if let hir::ItemKind::Const(_, body_id) = item.kind {
+                check_std_item(cx, item, &cx.tcx.hir().body(body_id).value);
+            }
+            return;
+        }
         if_chain! {
             if mod_name.as_str() == "paths";
             if let hir::ItemKind::Const(ty, body_id) = item.kind;
@@ -835,8 +915,250 @@ impl<'tcx> LateLintPass<'tcx> for InvalidPaths {
                 }).collect();
             if !check_path(cx, &path[..]);
             then {
-                span_lint(cx, CLIPPY_LINTS_INTERNAL, item.span, "invalid path");
+                span_lint(cx, INVALID_PATHS, item.span, "invalid path");
+            }
+        }
+    }
+}
+
+/// Checks that the fallback path of an item of the `std_items` module resolves, and that it names
+/// the diagnostic item when the toolchain has it.
+fn check_std_item(cx: &LateContext<'_>, item: &Item<'_>, expr: &hir::Expr<'_>) {
+    let fields = match expr.kind {
+        ExprKind::Struct(_, fields, None) => fields,
+        _ => return,
+    };
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|field| field.ident.name.as_str() == name)
+            .map(|field| field.expr)
+    };
+    if_chain! {
+        if let Some(diag_item) = field("diag_item");
+        if let ExprKind::Lit(lit) = &diag_item.kind;
+        if let LitKind::Str(diag_item, _) = lit.node;
+        if let Some(path) = field("path").and_then(|path| path_to_matched_type(cx, path));
+        then {
+            let path: Vec<&str> = path.iter().map(|sym| &**sym).collect();
+            match path_to_res(cx, &path).and_then(|res| res.opt_def_id()) {
+                None => span_lint(cx, INVALID_PATHS, item.span, "invalid path"),
+                Some(def_id) => {
+                    if cx.tcx.get_diagnostic_item(diag_item).map_or(false, |diag_did| diag_did != def_id) {
+                        span_lint(
+                            cx,
+                            INVALID_PATHS,
+                            item.span,
+                            &format!("the path doesn't name the diagnostic item `{}`", diag_item),
+                        );
+                    }
+                },
             }
         }
     }
 }
+
+#[derive(Default)]
+pub struct InterningDefinedSymbol {
+    // Maps the symbol value to the constant name.
+    symbol_map: FxHashMap<u32, String>,
+}
+
+impl_lint_pass!(InterningDefinedSymbol => [INTERNING_DEFINED_SYMBOL]);
+
+impl<'tcx> LateLintPass<'tcx> for InterningDefinedSymbol {
+    fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
+        if !self.symbol_map.is_empty() {
+            return;
+        }
+
+        if let Some(Res::Def(_, def_id)) = path_to_res(cx, &paths::SYM_MODULE) {
+            for item in cx.tcx.item_children(def_id).iter() {
+                if_chain! {
+                    if let Res::Def(DefKind::Const, item_def_id) = item.res;
+                    let ty = cx.tcx.type_of(item_def_id);
+                    if match_type(cx, ty, &paths::SYMBOL);
+                    if let Ok(ConstValue::Scalar(value)) = cx.tcx.const_eval_poly(item_def_id);
+                    if let Ok(value) = value.to_u32();
+                    then {
+                        self.symbol_map.insert(value, item.ident.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `Symbol::intern("f32")` or `sym!(f32)`
+        if_chain! {
+            if let ExprKind::Call(func, [arg]) = &expr.kind;
+            if let ty::FnDef(def_id, _) = cx.typeck_results().expr_ty(func).kind();
+            if match_def_path(cx, *def_id, &paths::SYMBOL_INTERN);
+            if let Some(Constant::Str(arg)) = constant_simple(cx, cx.typeck_results(), arg);
+            if let Some(symbol_const) = self.symbol_map.get(&Symbol::intern(&arg).as_u32());
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    INTERNING_DEFINED_SYMBOL,
+                    is_expn_of(expr.span, "sym").unwrap_or(expr.span),
+                    "interning a defined symbol",
+                    "try",
+                    format!("rustc_span::symbol::sym::{}", symbol_const),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+
+        // `ident.as_str() == "clone"` or `"clone" != name.as_str()`
+        if_chain! {
+            if let ExprKind::Binary(op, left, right) = &expr.kind;
+            if let BinOpKind::Eq | BinOpKind::Ne = op.node;
+            if let Some((symbol, str_lit)) = symbol_str_comparison(cx, left, right)
+                .or_else(|| symbol_str_comparison(cx, right, left));
+            if let Some(symbol_const) = self.symbol_map.get(&Symbol::intern(&str_lit).as_u32());
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let symbol_snip = snippet_with_applicability(cx, symbol.span, "..", &mut applicability);
+                let field = if match_type(cx, cx.typeck_results().expr_ty(symbol), &paths::IDENT) {
+                    ".name"
+                } else {
+                    ""
+                };
+                span_lint_and_sugg(
+                    cx,
+                    INTERNING_DEFINED_SYMBOL,
+                    expr.span,
+                    "comparing the string of a symbol with a defined symbol",
+                    "compare the symbols instead",
+                    format!(
+                        "{}{} {} rustc_span::symbol::sym::{}",
+                        symbol_snip,
+                        field,
+                        op.node.as_str(),
+                        symbol_const
+                    ),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Checks if `expr` is `x.as_str()` or `&*x.as_str()` on a `Symbol` or an `Ident`, and `other` a
+/// string literal. Returns `x` and the string.
+fn symbol_str_comparison<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    other: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, String)> {
+    let expr = match expr.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) => match inner.kind {
+            ExprKind::Unary(UnOp::UnDeref, inner) => inner,
+            _ => return None,
+        },
+        _ => expr,
+    };
+    if_chain! {
+        if let ExprKind::MethodCall(method, _, [symbol], _) = expr.kind;
+        if method.ident.as_str() == "as_str";
+        let ty = cx.typeck_results().expr_ty(symbol);
+        if match_type(cx, ty, &paths::SYMBOL) || match_type(cx, ty, &paths::IDENT);
+        if let ExprKind::Lit(ref lit) = other.kind;
+        if let LitKind::Str(str_lit, _) = lit.node;
+        then {
+            Some((symbol, str_lit.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+/// The functions and methods checking if a span comes from a macro expansion.
+const EXPANSION_CHECKS: [&str; 10] = [
+    "differing_macro_contexts",
+    "expn_data",
+    "from_expansion",
+    "in_derive_expn",
+    "in_external_macro",
+    "in_macro",
+    "is_direct_expn_of",
+    "is_expn_of",
+    "macro_backtrace",
+    "outer_expn",
+];
+
+declare_lint_pass!(MissingExpansionCheck => [MISSING_EXPANSION_CHECK]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingExpansionCheck {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        if !run_lints(cx, &[MISSING_EXPANSION_CHECK], impl_item.hir_id) {
+            return;
+        }
+
+        let method_name = impl_item.ident.as_str();
+        if_chain! {
+            if method_name == "check_expr" || method_name == "check_stmt";
+            if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            let parent = cx.tcx.hir().get_parent_item(impl_item.hir_id);
+            if let hir::ItemKind::Impl { of_trait: Some(ref trait_ref), .. } = cx.tcx.hir().expect_item(parent).kind;
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if match_def_path(cx, trait_id, &paths::LATE_LINT_PASS)
+                || match_def_path(cx, trait_id, &paths::EARLY_LINT_PASS);
+            then {
+                let mut visitor = ExpansionCheckVisitor {
+                    cx,
+                    lint_call: None,
+                    checks_expansion: false,
+                };
+                visitor.visit_expr(&cx.tcx.hir().body(body_id).value);
+                if let (Some(lint_call), false) = (visitor.lint_call, visitor.checks_expansion) {
+                    span_lint_and_help(
+                        cx,
+                        MISSING_EXPANSION_CHECK,
+                        lint_call,
+                        &format!("lint emitted in `{}` without checking for macro expansions", method_name),
+                        None,
+                        "check `span.from_expansion()` or use `in_macro` before emitting the lint",
+                    );
+                }
+            }
+        }
+    }
+}
+
+struct ExpansionCheckVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The first call emitting a lint
+    lint_call: Option<Span>,
+    checks_expansion: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ExpansionCheckVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        let name = match expr.kind {
+            ExprKind::Call(func, _) => match func.kind {
+                ExprKind::Path(ref qpath) => Some(last_path_segment(qpath).ident.name),
+                _ => None,
+            },
+            ExprKind::MethodCall(method, ..) => Some(method.ident.name),
+            _ => None,
+        };
+        if let Some(name) = name {
+            let name = name.as_str();
+            if EXPANSION_CHECKS.contains(&&*name) {
+                self.checks_expansion = true;
+            } else if self.lint_call.is_none()
+                && (name.starts_with("span_lint") || name.starts_with("struct_span_lint"))
+            {
+                self.lint_call = Some(expr.span);
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -16,11 +16,14 @@ mod hir_utils;
 pub mod inspector;
 #[cfg(feature = "internal-lints")]
 pub mod internal_lints;
+pub mod mir;
 pub mod numeric_literal;
 pub mod paths;
 pub mod ptr;
 pub mod qualify_min_const_fn;
+pub mod std_items;
 pub mod sugg;
+pub mod unused_conf_keys;
 pub mod usage;
 pub mod visitors;
 
@@ -36,7 +39,7 @@ use std::mem;
 use if_chain::if_chain;
 use rustc_ast::ast::{self, Attribute, LitKind};
 use rustc_attr as attr;
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
@@ -202,6 +205,23 @@ pub fn is_type_diagnostic_item(cx: &LateContext<'_>, ty: Ty<'_>, diag_item: Symb
     }
 }
 
+/// Checks if the item is the item of the standard library, by its diagnostic item, or by its path
+/// if the toolchain doesn't have the diagnostic item.
+pub fn is_std_item(cx: &LateContext<'_>, did: DefId, item: &std_items::StdItem) -> bool {
+    match cx.tcx.get_diagnostic_item(Symbol::intern(item.diag_item)) {
+        Some(diag_did) => diag_did == did,
+        None => match_def_path(cx, did, item.path),
+    }
+}
+
+/// Checks if the type is the struct, enum or union of the standard library, see `is_std_item`.
+pub fn is_type_std_item(cx: &LateContext<'_>, ty: Ty<'_>, item: &std_items::StdItem) -> bool {
+    match ty.kind() {
+        ty::Adt(adt, _) => is_std_item(cx, adt.did, item),
+        _ => false,
+    }
+}
+
 /// Checks if the type is equal to a lang item
 pub fn is_type_lang_item(cx: &LateContext<'_>, ty: Ty<'_>, lang_item: hir::LangItem) -> bool {
     match ty.kind() {
@@ -227,6 +247,39 @@ pub fn match_var(expr: &Expr<'_>, var: Symbol) -> bool {
     false
 }
 
+/// Checks if the expression is a path to the binding of the pattern, like `x` in `|x| x`.
+pub fn is_param(expr: &Expr<'_>, pat: &Pat<'_>) -> bool {
+    if let (
+        ExprKind::Path(QPath::Resolved(None, ref path)),
+        PatKind::Binding(hir::BindingAnnotation::Unannotated, id, _, None),
+    ) = (&expr.kind, &pat.kind)
+    {
+        path.res == Res::Local(*id)
+    } else {
+        false
+    }
+}
+
+/// Checks if the expression is a path to the binding of the pattern or its dereference, like `x`
+/// or `*x` in `|x| x.clone()`.
+pub fn is_param_or_deref(expr: &Expr<'_>, pat: &Pat<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Unary(hir::UnOp::UnDeref, ref inner) => is_param(inner, pat),
+        _ => is_param(expr, pat),
+    }
+}
+
+/// Checks if the expression is a path to `Clone::clone`.
+pub fn is_clone_method_path(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.kind {
+        qpath_res(cx, qpath, expr.hir_id)
+            .opt_def_id()
+            .map_or(false, |def_id| match_def_path(cx, def_id, &paths::CLONE_TRAIT_METHOD))
+    } else {
+        false
+    }
+}
+
 pub fn last_path_segment<'tcx>(path: &QPath<'tcx>) -> &'tcx PathSegment<'tcx> {
     match *path {
         QPath::Resolved(_, ref path) => path.segments.last().expect("A path must have at least one segment"),
@@ -370,6 +423,18 @@ pub fn path_to_res(cx: &LateContext<'_>, path: &[&str]) -> Option<def::Res> {
     }
 }
 
+/// Gets the `DefId`s of the `::`-separated paths, e.g. from the configuration. Paths of crates
+/// which aren't dependencies of this crate don't resolve and are skipped.
+pub fn def_ids_of_paths(cx: &LateContext<'_>, paths: &[String]) -> FxHashSet<DefId> {
+    paths
+        .iter()
+        .filter_map(|path| {
+            let segments: Vec<&str> = path.split("::").collect();
+            path_to_res(cx, &segments)?.opt_def_id()
+        })
+        .collect()
+}
+
 pub fn qpath_res(cx: &LateContext<'_>, qpath: &hir::QPath<'_>, id: hir::HirId) -> Res {
     match qpath {
         hir::QPath::Resolved(_, path) => path.res,
@@ -852,6 +917,55 @@ fn reindent_multiline_inner(s: &str, ignore_first: bool, indent: Option<usize>,
         .join("\n")
 }
 
+/// Checks if the function is a `#[test]`, or inside a `#[cfg(test)]` module.
+pub fn is_test_fn(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let map = cx.tcx.hir();
+    let in_test_module = map.parent_iter(hir_id).any(|(_, node)| {
+        if let Node::Item(item) = node {
+            item.attrs.iter().any(|attr| {
+                attr.has_name(rustc_sym::cfg)
+                    && attr
+                        .meta_item_list()
+                        .map_or(false, |list| list.iter().any(|meta| meta.has_name(rustc_sym::test)))
+            })
+        } else {
+            false
+        }
+    });
+    if in_test_module {
+        return true;
+    }
+
+    // the test harness adds a `#[rustc_test_marker]` const named like the test next to it
+    let name = match map.find(hir_id) {
+        Some(Node::Item(item)) => item.ident.name,
+        _ => return false,
+    };
+    let item_ids = match map.find(map.get_module_parent_node(hir_id)) {
+        Some(Node::Item(hir::Item {
+            kind: hir::ItemKind::Mod(module),
+            ..
+        })) => module.item_ids,
+        Some(Node::Crate(krate)) => krate.module.item_ids,
+        _ => return false,
+    };
+    item_ids.iter().any(|item_id| {
+        let item = map.item(item_id.id);
+        matches!(item.kind, hir::ItemKind::Const(..))
+            && item.ident.name == name
+            && item.attrs.iter().any(|attr| attr.has_name(rustc_sym::rustc_test_marker))
+    })
+}
+
+/// Checks if the node is in a `#[test]` function or a `#[cfg(test)]` module, or is one.
+pub fn is_in_test(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    is_test_fn(cx, hir_id)
+        || cx.tcx.hir().parent_iter(hir_id).any(|(parent_id, node)| match node {
+            Node::Item(item) => matches!(item.kind, ItemKind::Fn(..)) && is_test_fn(cx, parent_id),
+            _ => false,
+        })
+}
+
 /// Gets the parent expression, if any –- this is useful to constrain a lint.
 pub fn get_parent_expr<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
     let map = &cx.tcx.hir();
@@ -1026,6 +1140,52 @@ pub fn is_copy<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     ty.is_copy_modulo_regions(cx.tcx.at(DUMMY_SP), cx.param_env)
 }
 
+/// Checks if the type has interior mutability, i.e. contains an `UnsafeCell` that isn't behind a
+/// pointer.
+///
+/// Unlike `Ty::is_freeze`, which reports every type parameter as `!Freeze`, this looks into the
+/// fields of types that aren't `Freeze` and assumes type parameters have no interior mutability.
+/// Projections like `<T as Deref>::Target` are normalized with the bounds of the current item
+/// first. `PhantomData` and the ADTs in `ignored` are considered to have no interior mutability.
+pub fn is_interior_mut_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, span: Span, ignored: &FxHashSet<DefId>) -> bool {
+    fn inner<'tcx>(
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        span: Span,
+        ignored: &FxHashSet<DefId>,
+        seen: &mut FxHashSet<Ty<'tcx>>,
+    ) -> bool {
+        let ty = if ty.has_projections() && !ty.has_escaping_bound_vars() {
+            cx.tcx.normalize_erasing_regions(cx.param_env, ty)
+        } else {
+            ty
+        };
+        match *ty.kind() {
+            ty::Slice(inner_ty) => inner(cx, inner_ty, span, ignored, seen),
+            ty::Array(inner_ty, size) => {
+                size.try_eval_usize(cx.tcx, cx.param_env).map_or(true, |u| u != 0)
+                    && inner(cx, inner_ty, span, ignored, seen)
+            },
+            ty::Tuple(..) => ty.tuple_fields().any(|ty| inner(cx, ty, span, ignored, seen)),
+            ty::Adt(def, substs) => {
+                if def.is_phantom_data() || ignored.contains(&def.did) || !seen.insert(ty) {
+                    false
+                } else if Some(def.did) == cx.tcx.lang_items().unsafe_cell_type() {
+                    true
+                } else if !ty.has_escaping_bound_vars() && ty.is_freeze(cx.tcx.at(span), cx.param_env) {
+                    false
+                } else {
+                    def.all_fields()
+                        .any(|field| inner(cx, field.ty(cx.tcx, substs), span, ignored, seen))
+                }
+            },
+            _ => false,
+        }
+    }
+
+    inner(cx, ty, span, ignored, &mut FxHashSet::default())
+}
+
 /// Checks if an expression is constructing a tuple-like enum variant or struct
 pub fn is_ctor_or_promotable_const_function(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     if let ExprKind::Call(ref fun, _) = expr.kind {
@@ -1481,6 +1641,55 @@ pub fn is_must_use_func_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     did.map_or(false, |did| must_use_attr(&cx.tcx.get_attrs(did)).is_some())
 }
 
+/// The kinds of types whose values are significant when they're dropped or ignored, as with
+/// `let _ = <expr>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignificantTy {
+    /// A `MutexGuard`, `RwLockReadGuard` or `RwLockWriteGuard`, dropping it releases the lock
+    SyncGuard,
+    /// A `Result`, ignoring it ignores the error
+    Result,
+    /// A type implementing `Drop`
+    Drop,
+    /// A `#[must_use]` type
+    MustUse,
+}
+
+/// Returns the significant kinds of the type, from the most to the least specific. The type
+/// parameters are also checked for the sync guards and the `Drop` types, e.g.
+/// `LockResult<MutexGuard<'_, T>>` is a `SyncGuard`, a `Result` and a `MustUse` type.
+pub fn significant_tys<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Vec<SignificantTy> {
+    const SYNC_GUARD_PATHS: [&[&str]; 3] = [
+        &paths::MUTEX_GUARD,
+        &paths::RWLOCK_READ_GUARD,
+        &paths::RWLOCK_WRITE_GUARD,
+    ];
+
+    let walk_types = |f: &dyn Fn(Ty<'tcx>) -> bool| {
+        ty.walk().any(|inner| match inner.unpack() {
+            GenericArgKind::Type(inner_ty) => f(inner_ty),
+            GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
+        })
+    };
+
+    let mut kinds = Vec::new();
+    if walk_types(&|inner_ty| SYNC_GUARD_PATHS.iter().any(|path| match_type(cx, inner_ty, path))) {
+        kinds.push(SignificantTy::SyncGuard);
+    }
+    if is_type_diagnostic_item(cx, ty, rustc_sym::result_type) {
+        kinds.push(SignificantTy::Result);
+    }
+    if let Some(drop_trait) = cx.tcx.lang_items().drop_trait() {
+        if walk_types(&|inner_ty| implements_trait(cx, inner_ty, drop_trait, &[])) {
+            kinds.push(SignificantTy::Drop);
+        }
+    }
+    if is_must_use_ty(cx, ty) {
+        kinds.push(SignificantTy::MustUse);
+    }
+    kinds
+}
+
 pub fn is_no_std_crate(krate: &Crate<'_>) -> bool {
     krate.item.attrs.iter().any(|attr| {
         if let ast::AttrKind::Normal(ref attr, _) = attr.kind {
@@ -1,3 +1,4 @@
+use crate::utils::meets_msrv;
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{
@@ -6,6 +7,7 @@ use rustc_middle::mir::{
 };
 use rustc_middle::ty::subst::GenericArgKind;
 use rustc_middle::ty::{self, adjustment::PointerCast, Ty, TyCtxt};
+use rustc_semver::RustcVersion;
 use rustc_span::symbol::sym;
 use rustc_span::Span;
 use rustc_target::spec::abi::Abi::RustIntrinsic;
@@ -13,7 +15,10 @@ use std::borrow::Cow;
 
 type McfResult = Result<(), (Span, Cow<'static, str>)>;
 
-pub fn is_min_const_fn(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>) -> McfResult {
+/// `if`, `match` and loops are allowed in `const fn` since Rust 1.46.
+const CONST_IF_MATCH_MSRV: RustcVersion = RustcVersion::new(1, 46, 0);
+
+pub fn is_min_const_fn(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>, msrv: Option<&RustcVersion>) -> McfResult {
     let def_id = body.source.def_id();
     let mut current = def_id;
     loop {
@@ -70,7 +75,7 @@ pub fn is_min_const_fn(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>) -> McfResult {
     )?;
 
     for bb in body.basic_blocks() {
-        check_terminator(tcx, body, bb.terminator())?;
+        check_terminator(tcx, body, def_id, bb.terminator(), msrv)?;
         for stmt in &bb.statements {
             check_statement(tcx, body, def_id, stmt)?;
         }
@@ -263,7 +268,13 @@ fn check_place(tcx: TyCtxt<'tcx>, place: Place<'tcx>, span: Span, body: &Body<'t
     Ok(())
 }
 
-fn check_terminator(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>, terminator: &Terminator<'tcx>) -> McfResult {
+fn check_terminator(
+    tcx: TyCtxt<'tcx>,
+    body: &'a Body<'tcx>,
+    def_id: DefId,
+    terminator: &Terminator<'tcx>,
+    msrv: Option<&RustcVersion>,
+) -> McfResult {
     let span = terminator.source_info.span;
     match &terminator.kind {
         TerminatorKind::FalseEdge { .. }
@@ -273,8 +284,12 @@ fn check_terminator(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>, terminator: &Termin
         | TerminatorKind::Resume
         | TerminatorKind::Unreachable => Ok(()),
 
-        TerminatorKind::Drop { place, .. } => check_place(tcx, *place, span, body),
+        TerminatorKind::Drop { place, .. } => {
+            check_drop(tcx, body, def_id, *place, span)?;
+            check_place(tcx, *place, span, body)
+        },
         TerminatorKind::DropAndReplace { place, value, .. } => {
+            check_drop(tcx, body, def_id, *place, span)?;
             check_place(tcx, *place, span, body)?;
             check_operand(tcx, value, span, body)
         },
@@ -283,7 +298,15 @@ fn check_terminator(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>, terminator: &Termin
             discr,
             switch_ty: _,
             targets: _,
-        } => check_operand(tcx, discr, span, body),
+        } => {
+            if !meets_msrv(msrv, &CONST_IF_MATCH_MSRV) {
+                return Err((
+                    span,
+                    "`if`, `match`, `&&` and `||` in const fn require Rust 1.46".into(),
+                ));
+            }
+            check_operand(tcx, discr, span, body)
+        },
 
         TerminatorKind::Abort => Err((span, "abort is not stable in const fn".into())),
         TerminatorKind::GeneratorDrop | TerminatorKind::Yield { .. } => {
@@ -345,3 +368,17 @@ fn check_terminator(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>, terminator: &Termin
         TerminatorKind::InlineAsm { .. } => Err((span, "cannot use inline assembly in const fn".into())),
     }
 }
+
+/// Destructors can't be evaluated at compile time. Drops of values without drop glue are removed
+/// from the MIR when it's optimized, but check the type anyway in case they are still around.
+fn check_drop(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, def_id: DefId, place: Place<'tcx>, span: Span) -> McfResult {
+    let ty = place.ty(body, tcx).ty;
+    if ty.needs_drop(tcx, tcx.param_env(def_id)) {
+        Err((
+            span,
+            "cannot drop locals with a non-trivial destructor in const fn".into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
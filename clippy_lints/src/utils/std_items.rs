@@ -0,0 +1,83 @@
+//! This module contains the items of the standard library which Clippy identifies by their
+//! diagnostic item, for the diagnostic items which aren't in every toolchain.
+//!
+//! Each item has a fallback path in the `paths` module, which is matched when the toolchain
+//! doesn't have the diagnostic item, e.g. after it was renamed. The `invalid_paths` internal lint
+//! checks that the fallback paths resolve, and that they name the same item as the diagnostic
+//! item when it exists.
+
+use crate::utils::paths;
+
+/// An item of the standard library, see `is_std_item` and `is_type_std_item`.
+#[derive(Clone, Copy)]
+pub struct StdItem {
+    /// The name of the diagnostic item
+    pub diag_item: &'static str,
+    /// The path of the item, used when the toolchain has no diagnostic item with this name
+    pub path: &'static [&'static str],
+}
+
+pub const BINARY_HEAP: StdItem = StdItem {
+    diag_item: "BinaryHeap",
+    path: &paths::BINARY_HEAP,
+};
+pub const BTREEMAP: StdItem = StdItem {
+    diag_item: "BTreeMap",
+    path: &paths::BTREEMAP,
+};
+pub const BTREESET: StdItem = StdItem {
+    diag_item: "BTreeSet",
+    path: &paths::BTREESET,
+};
+pub const COW: StdItem = StdItem {
+    diag_item: "Cow",
+    path: &paths::COW,
+};
+pub const DURATION: StdItem = StdItem {
+    diag_item: "Duration",
+    path: &paths::DURATION,
+};
+pub const FILE: StdItem = StdItem {
+    diag_item: "File",
+    path: &paths::FILE,
+};
+pub const HASHMAP: StdItem = StdItem {
+    diag_item: "hashmap_type",
+    path: &paths::HASHMAP,
+};
+pub const HASHSET: StdItem = StdItem {
+    diag_item: "hashset_type",
+    path: &paths::HASHSET,
+};
+pub const LINKED_LIST: StdItem = StdItem {
+    diag_item: "LinkedList",
+    path: &paths::LINKED_LIST,
+};
+pub const MUTEX: StdItem = StdItem {
+    diag_item: "mutex_type",
+    path: &paths::MUTEX,
+};
+pub const OS_STRING: StdItem = StdItem {
+    diag_item: "OsString",
+    path: &paths::OS_STRING,
+};
+pub const PATH: StdItem = StdItem {
+    diag_item: "Path",
+    path: &paths::PATH,
+};
+pub const PATH_BUF: StdItem = StdItem {
+    diag_item: "PathBuf",
+    path: &paths::PATH_BUF,
+};
+pub const VEC_DEQUE: StdItem = StdItem {
+    diag_item: "vecdeque_type",
+    path: &paths::VEC_DEQUE,
+};
+pub const WEAK_ARC: StdItem = StdItem {
+    diag_item: "ArcWeak",
+    path: &paths::WEAK_ARC,
+};
+pub const WEAK_RC: StdItem = StdItem {
+    diag_item: "RcWeak",
+    path: &paths::WEAK_RC,
+};
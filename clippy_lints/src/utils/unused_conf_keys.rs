@@ -0,0 +1,57 @@
+//! Warns about configuration options which are set, but only used by lints that are allowed.
+
+use crate::utils::conf::lints_using_key;
+use crate::utils::run_lints;
+use rustc_hir::{hir_id::CRATE_HIR_ID, Crate};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
+use rustc_session::impl_lint_pass;
+use std::path::PathBuf;
+
+pub struct UnusedConfKeys {
+    set_keys: Vec<(String, PathBuf)>,
+}
+
+impl UnusedConfKeys {
+    #[must_use]
+    pub fn new(set_keys: Vec<(String, PathBuf)>) -> Self {
+        Self { set_keys }
+    }
+}
+
+impl_lint_pass!(UnusedConfKeys => []);
+
+impl LateLintPass<'_> for UnusedConfKeys {
+    fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
+        for (key, file_name) in &self.set_keys {
+            let lint_names: Vec<String> = lints_using_key(key)
+                .into_iter()
+                .map(|name| format!("clippy::{}", name.to_lowercase()))
+                .collect();
+            let lints: Vec<&'static Lint> = lint_names
+                .iter()
+                .filter_map(|name| cx.lints().find_lints(name).ok())
+                .flatten()
+                .map(|id| id.lint)
+                .collect();
+            // Keys of deprecated options or unknown lints are handled elsewhere
+            if lints.is_empty() || run_lints(cx, &lints, CRATE_HIR_ID) {
+                continue;
+            }
+
+            let lint_names: Vec<String> = lint_names.iter().map(|name| format!("`{}`", name)).collect();
+            cx.sess()
+                .struct_warn(&format!(
+                    "the key `{}` in Clippy's configuration file `{}` has no effect",
+                    key,
+                    file_name.display()
+                ))
+                .note(&format!(
+                    "it is only used by {}, which {} allowed",
+                    lint_names.join(", "),
+                    if lint_names.len() == 1 { "is" } else { "are all" }
+                ))
+                .help("enable the lint or remove the key from the configuration file")
+                .emit();
+        }
+    }
+}
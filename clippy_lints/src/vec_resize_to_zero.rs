@@ -29,6 +29,7 @@ declare_clippy_lint! {
 declare_lint_pass!(VecResizeToZero => [VEC_RESIZE_TO_ZERO]);
 
 impl<'tcx> LateLintPass<'tcx> for VecResizeToZero {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             if let hir::ExprKind::MethodCall(path_segment, _, ref args, _) = expr.kind;
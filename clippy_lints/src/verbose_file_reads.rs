@@ -1,4 +1,4 @@
-use crate::utils::{match_type, paths, span_lint_and_help};
+use crate::utils::{is_type_std_item, span_lint_and_help, std_items};
 use if_chain::if_chain;
 use rustc_hir::{Expr, ExprKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
@@ -34,6 +34,7 @@ declare_clippy_lint! {
 declare_lint_pass!(VerboseFileReads => [VERBOSE_FILE_READS]);
 
 impl<'tcx> LateLintPass<'tcx> for VerboseFileReads {
+    #[cfg_attr(feature = "internal-lints", allow(clippy::missing_expansion_check))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         if is_file_read_to_end(cx, expr) {
             span_lint_and_help(
@@ -63,7 +64,7 @@ fn is_file_read_to_end<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) ->
         if method_name.ident.as_str() == "read_to_end";
         if let ExprKind::Path(QPath::Resolved(None, _)) = &exprs[0].kind;
         let ty = cx.typeck_results().expr_ty(&exprs[0]);
-        if match_type(cx, ty, &paths::FILE);
+        if is_type_std_item(cx, ty, &std_items::FILE);
         then {
             return true
         }
@@ -77,7 +78,7 @@ fn is_file_read_to_string<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>)
         if method_name.ident.as_str() == "read_to_string";
         if let ExprKind::Path(QPath::Resolved(None, _)) = &exprs[0].kind;
         let ty = cx.typeck_results().expr_ty(&exprs[0]);
-        if match_type(cx, ty, &paths::FILE);
+        if is_type_std_item(cx, ty, &std_items::FILE);
         then {
             return true
         }
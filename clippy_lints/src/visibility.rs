@@ -0,0 +1,119 @@
+use crate::utils::{in_macro, snippet_opt, span_lint_and_sugg};
+use rustc_ast::ast::{self, AssocItem, CrateSugar, Item, StructField, VisibilityKind};
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::kw;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for visibilities written with the shorthand syntax, like
+    /// `pub(crate)`, `pub(super)` and `pub(self)`.
+    ///
+    /// **Why is this bad?** Some teams prefer to always write the module with `in`, which is
+    /// required for any other path anyway. This lint is the opposite of `pub_without_shorthand`,
+    /// and only one of them should be enabled.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub(crate) fn foo() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub(in crate) fn foo() {}
+    /// ```
+    pub PUB_WITH_SHORTHAND,
+    restriction,
+    "visibilities written as `pub(crate)`, `pub(super)` or `pub(self)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for visibilities restricted to `crate`, `super` or `self` which
+    /// are written with `in`, like `pub(in crate)`.
+    ///
+    /// **Why is this bad?** The shorthand syntax is shorter and more common. This lint is the
+    /// opposite of `pub_with_shorthand`, and only one of them should be enabled.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub(in crate) fn foo() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub(crate) fn foo() {}
+    /// ```
+    pub PUB_WITHOUT_SHORTHAND,
+    restriction,
+    "visibilities written as `pub(in crate)`, `pub(in super)` or `pub(in self)`"
+}
+
+declare_lint_pass!(Visibility => [PUB_WITH_SHORTHAND, PUB_WITHOUT_SHORTHAND]);
+
+impl EarlyLintPass for Visibility {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        check_vis(cx, &item.vis);
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext<'_>, item: &AssocItem) {
+        check_vis(cx, &item.vis);
+    }
+
+    fn check_struct_field(&mut self, cx: &EarlyContext<'_>, field: &StructField) {
+        check_vis(cx, &field.vis);
+    }
+}
+
+fn check_vis(cx: &EarlyContext<'_>, vis: &ast::Visibility) {
+    if in_macro(vis.span) {
+        return;
+    }
+
+    match vis.kind {
+        VisibilityKind::Crate(CrateSugar::PubCrate) => lint_shorthand(cx, vis, "crate"),
+        VisibilityKind::Restricted { ref path, .. } => {
+            let is_shorthand_path = matches!(
+                path.segments.as_slice(),
+                [segment] if matches!(segment.ident.name, kw::Crate | kw::Super | kw::SelfLower)
+            );
+            if !is_shorthand_path {
+                return;
+            }
+            let (before_path, path) = match (
+                snippet_opt(cx, vis.span.with_hi(path.span.lo())),
+                snippet_opt(cx, path.span),
+            ) {
+                (Some(before_path), Some(path)) => (before_path, path),
+                _ => return,
+            };
+            if before_path.trim_end().ends_with("in") {
+                span_lint_and_sugg(
+                    cx,
+                    PUB_WITHOUT_SHORTHAND,
+                    vis.span,
+                    "visibility written with `in`",
+                    "use the shorthand",
+                    format!("pub({})", path),
+                    Applicability::MachineApplicable,
+                );
+            } else {
+                lint_shorthand(cx, vis, &path);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn lint_shorthand(cx: &EarlyContext<'_>, vis: &ast::Visibility, path: &str) {
+    span_lint_and_sugg(
+        cx,
+        PUB_WITH_SHORTHAND,
+        vis.span,
+        "visibility written with the shorthand",
+        "add `in`",
+        format!("pub(in {})", path),
+        Applicability::MachineApplicable,
+    );
+}
@@ -1,10 +1,11 @@
 use crate::utils::{run_lints, span_lint};
+use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::{hir_id::CRATE_HIR_ID, Crate};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::DUMMY_SP;
 
-use if_chain::if_chain;
+use cargo_metadata::{Dependency, DependencyKind};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for wildcard dependencies in the `Cargo.toml`.
@@ -26,32 +27,133 @@ declare_clippy_lint! {
     "wildcard dependencies being used"
 }
 
-declare_lint_pass!(WildcardDependencies => [WILDCARD_DEPENDENCIES]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for git dependencies in the `Cargo.toml` which are not pinned to a
+    /// specific `rev`.
+    ///
+    /// **Why is this bad?** Without a `rev`, the dependency follows a branch or a tag, which can
+    /// change at any time. Builds without a `Cargo.lock` are then not reproducible and can break
+    /// unexpectedly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// regex = { git = "https://github.com/rust-lang/regex" }
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// [dependencies]
+    /// regex = { git = "https://github.com/rust-lang/regex", rev = "0d0023e" }
+    /// ```
+    pub UNPINNED_GIT_DEPENDENCIES,
+    cargo,
+    "git dependencies without a `rev`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for path dependencies without a version requirement in the
+    /// `Cargo.toml` of crates which can be published.
+    ///
+    /// **Why is this bad?** crates.io doesn't accept path dependencies without a version, so the
+    /// crate can't be published. Crates that are only used inside of a workspace should set
+    /// `publish = false`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// my_utils = { path = "../my_utils" }
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// [dependencies]
+    /// my_utils = { path = "../my_utils", version = "0.1" }
+    /// ```
+    pub PATH_DEPENDENCIES,
+    cargo,
+    "path dependencies without a version in crates which can be published"
+}
+
+declare_lint_pass!(WildcardDependencies => [WILDCARD_DEPENDENCIES, UNPINNED_GIT_DEPENDENCIES, PATH_DEPENDENCIES]);
 
 impl LateLintPass<'_> for WildcardDependencies {
     fn check_crate(&mut self, cx: &LateContext<'_>, _: &Crate<'_>) {
-        if !run_lints(cx, &[WILDCARD_DEPENDENCIES], CRATE_HIR_ID) {
+        if !run_lints(
+            cx,
+            &[WILDCARD_DEPENDENCIES, UNPINNED_GIT_DEPENDENCIES, PATH_DEPENDENCIES],
+            CRATE_HIR_ID,
+        ) {
             return;
         }
 
         let metadata = unwrap_cargo_metadata!(cx, WILDCARD_DEPENDENCIES, false);
+        let local_name = cx.tcx.crate_name(LOCAL_CRATE).as_str();
+        let package = metadata
+            .packages
+            .iter()
+            // The path dependencies are packages of the metadata too, and may come before this one
+            .find(|p| p.name.replace('-', "_") == *local_name)
+            .unwrap_or(&metadata.packages[0]);
+        // `publish = false` is an empty list of registries
+        let is_published = package
+            .publish
+            .as_ref()
+            .map_or(true, |registries| !registries.is_empty());
 
-        for dep in &metadata.packages[0].dependencies {
-            // VersionReq::any() does not work
-            if_chain! {
-                if let Ok(wildcard_ver) = semver::VersionReq::parse("*");
-                if let Some(ref source) = dep.source;
-                if !source.starts_with("git");
-                if dep.req == wildcard_ver;
-                then {
-                    span_lint(
-                        cx,
-                        WILDCARD_DEPENDENCIES,
-                        DUMMY_SP,
-                        &format!("wildcard dependency for `{}`", dep.name),
-                    );
-                }
+        for dep in &package.dependencies {
+            match dep.source {
+                Some(ref source) if source.starts_with("git+") => {
+                    if !is_pinned_git_source(source) {
+                        span_lint(
+                            cx,
+                            UNPINNED_GIT_DEPENDENCIES,
+                            DUMMY_SP,
+                            &format!("git dependency `{}` is not pinned to a `rev`", dep.name),
+                        );
+                    }
+                },
+                Some(_) => {
+                    if is_wildcard(dep) {
+                        span_lint(
+                            cx,
+                            WILDCARD_DEPENDENCIES,
+                            DUMMY_SP,
+                            &format!("wildcard dependency for `{}`", dep.name),
+                        );
+                    }
+                },
+                // Path dependencies don't have a source. Dev-dependencies without a version are
+                // removed when publishing, so they are fine.
+                None => {
+                    if is_published && !matches!(dep.kind, DependencyKind::Development) && is_wildcard(dep) {
+                        span_lint(
+                            cx,
+                            PATH_DEPENDENCIES,
+                            DUMMY_SP,
+                            &format!("path dependency `{}` has no version requirement", dep.name),
+                        );
+                    }
+                },
             }
         }
     }
 }
+
+fn is_wildcard(dep: &Dependency) -> bool {
+    // VersionReq::any() does not work
+    semver::VersionReq::parse("*").map_or(false, |wildcard_ver| dep.req == wildcard_ver)
+}
+
+/// Checks if the source of a git dependency, e.g. `git+https://github.com/rust-lang/regex?rev=0d0023e`,
+/// specifies a `rev`.
+fn is_pinned_git_source(source: &str) -> bool {
+    let url = source.splitn(2, '#').next().unwrap_or(source);
+    url.splitn(2, '?')
+        .nth(1)
+        .map_or(false, |query| query.split('&').any(|param| param.starts_with("rev=")))
+}
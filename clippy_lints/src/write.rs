@@ -2,9 +2,9 @@ use std::borrow::Cow;
 use std::ops::Range;
 
 use crate::utils::{snippet_with_applicability, span_lint, span_lint_and_sugg, span_lint_and_then};
-use rustc_ast::ast::{Expr, ExprKind, Item, ItemKind, MacCall, StrLit, StrStyle};
+use rustc_ast::ast::{Expr, ExprKind, Item, ItemKind, MacCall, Path, StrLit, StrStyle};
 use rustc_ast::token;
-use rustc_ast::tokenstream::TokenStream;
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
 use rustc_errors::Applicability;
 use rustc_lexer::unescape::{self, EscapeError};
 use rustc_lint::{EarlyContext, EarlyLintPass};
@@ -64,6 +64,9 @@ declare_clippy_lint! {
     /// **Why is this bad?** People often print on *stdout* while debugging an
     /// application and might forget to remove those prints afterward.
     ///
+    /// If the `logging-macro` configuration names the logging macro of the project, like
+    /// `tracing::info`, it is suggested instead.
+    ///
     /// **Known problems:** Only catches `print!` and `println!` calls.
     ///
     /// **Example:**
@@ -99,6 +102,9 @@ declare_clippy_lint! {
     /// (c.f., https://github.com/matthiaskrgr/rust-str-bench) and unnecessary
     /// (i.e., just put the literal in the format string)
     ///
+    /// Third-party macros taking a format string, like `log::info!`, are checked if they are
+    /// listed in the `format-macro-paths` configuration.
+    ///
     /// **Known problems:** Will also warn with macro calls as arguments that expand to literals
     /// -- e.g., `println!("{}", env!("FOO"))`.
     ///
@@ -192,9 +198,64 @@ declare_clippy_lint! {
     "writing a literal with a format string"
 }
 
-#[derive(Default)]
 pub struct Write {
     in_debug_impl: bool,
+    /// The paths of third-party macros taking a format string and its arguments, like `log::info`
+    format_macro_paths: Vec<Vec<Symbol>>,
+    /// The logging macro to suggest instead of printing on stdout, without the `!`
+    logging_macro: Option<String>,
+}
+
+impl Write {
+    pub fn new(format_macro_paths: &[String], logging_macro: Option<&str>) -> Self {
+        Self {
+            in_debug_impl: false,
+            format_macro_paths: format_macro_paths
+                .iter()
+                .map(|s| s.split("::").map(|seg| Symbol::intern(seg)).collect::<Vec<_>>())
+                .collect(),
+            logging_macro: logging_macro.map(|name| name.trim_end_matches('!').to_string()),
+        }
+    }
+
+    /// Lints a use of `print!` or `println!`, pointing at the configured logging macro if there
+    /// is one.
+    fn lint_print_stdout(&self, cx: &EarlyContext<'_>, mac: &MacCall, name: &str) {
+        let msg = format!("use of `{}!`", name);
+        let logging_macro = match self.logging_macro {
+            Some(ref logging_macro) => logging_macro,
+            None => return span_lint(cx, PRINT_STDOUT, mac.span(), &msg),
+        };
+
+        span_lint_and_then(cx, PRINT_STDOUT, mac.span(), &msg, |diag| {
+            let help = format!("use `{}!` to log the message instead", logging_macro);
+            // a log record is a whole line, so only `println!` with a format string maps to one
+            if name == "println" && starts_with_str_lit(mac.args.inner_tokens()) {
+                diag.span_suggestion(
+                    mac.path.span,
+                    &help,
+                    logging_macro.clone(),
+                    Applicability::MaybeIncorrect,
+                );
+            } else {
+                diag.help(&help);
+            }
+        });
+    }
+
+    /// Checks if the macro is one of the configured format macros. Macro paths aren't resolved
+    /// before expansion, so `info!` matches `log::info` as well as any other `info` macro.
+    fn is_format_macro(&self, path: &Path) -> bool {
+        self.format_macro_paths.iter().any(|format_path| {
+            path.segments.len() <= format_path.len()
+                && path
+                    .segments
+                    .iter()
+                    .rev()
+                    .zip(format_path.iter().rev())
+                    .all(|(segment, name)| segment.ident.name == *name)
+        })
+    }
 }
 
 impl_lint_pass!(Write => [
@@ -245,7 +306,7 @@ impl EarlyLintPass for Write {
 
         if mac.path == sym!(println) {
             if !is_build_script(cx) {
-                span_lint(cx, PRINT_STDOUT, mac.span(), "use of `println!`");
+                self.lint_print_stdout(cx, mac, "println");
             }
             if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), false) {
                 if fmt_str.symbol == Symbol::intern("") {
@@ -262,7 +323,7 @@ impl EarlyLintPass for Write {
             }
         } else if mac.path == sym!(print) {
             if !is_build_script(cx) {
-                span_lint(cx, PRINT_STDOUT, mac.span(), "use of `print!`");
+                self.lint_print_stdout(cx, mac, "print");
             }
             if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), false) {
                 if check_newlines(&fmt_str) {
@@ -329,6 +390,8 @@ impl EarlyLintPass for Write {
                     );
                 }
             }
+        } else if self.is_format_macro(&mac.path) {
+            self.check_tts(cx, mac.args.inner_tokens(), false);
         }
     }
 }
@@ -489,6 +552,21 @@ impl Write {
     }
 }
 
+/// Checks if the macro arguments start with a string literal, like the format string of
+/// `println!("..", ..)`.
+fn starts_with_str_lit(tts: TokenStream) -> bool {
+    matches!(
+        tts.trees().next(),
+        Some(TokenTree::Token(token::Token {
+            kind: token::Literal(token::Lit {
+                kind: token::LitKind::Str | token::LitKind::StrRaw(_),
+                ..
+            }),
+            ..
+        }))
+    )
+}
+
 /// Checks if the format string contains a single newline that terminates it.
 ///
 /// Literal and escaped newlines are both checked (only literal for raw strings).
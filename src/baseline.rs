@@ -0,0 +1,92 @@
+//! Baseline files, for `cargo clippy --baseline <file>`. A baseline records the diagnostics of a
+//! project, so that later runs only report the new ones.
+//!
+//! Diagnostics are identified by their lint, their file and the source lines of their primary
+//! span, without line numbers, so they still match after code is added above them.
+
+use crate::messages::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Fingerprint {
+    /// The lint name, or the message of diagnostics without a code
+    lint: String,
+    file: String,
+    /// The trimmed source lines of the primary span
+    context: String,
+}
+
+impl Fingerprint {
+    fn new(diagnostic: &Diagnostic) -> Option<Self> {
+        let span = diagnostic.primary_span()?;
+        let context = span
+            .text
+            .iter()
+            .map(|line| line.text.trim())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(Self {
+            lint: diagnostic
+                .code
+                .as_ref()
+                .map_or_else(|| diagnostic.message.clone(), |code| code.code.clone()),
+            file: span.file_name.replace('\\', "/"),
+            context,
+        })
+    }
+}
+
+pub struct Baseline {
+    /// How many times each fingerprint is allowed, as the same diagnostic may be emitted for
+    /// identical lines
+    counts: BTreeMap<Fingerprint, usize>,
+}
+
+impl Baseline {
+    /// Reads the baseline file, returning `None` if it doesn't exist yet.
+    pub fn read(path: &Path) -> io::Result<Option<Self>> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        let fingerprints: Vec<Fingerprint> = serde_json::from_str(&contents)?;
+
+        let mut counts = BTreeMap::new();
+        for fingerprint in fingerprints {
+            *counts.entry(fingerprint).or_insert(0) += 1;
+        }
+        Ok(Some(Self { counts }))
+    }
+
+    /// Writes the baseline file recording the diagnostics.
+    pub fn write(path: &Path, diagnostics: &[Diagnostic]) -> io::Result<()> {
+        let mut fingerprints: Vec<_> = diagnostics.iter().filter_map(Fingerprint::new).collect();
+        // sorted, so that the file can be reviewed and diffed
+        fingerprints.sort();
+        let mut contents = serde_json::to_string_pretty(&fingerprints)?;
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+
+    /// Removes the diagnostics recorded in the baseline.
+    pub fn filter(mut self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let count = Fingerprint::new(diagnostic).and_then(|fingerprint| self.counts.get_mut(&fingerprint));
+                match count {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    },
+                    _ => true,
+                }
+            })
+            .collect()
+    }
+}
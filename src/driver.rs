@@ -11,8 +11,11 @@
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_interface;
+extern crate rustc_lint;
 extern crate rustc_middle;
 
+mod json_out;
+
 use rustc_interface::interface;
 use rustc_middle::ty::TyCtxt;
 use rustc_tools_util::VersionInfo;
@@ -77,8 +80,9 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
 
             let conf = clippy_lints::read_conf(&[], &sess);
             clippy_lints::register_plugins(&mut lint_store, &sess, &conf);
-            clippy_lints::register_pre_expansion_lints(&mut lint_store);
+            clippy_lints::register_pre_expansion_lints(&mut lint_store, &conf);
             clippy_lints::register_renamed(&mut lint_store);
+            json_out::set_conf_and_lints(&conf, lint_store);
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -101,6 +105,9 @@ Common options:
     -h, --help               Print this message
         --rustc              Pass all args to rustc
     -V, --version            Print version info and exit
+        --clippy-json-out <PATH>
+                             Write the Clippy diagnostics, the configuration and
+                             the lints to a JSON file, e.g. for build systems
 
 Other options are the same as `cargo check`.
 
@@ -115,10 +122,33 @@ with:
 You can use tool lints to allow or deny lints from your code, eg.:
 
     #[allow(clippy::needless_lifetimes)]
+
+When clippy-driver is run directly, e.g. by a build system, it reads:
+
+    CLIPPY_ARGS         Args passed after the command line args, separated by
+                        whitespace or `__CLIPPY_HACKERY__`
+    CLIPPY_CONF_DIR     The directory the search for `clippy.toml` starts in,
+                        instead of `CARGO_MANIFEST_DIR`
+    CLIPPY_CONF_PATH    The configuration file to use instead of searching
 "
     );
 }
 
+/// Splits the args of `CLIPPY_ARGS`. `cargo clippy` separates them with `__CLIPPY_HACKERY__`, so
+/// that they can contain whitespace, but when the driver is run directly by a build system they
+/// can also be separated by whitespace.
+fn split_clippy_args(clippy_args: &str) -> Vec<String> {
+    if clippy_args.contains("__CLIPPY_HACKERY__") {
+        clippy_args
+            .split("__CLIPPY_HACKERY__")
+            .filter(|s| !s.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    } else {
+        clippy_args.split_whitespace().map(ToString::to_string).collect()
+    }
+}
+
 const BUG_REPORT_URL: &str = "https://github.com/rust-lang/rust-clippy/issues/new";
 
 static ICE_HOOK: SyncLazy<Box<dyn Fn(&panic::PanicInfo<'_>) + Sync + Send + 'static>> = SyncLazy::new(|| {
@@ -182,12 +212,49 @@ fn toolchain_path(home: Option<String>, toolchain: Option<String>) -> Option<Pat
     })
 }
 
+/// Removes the option `find_arg` from the args, returning its value. The option is either
+/// `--arg=value` or `--arg value`.
+fn take_arg_value(args: &mut Vec<String>, find_arg: &str) -> Option<String> {
+    let pos = args
+        .iter()
+        .position(|arg| arg == find_arg || arg.strip_prefix(find_arg).map_or(false, |s| s.starts_with('=')))?;
+    let arg = args.remove(pos);
+    if arg != find_arg {
+        Some(arg[find_arg.len() + 1..].to_string())
+    } else if pos < args.len() {
+        Some(args.remove(pos))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_take_arg_value() {
+    let mut args: Vec<String> = ["--bar=bar", "--foobar", "123", "--foo"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    assert_eq!(take_arg_value(&mut args, "--baz"), None);
+    assert_eq!(take_arg_value(&mut args, "--bar"), Some("bar".to_string()));
+    assert_eq!(take_arg_value(&mut args, "--foobar"), Some("123".to_string()));
+    assert_eq!(take_arg_value(&mut args, "--foo"), None);
+    assert!(args.is_empty());
+}
+
 pub fn main() {
     rustc_driver::init_rustc_env_logger();
     SyncLazy::force(&ICE_HOOK);
     exit(rustc_driver::catch_with_exit_code(move || {
         let mut orig_args: Vec<String> = env::args().collect();
 
+        // the file is written even when Clippy doesn't run, so that build systems can expect
+        // one for every crate
+        let json_out_path = take_arg_value(&mut orig_args, "--clippy-json-out").map(PathBuf::from);
+        if json_out_path.is_some() {
+            json_out::init();
+        }
+
         // Get the sysroot, looking from most specific to this invocation to the least:
         // - command line
         // - runtime environment
@@ -284,20 +351,22 @@ pub fn main() {
 
         if clippy_enabled {
             args.extend(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()]);
+            // `CLIPPY_ARGS` comes after the command line args, so its lint levels take precedence
             if let Ok(extra_args) = env::var("CLIPPY_ARGS") {
-                args.extend(extra_args.split("__CLIPPY_HACKERY__").filter_map(|s| {
-                    if s.is_empty() {
-                        None
-                    } else {
-                        Some(s.to_string())
-                    }
-                }));
+                args.extend(split_clippy_args(&extra_args));
             }
         }
         let mut clippy = ClippyCallbacks;
         let mut default = DefaultCallbacks;
         let callbacks: &mut (dyn rustc_driver::Callbacks + Send) =
             if clippy_enabled { &mut clippy } else { &mut default };
-        rustc_driver::RunCompiler::new(&args, callbacks).run()
+        let result = rustc_driver::RunCompiler::new(&args, callbacks).run();
+        if let Some(path) = json_out_path {
+            if let Err(error) = json_out::write(&path) {
+                eprintln!("error: could not write `{}`: {}", path.display(), error);
+                exit(1);
+            }
+        }
+        result
     }))
 }
@@ -0,0 +1,264 @@
+//! The static HTML report written by `cargo clippy --report-html <dir>`, listing the diagnostics
+//! by lint group and lint, to triage them outside of the terminal.
+
+use crate::lint_groups::lint_group;
+use crate::messages::{Diagnostic, DiagnosticSpan, DiagnosticSpanLine};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+const CLIPPY_LINTS_URI: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; }
+summary { cursor: pointer; }
+details.group > summary { font-size: 1.4em; font-weight: bold; margin-top: 1em; }
+details.lint { margin-left: 1.5em; }
+details.lint > summary { font-size: 1.1em; margin-top: 0.5em; }
+div.diagnostic { border-left: 3px solid #ccc; margin: 0.8em 0 0.8em 1.5em; padding-left: 0.8em; }
+div.error { border-color: #d33; }
+div.warning { border-color: #e90; }
+pre { background: #f5f5f5; overflow-x: auto; padding: 0.5em; }
+mark { background: #fd6; }
+span.line-number { color: #888; user-select: none; }
+";
+
+/// The group of diagnostics which aren't Clippy lints, like errors and the lints of rustc
+const OTHER_GROUP: &str = "rustc";
+
+/// group -> lint -> diagnostics, the lint being empty for diagnostics without a code
+type Groups<'a> = BTreeMap<&'static str, BTreeMap<&'a str, Vec<&'a Diagnostic>>>;
+
+/// Writes `index.html` in the directory, creating it if needed. Returns the path of the file.
+pub fn write_report(diagnostics: &[Diagnostic], dir: &Path) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join("index.html");
+    let mut out = BufWriter::new(File::create(&path)?);
+    write_html(&mut out, diagnostics)?;
+    out.flush()?;
+    Ok(path)
+}
+
+fn group_diagnostics(diagnostics: &[Diagnostic]) -> Groups<'_> {
+    let mut groups = Groups::new();
+    for diagnostic in diagnostics {
+        let lint = diagnostic.code.as_ref().map_or("", |code| code.code.as_str());
+        groups
+            .entry(lint_group(lint).unwrap_or(OTHER_GROUP))
+            .or_default()
+            .entry(lint)
+            .or_default()
+            .push(diagnostic);
+    }
+    groups
+}
+
+fn write_html(out: &mut impl Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    let groups = group_diagnostics(diagnostics);
+    writeln!(out, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">")?;
+    writeln!(out, "<title>Clippy report</title>\n<style>{}</style>", STYLE)?;
+    writeln!(out, "</head>\n<body>\n<h1>Clippy report</h1>")?;
+    writeln!(
+        out,
+        "<p>{} diagnostics in {} groups.</p>",
+        diagnostics.len(),
+        groups.len()
+    )?;
+
+    for (group, lints) in &groups {
+        let count: usize = lints.values().map(Vec::len).sum();
+        writeln!(
+            out,
+            "<details class=\"group\" open>\n<summary>{} ({})</summary>",
+            escape(group),
+            count
+        )?;
+        for (lint, diagnostics) in lints {
+            writeln!(
+                out,
+                "<details class=\"lint\" open>\n<summary>{} ({})</summary>",
+                lint_title(lint),
+                diagnostics.len()
+            )?;
+            for diagnostic in diagnostics {
+                write_diagnostic(out, diagnostic)?;
+            }
+            writeln!(out, "</details>")?;
+        }
+        writeln!(out, "</details>")?;
+    }
+    writeln!(out, "</body>\n</html>")
+}
+
+/// The name of the lint, linking to its documentation if it's a Clippy lint.
+fn lint_title(lint: &str) -> String {
+    match lint.strip_prefix("clippy::") {
+        Some(name) => format!(
+            "<a href=\"{}#{}\"><code>{}</code></a>",
+            CLIPPY_LINTS_URI,
+            escape(name),
+            escape(lint)
+        ),
+        None if lint.is_empty() => "without a lint".to_string(),
+        None => format!("<code>{}</code>", escape(lint)),
+    }
+}
+
+fn write_diagnostic(out: &mut impl Write, diagnostic: &Diagnostic) -> io::Result<()> {
+    writeln!(
+        out,
+        "<div class=\"diagnostic {}\">\n<p><strong>{}</strong>: {}</p>",
+        escape(&diagnostic.level),
+        escape(&diagnostic.level),
+        escape(&diagnostic.message)
+    )?;
+    if let Some(span) = diagnostic.primary_span() {
+        write_span(out, span)?;
+    }
+    if !diagnostic.children.is_empty() {
+        writeln!(out, "<ul>")?;
+        for child in &diagnostic.children {
+            write!(out, "<li>{}: {}", escape(&child.level), escape(&child.message))?;
+            for span in &child.spans {
+                if let Some(ref replacement) = span.suggested_replacement {
+                    write!(out, "<pre>{}</pre>", escape(replacement))?;
+                }
+            }
+            writeln!(out, "</li>")?;
+        }
+        writeln!(out, "</ul>")?;
+    }
+    writeln!(out, "</div>")
+}
+
+/// Writes the location and the source lines of the span, with the span highlighted.
+fn write_span(out: &mut impl Write, span: &DiagnosticSpan) -> io::Result<()> {
+    writeln!(
+        out,
+        "<p><code>{}:{}:{}</code></p>",
+        escape(&span.file_name),
+        span.line_start,
+        span.column_start
+    )?;
+    write!(out, "<pre>")?;
+    for (line_number, line) in (span.line_start..).zip(&span.text) {
+        let (before, highlight, after) = split_highlight(line);
+        writeln!(
+            out,
+            "<span class=\"line-number\">{:>5} </span>{}<mark>{}</mark>{}",
+            line_number,
+            escape(before),
+            escape(highlight),
+            escape(after)
+        )?;
+    }
+    writeln!(out, "</pre>")
+}
+
+/// Splits the line into the text before the highlight, the highlight and the text after it.
+fn split_highlight(line: &DiagnosticSpanLine) -> (&str, &str, &str) {
+    // the columns are counted in characters
+    let byte_index = |column: usize| {
+        line.text
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(line.text.len(), |(index, _)| index)
+    };
+    let start = byte_index(line.highlight_start);
+    let end = byte_index(line.highlight_end).max(start);
+    (&line.text[..start], &line.text[start..end], &line.text[end..])
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, group_diagnostics, split_highlight, write_html, OTHER_GROUP};
+    use crate::messages::{Diagnostic, DiagnosticCode, DiagnosticSpanLine};
+
+    fn diagnostic(code: Option<&str>) -> Diagnostic {
+        Diagnostic {
+            message: String::new(),
+            code: code.map(|code| DiagnosticCode { code: code.to_string() }),
+            level: "warning".to_string(),
+            spans: Vec::new(),
+            children: Vec::new(),
+            rendered: None,
+        }
+    }
+
+    fn line(text: &str, highlight_start: usize, highlight_end: usize) -> DiagnosticSpanLine {
+        DiagnosticSpanLine {
+            text: text.to_string(),
+            highlight_start,
+            highlight_end,
+        }
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(
+            escape("a < b && c > \"d\" + 'e'"),
+            "a &lt; b &amp;&amp; c &gt; &quot;d&quot; + &#39;e&#39;"
+        );
+        assert_eq!(escape("Vec<T>"), "Vec&lt;T&gt;");
+        assert_eq!(escape("plain"), "plain");
+    }
+
+    #[test]
+    fn grouping() {
+        let diagnostics = vec![
+            diagnostic(Some("clippy::needless_return")),
+            diagnostic(Some("clippy::redundant_clone")),
+            diagnostic(Some("clippy::needless_return")),
+            diagnostic(Some("E0308")),
+            diagnostic(None),
+        ];
+        let groups = group_diagnostics(&diagnostics);
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            ["perf", OTHER_GROUP, "style"]
+        );
+        assert_eq!(groups["style"]["clippy::needless_return"].len(), 2);
+        assert_eq!(groups["perf"]["clippy::redundant_clone"].len(), 1);
+        assert_eq!(groups[OTHER_GROUP].keys().copied().collect::<Vec<_>>(), ["", "E0308"]);
+    }
+
+    #[test]
+    fn highlight() {
+        assert_eq!(split_highlight(&line("let x = 1;", 5, 6)), ("let ", "x", " = 1;"));
+        // the columns count characters, not bytes
+        assert_eq!(
+            split_highlight(&line("let é = \"ü\";", 9, 12)),
+            ("let é = ", "\"ü\"", ";")
+        );
+        // columns past the end of the line
+        assert_eq!(split_highlight(&line("x", 1, 10)), ("", "x", ""));
+        // a highlight ending before it starts is empty
+        assert_eq!(split_highlight(&line("abc", 3, 1)), ("ab", "", "c"));
+    }
+
+    #[test]
+    fn html() {
+        let mut out = Vec::new();
+        write_html(&mut out, &[diagnostic(Some("clippy::needless_return"))]).unwrap();
+        let html = String::from_utf8(out).unwrap();
+        assert!(html.contains("<p>1 diagnostics in 1 groups.</p>"));
+        assert!(html.contains("<summary>style (1)</summary>"));
+        assert!(html.contains("#needless_return\"><code>clippy::needless_return</code></a> (1)</summary>"));
+    }
+}
@@ -0,0 +1,86 @@
+//! The JSON file written by `clippy-driver --clippy-json-out <path>`, for build systems which run
+//! the driver directly and collect the results of each crate without parsing the output of rustc.
+
+use clippy_lints::{take_recorded_diagnostics, Conf, RecordedDiagnostic};
+use rustc_lint::LintStore;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::lazy::SyncLazy;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The version of the format of the file. It's increased when a field is removed or changes its
+/// meaning, new fields can be added without changing it.
+const VERSION: u32 = 1;
+
+/// The configuration and the lints of the crate, set while the lints are registered.
+static JSON_OUT: SyncLazy<Mutex<Option<JsonOut>>> = SyncLazy::new(|| Mutex::new(None));
+
+#[derive(Default, Serialize)]
+struct JsonOut {
+    version: u32,
+    /// The configuration files setting at least one key
+    config_files: Vec<PathBuf>,
+    /// The value of every configuration key, including the default values
+    config: Option<serde_json::Value>,
+    lints: Vec<LintMetadata>,
+    diagnostics: Vec<RecordedDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct LintMetadata {
+    name: String,
+    default_level: &'static str,
+    description: &'static str,
+    groups: Vec<&'static str>,
+}
+
+/// Starts collecting the data written to the file.
+pub fn init() {
+    *JSON_OUT.lock().unwrap() = Some(JsonOut {
+        version: VERSION,
+        ..JsonOut::default()
+    });
+    clippy_lints::record_diagnostics();
+}
+
+/// Sets the configuration and the registered Clippy lints.
+pub fn set_conf_and_lints(conf: &Conf, lint_store: &LintStore) {
+    let mut json_out = JSON_OUT.lock().unwrap();
+    let json_out = match *json_out {
+        Some(ref mut json_out) => json_out,
+        None => return,
+    };
+
+    json_out.config_files = conf.set_keys.iter().map(|(_, file)| file.clone()).collect();
+    json_out.config_files.sort();
+    json_out.config_files.dedup();
+    json_out.config = serde_json::to_value(conf).ok();
+
+    let groups = lint_store.get_lint_groups();
+    json_out.lints = lint_store
+        .get_lints()
+        .iter()
+        .filter(|lint| lint.name.starts_with("clippy::"))
+        .map(|lint| LintMetadata {
+            name: lint.name_lower(),
+            default_level: lint.default_level.as_str(),
+            description: lint.desc,
+            groups: groups
+                .iter()
+                .filter(|(_, lints, _)| lints.iter().any(|id| id.lint.name == lint.name))
+                .map(|&(group, ..)| group)
+                .collect(),
+        })
+        .collect();
+}
+
+/// Writes the file, with the diagnostics recorded since `init`.
+pub fn write(path: &Path) -> io::Result<()> {
+    let mut json_out = JSON_OUT.lock().unwrap().take().unwrap_or_default();
+    json_out.version = VERSION;
+    json_out.diagnostics = take_recorded_diagnostics();
+    let contents = serde_json::to_string_pretty(&json_out)?;
+    fs::write(path, contents)
+}
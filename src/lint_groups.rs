@@ -0,0 +1,501 @@
+//! The groups of the Clippy lints, which aren't part of the JSON messages of rustc.
+
+/// The names of the lints and their groups, sorted by name.
+const LINT_GROUPS: &[(&str, &str)] = &[
+    // begin lint groups, generated by `cargo dev update_lints`
+    ("absurd_extreme_comparisons", "correctness"),
+    ("alloc_instead_of_core", "restriction"),
+    ("almost_swapped", "correctness"),
+    ("approx_constant", "correctness"),
+    ("arbitrary_source_item_ordering", "restriction"),
+    ("arc_with_non_send_sync", "pedantic"),
+    ("as_conversions", "restriction"),
+    ("assertions_on_constants", "style"),
+    ("assign_op_pattern", "style"),
+    ("async_yields_async", "correctness"),
+    ("atomic_ordering_misuse", "nursery"),
+    ("await_holding_lock", "pedantic"),
+    ("await_holding_refcell_ref", "pedantic"),
+    ("bad_bit_mask", "correctness"),
+    ("bind_instead_of_map", "complexity"),
+    ("blacklisted_name", "style"),
+    ("blanket_clippy_restriction_lints", "style"),
+    ("blocking_call_in_async", "pedantic"),
+    ("blocks_in_if_conditions", "style"),
+    ("bool_comparison", "complexity"),
+    ("borrow_interior_mutable_const", "style"),
+    ("borrowed_box", "complexity"),
+    ("box_vec", "perf"),
+    ("boxed_local", "perf"),
+    ("builtin_type_shadow", "style"),
+    ("cargo_common_metadata", "cargo"),
+    ("cast_lossless", "pedantic"),
+    ("cast_possible_truncation", "pedantic"),
+    ("cast_possible_wrap", "pedantic"),
+    ("cast_precision_loss", "pedantic"),
+    ("cast_ptr_alignment", "pedantic"),
+    ("cast_ref_to_mut", "correctness"),
+    ("cast_sign_loss", "pedantic"),
+    ("char_lit_as_u8", "complexity"),
+    ("chars_last_cmp", "style"),
+    ("chars_next_cmp", "style"),
+    ("checked_conversions", "pedantic"),
+    ("clone_double_ref", "correctness"),
+    ("clone_on_copy", "complexity"),
+    ("clone_on_ref_ptr", "restriction"),
+    ("cmp_nan", "correctness"),
+    ("cmp_null", "style"),
+    ("cmp_owned", "perf"),
+    ("cognitive_complexity", "nursery"),
+    ("collapsible_if", "style"),
+    ("collapsible_match", "style"),
+    ("comparison_chain", "style"),
+    ("comparison_to_empty", "style"),
+    ("copy_iterator", "pedantic"),
+    ("create_dir", "restriction"),
+    ("crosspointer_transmute", "complexity"),
+    ("dbg_macro", "restriction"),
+    ("dbg_macro_in_release", "restriction"),
+    ("debug_assert_with_mut_call", "nursery"),
+    ("decimal_literal_representation", "restriction"),
+    ("declare_interior_mutable_const", "style"),
+    ("default_numeric_fallback", "restriction"),
+    ("default_trait_access", "pedantic"),
+    ("deprecated_cfg_attr", "complexity"),
+    ("deprecated_semver", "correctness"),
+    ("deref_addrof", "complexity"),
+    ("derive_hash_xor_eq", "correctness"),
+    ("derive_ord_xor_partial_ord", "correctness"),
+    ("disallowed_function", "nursery"),
+    ("disallowed_macro", "nursery"),
+    ("disallowed_method", "nursery"),
+    ("disallowed_type", "nursery"),
+    ("diverging_sub_expression", "complexity"),
+    ("doc_markdown", "pedantic"),
+    ("double_comparisons", "complexity"),
+    ("double_must_use", "style"),
+    ("double_neg", "style"),
+    ("double_parens", "complexity"),
+    ("drop_copy", "correctness"),
+    ("drop_non_drop", "complexity"),
+    ("drop_ref", "correctness"),
+    ("duplicate_underscore_argument", "style"),
+    ("duration_subsec", "complexity"),
+    ("else_if_without_else", "restriction"),
+    ("empty_enum", "pedantic"),
+    ("empty_enum_variants_with_brackets", "restriction"),
+    ("empty_line_after_outer_attr", "nursery"),
+    ("empty_loop", "style"),
+    ("enum_clike_unportable_variant", "correctness"),
+    ("enum_glob_use", "pedantic"),
+    ("enum_variant_names", "style"),
+    ("eq_op", "correctness"),
+    ("erasing_op", "correctness"),
+    ("error_impl_error", "restriction"),
+    ("eval_order_dependence", "complexity"),
+    ("excessive_precision", "style"),
+    ("exhaustive_enums", "restriction"),
+    ("exhaustive_structs", "restriction"),
+    ("exit", "restriction"),
+    ("expect_fun_call", "perf"),
+    ("expect_used", "restriction"),
+    ("expl_impl_clone_on_copy", "pedantic"),
+    ("explicit_counter_loop", "complexity"),
+    ("explicit_deref_methods", "pedantic"),
+    ("explicit_into_iter_loop", "pedantic"),
+    ("explicit_iter_loop", "pedantic"),
+    ("explicit_write", "complexity"),
+    ("extra_unused_lifetimes", "complexity"),
+    ("fallible_impl_from", "nursery"),
+    ("field_reassign_with_default", "style"),
+    ("filetype_is_file", "restriction"),
+    ("filter_map", "pedantic"),
+    ("filter_map_next", "pedantic"),
+    ("filter_next", "complexity"),
+    ("find_map", "pedantic"),
+    ("flat_map_identity", "complexity"),
+    ("float_arithmetic", "restriction"),
+    ("float_cmp", "correctness"),
+    ("float_cmp_const", "restriction"),
+    ("float_equality_without_abs", "correctness"),
+    ("fn_address_comparisons", "correctness"),
+    ("fn_params_excessive_bools", "pedantic"),
+    ("fn_to_numeric_cast", "style"),
+    ("fn_to_numeric_cast_with_truncation", "style"),
+    ("for_kv_map", "style"),
+    ("for_loops_over_fallibles", "correctness"),
+    ("forget_copy", "correctness"),
+    ("forget_non_drop", "complexity"),
+    ("forget_ref", "correctness"),
+    ("format_in_format_args", "perf"),
+    ("from_iter_instead_of_collect", "style"),
+    ("future_not_send", "nursery"),
+    ("get_last_with_len", "complexity"),
+    ("get_unwrap", "restriction"),
+    ("identity_op", "complexity"),
+    ("if_let_mutex", "correctness"),
+    ("if_let_some_result", "style"),
+    ("if_not_else", "pedantic"),
+    ("if_same_then_else", "correctness"),
+    ("ifs_same_cond", "correctness"),
+    ("impl_trait_in_params", "restriction"),
+    ("implicit_hasher", "pedantic"),
+    ("implicit_return", "restriction"),
+    ("implicit_saturating_sub", "pedantic"),
+    ("imprecise_flops", "nursery"),
+    ("inconsistent_digit_grouping", "style"),
+    ("indexing_slicing", "restriction"),
+    ("ineffective_bit_mask", "correctness"),
+    ("inefficient_to_string", "pedantic"),
+    ("infallible_destructuring_match", "style"),
+    ("infinite_iter", "correctness"),
+    ("inherent_to_string", "style"),
+    ("inherent_to_string_shadow_display", "correctness"),
+    ("inline_always", "pedantic"),
+    ("inline_asm_x86_att_syntax", "restriction"),
+    ("inline_asm_x86_intel_syntax", "restriction"),
+    ("inline_fn_without_body", "correctness"),
+    ("int_plus_one", "complexity"),
+    ("integer_arithmetic", "restriction"),
+    ("integer_division", "restriction"),
+    ("into_iter_on_ref", "style"),
+    ("invalid_atomic_ordering", "correctness"),
+    ("invalid_regex", "correctness"),
+    ("invalid_upcast_comparisons", "pedantic"),
+    ("invisible_characters", "correctness"),
+    ("items_after_statements", "pedantic"),
+    ("iter_cloned_collect", "style"),
+    ("iter_next_loop", "correctness"),
+    ("iter_next_slice", "style"),
+    ("iter_nth", "perf"),
+    ("iter_nth_zero", "style"),
+    ("iter_skip_next", "style"),
+    ("iterator_step_by_zero", "correctness"),
+    ("join_absolute_paths", "nursery"),
+    ("just_underscores_and_digits", "style"),
+    ("large_const_arrays", "perf"),
+    ("large_digit_groups", "pedantic"),
+    ("large_enum_variant", "perf"),
+    ("large_error_variant", "perf"),
+    ("large_stack_arrays", "pedantic"),
+    ("large_types_passed_by_value", "pedantic"),
+    ("len_without_is_empty", "style"),
+    ("len_zero", "style"),
+    ("let_and_return", "style"),
+    ("let_underscore_drop", "pedantic"),
+    ("let_underscore_lock", "correctness"),
+    ("let_underscore_must_use", "restriction"),
+    ("let_underscore_result", "pedantic"),
+    ("let_unit_value", "pedantic"),
+    ("lines_filter_map_ok", "pedantic"),
+    ("linkedlist", "pedantic"),
+    ("lint_groups_priority", "cargo"),
+    ("literal_string_with_formatting_args", "pedantic"),
+    ("logic_bug", "correctness"),
+    ("lossy_float_literal", "restriction"),
+    ("macro_use_imports", "pedantic"),
+    ("main_recursion", "style"),
+    ("manual_async_fn", "style"),
+    ("manual_contains", "perf"),
+    ("manual_find", "complexity"),
+    ("manual_inspect", "complexity"),
+    ("manual_memcpy", "perf"),
+    ("manual_non_exhaustive", "style"),
+    ("manual_ok_or", "pedantic"),
+    ("manual_range_contains", "style"),
+    ("manual_range_patterns", "complexity"),
+    ("manual_retain", "perf"),
+    ("manual_saturating_arithmetic", "style"),
+    ("manual_strip", "complexity"),
+    ("manual_swap", "complexity"),
+    ("manual_unwrap_or", "complexity"),
+    ("many_single_char_names", "style"),
+    ("map_clone", "style"),
+    ("map_collect_result_unit", "style"),
+    ("map_entry", "perf"),
+    ("map_err_ignore", "pedantic"),
+    ("map_flatten", "pedantic"),
+    ("map_identity", "complexity"),
+    ("map_unwrap_or", "pedantic"),
+    ("match_as_ref", "complexity"),
+    ("match_bool", "pedantic"),
+    ("match_like_matches_macro", "style"),
+    ("match_on_vec_items", "pedantic"),
+    ("match_overlapping_arm", "style"),
+    ("match_ref_pats", "style"),
+    ("match_same_arms", "pedantic"),
+    ("match_single_binding", "complexity"),
+    ("match_wild_err_arm", "pedantic"),
+    ("match_wildcard_for_single_variants", "pedantic"),
+    ("maybe_infinite_iter", "pedantic"),
+    ("mem_discriminant_non_enum", "correctness"),
+    ("mem_forget", "restriction"),
+    ("mem_replace_option_with_none", "style"),
+    ("mem_replace_with_default", "style"),
+    ("mem_replace_with_uninit", "correctness"),
+    ("min_max", "correctness"),
+    ("mismatched_target_os", "correctness"),
+    ("misrefactored_assign_op", "complexity"),
+    ("missing_const_for_fn", "nursery"),
+    ("missing_docs_in_private_items", "restriction"),
+    ("missing_errors_doc", "pedantic"),
+    ("missing_inline_in_public_items", "restriction"),
+    ("missing_safety_doc", "style"),
+    ("mistyped_literal_suffixes", "correctness"),
+    ("mixed_attributes_style", "style"),
+    ("mixed_case_hex_literals", "style"),
+    ("module_inception", "style"),
+    ("module_name_repetitions", "pedantic"),
+    ("modulo_arithmetic", "restriction"),
+    ("modulo_one", "correctness"),
+    ("multiple_crate_versions", "cargo"),
+    ("multiple_inherent_impl", "restriction"),
+    ("must_use_candidate", "pedantic"),
+    ("must_use_unit", "style"),
+    ("mut_from_ref", "correctness"),
+    ("mut_mut", "pedantic"),
+    ("mut_mutex_lock", "style"),
+    ("mut_range_bound", "complexity"),
+    ("mutable_key_type", "correctness"),
+    ("mutex_atomic", "perf"),
+    ("mutex_integer", "nursery"),
+    ("mutex_integer_use_atomic", "perf"),
+    ("naive_bytecount", "perf"),
+    ("needless_arbitrary_self_type", "complexity"),
+    ("needless_bool", "complexity"),
+    ("needless_borrow", "nursery"),
+    ("needless_borrowed_reference", "complexity"),
+    ("needless_borrows_for_generic_args", "nursery"),
+    ("needless_collect", "perf"),
+    ("needless_continue", "pedantic"),
+    ("needless_doctest_main", "style"),
+    ("needless_lifetimes", "complexity"),
+    ("needless_pass_by_value", "pedantic"),
+    ("needless_range_loop", "style"),
+    ("needless_return", "style"),
+    ("needless_update", "complexity"),
+    ("neg_cmp_op_on_partial_ord", "complexity"),
+    ("neg_multiply", "style"),
+    ("negative_feature_names", "cargo"),
+    ("nested_containers", "pedantic"),
+    ("never_loop", "correctness"),
+    ("new_ret_no_self", "style"),
+    ("new_without_default", "style"),
+    ("no_effect", "complexity"),
+    ("non_ascii_literal", "pedantic"),
+    ("nonminimal_bool", "complexity"),
+    ("nonsensical_open_options", "correctness"),
+    ("not_unsafe_ptr_arg_deref", "correctness"),
+    ("ok_expect", "style"),
+    ("once_cell_lazy", "pedantic"),
+    ("only_used_in_recursion", "complexity"),
+    ("op_ref", "style"),
+    ("option_as_ref_cloned", "style"),
+    ("option_as_ref_deref", "complexity"),
+    ("option_env_unwrap", "correctness"),
+    ("option_if_let_else", "pedantic"),
+    ("option_map_or_none", "style"),
+    ("option_map_unit_fn", "complexity"),
+    ("option_option", "pedantic"),
+    ("or_fun_call", "perf"),
+    ("out_of_bounds_indexing", "correctness"),
+    ("overflow_check_conditional", "complexity"),
+    ("panic", "restriction"),
+    ("panic_in_result_fn", "restriction"),
+    ("panicking_unwrap", "correctness"),
+    ("partialeq_ne_impl", "complexity"),
+    ("path_buf_push_overwrite", "nursery"),
+    ("path_dependencies", "cargo"),
+    ("pattern_type_mismatch", "restriction"),
+    ("possible_missing_comma", "correctness"),
+    ("precedence", "complexity"),
+    ("print_literal", "style"),
+    ("print_stdout", "restriction"),
+    ("print_with_newline", "style"),
+    ("println_empty_string", "style"),
+    ("ptr_arg", "style"),
+    ("ptr_eq", "style"),
+    ("ptr_offset_with_cast", "complexity"),
+    ("pub_enum_variant_names", "pedantic"),
+    ("pub_with_shorthand", "restriction"),
+    ("pub_without_shorthand", "restriction"),
+    ("question_mark", "style"),
+    ("range_minus_one", "pedantic"),
+    ("range_plus_one", "pedantic"),
+    ("range_zip_with_len", "complexity"),
+    ("rc_buffer", "restriction"),
+    ("reachable_panic", "restriction"),
+    ("read_zero_byte_vec", "correctness"),
+    ("redundant_allocation", "perf"),
+    ("redundant_clone", "perf"),
+    ("redundant_closure", "style"),
+    ("redundant_closure_call", "complexity"),
+    ("redundant_closure_for_method_calls", "pedantic"),
+    ("redundant_feature_names", "cargo"),
+    ("redundant_field_names", "style"),
+    ("redundant_guards", "complexity"),
+    ("redundant_pattern", "style"),
+    ("redundant_pattern_matching", "style"),
+    ("redundant_pub_crate", "nursery"),
+    ("redundant_static_lifetimes", "style"),
+    ("ref_in_deref", "complexity"),
+    ("ref_option_ref", "pedantic"),
+    ("repeat_once", "complexity"),
+    ("rest_pat_in_fully_bound_structs", "restriction"),
+    ("result_map_or_into_option", "style"),
+    ("result_map_unit_fn", "complexity"),
+    ("result_ok_ok_or", "style"),
+    ("result_unit_err", "style"),
+    ("reversed_empty_ranges", "correctness"),
+    ("same_functions_in_if_condition", "pedantic"),
+    ("same_item_push", "style"),
+    ("search_is_some", "complexity"),
+    ("self_assignment", "correctness"),
+    ("seq_cst_ordering", "pedantic"),
+    ("serde_api_misuse", "correctness"),
+    ("shadow_reuse", "restriction"),
+    ("shadow_same", "restriction"),
+    ("shadow_unrelated", "pedantic"),
+    ("short_circuit_statement", "complexity"),
+    ("should_implement_trait", "style"),
+    ("significant_drop_in_scrutinee", "nursery"),
+    ("similar_names", "pedantic"),
+    ("single_char_add_str", "style"),
+    ("single_char_pattern", "perf"),
+    ("single_component_path_imports", "style"),
+    ("single_element_loop", "complexity"),
+    ("single_match", "style"),
+    ("single_match_else", "pedantic"),
+    ("single_range_in_vec_init", "style"),
+    ("skip_while_next", "complexity"),
+    ("slow_vector_initialization", "perf"),
+    ("spin_loop_without_hint", "perf"),
+    ("stable_sort_primitive", "perf"),
+    ("std_instead_of_alloc", "restriction"),
+    ("std_instead_of_core", "restriction"),
+    ("str_to_string", "restriction"),
+    ("string_add", "restriction"),
+    ("string_add_assign", "pedantic"),
+    ("string_extend_chars", "style"),
+    ("string_from_utf8_as_bytes", "complexity"),
+    ("string_lit_as_bytes", "nursery"),
+    ("string_to_string", "restriction"),
+    ("struct_excessive_bools", "pedantic"),
+    ("struct_fields_rest_default", "style"),
+    ("suboptimal_flops", "nursery"),
+    ("suspicious_arithmetic_impl", "correctness"),
+    ("suspicious_assignment_formatting", "style"),
+    ("suspicious_else_formatting", "style"),
+    ("suspicious_map", "complexity"),
+    ("suspicious_op_assign_impl", "correctness"),
+    ("suspicious_operation_groupings", "style"),
+    ("suspicious_unary_op_formatting", "style"),
+    ("tabs_in_doc_comments", "style"),
+    ("temporary_assignment", "complexity"),
+    ("thread_spawn_without_join_or_detach_policy", "restriction"),
+    ("to_digit_is_some", "style"),
+    ("to_string_in_display", "correctness"),
+    ("todo", "restriction"),
+    ("too_many_arguments", "complexity"),
+    ("too_many_lines", "pedantic"),
+    ("toplevel_ref_arg", "style"),
+    ("trait_duplication_in_bounds", "pedantic"),
+    ("transmute_bytes_to_str", "complexity"),
+    ("transmute_float_to_int", "complexity"),
+    ("transmute_int_to_bool", "complexity"),
+    ("transmute_int_to_char", "complexity"),
+    ("transmute_int_to_float", "complexity"),
+    ("transmute_ptr_to_ptr", "complexity"),
+    ("transmute_ptr_to_ref", "complexity"),
+    ("transmutes_expressible_as_ptr_casts", "complexity"),
+    ("transmuting_null", "correctness"),
+    ("trivial_regex", "style"),
+    ("trivially_copy_pass_by_ref", "pedantic"),
+    ("try_err", "style"),
+    ("type_complexity", "complexity"),
+    ("type_repetition_in_bounds", "pedantic"),
+    ("unbounded_channel", "pedantic"),
+    ("underscore_assignment", "restriction"),
+    ("undropped_manually_drops", "correctness"),
+    ("unfulfilled_allow", "nursery"),
+    ("unicode_not_nfc", "pedantic"),
+    ("unimplemented", "restriction"),
+    ("uninit_assumed_init", "correctness"),
+    ("unit_arg", "complexity"),
+    ("unit_cmp", "correctness"),
+    ("unit_return_expecting_ord", "correctness"),
+    ("unknown_clippy_lints", "style"),
+    ("unnecessary_cast", "complexity"),
+    ("unnecessary_debug_formatting", "pedantic"),
+    ("unnecessary_filter_map", "complexity"),
+    ("unnecessary_fold", "style"),
+    ("unnecessary_lazy_evaluations", "style"),
+    ("unnecessary_mut_passed", "style"),
+    ("unnecessary_operation", "complexity"),
+    ("unnecessary_self_imports", "restriction"),
+    ("unnecessary_sort_by", "complexity"),
+    ("unnecessary_unwrap", "complexity"),
+    ("unnecessary_wraps", "complexity"),
+    ("unneeded_field_pattern", "restriction"),
+    ("unneeded_wildcard_pattern", "complexity"),
+    ("unnested_or_patterns", "pedantic"),
+    ("unordered_iter_in_assert", "pedantic"),
+    ("unpinned_git_dependencies", "cargo"),
+    ("unreachable", "restriction"),
+    ("unreadable_literal", "pedantic"),
+    ("unsafe_derive_deserialize", "pedantic"),
+    ("unsafe_removed_from_name", "style"),
+    ("unseparated_literal_suffix", "pedantic"),
+    ("unsound_collection_transmute", "correctness"),
+    ("untracked_todo", "restriction"),
+    ("unused_io_amount", "correctness"),
+    ("unused_self", "pedantic"),
+    ("unused_trait_names", "restriction"),
+    ("unused_unit", "style"),
+    ("unusual_byte_groupings", "style"),
+    ("unwrap_in_result", "restriction"),
+    ("unwrap_used", "restriction"),
+    ("use_debug", "restriction"),
+    ("use_self", "nursery"),
+    ("used_underscore_binding", "pedantic"),
+    ("useless_asref", "complexity"),
+    ("useless_attribute", "correctness"),
+    ("useless_conversion", "complexity"),
+    ("useless_format", "complexity"),
+    ("useless_let_if_seq", "nursery"),
+    ("useless_transmute", "nursery"),
+    ("useless_vec", "perf"),
+    ("vec_box", "complexity"),
+    ("vec_resize_to_zero", "correctness"),
+    ("verbose_bit_mask", "pedantic"),
+    ("verbose_file_reads", "restriction"),
+    ("vtable_address_comparisons", "correctness"),
+    ("while_float", "nursery"),
+    ("while_immutable_condition", "correctness"),
+    ("while_let_loop", "complexity"),
+    ("while_let_on_iterator", "style"),
+    ("wildcard_dependencies", "cargo"),
+    ("wildcard_enum_match_arm", "restriction"),
+    ("wildcard_imports", "pedantic"),
+    ("wildcard_in_or_patterns", "complexity"),
+    ("write_literal", "style"),
+    ("write_with_newline", "style"),
+    ("writeln_empty_string", "style"),
+    ("wrong_pub_self_convention", "restriction"),
+    ("wrong_self_convention", "style"),
+    ("wrong_transmute", "correctness"),
+    ("zero_divided_by_zero", "complexity"),
+    ("zero_prefixed_literal", "complexity"),
+    ("zero_ptr", "style"),
+    ("zst_offset", "correctness"),
+    // end lint groups
+];
+
+/// Returns the group of a Clippy lint, e.g. `style` for `clippy::needless_return`.
+pub fn lint_group(lint: &str) -> Option<&'static str> {
+    let name = lint.strip_prefix("clippy::")?;
+    LINT_GROUPS
+        .binary_search_by_key(&name, |&(name, _)| name)
+        .ok()
+        .map(|index| LINT_GROUPS[index].1)
+}
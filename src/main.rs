@@ -2,11 +2,21 @@
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
+use baseline::Baseline;
 use rustc_tools_util::VersionInfo;
 use std::env;
 use std::ffi::OsString;
+use std::io::{self, BufReader};
 use std::path::PathBuf;
-use std::process::{self, Command};
+use std::process::{self, Command, Stdio};
+use summary::Summary;
+
+mod baseline;
+mod html_report;
+mod lint_groups;
+mod messages;
+mod sarif;
+mod summary;
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -16,6 +26,18 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --config-path <PATH>     Use the given configuration file instead of searching
+                             for `clippy.toml` files
+    --message-format sarif   Print the diagnostics as a SARIF 2.1.0 log, e.g. for
+                             GitHub code scanning
+    --baseline <PATH>        Only report the diagnostics missing from the given
+                             baseline file, and fail if there are any. The file is
+                             created with the current diagnostics if it doesn't exist
+    --summary[=json]         Print the number of diagnostics of each lint after the
+                             diagnostics, as a table on stderr or as JSON on stdout
+    --report-html <DIR>      Also write the diagnostics to an HTML report in the
+                             given directory, grouped by lint group and lint
+    --unstable-lints         Also run the unstable lints, which are still experimental
 
 Other options are the same as `cargo check`.
 
@@ -63,6 +85,17 @@ struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: String,
+    config_path: Option<PathBuf>,
+    /// Whether the JSON messages of cargo are converted to a SARIF log
+    sarif: bool,
+    baseline_path: Option<PathBuf>,
+    /// The comma separated lints whose suggestions are applied by `--fix --only`
+    fix_only: Option<String>,
+    summary: Option<summary::Format>,
+    /// The directory of the HTML report
+    report_dir: Option<PathBuf>,
+    /// Whether the unstable lints are run
+    unstable_lints: bool,
 }
 
 impl ClippyCmd {
@@ -72,14 +105,88 @@ impl ClippyCmd {
     {
         let mut cargo_subcommand = "check";
         let mut unstable_options = false;
+        let mut config_path = None;
+        let mut sarif = false;
+        let mut baseline_path = None;
+        let mut fix_only = None;
+        let mut summary = None;
+        let mut report_dir = None;
+        let mut unstable_lints = false;
         let mut args = vec![];
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
                     continue;
                 },
+                "--config-path" => {
+                    let path = old_args.next().expect("`--config-path` requires a path");
+                    config_path = Some(PathBuf::from(path));
+                    continue;
+                },
+                s if s.starts_with("--config-path=") => {
+                    config_path = Some(PathBuf::from(&s["--config-path=".len()..]));
+                    continue;
+                },
+                "--baseline" => {
+                    let path = old_args.next().expect("`--baseline` requires a path");
+                    baseline_path = Some(PathBuf::from(path));
+                    continue;
+                },
+                s if s.starts_with("--baseline=") => {
+                    baseline_path = Some(PathBuf::from(&s["--baseline=".len()..]));
+                    continue;
+                },
+                "--only" => {
+                    fix_only = Some(old_args.next().expect("`--only` requires a list of lints"));
+                    continue;
+                },
+                s if s.starts_with("--only=") => {
+                    fix_only = Some(s["--only=".len()..].to_string());
+                    continue;
+                },
+                "--summary" => {
+                    summary = Some(summary::Format::Table);
+                    continue;
+                },
+                s if s.starts_with("--summary=") => {
+                    let format = &s["--summary=".len()..];
+                    summary =
+                        Some(summary::Format::parse(format).unwrap_or_else(|| {
+                            panic!("unknown summary format `{}`, expected `table` or `json`", format)
+                        }));
+                    continue;
+                },
+                "--report-html" => {
+                    let dir = old_args.next().expect("`--report-html` requires a directory");
+                    report_dir = Some(PathBuf::from(dir));
+                    continue;
+                },
+                s if s.starts_with("--report-html=") => {
+                    report_dir = Some(PathBuf::from(&s["--report-html=".len()..]));
+                    continue;
+                },
+                "--unstable-lints" => {
+                    unstable_lints = true;
+                    continue;
+                },
+                "--message-format" => {
+                    let format = old_args.next().expect("`--message-format` requires a format");
+                    if format == "sarif" {
+                        sarif = true;
+                        args.push("--message-format=json".to_string());
+                    } else {
+                        args.push(arg);
+                        args.push(format);
+                    }
+                    continue;
+                },
+                "--message-format=sarif" => {
+                    sarif = true;
+                    args.push("--message-format=json".to_string());
+                    continue;
+                },
                 "--" => break,
                 // Cover -Zunstable-options and -Z unstable-options
                 s if s.ends_with("unstable-options") => unstable_options = true,
@@ -89,23 +196,43 @@ impl ClippyCmd {
             args.push(arg);
         }
 
+        // the baseline, the summary and the report are computed from the JSON messages
+        if (baseline_path.is_some() || summary.is_some() || report_dir.is_some()) && !sarif {
+            args.push("--message-format=json".to_string());
+        }
+
         if cargo_subcommand == "fix" && !unstable_options {
             panic!("Usage of `--fix` requires `-Z unstable-options`");
         }
 
+        if fix_only.is_some() && cargo_subcommand != "fix" {
+            panic!("Usage of `--only` requires `--fix`");
+        }
+
+        if sarif && summary == Some(summary::Format::Json) {
+            panic!("`--summary=json` can't be used with `--message-format=sarif`, as both are printed to stdout");
+        }
+
         // Run the dogfood tests directly on nightly cargo. This is required due
         // to a bug in rustup.rs when running cargo on custom toolchains. See issue #3118.
         if env::var_os("CLIPPY_DOGFOOD").is_some() && cfg!(windows) {
             args.insert(0, "+nightly".to_string());
         }
 
-        let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
+        let clippy_args = merge_clippy_args(env::var("CLIPPY_ARGS").ok().as_deref(), old_args);
 
         ClippyCmd {
             unstable_options,
             cargo_subcommand,
             args,
             clippy_args,
+            config_path,
+            sarif,
+            baseline_path,
+            fix_only,
+            summary,
+            report_dir,
+            unstable_lints,
         }
     }
 
@@ -151,37 +278,147 @@ impl ClippyCmd {
         cmd.env(self.path_env(), Self::path())
             .envs(ClippyCmd::target_dir())
             .env("CLIPPY_ARGS", self.clippy_args)
+            .envs(self.config_path.map(|path| ("CLIPPY_CONF_PATH", absolute_path(path))))
+            .envs(env::var_os("CLIPPY_CONF_DIR").map(|dir| ("CLIPPY_CONF_DIR", absolute_path(dir.into()))))
+            .envs(self.fix_only.map(|lints| ("CLIPPY_FIX_ONLY", lints)))
             .arg(self.cargo_subcommand)
             .args(&self.args);
+        if self.unstable_lints {
+            cmd.env("CLIPPY_UNSTABLE_LINTS", "1");
+        }
 
         cmd
     }
 }
 
+/// Joins the args passed to the driver in `CLIPPY_ARGS`. The args already set in `CLIPPY_ARGS`
+/// come first, so that the args after `--` take precedence over them. They can be separated by
+/// whitespace, or by `__CLIPPY_HACKERY__` like the driver expects if they contain whitespace.
+fn merge_clippy_args<I>(env_args: Option<&str>, args: I) -> String
+where
+    I: Iterator<Item = String>,
+{
+    let env_args: Vec<&str> = match env_args {
+        Some(env_args) if env_args.contains("__CLIPPY_HACKERY__") => env_args.split("__CLIPPY_HACKERY__").collect(),
+        Some(env_args) => env_args.split_whitespace().collect(),
+        None => Vec::new(),
+    };
+    env_args
+        .into_iter()
+        .filter(|arg| !arg.is_empty())
+        .map(ToString::to_string)
+        .chain(args)
+        .map(|arg| format!("{}__CLIPPY_HACKERY__", arg))
+        .collect()
+}
+
+/// Cargo runs the driver in the directory of each package, so relative paths are resolved against
+/// the current directory first.
+fn absolute_path(path: PathBuf) -> PathBuf {
+    if path.is_relative() {
+        env::current_dir().expect("current directory invalid").join(path)
+    } else {
+        path
+    }
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
     let cmd = ClippyCmd::new(old_args);
+    let sarif = cmd.sarif;
+    let baseline_path = cmd.baseline_path.clone();
+    let summary = cmd.summary;
+    let report_dir = cmd.report_dir.clone();
 
     let mut cmd = cmd.into_std_cmd();
+    if sarif || baseline_path.is_some() || summary.is_some() || report_dir.is_some() {
+        cmd.stdout(Stdio::piped());
+    }
 
-    let exit_status = cmd
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    let mut child = cmd.spawn().expect("could not run cargo");
+    let mut new_diagnostics = false;
+    let mut report_failed = false;
+    if let Some(stdout) = child.stdout.take() {
+        let mut diagnostics =
+            messages::read_diagnostics(BufReader::new(stdout)).expect("failed to read the messages of cargo");
+
+        if let Some(path) = baseline_path {
+            match Baseline::read(&path).expect("failed to read the baseline file") {
+                Some(baseline) => {
+                    diagnostics = baseline.filter(diagnostics);
+                    new_diagnostics = !diagnostics.is_empty();
+                },
+                None => {
+                    Baseline::write(&path, &diagnostics).expect("failed to write the baseline file");
+                    eprintln!(
+                        "Recorded {} diagnostics in the baseline file `{}`",
+                        diagnostics.len(),
+                        path.display()
+                    );
+                    diagnostics.clear();
+                },
+            }
+        }
 
-    if exit_status.success() {
-        Ok(())
-    } else {
+        if sarif {
+            let out = io::stdout();
+            sarif::write_log(&diagnostics, out.lock()).expect("failed to write the SARIF log");
+            println!();
+        } else {
+            for diagnostic in &diagnostics {
+                if let Some(ref rendered) = diagnostic.rendered {
+                    eprint!("{}", rendered);
+                }
+            }
+            if new_diagnostics {
+                eprintln!("{} diagnostics are not in the baseline file", diagnostics.len());
+            }
+        }
+
+        match summary {
+            Some(summary::Format::Table) => {
+                let out = io::stderr();
+                Summary::new(&diagnostics)
+                    .write(summary::Format::Table, out.lock())
+                    .expect("failed to write the summary");
+            },
+            Some(summary::Format::Json) => {
+                let out = io::stdout();
+                Summary::new(&diagnostics)
+                    .write(summary::Format::Json, out.lock())
+                    .expect("failed to write the summary");
+                println!();
+            },
+            None => {},
+        }
+
+        if let Some(dir) = report_dir {
+            match html_report::write_report(&diagnostics, &dir) {
+                Ok(path) => eprintln!("Wrote the HTML report to `{}`", path.display()),
+                Err(e) => {
+                    eprintln!("error: failed to write the HTML report to `{}`: {}", dir.display(), e);
+                    report_failed = true;
+                },
+            }
+        }
+    }
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+
+    if !exit_status.success() {
         Err(exit_status.code().unwrap_or(-1))
+    } else if new_diagnostics || report_failed {
+        Err(1)
+    } else {
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ClippyCmd;
+    use super::{summary, ClippyCmd};
+    use std::path::PathBuf;
 
     #[test]
     #[should_panic]
@@ -201,6 +438,32 @@ mod tests {
         assert!(cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
     }
 
+    #[test]
+    fn fix_only() {
+        let args = "cargo clippy --fix -Zunstable-options --only clippy::redundant_clone,needless_return"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!("fix", cmd.cargo_subcommand);
+        assert_eq!(Some("clippy::redundant_clone,needless_return"), cmd.fix_only.as_deref());
+        assert!(!cmd.args.iter().any(|arg| arg.contains("redundant_clone")));
+
+        let args = "cargo clippy --fix -Zunstable-options --only=clippy::redundant_clone"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some("clippy::redundant_clone"), cmd.fix_only.as_deref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn only_without_fix() {
+        let args = "cargo clippy --only clippy::redundant_clone"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let _ = ClippyCmd::new(args);
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
@@ -218,4 +481,134 @@ mod tests {
         assert_eq!("check", cmd.cargo_subcommand);
         assert_eq!("RUSTC_WORKSPACE_WRAPPER", cmd.path_env());
     }
+
+    #[test]
+    fn config_path() {
+        let args = "cargo clippy --config-path ../clippy.toml -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(PathBuf::from("../clippy.toml")), cmd.config_path);
+        assert!(!cmd.args.iter().any(|arg| arg.contains("clippy.toml")));
+        assert_eq!("-D__CLIPPY_HACKERY__warnings__CLIPPY_HACKERY__", cmd.clippy_args);
+
+        let args = "cargo clippy --config-path=conf/clippy.toml"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(PathBuf::from("conf/clippy.toml")), cmd.config_path);
+    }
+
+    #[test]
+    fn clippy_args_env() {
+        let args = || vec!["-D".to_string(), "warnings".to_string()].into_iter();
+        assert_eq!(
+            "-D__CLIPPY_HACKERY__warnings__CLIPPY_HACKERY__",
+            merge_clippy_args(None, args())
+        );
+        assert_eq!(
+            "-W__CLIPPY_HACKERY__clippy::pedantic__CLIPPY_HACKERY__-D__CLIPPY_HACKERY__warnings__CLIPPY_HACKERY__",
+            merge_clippy_args(Some(" -W  clippy::pedantic "), args())
+        );
+        assert_eq!(
+            "--cfg__CLIPPY_HACKERY__feature=\"a b\"__CLIPPY_HACKERY__-D__CLIPPY_HACKERY__warnings__CLIPPY_HACKERY__",
+            merge_clippy_args(Some("--cfg__CLIPPY_HACKERY__feature=\"a b\"__CLIPPY_HACKERY__"), args())
+        );
+    }
+
+    #[test]
+    fn unstable_lints() {
+        let args = "cargo clippy --unstable-lints -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.unstable_lints);
+        assert!(cmd.args.is_empty());
+
+        let args = "cargo clippy".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.unstable_lints);
+    }
+
+    #[test]
+    fn message_format_sarif() {
+        let args = "cargo clippy --message-format=sarif -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.sarif);
+        assert_eq!(vec!["--message-format=json"], cmd.args);
+
+        let args = "cargo clippy --message-format sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.sarif);
+        assert_eq!(vec!["--message-format=json"], cmd.args);
+
+        let args = "cargo clippy --message-format short"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.sarif);
+        assert_eq!(vec!["--message-format", "short"], cmd.args);
+    }
+
+    #[test]
+    fn baseline() {
+        let args = "cargo clippy --baseline clippy-baseline.json -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(PathBuf::from("clippy-baseline.json")), cmd.baseline_path);
+        assert_eq!(vec!["--message-format=json"], cmd.args);
+
+        let args = "cargo clippy --baseline=clippy-baseline.json --message-format=sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(PathBuf::from("clippy-baseline.json")), cmd.baseline_path);
+        assert_eq!(vec!["--message-format=json"], cmd.args);
+    }
+
+    #[test]
+    fn summary() {
+        let args = "cargo clippy --summary -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(summary::Format::Table), cmd.summary);
+        assert!(cmd.args.iter().any(|arg| arg == "--message-format=json"));
+        assert!(!cmd.args.iter().any(|arg| arg.starts_with("--summary")));
+
+        let args = "cargo clippy --summary=json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(summary::Format::Json), cmd.summary);
+    }
+
+    #[test]
+    fn report_html() {
+        let args = "cargo clippy --report-html target/clippy-report -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(PathBuf::from("target/clippy-report")), cmd.report_dir);
+        assert_eq!(vec!["--message-format=json"], cmd.args);
+
+        let args = "cargo clippy --report-html=report --message-format=sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(Some(PathBuf::from("report")), cmd.report_dir);
+        assert_eq!(vec!["--message-format=json"], cmd.args);
+    }
+
+    #[test]
+    #[should_panic]
+    fn summary_unknown_format() {
+        let args = "cargo clippy --summary=csv".split_whitespace().map(ToString::to_string);
+        let _ = ClippyCmd::new(args);
+    }
 }
@@ -0,0 +1,79 @@
+//! The JSON messages printed by `cargo check --message-format=json`, for the options of
+//! cargo-clippy which post-process the diagnostics.
+
+use serde::Deserialize;
+use std::io::{self, BufRead};
+
+/// A message printed by cargo. Only compiler messages are read, other messages like
+/// `compiler-artifact` are skipped.
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<Diagnostic>,
+}
+
+#[derive(Deserialize)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<DiagnosticCode>,
+    pub level: String,
+    pub spans: Vec<DiagnosticSpan>,
+    pub children: Vec<Diagnostic>,
+    /// The diagnostic as rustc would print it
+    pub rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub line_start: u32,
+    pub line_end: u32,
+    pub column_start: u32,
+    pub column_end: u32,
+    pub is_primary: bool,
+    /// The source lines of the span
+    pub text: Vec<DiagnosticSpanLine>,
+    pub suggested_replacement: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticSpanLine {
+    pub text: String,
+    /// The 1-based column of the first character of the span in this line
+    pub highlight_start: usize,
+    /// The 1-based column after the last character of the span in this line
+    pub highlight_end: usize,
+}
+
+impl Diagnostic {
+    pub fn primary_span(&self) -> Option<&DiagnosticSpan> {
+        self.spans.iter().find(|span| span.is_primary)
+    }
+}
+
+/// Reads the diagnostics from the messages printed by cargo. Diagnostics without a primary
+/// span, like the `aborting due to previous error` summary, are skipped.
+pub fn read_diagnostics(messages: impl BufRead) -> io::Result<Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+    for line in messages.lines() {
+        let message = match serde_json::from_str::<CargoMessage>(&line?) {
+            Ok(message) => message,
+            // cargo may print other lines, e.g. from build scripts
+            Err(_) => continue,
+        };
+        if message.reason != "compiler-message" {
+            continue;
+        }
+        if let Some(diagnostic) = message.message {
+            if diagnostic.primary_span().is_some() {
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+    Ok(diagnostics)
+}
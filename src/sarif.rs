@@ -0,0 +1,255 @@
+//! Conversion of the JSON messages of `cargo check --message-format=json` to a [SARIF 2.1.0]
+//! log, for `cargo clippy --message-format=sarif`.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::messages::{Diagnostic, DiagnosticSpan};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const CLIPPY_LINTS_URI: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+#[derive(Serialize)]
+struct Log {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+    /// rustc counts columns in characters, SARIF in UTF-16 code units by default
+    column_kind: &'static str,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri: String,
+    /// Paths printed by rustc are relative to the workspace root, unless they are absolute
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: u32,
+    start_column: u32,
+    end_line: u32,
+    end_column: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: Message,
+}
+
+/// Writes the SARIF log of the diagnostics.
+pub fn write_log(diagnostics: &[Diagnostic], out: impl Write) -> io::Result<()> {
+    let results: Vec<_> = diagnostics.iter().filter_map(convert_diagnostic).collect();
+
+    let rules = results
+        .iter()
+        .filter_map(|result| result.rule_id.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|id| Rule {
+            help_uri: id
+                .strip_prefix("clippy::")
+                .map(|name| format!("{}#{}", CLIPPY_LINTS_URI, name)),
+            id,
+        })
+        .collect();
+
+    let log = Log {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "clippy",
+                    information_uri: "https://github.com/rust-lang/rust-clippy",
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules,
+                },
+            },
+            results,
+            column_kind: "unicodeCodePoints",
+        }],
+    };
+    serde_json::to_writer_pretty(out, &log)?;
+    Ok(())
+}
+
+/// Converts a diagnostic with a primary span.
+fn convert_diagnostic(diagnostic: &Diagnostic) -> Option<SarifResult> {
+    let primary = diagnostic.primary_span()?;
+
+    let mut text = diagnostic.message.clone();
+    for child in &diagnostic.children {
+        text.push_str(&format!("\n{}: {}", child.level, child.message));
+    }
+
+    let fixes = diagnostic
+        .children
+        .iter()
+        .filter_map(|child| {
+            let mut artifact_changes: Vec<ArtifactChange> = Vec::new();
+            for span in &child.spans {
+                let text = match span.suggested_replacement {
+                    Some(ref text) => text.clone(),
+                    None => continue,
+                };
+                let replacement = Replacement {
+                    deleted_region: region(span),
+                    inserted_content: Message { text },
+                };
+                let location = artifact_location(&span.file_name);
+                match artifact_changes
+                    .iter_mut()
+                    .find(|change| change.artifact_location.uri == location.uri)
+                {
+                    Some(change) => change.replacements.push(replacement),
+                    None => artifact_changes.push(ArtifactChange {
+                        artifact_location: location,
+                        replacements: vec![replacement],
+                    }),
+                }
+            }
+
+            if artifact_changes.is_empty() {
+                None
+            } else {
+                Some(Fix {
+                    description: Message {
+                        text: child.message.clone(),
+                    },
+                    artifact_changes,
+                })
+            }
+        })
+        .collect();
+
+    Some(SarifResult {
+        rule_id: diagnostic.code.as_ref().map(|code| code.code.clone()),
+        level: match diagnostic.level.as_str() {
+            "error" | "error: internal compiler error" => "error",
+            "warning" => "warning",
+            _ => "note",
+        },
+        message: Message { text },
+        locations: vec![Location {
+            physical_location: PhysicalLocation {
+                artifact_location: artifact_location(&primary.file_name),
+                region: region(primary),
+            },
+        }],
+        fixes,
+    })
+}
+
+fn artifact_location(file_name: &str) -> ArtifactLocation {
+    let path = file_name.replace('\\', "/");
+    if Path::new(file_name).is_absolute() {
+        ArtifactLocation {
+            // `C:/..` needs a slash before the drive letter
+            uri: if path.starts_with('/') {
+                format!("file://{}", path)
+            } else {
+                format!("file:///{}", path)
+            },
+            uri_base_id: None,
+        }
+    } else {
+        ArtifactLocation {
+            uri: path,
+            uri_base_id: Some("%SRCROOT%"),
+        }
+    }
+}
+
+fn region(span: &DiagnosticSpan) -> Region {
+    Region {
+        start_line: span.line_start,
+        start_column: span.column_start,
+        end_line: span.line_end,
+        end_column: span.column_end,
+    }
+}
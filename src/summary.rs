@@ -0,0 +1,156 @@
+//! The number of diagnostics of each lint, printed after the diagnostics by
+//! `cargo clippy --summary[=json]` to track the lints of a project over time.
+
+use crate::messages::Diagnostic;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl Format {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "table" => Some(Self::Table),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// The counts of the lints, by level (`warning`, `error`, ...) and lint name
+pub struct Summary(BTreeMap<String, BTreeMap<String, usize>>);
+
+impl Summary {
+    /// Counts the lint diagnostics. Diagnostics without a code and errors with an error code, like
+    /// `E0308`, aren't lints and are skipped.
+    pub fn new(diagnostics: &[Diagnostic]) -> Self {
+        let mut levels = BTreeMap::<String, BTreeMap<String, usize>>::new();
+        for diagnostic in diagnostics {
+            if let Some(ref code) = diagnostic.code {
+                if !is_error_code(&code.code) {
+                    *levels
+                        .entry(diagnostic.level.clone())
+                        .or_default()
+                        .entry(code.code.clone())
+                        .or_default() += 1;
+                }
+            }
+        }
+        Self(levels)
+    }
+
+    pub fn write(&self, format: Format, out: impl Write) -> io::Result<()> {
+        match format {
+            Format::Table => self.write_table(out),
+            Format::Json => {
+                serde_json::to_writer_pretty(out, &self.0)?;
+                Ok(())
+            },
+        }
+    }
+
+    /// Writes the lints of each level, the most frequent first.
+    fn write_table(&self, mut out: impl Write) -> io::Result<()> {
+        if self.0.is_empty() {
+            return writeln!(out, "lint summary: no lints fired");
+        }
+        writeln!(out, "lint summary:")?;
+        for (level, lints) in &self.0 {
+            let total: usize = lints.values().sum();
+            writeln!(out, "  {}: {} in total", level, total)?;
+
+            let mut lints: Vec<_> = lints.iter().collect();
+            lints.sort_by(|(a_name, a_count), (b_name, b_count)| b_count.cmp(a_count).then(a_name.cmp(b_name)));
+            let width = lints[0].1.to_string().len();
+            for (name, count) in lints {
+                writeln!(out, "    {:>width$}  {}", count, name, width = width)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_error_code(code: &str) -> bool {
+    code.len() == 5 && code.starts_with('E') && code[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Format, Summary};
+    use crate::messages::{Diagnostic, DiagnosticCode};
+
+    fn diagnostic(level: &str, code: Option<&str>) -> Diagnostic {
+        Diagnostic {
+            message: String::new(),
+            code: code.map(|code| DiagnosticCode { code: code.to_string() }),
+            level: level.to_string(),
+            spans: Vec::new(),
+            children: Vec::new(),
+            rendered: None,
+        }
+    }
+
+    fn summary() -> Summary {
+        let mut diagnostics: Vec<_> = (0..10)
+            .map(|_| diagnostic("warning", Some("clippy::redundant_clone")))
+            .collect();
+        diagnostics.extend((0..2).map(|_| diagnostic("warning", Some("clippy::needless_return"))));
+        diagnostics.push(diagnostic("error", Some("clippy::unwrap_used")));
+        // not lints
+        diagnostics.push(diagnostic("error", Some("E0308")));
+        diagnostics.push(diagnostic("warning", None));
+        Summary::new(&diagnostics)
+    }
+
+    fn write(summary: &Summary, format: Format) -> String {
+        let mut out = Vec::new();
+        summary.write(format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn counts() {
+        let summary = summary();
+        assert_eq!(summary.0.len(), 2);
+        assert_eq!(summary.0["warning"]["clippy::redundant_clone"], 10);
+        assert_eq!(summary.0["warning"]["clippy::needless_return"], 2);
+        assert_eq!(summary.0["error"].len(), 1);
+        assert_eq!(summary.0["error"]["clippy::unwrap_used"], 1);
+    }
+
+    #[test]
+    fn table() {
+        let expected = "\
+lint summary:
+  error: 1 in total
+    1  clippy::unwrap_used
+  warning: 12 in total
+    10  clippy::redundant_clone
+     2  clippy::needless_return
+";
+        assert_eq!(write(&summary(), Format::Table), expected);
+        assert_eq!(
+            write(&Summary::new(&[]), Format::Table),
+            "lint summary: no lints fired\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let expected = r#"{
+  "error": {
+    "clippy::unwrap_used": 1
+  },
+  "warning": {
+    "clippy::needless_return": 2,
+    "clippy::redundant_clone": 10
+  }
+}"#;
+        assert_eq!(write(&summary(), Format::Json), expected);
+        assert_eq!(write(&Summary::new(&[]), Format::Json), "{}");
+    }
+}
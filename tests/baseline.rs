@@ -0,0 +1,61 @@
+// Like dogfood, this runs `cargo-clippy`, which cannot run on Windows
+#![cfg(not(windows))]
+#![feature(once_cell)]
+
+use std::fs;
+use std::lazy::SyncLazy;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+mod cargo;
+
+static CLIPPY_PATH: SyncLazy<PathBuf> = SyncLazy::new(|| cargo::TARGET_LIB.join("cargo-clippy"));
+
+fn run_with_baseline(baseline: &Path) -> Output {
+    let project_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("diagnostics_project");
+
+    let output = Command::new(&*CLIPPY_PATH)
+        .current_dir(&project_dir)
+        .env("CLIPPY_DOGFOOD", "1")
+        .env("CARGO_INCREMENTAL", "0")
+        .arg("clippy")
+        .arg("--baseline")
+        .arg(baseline)
+        .output()
+        .unwrap();
+
+    println!("status: {}", output.status);
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+#[test]
+fn baseline() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let baseline = cargo::CARGO_TARGET_DIR.join("clippy-baseline.json");
+    let _ = fs::remove_file(&baseline);
+
+    // the first run records the diagnostics
+    let output = run_with_baseline(&baseline);
+    assert!(output.status.success());
+    let contents = fs::read_to_string(&baseline).unwrap();
+    assert!(contents.contains("\"lint\": \"clippy::needless_return\""));
+    assert!(contents.contains("\"context\": \"return 42;\""));
+
+    // the recorded diagnostics are not reported anymore
+    let output = run_with_baseline(&baseline);
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("unneeded `return` statement"));
+
+    // diagnostics missing from the baseline are reported
+    fs::write(&baseline, "[]").unwrap();
+    let output = run_with_baseline(&baseline);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unneeded `return` statement"));
+
+    fs::remove_file(&baseline).unwrap();
+}
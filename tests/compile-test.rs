@@ -109,6 +109,14 @@ fn run_internal_tests(cfg: &mut compiletest::Config) {
     compiletest::run_tests(&cfg);
 }
 
+fn run_ui_fix_only(cfg: &mut compiletest::Config) {
+    cfg.mode = TestMode::Ui;
+    cfg.src_base = Path::new("tests").join("ui-fix-only");
+    set_var("CLIPPY_FIX_ONLY", "clippy::redundant_clone");
+    compiletest::run_tests(&cfg);
+    env::remove_var("CLIPPY_FIX_ONLY");
+}
+
 fn run_ui_toml(config: &mut compiletest::Config) {
     fn run_tests(config: &compiletest::Config, mut tests: Vec<tester::TestDescAndFn>) -> Result<bool, io::Error> {
         let mut result = true;
@@ -200,6 +208,8 @@ fn run_ui_cargo(config: &mut compiletest::Config) {
                 }
 
                 env::set_current_dir(&src_path)?;
+                // Search for the configuration files starting in the package directory
+                env::set_var("CLIPPY_CONF_DIR", case.path());
                 for file in fs::read_dir(&src_path)? {
                     let file = file?;
                     if file.file_type()?.is_dir() {
@@ -242,6 +252,7 @@ fn run_ui_cargo(config: &mut compiletest::Config) {
     let filter = env::var("TESTNAME").ok();
     let res = run_tests(&config, &filter, tests);
     env::set_current_dir(current_dir).unwrap();
+    env::remove_var("CLIPPY_CONF_DIR");
 
     match res {
         Ok(true) => {},
@@ -263,6 +274,7 @@ fn compile_test() {
     prepare_env();
     let mut config = default_config();
     run_mode(&mut config);
+    run_ui_fix_only(&mut config);
     run_ui_toml(&mut config);
     run_ui_cargo(&mut config);
     run_internal_tests(&mut config);
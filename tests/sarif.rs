@@ -0,0 +1,66 @@
+// Like dogfood, this runs `cargo-clippy`, which cannot run on Windows
+#![cfg(not(windows))]
+#![feature(once_cell)]
+
+use serde_json::Value;
+use std::lazy::SyncLazy;
+use std::path::PathBuf;
+use std::process::Command;
+
+mod cargo;
+
+static CLIPPY_PATH: SyncLazy<PathBuf> = SyncLazy::new(|| cargo::TARGET_LIB.join("cargo-clippy"));
+
+#[test]
+fn sarif_log() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let project_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("diagnostics_project");
+
+    let output = Command::new(&*CLIPPY_PATH)
+        .current_dir(&project_dir)
+        .env("CLIPPY_DOGFOOD", "1")
+        .env("CARGO_INCREMENTAL", "0")
+        .arg("clippy")
+        .args(&["--message-format", "sarif"])
+        .output()
+        .unwrap();
+
+    println!("status: {}", output.status);
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+
+    let log: Value = serde_json::from_slice(&output.stdout).expect("the output is not JSON");
+    assert_eq!(log["version"], "2.1.0");
+
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "clippy");
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "clippy::needless_return");
+    assert_eq!(
+        run["tool"]["driver"]["rules"][0]["helpUri"],
+        "https://rust-lang.github.io/rust-clippy/master/index.html#needless_return"
+    );
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert_eq!(result["ruleId"], "clippy::needless_return");
+    assert_eq!(result["level"], "warning");
+    assert!(result["message"]["text"]
+        .as_str()
+        .unwrap()
+        .starts_with("unneeded `return` statement"));
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/main.rs");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 5);
+    assert_eq!(location["region"]["endColumn"], 15);
+
+    let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["deletedRegion"]["startColumn"], 5);
+    assert_eq!(replacement["insertedContent"]["text"], "42");
+}
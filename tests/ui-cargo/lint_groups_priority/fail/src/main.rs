@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=lint_groups_priority
+#![warn(clippy::lint_groups_priority)]
+
+fn main() {}
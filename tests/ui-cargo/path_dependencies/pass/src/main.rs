@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=path_dependencies
+#![warn(clippy::path_dependencies)]
+
+fn main() {}
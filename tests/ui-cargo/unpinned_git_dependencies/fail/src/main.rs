@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=unpinned_git_dependencies
+#![warn(clippy::unpinned_git_dependencies)]
+
+fn main() {}
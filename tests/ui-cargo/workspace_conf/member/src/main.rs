@@ -0,0 +1,12 @@
+#![warn(clippy::blacklisted_name, clippy::too_many_arguments)]
+
+fn three(_: u8, _: u8, _: u8) {}
+
+fn four(_: u8, _: u8, _: u8, _: u8) {}
+
+fn main() {
+    let qux = 42;
+    let foo = qux;
+    three(1, 2, foo);
+    four(1, 2, 3, foo);
+}
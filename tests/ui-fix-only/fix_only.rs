@@ -0,0 +1,15 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+
+// `CLIPPY_FIX_ONLY` is set to `clippy::redundant_clone` by `compile-test.rs`
+
+#![warn(clippy::redundant_clone, clippy::needless_return)]
+
+fn name() -> String {
+    return String::from("ferris");
+}
+
+fn main() {
+    let s = name();
+    let _s = s.clone();
+}
@@ -1,5 +1,6 @@
 // run-rustfix
 #![deny(clippy::internal)]
+#![allow(clippy::missing_expansion_check)]
 #![feature(rustc_private)]
 
 extern crate rustc_ast;
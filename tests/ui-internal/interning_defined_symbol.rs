@@ -0,0 +1,39 @@
+// run-rustfix
+#![deny(clippy::internal)]
+#![feature(rustc_private)]
+
+extern crate rustc_span;
+
+use rustc_span::symbol::{Ident, Symbol};
+
+macro_rules! sym {
+    ($tt:tt) => {
+        rustc_span::symbol::Symbol::intern(stringify!($tt))
+    };
+}
+
+fn main() {
+    // Direct use of Symbol::intern
+    let _ = Symbol::intern("f32");
+
+    // Using a sym macro
+    let _ = sym!(f32);
+
+    // Interning a symbol that is not defined
+    let _ = Symbol::intern("xyz123");
+    let _ = sym!(xyz123);
+
+    // Using a different `intern` function
+    let _ = intern("f32");
+
+    // Comparing the string of a symbol
+    let ident = Ident::with_dummy_span(sym!(xyz123));
+    let _ = ident.as_str() == "clone";
+    let _ = "clone" != &*ident.name.as_str();
+    let _ = &*ident.as_str() == "clone";
+
+    // Comparing with a string which isn't a defined symbol
+    let _ = ident.as_str() == "xyz123";
+}
+
+fn intern(_: &str) {}
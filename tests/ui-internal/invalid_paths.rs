@@ -18,6 +18,36 @@ mod paths {
 
     // Path with bad module
     pub const BAD_MOD_PATH: [&str; 2] = ["std", "xxx"];
+
+    pub const HASHMAP: [&str; 5] = ["std", "collections", "hash", "map", "HashMap"];
+    pub const HASHSET: [&str; 5] = ["std", "collections", "hash", "set", "HashSet"];
+}
+
+mod std_items {
+    use super::paths;
+
+    pub struct StdItem {
+        pub diag_item: &'static str,
+        pub path: &'static [&'static str],
+    }
+
+    // Good item
+    pub const HASHMAP: StdItem = StdItem {
+        diag_item: "hashmap_type",
+        path: &paths::HASHMAP,
+    };
+
+    // Path naming another item
+    pub const BAD_HASHMAP: StdItem = StdItem {
+        diag_item: "hashmap_type",
+        path: &paths::HASHSET,
+    };
+
+    // Path with bad module
+    pub const BAD_MOD_ITEM: StdItem = StdItem {
+        diag_item: "xxx_type",
+        path: &["std", "xxx"],
+    };
 }
 
 fn main() {}
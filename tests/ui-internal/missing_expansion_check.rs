@@ -0,0 +1,81 @@
+#![deny(clippy::missing_expansion_check)]
+#![feature(rustc_private)]
+
+extern crate rustc_hir;
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+extern crate rustc_span;
+
+use rustc_hir::{Expr, Item, Stmt};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+
+declare_tool_lint! {
+    pub clippy::TEST_LINT,
+    Warn,
+    "",
+    report_in_external_macro: true
+}
+
+fn span_lint(_cx: &LateContext<'_>, _span: Span, _msg: &str) {}
+
+fn in_macro(span: Span) -> bool {
+    span.from_expansion()
+}
+
+declare_lint_pass!(Unchecked => [TEST_LINT]);
+
+impl<'tcx> LateLintPass<'tcx> for Unchecked {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        span_lint(cx, expr.span, "lint message");
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        cx.struct_span_lint(TEST_LINT, stmt.span, |diag| diag.build("lint message").emit());
+    }
+
+    // only `check_expr` and `check_stmt` are checked
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        span_lint(cx, item.span, "lint message");
+    }
+}
+
+declare_lint_pass!(NotAGuard => [TEST_LINT]);
+
+impl<'tcx> LateLintPass<'tcx> for NotAGuard {
+    // getting the context or the call site doesn't check for expansions
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let _ = expr.span.ctxt();
+        span_lint(cx, expr.span.source_callsite(), "lint message");
+    }
+}
+
+declare_lint_pass!(Checked => [TEST_LINT]);
+
+impl<'tcx> LateLintPass<'tcx> for Checked {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        span_lint(cx, expr.span, "lint message");
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        if !in_macro(stmt.span) {
+            span_lint(cx, stmt.span, "lint message");
+        }
+    }
+}
+
+declare_lint_pass!(Allowed => [TEST_LINT]);
+
+impl<'tcx> LateLintPass<'tcx> for Allowed {
+    // the lint is about macro calls
+    #[allow(clippy::missing_expansion_check)]
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        span_lint(cx, expr.span, "lint message");
+    }
+}
+
+fn main() {}
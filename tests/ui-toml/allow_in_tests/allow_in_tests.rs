@@ -0,0 +1,32 @@
+// compile-flags: --test
+#![warn(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+pub fn parse(value: &str) -> i32 {
+    value.parse().unwrap()
+}
+
+pub fn first(values: &[i32]) -> i32 {
+    *values.first().expect("no values")
+}
+
+#[test]
+fn test_parse() {
+    let parsed: i32 = "1".parse().unwrap();
+    assert_eq!(parse("1"), parsed);
+    let parse_two = || "2".parse::<i32>().expect("not a number");
+    assert_eq!(parse_two(), 2);
+}
+
+#[cfg(test)]
+mod tests {
+    fn parse_first(values: &[&str]) -> i32 {
+        // not listed in `allow-in-tests`
+        values[0].parse().unwrap()
+    }
+
+    #[test]
+    fn test_first() {
+        assert_eq!(super::first(&[1, 2]), Some(1).unwrap());
+        assert_eq!(parse_first(&["1"]), 1);
+    }
+}
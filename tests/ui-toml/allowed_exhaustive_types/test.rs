@@ -0,0 +1,21 @@
+#![warn(clippy::exhaustive_enums)]
+
+pub enum Color {
+    Red,
+    Green,
+}
+
+pub mod shapes {
+    pub enum Shape {
+        Circle,
+        Square,
+    }
+}
+
+pub mod other {
+    pub enum Shape {
+        Triangle,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![warn(clippy::arbitrary_source_item_ordering)]
+#![allow(dead_code)]
+
+struct Unlisted;
+
+const A: u8 = 0;
+
+fn f() {}
+
+const B: u8 = 0;
+
+enum Sorted {
+    A,
+    B,
+    C,
+}
+
+enum Unsorted {
+    A,
+    C,
+    B,
+}
+
+struct Fields {
+    a: u8,
+    c: u8,
+    b: u8,
+}
+
+struct Tuple(u8, u8);
+
+fn main() {}
@@ -0,0 +1,34 @@
+#![warn(clippy::as_conversions)]
+
+enum Color {
+    Red,
+    Green,
+}
+
+fn main() {
+    // lossless
+    let _ = 1_u8 as u32;
+    let _ = 1_i16 as f32;
+    let _ = 1.0_f32 as f64;
+
+    // index
+    let v = vec![1, 2, 3];
+    let i = 1_u32;
+    let _ = v[i as usize];
+    let _ = &v[i as usize..];
+
+    // pointer
+    let x = 0_u64;
+    let _ = &x as *const u64 as *mut u64;
+
+    // enum
+    let _ = Color::Red as u8;
+    let _ = Color::Green as i32;
+
+    // still linted
+    let _ = 1_u32 as u8;
+    let _ = 1_i32 as u64;
+    let _ = 1_usize as u64;
+    let _ = i as usize;
+    let _ = &x as *const u64 as usize;
+}
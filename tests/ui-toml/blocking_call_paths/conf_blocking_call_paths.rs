@@ -0,0 +1,13 @@
+// edition:2018
+#![warn(clippy::blocking_call_in_async)]
+
+async fn cleanup() {
+    let handle = std::thread::spawn(|| 42);
+    let _ = handle.join();
+    let _ = std::fs::remove_file("cache.bin");
+    std::thread::sleep(std::time::Duration::from_millis(10));
+}
+
+fn main() {
+    let _ = cleanup();
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::print_literal)]
+
+macro_rules! info {
+    ($($arg:tt)*) => {
+        println!($($arg)*)
+    };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        println!($($arg)*)
+    };
+}
+
+fn main() {
+    let name = "world";
+    info!("hello {}", "world");
+    info!("hello {}", name);
+
+    // not configured
+    debug!("hello {}", "world");
+}
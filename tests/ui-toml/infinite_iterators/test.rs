@@ -0,0 +1,20 @@
+struct Counter(u32);
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0 += 1;
+        Some(self.0)
+    }
+}
+
+fn naturals() -> impl Iterator<Item = u32> {
+    Counter(0)
+}
+
+fn main() {
+    Counter(0).count();
+    naturals().map(|x| x * 2).sum::<u32>();
+    Counter(0).take(5).count();
+}
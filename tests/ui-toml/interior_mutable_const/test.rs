@@ -0,0 +1,23 @@
+#![warn(clippy::declare_interior_mutable_const, clippy::borrow_interior_mutable_const)]
+
+use std::cell::Cell;
+use std::sync::Once;
+
+struct Init {
+    once: Once,
+    count: usize,
+}
+
+const ONCE: Once = Once::new();
+const INIT: Init = Init {
+    once: Once::new(),
+    count: 0,
+};
+const CELL: Cell<usize> = Cell::new(0);
+
+fn main() {
+    ONCE.call_once(|| {});
+    INIT.once.call_once(|| {});
+    let _ = INIT.count;
+    CELL.set(1);
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::large_error_variant)]
+
+pub fn string_err() -> Result<(), String> {
+    Ok(())
+}
+
+pub fn boxed_err() -> Result<(), Box<String>> {
+    Ok(())
+}
+
+fn main() {}
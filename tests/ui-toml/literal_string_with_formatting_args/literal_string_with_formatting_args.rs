@@ -0,0 +1,17 @@
+#![warn(clippy::literal_string_with_formatting_args)]
+
+fn render(template: &str) -> String {
+    template.replace("{}", "value")
+}
+
+fn log(message: &str) {
+    println!("{}", message);
+}
+
+fn main() {
+    log(&render("value: {}"));
+    log("value: {}");
+
+    // named placeholders aren't checked
+    log("value: {value}");
+}
@@ -0,0 +1,8 @@
+#![warn(clippy::print_stdout)]
+
+fn main() {
+    let name = "world";
+    println!("hello {}", name);
+    print!("hello ");
+    println!();
+}
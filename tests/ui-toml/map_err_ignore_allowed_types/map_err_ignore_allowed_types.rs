@@ -0,0 +1,17 @@
+#![warn(clippy::map_err_ignore)]
+
+use std::convert::TryFrom;
+use std::sync::Mutex;
+
+#[derive(Debug)]
+enum Error {
+    Poisoned,
+    Conversion,
+}
+
+fn main() -> Result<(), Error> {
+    let lock = Mutex::new(-123_i32);
+    let value = lock.lock().map_err(|_| Error::Poisoned)?;
+    let _ = u32::try_from(*value).map_err(|_| Error::Conversion);
+    Ok(())
+}
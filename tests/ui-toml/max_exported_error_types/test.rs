@@ -0,0 +1,16 @@
+#![warn(clippy::error_impl_error)]
+
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("error")
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn main() {}
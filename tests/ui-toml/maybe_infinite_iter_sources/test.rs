@@ -0,0 +1,8 @@
+#![warn(clippy::maybe_infinite_iter)]
+
+use std::iter::successors;
+
+fn main() {
+    successors(Some(1_u32), |x| x.checked_mul(2)).count();
+    successors(Some(1_u32), |x| Some(x * 2)).count();
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::missing_inline_in_public_items)]
+#![crate_type = "lib"]
+
+pub fn few_statements() {
+    let a = 1;
+    let b = a + 1;
+}
+
+pub fn too_many_statements() {
+    let a = 1;
+    let b = a + 1;
+    let c = b + 1;
+}
+
+// statements in nested blocks are counted, too
+pub fn nested_statements(x: bool) {
+    let a = 1;
+    if x {
+        let b = a + 1;
+        let c = b + 1;
+    }
+}
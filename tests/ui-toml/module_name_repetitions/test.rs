@@ -0,0 +1,13 @@
+#![warn(clippy::module_name_repetitions)]
+#![allow(dead_code)]
+
+mod foo {
+    // Should not warn
+    pub fn with_foo() {}
+    pub struct FooError;
+
+    pub fn to_foo() {}
+    pub struct FooCake;
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+
+struct Counter {
+    value: usize,
+    hits: Cell<usize>,
+}
+
+fn ignored_type(_m: &mut HashSet<Cell<usize>>) {}
+
+fn ignored_field(_m: &mut HashSet<Counter>) {}
+
+fn not_ignored(_m: &mut HashSet<RefCell<usize>>) {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![warn(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+#[path = "tests/mod.rs"]
+mod tests;
+
+fn main() {
+    let opt = Some(1);
+    let _ = opt.unwrap();
+    // allowed by `clippy.toml`, but the attribute of this file overrides it
+    let _ = opt.expect("one");
+    tests::test(&[1, 2], 1);
+}
@@ -0,0 +1,6 @@
+pub fn test(x: &[i32], index: usize) {
+    let opt = Some(1);
+    let _ = opt.unwrap();
+    let _ = opt.expect("one");
+    let _ = &x[index..];
+}
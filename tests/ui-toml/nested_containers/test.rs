@@ -0,0 +1,11 @@
+#![warn(clippy::nested_containers)]
+
+fn grid() -> Vec<Vec<u8>> {
+    vec![]
+}
+
+fn parse() -> Result<Result<u32, String>, String> {
+    Ok(Ok(1))
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![warn(clippy::reachable_panic)]
+
+pub fn direct(x: u32) -> u32 {
+    assert!(x > 0);
+    x
+}
+
+pub fn indirect(input: &str) -> u32 {
+    digits(input)
+}
+
+fn digits(input: &str) -> u32 {
+    if input.is_empty() {
+        unreachable!();
+    }
+    0
+}
+
+fn main() {}
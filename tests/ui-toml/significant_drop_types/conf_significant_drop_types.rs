@@ -0,0 +1,17 @@
+#![warn(clippy::significant_drop_in_scrutinee)]
+#![allow(clippy::single_match)]
+
+use std::cell::RefCell;
+
+fn push_if_empty(cell: &RefCell<Vec<u32>>) {
+    match cell.borrow().len() {
+        0 => cell.borrow_mut().push(1),
+        _ => {},
+    }
+    // `RefMut` isn't configured
+    if let Some(value) = cell.borrow_mut().pop() {
+        println!("{}", value);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![warn(clippy::untracked_todo)]
+
+fn tracked() {
+    // see https://github.com/rust-lang/rust-clippy/issues/42
+    todo!()
+}
+
+fn default_pattern() {
+    todo!("TODO(#42)");
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![warn(clippy::disallowed_function)]
+
+use std::env;
+use std::time::Instant;
+
+fn main() {
+    let _ = env::var("HOME");
+    let _: Vec<_> = ["HOME"].iter().map(env::var).collect();
+    let start = Instant::now();
+
+    // not disallowed
+    let _ = env::var_os("HOME");
+    let _ = start.elapsed();
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::disallowed_macro)]
+
+fn main() {
+    println!("starting");
+    std::println!("starting");
+    let x = dbg!(1 + 1);
+
+    // not disallowed
+    eprintln!("starting");
+    assert_eq!(x, 2);
+}
@@ -0,0 +1,18 @@
+#![warn(clippy::disallowed_type)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+fn len(map: &HashMap<u32, u32>) -> usize {
+    map.len()
+}
+
+fn main() {
+    let _lock: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+    let map = HashMap::new();
+    len(&map);
+
+    // not disallowed
+    let _set: HashSet<u32> = HashSet::new();
+}
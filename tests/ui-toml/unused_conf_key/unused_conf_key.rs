@@ -0,0 +1,3 @@
+#![warn(clippy::struct_excessive_bools)]
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![warn(clippy::unnecessary_debug_formatting)]
+
+use std::io::Write;
+
+fn main() {
+    let name = "config.toml";
+    let mut out = std::io::stdout();
+
+    writeln!(out, "reading {:?}", name).unwrap();
+
+    // not in the configured list
+    println!("reading {:?}", name);
+}
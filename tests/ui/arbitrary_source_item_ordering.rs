@@ -0,0 +1,61 @@
+#![warn(clippy::arbitrary_source_item_ordering)]
+#![allow(dead_code, unused_imports)]
+
+mod ordered {
+    use std::fmt;
+
+    const A: u8 = 0;
+
+    struct S;
+
+    impl S {}
+
+    fn f() {}
+
+    #[cfg(test)]
+    mod tests {}
+}
+
+mod fn_before_const {
+    fn f() {}
+
+    const A: u8 = 0;
+}
+
+mod impl_before_struct {
+    struct A;
+
+    impl A {}
+
+    struct B;
+}
+
+mod use_after_items {
+    struct A;
+
+    use std::fmt;
+}
+
+mod only_first {
+    fn f() {}
+
+    const A: u8 = 0;
+
+    static B: u8 = 0;
+}
+
+mod from_macro {
+    macro_rules! items {
+        () => {
+            fn from_macro() {}
+
+            const FROM_MACRO: u8 = 0;
+        };
+    }
+
+    fn f() {}
+
+    items!();
+}
+
+fn main() {}
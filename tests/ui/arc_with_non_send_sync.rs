@@ -0,0 +1,25 @@
+#![warn(clippy::arc_with_non_send_sync)]
+
+use std::cell::RefCell;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+
+fn generic<T>(value: T) -> Arc<T> {
+    // generic types are not linted
+    Arc::new(value)
+}
+
+fn main() {
+    // `Send` and `Sync`
+    let _ = Arc::new(42);
+    let _ = Arc::new(Mutex::new(vec![1]));
+    let _ = generic(RefCell::new(1));
+
+    // `Send` but not `Sync`
+    let _ = Arc::new(RefCell::new(42));
+    // `Sync` but not `Send`
+    let mutex = Mutex::new(1);
+    let _ = Arc::new(mutex.lock().unwrap());
+    // neither `Send` nor `Sync`
+    let _ = Arc::new(ptr::null::<u8>());
+}
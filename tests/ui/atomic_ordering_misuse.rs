@@ -0,0 +1,25 @@
+#![warn(clippy::atomic_ordering_misuse)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+fn main() {
+    let locked = AtomicBool::new(false);
+    let count = AtomicUsize::new(0);
+
+    // the loaded value is unused
+    count.fetch_add(1, Ordering::Acquire);
+    let _ = locked.swap(false, Ordering::Acquire);
+    count.fetch_add(1, Ordering::Release);
+    count.fetch_add(1, Ordering::AcqRel);
+    let previous = count.fetch_sub(1, Ordering::Acquire);
+
+    // the loaded value is a condition
+    while locked.swap(true, Ordering::Release) {}
+    if locked.swap(true, Ordering::Release) {
+        count.store(previous, Ordering::Relaxed);
+    }
+    while locked.swap(true, Ordering::Acquire) {}
+    if count.fetch_sub(1, Ordering::Release) == 1 {
+        std::sync::atomic::fence(Ordering::Acquire);
+    }
+}
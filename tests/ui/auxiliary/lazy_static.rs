@@ -0,0 +1,42 @@
+//! A stand-in for the `lazy_static` crate, expanding like it does.
+
+#[macro_export]
+macro_rules! lazy_static {
+    ($(#[$attr:meta])* static ref $N:ident : $T:ty = $e:expr; $($t:tt)*) => {
+        $crate::__lazy_static_internal!($(#[$attr])* () static ref $N : $T = $e);
+        $crate::lazy_static!($($t)*);
+    };
+    ($(#[$attr:meta])* pub static ref $N:ident : $T:ty = $e:expr; $($t:tt)*) => {
+        $crate::__lazy_static_internal!($(#[$attr])* (pub) static ref $N : $T = $e);
+        $crate::lazy_static!($($t)*);
+    };
+    () => ()
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __lazy_static_internal {
+    ($(#[$attr:meta])* ($($vis:tt)*) static ref $N:ident : $T:ty = $e:expr) => {
+        #[allow(missing_copy_implementations, non_camel_case_types, dead_code)]
+        $(#[$attr])*
+        $($vis)* struct $N {
+            __private_field: (),
+        }
+        #[doc(hidden)]
+        $($vis)* static $N: $N = $N { __private_field: () };
+        impl ::std::ops::Deref for $N {
+            type Target = $T;
+            fn deref(&self) -> &$T {
+                fn __static_ref_initialize() -> $T {
+                    $e
+                }
+                static ONCE: ::std::sync::Once = ::std::sync::Once::new();
+                static mut VALUE: Option<$T> = None;
+                unsafe {
+                    ONCE.call_once(|| VALUE = Some(__static_ref_initialize()));
+                    VALUE.as_ref().unwrap()
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,47 @@
+//! A stand-in for the `sync` module of the `once_cell` crate, without the synchronization.
+
+pub mod sync {
+    use std::cell::UnsafeCell;
+
+    pub struct OnceCell<T> {
+        value: UnsafeCell<Option<T>>,
+    }
+
+    unsafe impl<T: Sync + Send> Sync for OnceCell<T> {}
+
+    impl<T> OnceCell<T> {
+        pub const fn new() -> Self {
+            Self {
+                value: UnsafeCell::new(None),
+            }
+        }
+
+        pub fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+            unsafe { (*self.value.get()).get_or_insert_with(f) }
+        }
+    }
+
+    pub struct Lazy<T, F = fn() -> T> {
+        cell: OnceCell<T>,
+        init: F,
+    }
+
+    unsafe impl<T: Sync + Send, F: Sync> Sync for Lazy<T, F> {}
+
+    impl<T, F> Lazy<T, F> {
+        pub const fn new(init: F) -> Self {
+            Self {
+                cell: OnceCell::new(),
+                init,
+            }
+        }
+    }
+
+    impl<T, F: Fn() -> T> std::ops::Deref for Lazy<T, F> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.cell.get_or_init(&self.init)
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// edition:2018
+#![warn(clippy::blocking_call_in_async)]
+
+use std::net::TcpStream;
+use std::sync::Mutex;
+use std::time::Duration;
+
+async fn sleepy() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+async fn read_config() -> std::io::Result<String> {
+    std::fs::read_to_string("config.toml")
+}
+
+async fn connect() -> std::io::Result<TcpStream> {
+    TcpStream::connect("127.0.0.1:8080")
+}
+
+async fn increment(counter: &Mutex<u32>) {
+    *counter.lock().unwrap() += 1;
+}
+
+fn main() {
+    let _ = sleepy();
+    let _ = read_config();
+    let _ = connect();
+    let counter = Mutex::new(0);
+    let _ = increment(&counter);
+    let _ = async { std::fs::read("data.bin") };
+
+    // not in an async context
+    std::thread::sleep(Duration::from_millis(10));
+    let _ = async {
+        let work = || std::fs::read("data.bin");
+        work()
+    };
+}
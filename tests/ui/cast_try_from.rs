@@ -0,0 +1,32 @@
+#![warn(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use std::error::Error;
+use std::num::TryFromIntError;
+
+fn boxed_error(x: u64) -> Result<u8, Box<dyn Error>> {
+    Ok(x as u8)
+}
+
+fn int_error(x: i32) -> Result<u32, TryFromIntError> {
+    Ok(x as u32)
+}
+
+fn string_error(x: u64) -> Result<u16, String> {
+    Ok(x as u16)
+}
+
+fn closure(x: i64) -> Result<(), Box<dyn Error>> {
+    let f = || x as i8;
+    f();
+    Ok(())
+}
+
+const fn in_const_fn(x: u64) -> u8 {
+    x as u8
+}
+
+fn sign_loss_and_truncation(x: i64) -> Result<u8, TryFromIntError> {
+    Ok(x as u8)
+}
+
+fn main() {}
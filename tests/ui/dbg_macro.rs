@@ -1,3 +1,5 @@
+// run-rustfix
+
 #![warn(clippy::dbg_macro)]
 
 fn foo(n: u32) -> u32 {
@@ -20,4 +22,10 @@ fn main() {
     dbg!(42);
     dbg!(dbg!(dbg!(42)));
     foo(3) + dbg!(factorial(4));
+    let x = 2 * dbg!(1 + 2);
+    let (a, b) = dbg!(x, foo(x),);
+    dbg!(a, b);
+    dbg!();
+    dbg!(foo(b));
+    let _ = dbg!(a * b).pow(2);
 }
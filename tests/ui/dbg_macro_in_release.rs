@@ -0,0 +1,55 @@
+// run-rustfix
+
+#![warn(clippy::dbg_macro_in_release)]
+
+fn double(n: u32) -> u32 {
+    dbg!(n * 2)
+}
+
+fn not_yet() -> u32 {
+    todo!()
+}
+
+fn never() -> u32 {
+    unimplemented!()
+}
+
+fn main() {
+    let n = 3;
+    dbg!(n);
+    let _ = double(n) + dbg!(n + 1);
+    if n > 5 {
+        not_yet();
+        never();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn doubles() {
+        dbg!(super::double(2));
+        todo!()
+    }
+}
+
+#[test]
+fn standalone() {
+    unimplemented!();
+}
+
+struct Counter;
+
+impl Counter {
+    #[cfg(test)]
+    fn dump(&self) {
+        dbg!(1);
+    }
+}
+
+trait Dump {
+    #[cfg(test)]
+    fn dump(&self) {
+        todo!()
+    }
+}
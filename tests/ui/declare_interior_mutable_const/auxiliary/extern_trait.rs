@@ -0,0 +1,4 @@
+/// A trait like `lock_api::RawMutex`, whose implementors provide their initial value.
+pub trait RawMutex {
+    const INIT: Self;
+}
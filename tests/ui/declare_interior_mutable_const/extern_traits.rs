@@ -0,0 +1,26 @@
+// aux-build:extern_trait.rs
+
+#![warn(clippy::declare_interior_mutable_const)]
+
+extern crate extern_trait;
+
+use extern_trait::RawMutex;
+use std::sync::atomic::AtomicUsize;
+
+struct Mutex(AtomicUsize);
+
+// the consts of traits from other crates can't be changed by the implementor.
+impl RawMutex for Mutex {
+    const INIT: Self = Mutex(AtomicUsize::new(0));
+}
+
+// the same trait defined in this crate is linted at the implementation site.
+trait LocalRawMutex {
+    const INIT: Self;
+}
+
+impl LocalRawMutex for Mutex {
+    const INIT: Self = Mutex(AtomicUsize::new(0)); //~ ERROR interior mutable
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![warn(clippy::default_numeric_fallback)]
+#![allow(unused)]
+#![allow(clippy::no_effect)]
+#![allow(clippy::unnecessary_operation)]
+
+struct Point {
+    x: u32,
+    y: f32,
+}
+
+struct Wrapper<T> {
+    value: T,
+}
+
+fn takes_u64(_: u64) {}
+
+fn takes_generic<T>(_: T) {}
+
+fn returns_u8() -> u8 {
+    return 1;
+}
+
+fn main() {
+    // Lint unconstrained literals.
+    let x = 22;
+    let x = [1, 2, 3];
+    let x = if true { (1, 2) } else { (3, 4) };
+    let x = 0.12;
+    let x = -0.5;
+    takes_generic(1);
+    let x = Wrapper { value: 2.0 };
+    let x = Some(1);
+
+    // Don't lint literals whose type is fixed.
+    let x = 22_i32;
+    let x: f64 = 0.12;
+    let x: [u8; 3] = [1, 2, 3];
+    takes_u64(1);
+    let p = Point { x: 1, y: 2.0 };
+    let y: u16 = 3;
+    let z = y + 1;
+    let count = 5;
+    takes_u64(count);
+    let v = vec![1_u8];
+    let w = v.get(0);
+    let x: Option<i32> = Some(1);
+    let x: Result<u8, ()> = Ok(1);
+    let x: Option<Option<f32>> = Some(Some(1.0));
+    let x: Wrapper<f32> = Wrapper { value: 1.0 };
+    takes_option(Some(1));
+}
+
+fn takes_option(_: Option<u64>) {}
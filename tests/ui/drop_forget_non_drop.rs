@@ -0,0 +1,47 @@
+#![warn(clippy::drop_non_drop, clippy::forget_non_drop)]
+
+use std::mem::{drop, forget, ManuallyDrop};
+
+struct Span<'a> {
+    text: &'a str,
+}
+
+struct Droppable;
+
+impl Drop for Droppable {
+    fn drop(&mut self) {}
+}
+
+struct HasDroppable {
+    _inner: Droppable,
+}
+
+fn generic<T>(t: T) {
+    // `T` may need to be dropped
+    drop(t);
+}
+
+fn main() {
+    let text = String::from("a");
+
+    drop(Span { text: &text });
+    forget(Span { text: &text });
+    drop((1, Span { text: &text }));
+
+    // need to be dropped
+    drop(Droppable);
+    drop(HasDroppable { _inner: Droppable });
+    drop(vec![Span { text: &text }]);
+    forget((1, Droppable));
+    generic(Span { text: &text });
+
+    // linted by `undropped_manually_drops`
+    #[allow(clippy::undropped_manually_drops)]
+    drop(ManuallyDrop::new(Droppable));
+
+    // linted by `drop_ref` and `drop_copy`
+    #[allow(clippy::drop_ref)]
+    drop(&text);
+    #[allow(clippy::drop_copy)]
+    drop(1);
+}
@@ -0,0 +1,36 @@
+// run-rustfix
+#![warn(clippy::empty_enum_variants_with_brackets)]
+#![allow(dead_code)]
+
+pub enum PublicTestEnum {
+    NonEmptyBraces { x: i32, y: i32 }, // No error
+    NonEmptyParentheses(i32, i32),     // No error
+    EmptyBraces {},                    // Error
+    EmptyParentheses(),                // Error
+}
+
+enum TestEnum {
+    NonEmptyBraces { x: i32, y: i32 }, // No error
+    NonEmptyParentheses(i32, i32),     // No error
+    EmptyBraces {},                    // Error
+    EmptyParentheses(),                // Error
+    AnotherEnum,                       // No error
+}
+
+enum UsedAsFunction {
+    EmptyParentheses(), // No error, the constructor is used as a function
+}
+
+#[derive(Clone, PartialEq)]
+enum Derived {
+    EmptyBraces {}, // Error
+}
+
+fn test(value: TestEnum) -> bool {
+    let _ = TestEnum::EmptyBraces {};
+    let _ = TestEnum::EmptyParentheses();
+    let _: fn() -> UsedAsFunction = UsedAsFunction::EmptyParentheses;
+    matches!(value, TestEnum::EmptyBraces {} | TestEnum::EmptyParentheses(..))
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![deny(clippy::error_handling)]
+
+use std::convert::TryFrom;
+
+#[derive(Debug)]
+struct Error;
+
+fn main() {
+    let x = u32::try_from(-1_i32);
+    let _ = x.unwrap();
+    let _ = x.map_err(|_| Error);
+    panic!("giving up");
+}
@@ -0,0 +1,55 @@
+#![warn(clippy::error_impl_error)]
+#![allow(dead_code)]
+
+macro_rules! impl_error {
+    ($ty:ty) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("error")
+            }
+        }
+
+        impl std::error::Error for $ty {}
+    };
+}
+
+pub mod parse {
+    #[derive(Debug)]
+    pub struct Error;
+
+    impl_error!(Error);
+}
+
+pub mod io {
+    #[derive(Debug)]
+    pub enum Error {
+        NotFound,
+    }
+
+    impl_error!(Error);
+}
+
+// Only reachable as `ConfigError`
+mod config {
+    #[derive(Debug)]
+    pub struct Error;
+
+    impl_error!(Error);
+}
+
+pub use config::Error as ConfigError;
+
+// Doesn't implement `Error`
+pub mod data {
+    pub struct Error;
+}
+
+// Not exported
+mod internal {
+    #[derive(Debug)]
+    pub struct Error;
+
+    impl_error!(Error);
+}
+
+fn main() {}
@@ -202,3 +202,33 @@ impl std::ops::Deref for Bar {
 fn test_deref_with_trait_method() {
     let _ = [Bar].iter().map(|s| s.to_string()).collect::<Vec<_>>();
 }
+
+fn test_redundant_closure_with_non_copy_closure() {
+    let s = String::from("foo");
+    let closure = move |a: u8| println!("{} {}", s, a);
+    let a = Some(1u8).map(|a| closure(a));
+    closure(2);
+
+    let mut count = 0;
+    let mut count_closure = |a: u8| count += usize::from(a);
+    let a = Some(1u8).map(|a| count_closure(a));
+    count_closure(2);
+}
+
+fn takes_higher_ranked(f: impl Fn(&u8) -> u8) -> u8 {
+    f(&1)
+}
+
+fn by_ref(x: &u8) -> u8 {
+    *x
+}
+
+fn test_redundant_closure_with_late_bound_regions() {
+    // `generic::<&'a u8>` only accepts one lifetime
+    takes_higher_ranked(|x| generic(x));
+    takes_higher_ranked(|x| by_ref(x));
+}
+
+fn test_redundant_closure_with_generic_trait() {
+    let e: Option<u32> = Some(1u8).map(|x| x.into());
+}
@@ -37,6 +37,10 @@ impl Trait for S {
     fn g(_: bool, _: bool, _: bool, _: Vec<u32>) {}
 }
 
+type Flag = bool;
+
+fn aliased(_: Flag, _: Flag, _: bool, _: std::primitive::bool) {}
+
 fn main() {
     fn n(_: bool, _: u32, _: bool, _: Box<u32>, _: bool, _: bool) {
         fn nn(_: bool, _: bool, _: bool, _: bool) {}
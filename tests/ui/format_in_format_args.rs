@@ -0,0 +1,29 @@
+// run-rustfix
+
+#![warn(clippy::format_in_format_args)]
+#![allow(unused_must_use)]
+
+use std::fmt::Write;
+
+fn main() {
+    let name = "world";
+    let mut s = String::new();
+
+    let _ = format!("{}", format!("hello {}", name));
+    println!("{}", format!("hello {}", name));
+    eprint!("{}", format!("hello {}", name));
+    write!(s, "{}", format!("hello {}", name));
+    writeln!(s, "{}", format!("hello {}", name));
+
+    // the inner format string can't be inlined as is
+    println!("greeting: {}", format!("hello {}", name));
+    println!("{} and {}", format!("hello {}", name), name);
+
+    // the `String` is formatted with other options
+    println!("{:?}", format!("hello {}", name));
+    println!("{:>20}", format!("hello {}", name));
+
+    // not a `format!` call
+    println!("{}", name);
+    println!("{}", format!("hello {}", name).trim());
+}
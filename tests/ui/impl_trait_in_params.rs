@@ -0,0 +1,56 @@
+// run-rustfix
+#![warn(clippy::impl_trait_in_params)]
+#![allow(dead_code)]
+
+use std::fmt::{Debug, Display};
+
+pub fn print(value: impl Display) {
+    println!("{}", value);
+}
+
+pub fn print_all<'a, T: Debug>(first: &'a T, rest: Vec<impl Debug + Clone>, last: impl Display) {
+    println!("{:?} {:?} {}", first, rest, last);
+}
+
+pub struct Printer<T>(T);
+
+impl<T: Display> Printer<T> {
+    pub fn print_with(&self, suffix: impl Display) {
+        println!("{}{}", self.0, suffix);
+    }
+}
+
+pub trait Print {
+    fn print_to(&self, out: impl std::io::Write);
+}
+
+impl Print for String {
+    fn print_to(&self, _: impl std::io::Write) {}
+}
+
+// not exported
+fn private_print(value: impl Display) {
+    println!("{}", value);
+}
+
+mod private {
+    pub fn print(value: impl std::fmt::Display) {
+        println!("{}", value);
+    }
+}
+
+pub fn print_generic<T: Display>(value: T) {
+    println!("{}", value);
+}
+
+#[rustfmt::skip]
+pub fn print_trailing_comma<T: Debug,>(first: T, last: impl Display) {
+    println!("{:?} {}", first, last);
+}
+
+// the nested `impl Trait` can't be used in the bound of a type parameter
+pub fn print_iter(values: impl Iterator<Item = impl Display>) {
+    values.for_each(|value| println!("{}", value));
+}
+
+fn main() {}
@@ -115,4 +115,14 @@ impl EmptyEnum {
     }
 }
 
+#[allow(irrefutable_let_patterns)]
+fn irrefutable_if_let() {
+    let wrapper = SingleVariantEnum::Variant(0);
+
+    // This shouldn't lint, rustc's `irrefutable_let_patterns` already warns about it
+    if let SingleVariantEnum::Variant(i) = wrapper {
+        let _ = i;
+    }
+}
+
 fn main() {}
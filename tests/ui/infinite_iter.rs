@@ -67,3 +67,41 @@ mod finite_collect {
         let _: C = (0..).collect();
     }
 }
+
+mod adapters {
+    use std::iter::repeat_with;
+
+    #[deny(clippy::infinite_iter)]
+    fn infinite_iters() {
+        [1, 2].iter().cycle().copied().sum::<i32>(); // infinite iter
+        (0_u32..).step_by(2).count(); // infinite iter
+        repeat_with(|| 0).map(|x| x + 1).max(); // infinite iter
+        (0_u32..).peekable().for_each(|_| ()); // infinite iter
+        (0..8_u32).step_by(2).count(); // not infinite
+    }
+
+    #[deny(clippy::maybe_infinite_iter)]
+    fn potential_infinite_iters() {
+        (0_u32..).find_map(|x| if x > 10 { Some(x) } else { None }); // maybe infinite iter
+        let _ = (0_u32..).try_for_each(|x| if x > 10 { Err(x) } else { Ok(()) });
+    }
+}
+
+mod generators {
+    use std::iter::{from_fn, successors};
+
+    #[deny(clippy::infinite_iter)]
+    fn infinite_iters() {
+        successors(Some(1_u32), |x| Some(x + 1)).count(); // infinite iter
+        from_fn(|| Some(0)).max(); // infinite iter
+        successors(Some(1_u32), |x| Some(x + 1)).take(5).count(); // not infinite
+    }
+
+    #[deny(clippy::maybe_infinite_iter)]
+    fn potential_infinite_iters() {
+        successors(Some(1_u32), |x| Some(x * 2))
+            .take_while(|x| *x != 100)
+            .count(); // maybe infinite iter
+        successors(Some(1_u32), |x| x.checked_mul(2)).count(); // maybe infinite iter
+    }
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::join_absolute_paths)]
+#![allow(clippy::path_buf_push_overwrite)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let path = Path::new("/bin");
+    let _ = path.join("/sh");
+    let _ = path.join("\\sh");
+    let _ = path.join("C:\\sh");
+
+    let mut buf = PathBuf::from("/bin");
+    let _ = buf.join("/sh");
+    buf.push("c:sh");
+
+    // relative paths
+    let _ = path.join("sh");
+    let _ = path.join("./sh");
+    buf.push("sh");
+
+    // checked by `path_buf_push_overwrite`
+    buf.push("/sh");
+}
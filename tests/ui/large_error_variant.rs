@@ -0,0 +1,55 @@
+#![warn(clippy::large_error_variant)]
+#![allow(dead_code)]
+
+pub struct LargeError {
+    message: [u8; 512],
+}
+
+pub struct SmallError {
+    message: String,
+}
+
+pub fn large_err() -> Result<(), LargeError> {
+    Ok(())
+}
+
+pub fn large_array_err() -> Result<u8, [u64; 32]> {
+    Ok(0)
+}
+
+pub fn boxed_err() -> Result<(), Box<LargeError>> {
+    Ok(())
+}
+
+pub fn small_err() -> Result<(), SmallError> {
+    Ok(())
+}
+
+pub fn large_ok() -> Result<LargeError, ()> {
+    Err(())
+}
+
+pub fn generic_err<E>(err: E) -> Result<(), E> {
+    Err(err)
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self) -> Result<(), LargeError> {
+        Ok(())
+    }
+}
+
+pub trait Parse {
+    fn parse(&self) -> Result<(), LargeError>;
+}
+
+// linted at the trait
+impl Parse for Parser {
+    fn parse(&self) -> Result<(), LargeError> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -16,4 +16,5 @@ fn main() {
     let _ = Box::new(());
     let _ = Droppable;
     let _ = Some(Droppable);
+    let _ = droppable;
 }
@@ -10,4 +10,7 @@ fn main() {
     let _ = m.try_lock();
     let _ = rw.try_read();
     let _ = rw.try_write();
+
+    let guard = m.lock();
+    let _ = guard;
 }
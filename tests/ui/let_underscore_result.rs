@@ -0,0 +1,33 @@
+#![warn(clippy::let_underscore_result)]
+#![allow(clippy::let_underscore_must_use, clippy::unnecessary_wraps)]
+
+use std::sync::Mutex;
+
+fn g() -> Result<u32, u32> {
+    Ok(0)
+}
+
+#[must_use]
+fn f() -> u32 {
+    0
+}
+
+fn main() {
+    let _ = g();
+    let _ = g().map(|x| x + 1);
+    let _ = "1".parse::<u32>();
+
+    // ignored explicitly
+    g().ok();
+
+    // not a `Result`
+    let _ = f();
+    let _ = Some(1);
+
+    // linted by `let_underscore_lock`
+    #[allow(clippy::let_underscore_lock)]
+    let _ = Mutex::new(()).lock();
+
+    #[allow(clippy::let_underscore_result)]
+    let _ = g();
+}
@@ -0,0 +1,35 @@
+#![warn(clippy::lines_filter_map_ok)]
+
+use std::io::{self, BufRead, BufReader};
+
+fn main() -> io::Result<()> {
+    let reader = || BufReader::new(io::stdin());
+
+    for line in reader().lines().filter_map(Result::ok) {
+        println!("{}", line);
+    }
+    for line in reader().lines().flat_map(Result::ok) {
+        println!("{}", line);
+    }
+    for line in reader().lines().flatten() {
+        println!("{}", line);
+    }
+    let _lines: Vec<String> = reader().lines().filter_map(|line| line.ok()).collect();
+
+    // the errors are handled
+    for line in reader().lines() {
+        println!("{}", line?);
+    }
+    let _lines = reader().lines().collect::<io::Result<Vec<String>>>()?;
+
+    // not `Result::ok`
+    for len in reader().lines().filter_map(|line| line.ok().map(|line| line.len())) {
+        println!("{}", len);
+    }
+
+    // not the lines of a reader
+    for c in "a\nb".lines().flat_map(str::chars) {
+        println!("{}", c);
+    }
+    Ok(())
+}
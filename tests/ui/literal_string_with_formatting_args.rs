@@ -0,0 +1,31 @@
+#![warn(clippy::literal_string_with_formatting_args)]
+
+fn log(message: &str) {
+    println!("{}", message);
+}
+
+fn main() {
+    let mut s = String::new();
+    s.push_str("{}");
+    s.push_str("value: {:?}");
+    s.push_str("{0} and {1}");
+    s.push_str("{name:>8}");
+    log("failed with {}");
+    log("failed with {error}");
+
+    // not placeholders
+    s.push_str("{{}}");
+    s.push_str("{ }");
+    s.push_str("{\"key\": 1}");
+    s.push_str("{a: 1}");
+    s.push_str("{_}");
+    log("no braces");
+
+    // the receiver and patterns aren't displayed
+    let _ = "{}".replace("{}", "value");
+    let _ = s.contains("{}");
+
+    // formatting macros
+    log(&format!("failed with {}", 42));
+    println!("{}", s);
+}
@@ -0,0 +1,31 @@
+// run-rustfix
+
+#![warn(clippy::manual_contains)]
+
+use std::collections::HashSet;
+
+fn main() {
+    let values = [1, 2, 3];
+    let strings = vec![String::from("a"), String::from("b")];
+    let needle = 2;
+    let r = &needle;
+    let s = String::from("a");
+
+    let _ = values.iter().any(|x| *x == needle);
+    let _ = values.iter().any(|&x| x == needle);
+    let _ = values.iter().any(|x| needle == *x);
+    let _ = values.iter().any(|x| *x == *r);
+    let _ = values.iter().any(|x| x == r);
+    let _ = values.iter().any(|x| *x == 3);
+    let _ = strings.iter().any(|x| *x == s);
+    let _ = values.iter().position(|x| *x == needle).is_some();
+    let _ = values.iter().any(|x| *x == needle + 1);
+
+    // Don't lint
+    let _ = values.iter().any(|x| *x > needle);
+    let _ = values.iter().any(|x| *x == needle && *x > 0);
+    let _ = values.iter().any(|x| *x == x + 1);
+    let _ = strings.iter().any(|x| x == "a");
+    let set: HashSet<i32> = values.iter().copied().collect();
+    let _ = set.iter().any(|x| *x == needle);
+}
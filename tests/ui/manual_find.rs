@@ -0,0 +1,146 @@
+// run-rustfix
+
+#![warn(clippy::manual_find)]
+#![allow(dead_code)]
+
+fn is_long(s: &str) -> bool {
+    s.len() > 5
+}
+
+fn slice(v: &[u32]) -> Option<&u32> {
+    for x in v {
+        if *x == 2 {
+            return Some(x);
+        }
+    }
+    None
+}
+
+fn iterator(v: &[u32], early: bool) -> Option<u32> {
+    if early {
+        for x in v.iter().copied() {
+            if x > 10 {
+                return Some(x);
+            }
+        }
+        return None;
+    }
+    v.first().copied()
+}
+
+fn by_value(names: Vec<String>) -> Option<String> {
+    for name in names {
+        if name == "a" {
+            return Some(name);
+        }
+    }
+    None
+}
+
+fn by_value_method(names: Vec<String>) -> Option<String> {
+    for name in names {
+        if name.starts_with('b') && is_long(&name) {
+            return Some(name);
+        }
+    }
+    None
+}
+
+fn with_break(v: Vec<i32>) {
+    let mut found = None;
+    for x in v.iter() {
+        if *x > 3 {
+            found = Some(x);
+            break;
+        }
+    }
+    println!("{:?}", found);
+
+    let mut found: Option<&i32> = None;
+    for x in &v {
+        if *x < 0 {
+            found = Some(x);
+            break;
+        }
+    }
+    if found.is_none() {
+        found = v.first();
+    }
+    println!("{:?}", found);
+}
+
+// Don't lint
+
+fn with_else(v: &[u32]) -> Option<&u32> {
+    for x in v {
+        if *x == 2 {
+            return Some(x);
+        } else {
+            println!("{}", x);
+        }
+    }
+    None
+}
+
+fn other_statements(v: &[u32]) -> Option<&u32> {
+    for x in v {
+        println!("{}", x);
+        if *x == 2 {
+            return Some(x);
+        }
+    }
+    None
+}
+
+fn not_the_item(v: &[u32]) -> Option<u32> {
+    for x in v {
+        if *x == 2 {
+            return Some(x + 1);
+        }
+    }
+    None
+}
+
+fn not_none(v: &[u32]) -> Option<&u32> {
+    for x in v {
+        if *x == 2 {
+            return Some(x);
+        }
+    }
+    v.last()
+}
+
+fn not_the_fn_body(v: &[u32]) -> Option<&u32> {
+    let found = {
+        for x in v {
+            if *x == 2 {
+                return Some(x);
+            }
+        }
+        None
+    };
+    println!("not found");
+    found
+}
+
+fn returns_in_condition(v: &[Option<u32>]) -> Option<u32> {
+    for x in v.iter().copied() {
+        if x? == 2 {
+            return Some(x?);
+        }
+    }
+    None
+}
+
+fn condition_uses_result(v: Vec<i32>) {
+    let mut found = None;
+    for x in v.iter() {
+        if found.is_none() && *x > 3 {
+            found = Some(x);
+            break;
+        }
+    }
+    println!("{:?}", found);
+}
+
+fn main() {}
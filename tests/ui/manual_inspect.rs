@@ -0,0 +1,29 @@
+// run-rustfix
+#![warn(clippy::manual_inspect)]
+
+fn observe<T: std::fmt::Debug>(_: &T) {}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _: i32 = v.iter().copied().map(|x| { println!("{}", x); x }).sum();
+    let _: Vec<_> = v
+        .iter()
+        .map(|x| {
+            println!("{}", x);
+            x
+        })
+        .collect();
+
+    let strings = vec![String::from("a")];
+    let _: Vec<_> = strings.into_iter().map(|s| { observe(&s); s }).collect();
+
+    // no lint, the argument is changed
+    let _: Vec<_> = v.iter().map(|x| { println!("{}", x); x + 1 }).collect();
+    let _: Vec<_> = v.iter().map(|x| { let _ = x; let x = &0; x }).collect();
+
+    // no lint, another value may be returned
+    let _: Vec<_> = v.iter().map(|x| { if *x == 0 { return &1; } x }).collect();
+
+    // no lint, not an iterator
+    let _ = Some(1).map(|x| { println!("{}", x); x });
+}
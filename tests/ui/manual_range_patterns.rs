@@ -0,0 +1,36 @@
+// run-rustfix
+
+#![allow(unused)]
+#![warn(clippy::manual_range_patterns)]
+
+fn main() {
+    let x = 6;
+    let _ = matches!(x, 1 | 2 | 3 | 4 | 5);
+    let _ = matches!(x, 5 | 3 | 1 | 4 | 2);
+    let _ = matches!(x, 1 | 2..=4 | 5);
+    let _ = matches!(x, 1..=3 | 4..=10);
+    let _ = matches!(x, -3 | -2 | -1 | 0);
+    let c = 'b';
+    let _ = matches!(c, 'a' | 'b' | 'c' | 'd');
+    match x {
+        1 | 2 | 3 => println!("small"),
+        _ => println!("large"),
+    }
+
+    // not contiguous
+    let _ = matches!(x, 1 | 2 | 4 | 5);
+    let _ = matches!(x, 1..=3 | 5..=7);
+    // only two literals
+    let _ = matches!(x, 1 | 2);
+    // not only literals
+    const ONE: i32 = 1;
+    let _ = matches!(x, ONE | 2 | 3);
+    let _ = matches!(c, 'a' | 'b' | 'd');
+
+    macro_rules! small {
+        ($e:expr) => {
+            matches!($e, 1 | 2 | 3)
+        };
+    }
+    let _ = small!(x);
+}
@@ -0,0 +1,33 @@
+// run-rustfix
+#![warn(clippy::manual_retain)]
+#![allow(unused_assignments)]
+
+use std::collections::VecDeque;
+
+fn main() {
+    let mut v = vec![1, 2, 3, 4];
+    v = v.into_iter().filter(|x| x % 2 == 0).collect();
+    v = v.iter().cloned().filter(|&x| x > 1).collect();
+    v = v.iter().copied().filter(|x| *x != 3).collect();
+
+    let mut strings = vec![String::from("a"), String::new()];
+    strings = strings.iter().cloned().filter(|s| !s.is_empty()).collect();
+
+    // no lint, another `Vec` is filtered
+    let w = vec![1, 2, 3];
+    v = w.into_iter().filter(|x| x % 2 == 0).collect();
+
+    // no lint, collected into another type
+    let _: VecDeque<i32> = v.clone().into_iter().filter(|x| x % 2 == 0).collect();
+    let mut d: VecDeque<i32> = VecDeque::new();
+    d = v.iter().cloned().filter(|x| x % 2 == 0).collect();
+
+    // no lint, the elements are mapped
+    v = v.into_iter().map(|x| x + 1).filter(|x| x % 2 == 0).collect();
+    let _ = d;
+
+    // no lint, the receiver of `filter` isn't a method call on a `Vec`
+    v = (0..4).filter(|x| x % 2 == 0).collect();
+    let it = vec![1, 2, 3].into_iter();
+    v = it.filter(|x| x % 2 == 0).collect();
+}
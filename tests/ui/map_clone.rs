@@ -4,7 +4,7 @@
 #![allow(clippy::clone_on_copy, clippy::redundant_clone)]
 #![allow(clippy::let_underscore_drop)]
 #![allow(clippy::missing_docs_in_private_items)]
-#![allow(clippy::redundant_closure_for_method_calls)]
+#![allow(clippy::redundant_closure, clippy::redundant_closure_for_method_calls)]
 #![allow(clippy::many_single_char_names)]
 
 fn main() {
@@ -60,4 +60,16 @@ fn main() {
 
         let _ = Some(RefCell::new(String::new()).borrow()).map(|s| s.clone());
     }
+
+    // Deref-aware choice between `copied`, `cloned` and removing the call
+    {
+        struct NotClone;
+
+        let v = vec![String::new()];
+        let _: Vec<String> = v.iter().map(|x| (*x).clone()).collect();
+        let w = vec![1_u8, 2];
+        let _: Vec<u8> = w.iter().map(|x| Clone::clone(x)).collect();
+        let n = vec![NotClone];
+        let _: Vec<&NotClone> = n.iter().map(|x| x.clone()).collect();
+    }
 }
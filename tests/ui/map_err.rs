@@ -1,12 +1,16 @@
+// run-rustfix
 #![warn(clippy::map_err_ignore)]
 #![allow(clippy::unnecessary_wraps)]
 use std::convert::TryFrom;
 use std::error::Error;
+use std::num::TryFromIntError;
 use std::fmt;
 
 #[derive(Debug)]
 enum Errors {
     Ignored,
+    Wrapper(&'static str),
+    Parse(TryFromIntError),
 }
 
 impl Error for Errors {}
@@ -17,10 +21,33 @@ impl fmt::Display for Errors {
     }
 }
 
+#[derive(Debug)]
+enum Kind {
+    Io,
+}
+
+#[derive(Debug)]
+struct MyError {
+    kind: Kind,
+}
+
+fn make_error() -> Errors {
+    Errors::Ignored
+}
+
 fn main() -> Result<(), Errors> {
     let x = u32::try_from(-123_i32);
 
     println!("{:?}", x.map_err(|_| Errors::Ignored));
+    println!("{:?}", x.map_err(|_| Errors::Wrapper("context")));
+    println!("{:?}", x.map_err(|_| MyError { kind: Kind::Io }));
+    println!("{:?}", x.map_err(|_| make_error()));
+    println!("{:?}", x.map_err(move |_| Errors::Ignored));
+    println!("{:?}", x.map_err(|_| Errors::Parse(u8::try_from(300_u32).unwrap_err())));
+    let _ = x.map_err(|_| Errors::Parse);
+
+    // the original error is used
+    println!("{:?}", x.map_err(|e| e.to_string()));
 
     Ok(())
 }
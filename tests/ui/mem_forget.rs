@@ -21,3 +21,15 @@ fn main() {
 
     std::mem::forget(7);
 }
+
+#[warn(clippy::mem_forget)]
+fn contains_drop() {
+    struct Droppable;
+
+    impl Drop for Droppable {
+        fn drop(&mut self) {}
+    }
+
+    std::mem::forget((1, Droppable));
+    std::mem::forget((1, String::new()));
+}
@@ -1,4 +1,5 @@
 #![allow(clippy::redundant_clone)]
+#![warn(clippy::checked_conversions, clippy::use_self)]
 #![feature(custom_inner_attributes)]
 #![clippy::msrv = "1.0.0"]
 
@@ -42,12 +43,56 @@ pub fn manual_strip_msrv() {
     }
 }
 
+fn checked_conversion() {
+    let value: i64 = 42;
+    let _ = value <= (u32::max_value() as i64) && value >= 0;
+}
+
+fn manual_range_contains() {
+    let x = 5;
+    let _ = x >= 8 && x < 12;
+}
+
+fn mem_replace_with_default() {
+    let mut s = String::from("foo");
+    let _ = std::mem::replace(&mut s, String::default());
+}
+
+fn redundant_field_names() {
+    struct Foo {
+        bar: u8,
+    }
+
+    let bar: u8 = 123;
+    let value = Foo { bar: bar };
+    let _ = value.bar;
+}
+
+fn redundant_static_lifetime() {
+    const FOO: &'static str = "foo";
+    let _ = FOO;
+}
+
+struct UseSelf;
+
+impl UseSelf {
+    fn new() -> UseSelf {
+        UseSelf
+    }
+}
+
 fn main() {
     option_as_ref_deref();
     match_like_matches();
     match_same_arms();
     match_same_arms2();
     manual_strip_msrv();
+    checked_conversion();
+    manual_range_contains();
+    mem_replace_with_default();
+    redundant_field_names();
+    redundant_static_lifetime();
+    let _ = UseSelf::new();
 }
 
 mod meets_msrv {
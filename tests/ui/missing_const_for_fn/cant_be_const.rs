@@ -4,7 +4,7 @@
 
 #![warn(clippy::missing_const_for_fn)]
 #![allow(incomplete_features)]
-#![feature(start, const_generics)]
+#![feature(start, const_generics, custom_inner_attributes)]
 
 struct Game;
 
@@ -101,3 +101,40 @@ fn const_generic_return<T, const N: usize>(t: &[T]) -> &[T; N] {
 
     unsafe { &*p }
 }
+
+// Floating point arithmetic is unstable in const fn
+fn float_add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+// Trait methods can't be called in const fn
+fn default_u32() -> u32 {
+    Default::default()
+}
+
+// This can not be const because the `String` is dropped at the end of the function
+fn drop_string(s: String) -> u32 {
+    42
+}
+
+mod before_const_if_match {
+    #![clippy::msrv = "1.45.0"]
+
+    // `if` is only allowed in const fn since Rust 1.46
+    fn max(a: u32, b: u32) -> u32 {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+}
+
+mod before_const_fn {
+    #![clippy::msrv = "1.30.0"]
+
+    // `const fn` is only stable since Rust 1.31
+    fn one() -> u32 {
+        1
+    }
+}
@@ -1,6 +1,6 @@
 #![warn(clippy::missing_const_for_fn)]
 #![allow(incomplete_features, clippy::let_and_return)]
-#![feature(const_generics)]
+#![feature(const_generics, custom_inner_attributes)]
 
 use std::mem::transmute;
 
@@ -70,5 +70,18 @@ mod with_drop {
     }
 }
 
+mod const_if_match {
+    #![clippy::msrv = "1.46.0"]
+
+    // Could be const since Rust 1.46
+    fn max(a: u32, b: u32) -> u32 {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+}
+
 // Should not be const
 fn main() {}
@@ -64,3 +64,18 @@ impl PubFoo {
 // do not lint this since users cannot control the external code
 #[derive(Debug)]
 pub struct S {}
+
+// do not lint generic functions, they are instantiated in the crate using them
+pub fn pub_generic<T>(t: T) -> T {
+    t
+}
+
+pub fn pub_lifetime<'a>(s: &'a str) -> &'a str {
+    s
+} // missing #[inline]
+
+pub struct PubGeneric<T>(T);
+
+impl<T> PubGeneric<T> {
+    pub fn PubGenericImpl() {} // ok
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::mixed_attributes_style)]
+
+/// Outer doc comment.
+mod foo {
+    //! Inner doc comment.
+}
+
+#[allow(unused)]
+fn bar() {
+    #![allow(clippy::no_effect)]
+}
+
+/// Only outer doc comments.
+mod baz {}
+
+mod qux {
+    //! Only inner doc comments.
+}
+
+fn main() {}
@@ -13,8 +13,21 @@ mod foo {
 
     // Should not warn
     pub struct Foobar;
+    pub fn to_foo() {}
+    pub fn try_from_foo() {}
+    pub struct AsFoo;
+    pub struct FooReexported;
 }
 
+pub use foo::FooReexported;
+
+mod glob {
+    // Should not warn
+    pub struct GlobCake;
+}
+
+pub use glob::*;
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -33,4 +33,13 @@ fn main() {
     -1.6 % -2.1;
     (1.1 + 2.3) % (-1.1 + 2.3);
     (-1.1 - 2.3) % (1.1 - 2.3);
+
+    // No lint when both operands are known to be non-negative
+    let c_f64: f64 = -1.6;
+    let d_u32: u32 = 3;
+    c_f64.abs() % 2.1;
+    (d_u32 as f64) % c_f64.abs();
+
+    // Lint when an operand may still be negative
+    c_f64.abs() % c_f64;
 }
@@ -87,4 +87,16 @@ fn main() {
     a_usize % b_usize;
     let mut a_usize: usize = 1;
     a_usize %= 2;
+
+    // No lint when both operands are known to be non-negative
+    let a_u8: u8 = 17;
+    let a_u32: u32 = 17;
+    let b_i32: i32 = -3;
+    (a_u8 as i32) % 3;
+    (a_u32 as i64) % (a_u8 as i64);
+    b_i32.rem_euclid(10) % 3;
+
+    // Lint when an operand may still be negative
+    (a_u32 as i32) % 3;
+    (a_u8 as i32) % b_i32;
 }
@@ -1,5 +1,8 @@
+use std::cell::Cell;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 
 struct Key(AtomicUsize);
@@ -46,6 +49,16 @@ fn tuples<U>(_m: &mut HashMap<((), U), ()>) {}
 
 fn tuples_bad<U>(_m: &mut HashMap<(Key, U), bool>) {}
 
+struct Wrapper<T>(T, Cell<usize>);
+
+fn generic_wrapper_bad<U>(_m: &mut HashSet<Wrapper<U>>) {}
+
+struct Marker<T>(PhantomData<T>, usize);
+
+fn phantom_data_is_ok(_m: &mut HashSet<Marker<Cell<usize>>>) {}
+
+fn projection_bad<T: Deref<Target = Cell<usize>>>(_m: &mut HashSet<T::Target>) {}
+
 fn main() {
     let _ = should_not_take_this_arg(&mut HashMap::new(), 1);
     this_is_ok(&mut HashMap::new());
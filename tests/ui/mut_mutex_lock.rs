@@ -18,4 +18,28 @@ fn no_owned_mutex_lock() {
     *value += 1;
 }
 
+struct Counter {
+    count: Mutex<u32>,
+    boxed: Box<Mutex<u32>>,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        *self.count.lock().unwrap() += 1;
+        *self.boxed.lock().unwrap() += 1;
+    }
+
+    fn shared_increment(&self) {
+        *self.count.lock().unwrap() += 1;
+    }
+}
+
+fn deref_mut_mutex_lock(mutex: &mut Mutex<u32>, counter: &mut &Counter) {
+    *(*mutex).lock().unwrap() += 1;
+    // only a shared reference to the counter
+    *counter.count.lock().unwrap() += 1;
+    // explicitly referenced
+    *(&*mutex).lock().unwrap() += 1;
+}
+
 fn main() {}
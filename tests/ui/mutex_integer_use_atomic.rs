@@ -0,0 +1,42 @@
+// run-rustfix
+#![warn(clippy::mutex_integer_use_atomic)]
+#![allow(clippy::mutex_atomic)]
+
+use std::sync::Mutex;
+
+fn counter() {
+    let count = Mutex::new(0usize);
+    *count.lock().unwrap() += 2 * 3;
+    *count.lock().unwrap() -= 1;
+    *count.lock().unwrap() = 5;
+    println!("{}", *count.lock().unwrap());
+}
+
+fn flag() {
+    let flag = Mutex::new(false);
+    *flag.lock().unwrap() = true;
+    if *flag.lock().unwrap() {
+        *flag.lock().unwrap() ^= true;
+    }
+}
+
+fn no_lint() {
+    // the guard is kept
+    let count = Mutex::new(0usize);
+    let mut guard = count.lock().unwrap();
+    *guard += 1;
+
+    // there's no atomic for `*=`
+    let count = Mutex::new(1usize);
+    *count.lock().unwrap() *= 2;
+
+    // there's no atomic for `String`
+    let name = Mutex::new(String::new());
+    *name.lock().unwrap() = String::from("name");
+}
+
+fn main() {
+    counter();
+    flag();
+    no_lint();
+}
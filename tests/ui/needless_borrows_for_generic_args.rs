@@ -0,0 +1,80 @@
+// run-rustfix
+
+#![warn(clippy::needless_borrows_for_generic_args)]
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+fn takes_as_ref(_: impl AsRef<str>) {}
+fn takes_display<T: Display>(_: T) {}
+fn takes_path<P: AsRef<Path>>(_: P) {}
+fn takes_into_iter(_: impl IntoIterator) {}
+fn takes_clone<T: Clone>(_: T) {}
+fn takes_unbounded<T>(_: T) {}
+fn takes_two<T: AsRef<str>>(_: T, _: T) {}
+fn returns_same<T: AsRef<str>>(x: T) -> T {
+    x
+}
+
+struct S;
+
+impl S {
+    fn method<T: AsRef<str>>(&self, _: T) {}
+}
+
+fn main() {
+    let s = String::new();
+    takes_as_ref(&s);
+    let x = 5;
+    takes_display(&x);
+    takes_display(x);
+    takes_as_ref(&String::from("temporary"));
+    let s = "str";
+    takes_as_ref(&s);
+    takes_as_ref(s);
+    takes_path(&PathBuf::new());
+    S.method(&String::new());
+
+    // `s` is used later
+    let s = String::new();
+    takes_as_ref(&s);
+    println!("{}", s);
+
+    // `s` is still borrowed
+    let s = String::new();
+    let r = &s;
+    takes_as_ref(&s);
+    println!("{}", r);
+
+    // `s` is used in the next iteration
+    let s = String::new();
+    for _ in 0..2 {
+        takes_as_ref(&s);
+    }
+
+    // `str` is not `Sized`
+    let s = String::new();
+    takes_as_ref(&*s);
+
+    // `&Vec<T>` and `Vec<T>` use different impls
+    let v = vec![1, 2, 3];
+    takes_into_iter(&v);
+
+    // `Clone for &T` doesn't forward to `T`
+    let s = String::new();
+    takes_clone(&s);
+
+    // no bounds
+    let s = String::new();
+    takes_unbounded(&s);
+
+    // the type of the other argument or of the result would change
+    let s = String::new();
+    takes_two(&s, &String::new());
+    let s = String::new();
+    let _ = returns_same(&s);
+
+    // explicit type arguments
+    takes_display::<&i32>(&x);
+}
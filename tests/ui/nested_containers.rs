@@ -0,0 +1,24 @@
+#![warn(clippy::nested_containers)]
+#![allow(dead_code)]
+
+struct Grid {
+    cells: Vec<Vec<u8>>,
+    layers: Vec<Vec<Vec<u8>>>,
+}
+
+fn parse(input: &str) -> Result<Result<u32, std::num::ParseIntError>, String> {
+    Ok(input.parse())
+}
+
+// Only the `Ok` type is counted
+fn nested_err() -> Result<u32, Result<u32, String>> {
+    Ok(1)
+}
+
+fn deep(_: Vec<Vec<Vec<Vec<u8>>>>) {}
+
+fn mixed(_: Vec<Result<Vec<Vec<u8>>, ()>>) {}
+
+fn main() {
+    let _local: Vec<Vec<Vec<u8>>> = Vec::new();
+}
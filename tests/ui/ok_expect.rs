@@ -1,3 +1,5 @@
+// run-rustfix
+
 use std::io;
 
 struct MyError(()); // doesn't implement Debug
@@ -0,0 +1,56 @@
+// aux-build:once_cell.rs
+// aux-build:lazy_static.rs
+
+#![feature(custom_inner_attributes)]
+#![warn(clippy::once_cell_lazy)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate lazy_static;
+extern crate once_cell;
+
+use once_cell::sync::{Lazy, OnceCell};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+static NAMES: Lazy<Vec<String>> = Lazy::new(|| vec!["a".to_string()]);
+static COUNTS: once_cell::sync::Lazy<Mutex<HashMap<u32, u32>>> = once_cell::sync::Lazy::new(Default::default);
+static CONFIG: OnceCell<String> = OnceCell::new();
+
+// the initializer isn't a call of `new`
+#[allow(unused_braces)]
+static OTHER_NAMES: Lazy<Vec<String>> = { Lazy::new(Vec::new) };
+
+lazy_static! {
+    static ref NUMBERS: Vec<u32> = vec![1, 2, 3];
+    pub static ref DEFAULT_NAME: String = "a".to_string();
+}
+
+fn not_statics() {
+    let names: Lazy<Vec<String>> = Lazy::new(Vec::new);
+    let _ = names.len();
+}
+
+mod once_lock_msrv {
+    #![clippy::msrv = "1.70.0"]
+
+    use once_cell::sync::{Lazy, OnceCell};
+
+    static NAMES: Lazy<Vec<String>> = Lazy::new(Vec::new);
+    static CONFIG: OnceCell<String> = OnceCell::new();
+}
+
+mod below_msrv {
+    #![clippy::msrv = "1.69.0"]
+
+    use once_cell::sync::{Lazy, OnceCell};
+
+    static NAMES: Lazy<Vec<String>> = Lazy::new(Vec::new);
+    static CONFIG: OnceCell<String> = OnceCell::new();
+
+    lazy_static! {
+        static ref NUMBERS: Vec<u32> = vec![1, 2, 3];
+    }
+}
+
+fn main() {}
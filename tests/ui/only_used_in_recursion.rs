@@ -0,0 +1,94 @@
+// rustc-env:CLIPPY_UNSTABLE_LINTS=1
+#![warn(clippy::only_used_in_recursion)]
+
+fn simple(a: usize, b: usize) -> usize {
+    if a == 0 {
+        1
+    } else {
+        simple(a - 1, b)
+    }
+}
+
+fn with_calc(a: usize, b: isize) -> usize {
+    if a == 0 {
+        1
+    } else {
+        with_calc(a - 1, -b + 1)
+    }
+}
+
+fn swapped(flag: u32, a: usize, b: usize) -> usize {
+    if flag == 0 {
+        0
+    } else {
+        swapped(flag - 1, b, a)
+    }
+}
+
+fn swapped_used(flag: u32, a: usize, b: usize) -> usize {
+    if flag == 0 {
+        a
+    } else {
+        swapped_used(flag - 1, b, a)
+    }
+}
+
+fn ignored(a: usize, _b: usize) -> usize {
+    if a == 0 {
+        1
+    } else {
+        ignored(a - 1, _b)
+    }
+}
+
+fn pattern((a, b): (usize, usize), c: usize) -> usize {
+    if a == 0 {
+        b
+    } else {
+        pattern((a - 1, b), c)
+    }
+}
+
+struct A;
+
+impl A {
+    fn method(&self, a: usize, b: usize) -> usize {
+        if a == 0 {
+            1
+        } else {
+            self.method(a - 1, b)
+        }
+    }
+
+    fn method2(&self, a: usize) -> usize {
+        if a == 0 {
+            1
+        } else {
+            Self::method2(self, a - 1)
+        }
+    }
+}
+
+trait B {
+    fn method(&self, a: usize, b: usize) -> usize;
+
+    fn default(&self, a: usize, b: usize) -> usize {
+        if a == 0 {
+            1
+        } else {
+            self.default(a - 1, b)
+        }
+    }
+}
+
+impl B for A {
+    fn method(&self, a: usize, b: usize) -> usize {
+        if a == 0 {
+            1
+        } else {
+            self.method(a - 1, b)
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// run-rustfix
+#![warn(clippy::option_as_ref_cloned)]
+
+use std::rc::Rc;
+
+fn main() {
+    let mut opt = Some(String::from("a"));
+    let _: Option<String> = opt.as_ref().cloned();
+    let _: Option<String> = opt.as_ref().map(|x| x.clone());
+    let _: Option<String> = opt.as_ref().map(Clone::clone);
+    let _: Option<&String> = opt.as_mut().map(|x| &*x);
+
+    let num = Some(1);
+    let _: Option<i32> = num.as_ref().map(|x| *x);
+    let _: Option<i32> = num.as_ref().map(|&x| x);
+
+    // no lint, cloning through a reference to the `Option` only clones the reference
+    let opt_ref = &opt;
+    let _: Option<String> = opt_ref.as_ref().cloned();
+
+    // no lint, the clone changes the type through `Deref`
+    let rc = Some(Rc::new(String::new()));
+    let _: Option<String> = rc.as_ref().map(|x| (**x).clone());
+    let _: Option<&str> = opt.as_mut().map(|x| &**x);
+}
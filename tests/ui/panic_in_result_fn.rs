@@ -69,3 +69,25 @@ fn main() -> Result<(), String> {
     todo!("finish main method");
     Ok(())
 }
+
+fn option_with_panic() -> Option<bool> // should emit lint
+{
+    panic!("error");
+}
+
+fn option_without_banned_functions() -> Option<bool> // should not emit lint
+{
+    Some(true)
+}
+
+fn result_with_assert(x: u32) -> Result<bool, String> // should emit lint
+{
+    assert!(x > 0);
+    Ok(true)
+}
+
+fn result_with_assert_eq(x: u32) -> Result<bool, String> // should emit lint
+{
+    assert_eq!(x, 1, "x should be one");
+    Ok(true)
+}
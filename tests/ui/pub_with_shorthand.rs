@@ -0,0 +1,29 @@
+// run-rustfix
+#![warn(clippy::pub_with_shorthand)]
+#![allow(dead_code)]
+
+pub(crate) fn crate_fn() {}
+#[rustfmt::skip]
+pub(in crate) fn in_crate_fn() {}
+
+#[rustfmt::skip]
+pub mod a {
+    pub(super) struct Super {
+        pub(self) field: u8,
+        pub(in self) in_field: u8,
+        pub field_pub: u8,
+    }
+
+    pub(in super) struct InSuper;
+
+    impl Super {
+        pub(crate) fn method() {}
+        pub(in crate) fn in_method() {}
+    }
+
+    pub mod b {
+        pub(in crate::a) fn in_path() {}
+    }
+}
+
+fn main() {}
@@ -23,4 +23,13 @@ fn func_bad4(_: Rc<OsString>) {}
 // does not trigger lint
 fn func_good1(_: Rc<RefCell<String>>) {}
 
-fn main() {}
+fn main() {
+    // triggers lint
+    let _local: Rc<String> = Rc::from(String::new());
+    let _ = Rc::new(String::from("foo"));
+    let _ = Rc::new(vec![1u8]);
+    let _ = Rc::new(PathBuf::new());
+    // does not trigger lint
+    let _ = Rc::new(RefCell::new(String::new()));
+    let _ = Rc::<str>::from(String::from("foo"));
+}
@@ -22,4 +22,13 @@ fn func_bad4(_: Arc<OsString>) {}
 // does not trigger lint
 fn func_good1(_: Arc<Mutex<String>>) {}
 
-fn main() {}
+fn main() {
+    // triggers lint
+    let _local: Arc<String> = Arc::from(String::new());
+    let _ = Arc::new(String::from("foo"));
+    let _ = Arc::new(vec![1u8]);
+    let _ = Arc::new(PathBuf::new());
+    // does not trigger lint
+    let _ = Arc::new(Mutex::new(String::new()));
+    let _ = Arc::<str>::from(String::from("foo"));
+}
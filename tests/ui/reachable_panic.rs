@@ -0,0 +1,86 @@
+#![warn(clippy::reachable_panic)]
+
+pub fn direct(x: u32) -> u32 {
+    assert!(x > 0);
+    x
+}
+
+pub fn indirect(input: &str) -> u32 {
+    digits(input)
+}
+
+fn digits(input: &str) -> u32 {
+    if input.is_empty() {
+        unreachable!();
+    }
+    second_level()
+}
+
+fn second_level() -> u32 {
+    third_level()
+}
+
+fn third_level() -> u32 {
+    fourth_level()
+}
+
+// deeper than the default `reachable-panic-depth`
+fn fourth_level() -> u32 {
+    todo!()
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self) -> u32 {
+        self.check();
+        0
+    }
+
+    fn check(&self) {
+        panic!("invalid state");
+    }
+}
+
+pub fn recursive(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        recursive(n - 1)
+    }
+}
+
+pub fn no_panic() -> u32 {
+    helper()
+}
+
+fn helper() -> u32 {
+    1
+}
+
+// `deep` is 3 calls away through `shortcut`, and 4 through `detour`
+pub fn two_paths() -> u32 {
+    shortcut() + detour()
+}
+
+fn shortcut() -> u32 {
+    shared()
+}
+
+fn detour() -> u32 {
+    detour_again()
+}
+
+fn detour_again() -> u32 {
+    shared()
+}
+
+fn shared() -> u32 {
+    deep()
+}
+
+fn deep() -> u32 {
+    unimplemented!()
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+#![warn(clippy::read_zero_byte_vec)]
+#![allow(clippy::unused_io_amount)]
+
+use std::io::{self, Read};
+
+fn read(mut reader: impl Read) -> io::Result<()> {
+    let mut buf: Vec<u8> = Vec::new();
+    reader.read(&mut buf)?;
+
+    let mut buf: Vec<u8> = vec![];
+    reader.read_exact(&mut buf)?;
+
+    let mut buf: Vec<u8> = Vec::with_capacity(64);
+    let _ = reader.read_exact(&mut buf);
+
+    // no lint, the buffer is resized first
+    let mut buf: Vec<u8> = Vec::with_capacity(64);
+    buf.resize(64, 0);
+    reader.read_exact(&mut buf)?;
+
+    // no lint, `read_to_end` grows the buffer
+    let mut buf: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut buf)?;
+    reader.read(&mut buf)?;
+
+    // no lint, the buffer is used in a closure first
+    let mut buf: Vec<u8> = Vec::new();
+    let mut fill = || buf.push(0);
+    fill();
+    reader.read(&mut buf)?;
+
+    // no lint, not empty
+    let mut buf = vec![0; 64];
+    reader.read(&mut buf)?;
+    Ok(())
+}
+
+fn main() {
+    let _ = read(io::empty());
+}
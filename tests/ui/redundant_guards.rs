@@ -0,0 +1,80 @@
+// run-rustfix
+
+#![warn(clippy::redundant_guards)]
+#![allow(clippy::float_cmp, dead_code)]
+
+enum E {
+    A,
+    B,
+    C,
+}
+
+fn main() {
+    let x = Some(5);
+    match x {
+        Some(y) if y == 2 => println!("two"),
+        Some(y) if 3 == y => println!("three"),
+        Some(y) if y == -1 => println!("minus one"),
+        Some(y) if y == 4 => println!("{}", y),
+        _ => println!("other"),
+    }
+
+    match 'a' {
+        c if c == 'b' => println!("b"),
+        c if matches!(c, 'c' | 'd') => println!("c or d"),
+        _ => println!("other"),
+    }
+
+    match Some(E::A) {
+        Some(e) if matches!(e, E::A) => println!("a"),
+        Some(e) if matches!(e, E::B) => {
+            let _ = || e;
+        },
+        _ => println!("other"),
+    }
+
+    // Don't lint
+
+    // the binding is used, so the or-pattern would have to be nested
+    match 3 {
+        y if matches!(y, 1 | 2) => println!("{}", y),
+        _ => println!("other"),
+    }
+
+    // the or-pattern would have to be nested
+    match Some(3) {
+        Some(y) if matches!(y, 1 | 2) => println!("one or two"),
+        _ => println!("other"),
+    }
+
+    // floats can't be matched exactly
+    match Some(1.0) {
+        Some(f) if f == 1.5 => println!("one and a half"),
+        _ => println!("other"),
+    }
+
+    // the pattern of `matches!` has bindings
+    match Some(Some(3)) {
+        Some(y) if matches!(y, Some(z) if z > 2) => println!("big"),
+        _ => println!("other"),
+    }
+
+    // the binding is by reference
+    match &Some(3) {
+        Some(y) if *y == 2 => println!("two"),
+        _ => println!("other"),
+    }
+
+    // the binding is in an or-pattern
+    match (1, 2) {
+        (y, 3) | (3, y) if y == 1 => println!("one"),
+        _ => println!("other"),
+    }
+
+    // not a literal
+    let z = 2;
+    match Some(3) {
+        Some(y) if y == z => println!("z"),
+        _ => println!("other"),
+    }
+}
@@ -104,4 +104,36 @@ mod m4 {
 
 pub use m4::*;
 
+mod m5 {
+    pub struct Private {
+        pub(crate) field: u32, // private due to m5
+    }
+
+    impl Private {
+        pub(crate) const ZERO: u32 = 0; // private due to m5
+
+        pub(crate) fn new() -> Self {
+            // ^ private due to m5
+            Private { field: Self::ZERO }
+        }
+    }
+
+    pub struct Tuple(pub(crate) u32); // ignored: positional field
+
+    pub struct Leaked {
+        pub(crate) field: u32, // ok: `Leaked` is reachable through `m5_leak`
+    }
+
+    impl Leaked {
+        pub(crate) fn get(&self) -> u32 {
+            // ^ ok: `Leaked` is reachable through `m5_leak`
+            self.field
+        }
+    }
+}
+
+pub fn m5_leak() -> m5::Leaked {
+    m5::Leaked { field: 0 }
+}
+
 fn main() {}
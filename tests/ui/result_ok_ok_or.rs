@@ -0,0 +1,20 @@
+// run-rustfix
+#![warn(clippy::result_ok_ok_or)]
+
+fn parse(s: &str) -> Result<u32, &'static str> {
+    s.parse::<u32>().ok().ok_or("invalid number")
+}
+
+fn main() {
+    let r: Result<u32, &str> = Ok(1);
+    let _ = r.ok().ok_or("failed");
+    let _ = parse("1");
+
+    // the suggestion only evaluates the error on `Err`
+    let r: Result<u32, ()> = Ok(1);
+    let _ = r.ok().ok_or(String::from("failed"));
+
+    // no lint, `ok_or` isn't called on the `Option` of a `Result`
+    let opt = Some(1);
+    let _ = opt.ok_or("failed");
+}
@@ -51,6 +51,22 @@ fn main() {
 
     let x = 42;
     for i in x..10 {
+        println!("{}", i);
+    }
+
+    let n = std::env::args().count();
+    for i in n..0 {
+        println!("{}", i);
+    }
+
+    let mut y = 42;
+    y -= 40;
+    for i in y..10 {
+        // no error, `y` is mutable
+        println!("{}", i);
+    }
+
+    for i in n..10 {
         // no error, not constant-foldable
         println!("{}", i);
     }
@@ -12,4 +12,13 @@ fn main() {
 
     // Should not be linted, see issue #5689
     let _ = (42 + 10..42 + 10).map(|x| x / 2).find(|&x| x == 21);
+
+    let first = 3;
+    let last = 1;
+    let _ = &arr[first..last];
+
+    // no error, the bounds aren't constants, e.g. the gap between the end of a match and the
+    // start of the next one
+    let (end, start) = (1, 3);
+    let _ = &arr[end..start];
 }
@@ -0,0 +1,16 @@
+#![warn(clippy::seq_cst_ordering)]
+
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+fn main() {
+    let count = AtomicUsize::new(0);
+
+    count.store(1, Ordering::SeqCst);
+    let _ = count.load(Ordering::SeqCst);
+    let _ = count.compare_exchange(1, 2, Ordering::SeqCst, Ordering::Relaxed);
+    atomic::fence(Ordering::SeqCst);
+
+    count.store(1, Ordering::Release);
+    let _ = count.load(Ordering::Acquire);
+    atomic::fence(Ordering::AcqRel);
+}
@@ -0,0 +1,69 @@
+#![warn(clippy::significant_drop_in_scrutinee)]
+#![allow(
+    dead_code,
+    clippy::single_match,
+    clippy::match_single_binding,
+    clippy::redundant_closure_call
+)]
+
+use std::sync::{Mutex, RwLock};
+
+fn match_len(mutex: &Mutex<Vec<u32>>) {
+    match mutex.lock().unwrap().len() {
+        0 => mutex.lock().unwrap().push(1),
+        _ => {},
+    }
+}
+
+fn if_let_pop(mutex: &Mutex<Vec<u32>>, lock: &RwLock<Vec<u32>>) {
+    if let Some(value) = mutex.lock().unwrap().pop() {
+        mutex.lock().unwrap().push(value);
+    }
+    if let Some(value) = lock.read().unwrap().first() {
+        println!("{}", value);
+    }
+}
+
+fn while_let_pop(mutex: &Mutex<Vec<u32>>) {
+    while let Some(value) = mutex.lock().unwrap().pop() {
+        println!("{}", value);
+    }
+}
+
+fn deref(mutex: &Mutex<Option<u32>>) {
+    match *mutex.lock().unwrap() {
+        Some(value) => println!("{}", value),
+        None => {},
+    }
+    match &mutex.lock().unwrap() {
+        guard => println!("{:?}", guard),
+    }
+}
+
+fn not_temporaries(mutex: &Mutex<Vec<u32>>) {
+    // the guard is moved into the pattern
+    if let Ok(mut guard) = mutex.lock() {
+        guard.push(1);
+    }
+    match mutex.lock() {
+        Ok(guard) => println!("{:?}", guard),
+        Err(_) => {},
+    }
+
+    // the guard is dropped before the `match`
+    let len = mutex.lock().unwrap().len();
+    match len {
+        0 => mutex.lock().unwrap().push(1),
+        _ => {},
+    }
+
+    // the guard is dropped at the end of the closure
+    match (|| mutex.lock().unwrap().len())() {
+        0 => mutex.lock().unwrap().push(1),
+        _ => {},
+    }
+
+    assert_eq!(mutex.lock().unwrap().len(), 1);
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![warn(clippy::single_range_in_vec_init)]
+
+fn main() {
+    let n = 10;
+    let len: usize = 10;
+    let _ = vec![0..200];
+    let _ = [0..200];
+    let _ = vec![1..n];
+    let _ = vec![0..len];
+    let _ = [0..len];
+
+    // don't lint
+    let _ = vec![0..200, 200..400];
+    let _ = [0..=200];
+    let _ = vec![0.0..1.0];
+    let _ = [..200];
+}
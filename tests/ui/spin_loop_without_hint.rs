@@ -0,0 +1,77 @@
+// run-rustfix
+#![feature(custom_inner_attributes)]
+#![warn(clippy::spin_loop_without_hint)]
+#![allow(dead_code, deprecated)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct Lock {
+    locked: AtomicBool,
+}
+
+impl Lock {
+    fn lock(&self) {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {}
+    }
+}
+
+fn wait(ready: &AtomicBool, count: Arc<AtomicUsize>, limit: usize) {
+    while !ready.load(Ordering::Acquire) {}
+
+    while count.load(Ordering::Relaxed) < limit {}
+
+    loop {
+        if ready.load(Ordering::Acquire) {
+            break;
+        }
+    }
+}
+
+fn dont_lint(ready: &AtomicBool, mut local: bool) {
+    while !ready.load(Ordering::Acquire) {
+        std::hint::spin_loop();
+    }
+
+    while !ready.load(Ordering::Acquire) {
+        std::thread::yield_now();
+    }
+
+    // has side effects
+    while ready.swap(false, Ordering::AcqRel) {}
+
+    while !ready.load(Ordering::Acquire) && !next(&mut local) {}
+
+    loop {
+        if ready.load(Ordering::Acquire) {
+            break;
+        }
+        std::hint::spin_loop();
+    }
+
+    // doesn't poll an atomic
+    while !local {
+        local = next(&mut local);
+    }
+}
+
+fn next(local: &mut bool) -> bool {
+    *local = !*local;
+    *local
+}
+
+mod msrv {
+    #![clippy::msrv = "1.48.0"]
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn wait(ready: &AtomicBool) {
+        while !ready.load(Ordering::Acquire) {}
+    }
+}
+
+fn main() {}
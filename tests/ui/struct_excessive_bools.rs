@@ -26,6 +26,15 @@ struct BadFoo {
     d: bool,
 }
 
+type Flag = bool;
+
+struct AliasedFoo {
+    a: Flag,
+    b: Flag,
+    c: bool,
+    d: bool,
+}
+
 #[repr(C)]
 struct Bar {
     a: bool,
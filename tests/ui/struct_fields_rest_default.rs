@@ -0,0 +1,40 @@
+#![warn(clippy::struct_fields_rest_default)]
+
+#[derive(Default)]
+struct A {
+    i: i32,
+    j: i32,
+    k: i32,
+}
+
+fn main() {
+    // lint
+    let mut a = A {
+        i: 1,
+        ..Default::default()
+    };
+    a.j = 42;
+
+    // lint, all the defaulted fields are reassigned
+    let mut a = A { i: 1, ..Default::default() };
+    a.j = 42;
+    a.k = 43;
+
+    // lint, up to the reassignment of the explicitly initialized field
+    let mut a = A { i: 1, ..Default::default() };
+    a.j = 42;
+    a.i = 2;
+
+    // no lint, the explicitly initialized field is reassigned
+    let mut a = A { i: 1, ..Default::default() };
+    a.i = 2;
+
+    // no lint, the assigned value uses the binding
+    let mut a = A { i: 1, ..Default::default() };
+    a.j = a.i + 1;
+
+    // no lint, the binding is used before the reassignment
+    let mut a = A { i: 1, ..Default::default() };
+    println!("{}", a.i);
+    a.j = 42;
+}
@@ -0,0 +1,43 @@
+#![warn(clippy::thread_spawn_without_join_or_detach_policy)]
+#![allow(dead_code, unused_variables)]
+
+use std::thread::{self, JoinHandle};
+
+fn discarded() {
+    thread::spawn(|| println!("working"));
+
+    let _ = std::thread::spawn(|| println!("working"));
+
+    let unused = thread::spawn(|| println!("working"));
+
+    let _handle = thread::spawn(|| println!("working"));
+}
+
+fn joined() {
+    let handle = thread::spawn(|| println!("working"));
+    handle.join().unwrap();
+
+    thread::spawn(|| println!("working")).join().unwrap();
+}
+
+fn detached() {
+    let handle = thread::spawn(|| println!("working"));
+    drop(handle);
+
+    drop(thread::spawn(|| println!("working")));
+}
+
+fn returned() -> JoinHandle<()> {
+    let handle = thread::spawn(|| println!("working"));
+    println!("spawned");
+    handle
+}
+
+fn kept(handles: &mut Vec<JoinHandle<()>>) {
+    handles.push(thread::spawn(|| println!("working")));
+
+    let handle = thread::spawn(|| println!("working"));
+    handles.push(handle);
+}
+
+fn main() {}
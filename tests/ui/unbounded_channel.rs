@@ -0,0 +1,13 @@
+#![warn(clippy::unbounded_channel)]
+
+use std::sync::mpsc::{self, channel, sync_channel};
+
+fn main() {
+    let (_sender, _receiver) = channel::<u32>();
+    let (_sender, _receiver) = mpsc::channel::<u32>();
+    let (_sender, _receiver): (mpsc::Sender<u32>, _) = std::sync::mpsc::channel();
+
+    // bounded
+    let (_sender, _receiver) = sync_channel::<u32>(10);
+    let (_sender, _receiver) = mpsc::sync_channel::<u32>(0);
+}
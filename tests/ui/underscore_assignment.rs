@@ -0,0 +1,24 @@
+#![feature(destructuring_assignment)]
+#![warn(clippy::underscore_assignment, clippy::let_underscore_lock)]
+
+use std::sync::Mutex;
+
+fn f() -> u32 {
+    0
+}
+
+fn main() {
+    _ = f();
+    let m = Mutex::new(0);
+    _ = m.lock();
+
+    // `let` statements
+    let _ = f();
+    let _guard = m.lock();
+
+    // other destructuring assignments
+    let (mut a, mut b) = (0, 0);
+    (a, b) = (f(), f());
+    (a, _) = (b, a);
+    let _ = (a, b);
+}
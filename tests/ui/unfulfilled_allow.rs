@@ -0,0 +1,46 @@
+#![warn(clippy::unfulfilled_allow)]
+#![allow(clippy::let_and_return)]
+
+#[allow(clippy::needless_return)]
+fn fulfilled() -> u32 {
+    return 42;
+}
+
+#[allow(clippy::needless_return)]
+fn unfulfilled() -> u32 {
+    42
+}
+
+#[allow(dead_code, clippy::needless_return, clippy::needless_bool)]
+fn partially_fulfilled(x: bool) -> bool {
+    if x { true } else { false }
+}
+
+fn statement() -> u32 {
+    #[allow(clippy::needless_return)]
+    let x = 42;
+    x
+}
+
+// lint groups and the lint itself are not checked
+#[allow(clippy::all)]
+fn group() {}
+
+#[allow(clippy::unfulfilled_allow, clippy::needless_return)]
+fn allowed() {}
+
+// emitted by an early pass
+#[allow(clippy::precedence)]
+fn early() -> i32 {
+    1 << 2 + 3
+}
+
+fn main() {
+    fulfilled();
+    unfulfilled();
+    partially_fulfilled(true);
+    statement();
+    group();
+    allowed();
+    early();
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::unnecessary_debug_formatting)]
+#![allow(clippy::print_with_newline)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let name = "config.toml";
+    let owned = String::from("config.toml");
+    let count = 42u32;
+    let path = Path::new("/etc");
+    let path_buf = PathBuf::from("/etc");
+
+    println!("reading {:?}", name);
+    println!("reading {:?}", owned);
+    eprintln!("read {:?} lines", count);
+    let _ = format!("in {:?}", path);
+    print!("in {:?}\n", &path_buf);
+
+    // a `Display` argument next to the `Debug` one
+    println!("reading {} in {:?}", name, path);
+
+    // `Debug` is the only choice
+    let values = vec![1, 2, 3];
+    println!("values: {:?}", values);
+    println!("maybe: {:?}", Some(count));
+
+    // `Display` is used
+    println!("reading {} in {}", name, path.display());
+
+    // not a user-facing macro
+    let _ = std::fmt::Write::write_fmt(&mut String::new(), format_args!("{:?}", name));
+
+    // `{}` doesn't keep the flags
+    println!("reading {:#?}", name);
+    println!("read {:x?} lines", count);
+    println!("reading {} in {:#?}", name, path);
+}
@@ -0,0 +1,14 @@
+// run-rustfix
+#![warn(clippy::unnecessary_self_imports)]
+#![allow(unused_imports, dead_code)]
+
+use std::collections::hash_map::{self, *};
+use std::fs::{self as alias};
+use std::io::{self};
+use std::rc::{self};
+use std::{
+    cell::{self},
+    ptr,
+};
+
+fn main() {}
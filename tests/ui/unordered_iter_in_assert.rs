@@ -0,0 +1,60 @@
+// compile-flags: --test
+#![warn(clippy::unordered_iter_in_assert)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+fn map() -> HashMap<u32, &'static str> {
+    [(1, "a"), (2, "b")].iter().copied().collect()
+}
+
+#[test]
+fn collected_in_assert() {
+    let set: HashSet<u32> = [1, 2].iter().copied().collect();
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert_ne!(vec!["c"], map().values().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn collected_in_local() {
+    let keys: Vec<_> = map().keys().copied().collect();
+    assert_eq!(keys, vec![1, 2]);
+    debug_assert_eq!(keys, vec![1, 2]);
+}
+
+#[test]
+fn sorted() {
+    let mut keys: Vec<_> = map().keys().copied().collect();
+    keys.sort_unstable();
+    assert_eq!(keys, vec![1, 2]);
+
+    let mut values: Vec<_> = map().into_iter().map(|(_, v)| v).collect();
+    sort(&mut values);
+    assert_eq!(values, vec!["a", "b"]);
+}
+
+#[test]
+fn ordered() {
+    let map: BTreeMap<_, _> = map().into_iter().collect();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+    // the `HashSet` is compared as a whole
+    let set: HashSet<_> = map.into_iter().map(|(k, _)| k).collect();
+    assert_eq!(set, [1, 2].iter().copied().collect());
+}
+
+fn sort(values: &mut Vec<&str>) {
+    values.sort_unstable();
+}
+
+#[test]
+fn not_iterating_the_map() {
+    let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    map.insert(1, vec![1, 2]);
+    assert_eq!(map.get(&1).unwrap().iter().cloned().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+// not a test
+pub fn check_keys() {
+    let keys: Vec<_> = map().keys().copied().collect();
+    assert_eq!(keys, vec![1, 2]);
+}
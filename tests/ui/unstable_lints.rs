@@ -0,0 +1,14 @@
+#![warn(clippy::only_used_in_recursion)]
+
+// `only_used_in_recursion` is unstable, it's not run without `CLIPPY_UNSTABLE_LINTS`
+fn f(a: usize, b: usize) -> usize {
+    if a == 0 {
+        1
+    } else {
+        f(a - 1, b)
+    }
+}
+
+fn main() {
+    f(1, 2);
+}
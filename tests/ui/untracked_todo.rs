@@ -0,0 +1,49 @@
+#![warn(clippy::untracked_todo)]
+
+fn bare() {
+    todo!();
+}
+
+fn untracked_message() {
+    todo!("parse the config file");
+}
+
+fn untracked_unimplemented() {
+    unimplemented!("{} is not supported", "windows");
+}
+
+fn unrelated_comment() {
+    // the config file is optional
+    todo!()
+}
+
+fn tracked_message() {
+    todo!("TODO(#42): parse the config file");
+}
+
+fn tracked_unimplemented() {
+    unimplemented!("TODO(#7) {} is not supported", "windows");
+}
+
+fn tracked_comment_above() {
+    // TODO(#42): parse the config file
+    // and merge it with the defaults
+    todo!()
+}
+
+fn tracked_trailing_comment() {
+    todo!() // TODO(#42)
+}
+
+fn comment_separated_by_code() {
+    // TODO(#42): parse the config file
+    let _ = 1;
+    todo!()
+}
+
+fn other_panics() {
+    panic!("not tracked");
+    unreachable!();
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+// run-rustfix
+#![warn(clippy::unused_trait_names)]
+#![allow(dead_code, unused)]
+
+use std::fmt::Write;
+use std::io::Read as _;
+use std::str::FromStr;
+
+fn method_call() {
+    let mut s = String::new();
+    let _ = s.write_str("hello");
+}
+
+fn associated_fn() -> u32 {
+    u32::from_str("1").unwrap()
+}
+
+mod renamed {
+    use std::fmt::Write as FmtWrite;
+
+    fn f(s: &mut String) {
+        let _ = s.write_str("hello");
+    }
+}
+
+mod named {
+    use std::fmt::Write;
+
+    fn f<W: Write>(w: &mut W) {
+        let _ = w.write_str("hello");
+    }
+}
+
+mod named_path {
+    use std::str::FromStr;
+
+    fn f() -> u32 {
+        FromStr::from_str("1").unwrap()
+    }
+}
+
+mod public {
+    pub use std::fmt::Write;
+
+    fn f(s: &mut String) {
+        let _ = s.write_str("hello");
+    }
+}
+
+mod unused {
+    use std::fmt::Write;
+}
+
+fn main() {}
@@ -41,4 +41,27 @@ impl A {
 fn main() {
     A::bad_divisible_by_3("3".to_string());
     A::good_divisible_by_3("3".to_string());
+    let _ = double("3");
+    let _ = first_doubled(&[3]);
+}
+
+#[derive(Debug)]
+struct ParseError;
+
+impl From<std::num::ParseIntError> for ParseError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        ParseError
+    }
+}
+
+// should be detected, the error converts to `ParseError`
+fn double(i_str: &str) -> Result<i32, ParseError> {
+    let i = i_str.parse::<i32>().unwrap();
+    Ok(i * 2)
+}
+
+// should be detected
+fn first_doubled(values: &[i32]) -> Option<i32> {
+    let first = values.first().expect("no values");
+    Some(first * 2)
 }
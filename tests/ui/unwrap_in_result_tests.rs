@@ -0,0 +1,26 @@
+// compile-flags: --test
+#![warn(clippy::unwrap_in_result)]
+
+// should be detected
+pub fn parse(i_str: &str) -> Result<i32, String> {
+    let i = i_str.parse::<i32>().unwrap();
+    Ok(i)
+}
+
+#[test]
+fn test_parse() -> Result<(), String> {
+    assert_eq!(parse("3").unwrap(), 3);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    fn parse_first(values: &[&str]) -> Result<i32, String> {
+        Ok(values[0].parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse_first() {
+        assert_eq!(parse_first(&["1", "2"]), Ok(1));
+    }
+}
@@ -103,6 +103,12 @@ async fn await_desugaring() {
     .await
 }
 
+/// Tests that we don't suggest renaming a used guard to `_`, which would release the lock
+fn used_guard(mutex: &std::sync::Mutex<u32>) {
+    let _guard = mutex.lock().unwrap();
+    let _value = *_guard + 1;
+}
+
 fn main() {
     let foo = 0u32;
     // tests of unused_underscore lint
@@ -116,4 +122,5 @@ fn main() {
     let _ = multiple_underscores(foo);
     non_variables();
     await_desugaring();
+    used_guard(&std::sync::Mutex::new(0));
 }
@@ -0,0 +1,48 @@
+#![warn(clippy::while_float)]
+#![allow(clippy::float_cmp)]
+
+fn main() {
+    let mut x = 0.0_f64;
+    while x < 1.0 {
+        x += 0.1;
+    }
+
+    let mut y = 10.0_f32;
+    while 0.0 < y {
+        y = y - 0.5;
+    }
+
+    let limit = 100.0;
+    let mut z = 0.0;
+    while z != limit {
+        println!("{}", z);
+        z -= 1.0;
+    }
+
+    // integer counters are fine
+    let mut i = 0;
+    while i < 10 {
+        i += 1;
+    }
+
+    // the counter isn't incremented
+    let mut a = 0.0_f64;
+    while a < 1.0 {
+        a = a.sqrt() + 1.0;
+    }
+
+    // the float isn't the counter of the loop
+    let mut b = 0.0_f64;
+    let mut j = 0;
+    while j < 10 {
+        b += 0.1;
+        j += 1;
+    }
+    println!("{}", b);
+
+    // scaling by a factor is not an accumulation
+    let mut c = 1.0_f64;
+    while c < 100.0 {
+        c *= 2.0;
+    }
+}